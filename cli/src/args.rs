@@ -0,0 +1,145 @@
+use std::path::PathBuf;
+
+use unarm::{Extensions, Options, ParseEndian, ParseMode, R9Use, Version};
+
+pub const USAGE: &str = "\
+Usage: unarm [OPTIONS] <FILE>
+
+Disassembles a raw binary or an ELF file.
+
+Options:
+  --arch <arm|thumb>   Instruction set of raw files [default: arm]
+  --version <VERSION>  Architecture version: v4, v4t, v5t, v5te, v5tej, v6, v6k or v7
+                       [default: from ELF attributes, otherwise v7]
+  --base <ADDRESS>     Address of the first byte of raw files [default: 0]
+  --start <ADDRESS>    Address to start disassembling at
+  --end <ADDRESS>      Address to stop disassembling at
+  --big-endian         Read raw files as big-endian
+  --no-ual             Use divided syntax instead of UAL
+  --json               Print one JSON object per instruction instead of text
+  --av                 Write r0-r3 as a1-a4 and r4-r11 as v1-v8
+  --r9 <r9|sb|tr>      Write r9 as sb or tr [default: r9]
+  --sl                 Write r10 as sl
+  --fp                 Write r11 as fp
+  --ip                 Write r12 as ip
+  -h, --help           Print this help";
+
+pub struct Args {
+    pub path: PathBuf,
+    pub mode: ParseMode,
+    pub version: Option<Version>,
+    pub base: u32,
+    pub start: Option<u32>,
+    pub end: Option<u32>,
+    pub endian: ParseEndian,
+    pub ual: bool,
+    pub json: bool,
+    pub av: bool,
+    pub r9_use: R9Use,
+    pub sl: bool,
+    pub fp: bool,
+    pub ip: bool,
+}
+
+impl Args {
+    /// Parses the arguments after the program name. Returns `None` if the help was requested.
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut path = None;
+        let mut result = Self {
+            path: PathBuf::new(),
+            mode: ParseMode::Arm,
+            version: None,
+            base: 0,
+            start: None,
+            end: None,
+            endian: ParseEndian::Little,
+            ual: true,
+            json: false,
+            av: false,
+            r9_use: R9Use::R9,
+            sl: false,
+            fp: false,
+            ip: false,
+        };
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("Expected value after '{arg}'"));
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "--arch" => {
+                    result.mode = match value()?.as_str() {
+                        "arm" => ParseMode::Arm,
+                        "thumb" => ParseMode::Thumb,
+                        arch => return Err(format!("Unknown architecture '{arch}'")),
+                    }
+                }
+                "--version" => result.version = Some(parse_version(&value()?)?),
+                "--base" => result.base = parse_address(&value()?)?,
+                "--start" => result.start = Some(parse_address(&value()?)?),
+                "--end" => result.end = Some(parse_address(&value()?)?),
+                "--big-endian" => result.endian = ParseEndian::Big,
+                "--no-ual" => result.ual = false,
+                "--json" => result.json = true,
+                "--av" => result.av = true,
+                "--r9" => {
+                    result.r9_use = match value()?.as_str() {
+                        "r9" => R9Use::R9,
+                        "sb" => R9Use::Sb,
+                        "tr" => R9Use::Tr,
+                        r9 => return Err(format!("Unknown r9 use '{r9}'")),
+                    }
+                }
+                "--sl" => result.sl = true,
+                "--fp" => result.fp = true,
+                "--ip" => result.ip = true,
+                _ if arg.starts_with('-') => return Err(format!("Unknown argument '{arg}'")),
+                _ if path.is_some() => return Err(format!("Unexpected argument '{arg}'")),
+                _ => path = Some(PathBuf::from(arg)),
+            }
+        }
+
+        result.path = path.ok_or("Expected a file")?;
+        Ok(Some(result))
+    }
+
+    /// Returns the options to disassemble with, using `version` unless another version was given.
+    pub fn options(&self, version: Option<Version>) -> Options {
+        Options {
+            version: self.version.or(version).unwrap_or_default(),
+            extensions: Extensions::all(),
+            av: self.av,
+            r9_use: self.r9_use,
+            sl: self.sl,
+            fp: self.fp,
+            ip: self.ip,
+            ual: self.ual,
+        }
+    }
+
+    /// Returns true if `addr` is within `--start` and `--end`.
+    pub fn contains(&self, addr: u32) -> bool {
+        self.start.is_none_or(|start| addr >= start) && self.end.is_none_or(|end| addr < end)
+    }
+}
+
+fn parse_version(version: &str) -> Result<Version, String> {
+    Ok(match version {
+        "v4" => Version::V4,
+        "v4t" => Version::V4T,
+        "v5t" => Version::V5T,
+        "v5te" => Version::V5Te,
+        "v5tej" => Version::V5Tej,
+        "v6" => Version::V6,
+        "v6k" => Version::V6K,
+        "v7" => Version::V7,
+        _ => return Err(format!("Unknown version '{version}'")),
+    })
+}
+
+fn parse_address(address: &str) -> Result<u32, String> {
+    let result = match address.strip_prefix("0x").or_else(|| address.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => address.parse(),
+    };
+    result.map_err(|_| format!("Invalid address '{address}'"))
+}
@@ -0,0 +1,62 @@
+use serde::Serialize;
+use serde_json::Value;
+use unarm::{DefUseArgument, Ins, Operand, Options, ParseMode};
+
+/// One disassembled instruction, printed as a line of JSON by `--json`
+#[derive(Serialize)]
+pub struct JsonIns<'a> {
+    pub address: u32,
+    pub size: usize,
+    /// Encoded instruction as hex, in file order
+    pub bytes: String,
+    pub mode: &'static str,
+    /// Symbol starting at this address
+    pub symbol: Option<&'a str>,
+    pub mnemonic: &'static str,
+    pub condition: Option<Value>,
+    pub operands: Vec<Operand>,
+    /// Disassembly as printed without `--json`
+    pub text: String,
+    /// The decoded instruction with all of its operands, e.g. `{"Ldr": {"cond": "Al", ...}}`
+    pub ins: Value,
+    pub defs: Vec<DefUseArgument>,
+    pub uses: Vec<DefUseArgument>,
+}
+
+impl<'a> JsonIns<'a> {
+    pub fn new(
+        options: &Options,
+        address: u32,
+        bytes: &[u8],
+        mode: ParseMode,
+        ins: &Ins,
+        text: String,
+    ) -> serde_json::Result<Self> {
+        let ins_value = serde_json::to_value(ins)?;
+        // Every conditional instruction has a `cond` field in its variant
+        let condition = match &ins_value {
+            Value::Object(variant) => variant.values().find_map(|fields| fields.get("cond")),
+            _ => None,
+        }
+        .cloned();
+
+        Ok(Self {
+            address,
+            size: bytes.len(),
+            bytes: bytes.iter().map(|byte| format!("{byte:02x}")).collect(),
+            mode: match mode {
+                ParseMode::Arm => "arm",
+                ParseMode::Thumb => "thumb",
+                ParseMode::Data => "data",
+            },
+            symbol: None,
+            mnemonic: ins.mnemonic(options),
+            condition,
+            operands: ins.operands().collect(),
+            text,
+            ins: ins_value,
+            defs: ins.defs().into_iter().collect(),
+            uses: ins.uses().into_iter().collect(),
+        })
+    }
+}
@@ -0,0 +1,207 @@
+mod args;
+mod json;
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    io::{self, BufWriter, Write},
+    process::ExitCode,
+};
+
+use args::{Args, USAGE};
+use json::JsonIns;
+use unarm::{
+    ElfFile, FormatIns, Ins, ParseEndian, ParseMode, Parser, Relocation, StringFormatter,
+    SymbolFormatter,
+};
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) if is_broken_pipe(error.as_ref()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let data = std::fs::read(&args.path)
+        .map_err(|error| format!("Failed to read '{}': {error}", args.path.display()))?;
+    let mut out = Output { out: BufWriter::new(io::stdout().lock()), json: args.json };
+    if data.starts_with(b"\x7fELF") {
+        disassemble_elf(args, &data, &mut out)?;
+    } else {
+        disassemble_raw(args, &data, &mut out)?;
+    }
+    out.out.flush()?;
+    Ok(())
+}
+
+fn disassemble_elf(args: &Args, data: &[u8], out: &mut Output<impl Write>) -> io::Result<()> {
+    let elf = ElfFile::parse(data).map_err(io::Error::other)?;
+    let options = args.options(elf.version());
+    let endian = elf.endian();
+    let format = match endian {
+        ParseEndian::Little => "elf32-littlearm",
+        ParseEndian::Big => "elf32-bigarm",
+    };
+    out.write_header(args, format)?;
+
+    for section in elf.code_sections() {
+        out.write_section(section.name)?;
+        let symbols = section.symbols().iter().copied().collect::<BTreeMap<_, _>>();
+        let mut parser = section.parser(options.clone());
+        while let Some((addr, ins)) = parser.next() {
+            if !args.contains(addr) {
+                continue;
+            }
+            let offset = addr.wrapping_sub(section.address) as usize;
+            let bytes = &section.data[offset..parser.pc().wrapping_sub(section.address) as usize];
+            let symbol = symbols.get(&addr).copied();
+            let line = Line { addr, bytes, ins, mode: parser.mode(), endian, symbol };
+            let relocation = parser.relocation();
+            let formatter = SymbolFormatter::new(StringFormatter::new(&options), &symbols)
+                .with_literals(section.data, section.address, endian)
+                .with_literal_relocations(section.relocations());
+            out.write_line(line, formatter, relocation)?;
+        }
+    }
+    Ok(())
+}
+
+fn disassemble_raw(args: &Args, data: &[u8], out: &mut Output<impl Write>) -> io::Result<()> {
+    let options = args.options(None);
+    let size = u32::try_from(data.len()).ok();
+    let Some(end) = size.and_then(|size| args.base.checked_add(size)) else {
+        let message = format!("File does not fit in memory at base 0x{:x}", args.base);
+        return Err(io::Error::other(message));
+    };
+    let start = args.start.unwrap_or(args.base).clamp(args.base, end);
+    let end = args.end.unwrap_or(end).clamp(start, end);
+    let bytes = &data[(start - args.base) as usize..(end - args.base) as usize];
+
+    out.write_header(args, "binary")?;
+    out.write_section(".data")?;
+    let symbols = BTreeMap::<u32, &str>::new();
+    let mut parser = Parser::new(bytes, args.mode, args.endian, options.clone());
+    parser.set_pc(start);
+    loop {
+        let addr = parser.pc();
+        let offset = parser.offset();
+        let mode = parser.mode();
+        let Some(ins) = parser.next().filter(|_| parser.offset() <= bytes.len()) else {
+            if offset == bytes.len() || mode == ParseMode::Data {
+                break;
+            }
+            // Print trailing bytes that don't fill a whole instruction as data, like objdump
+            parser.set_mode(ParseMode::Data);
+            parser.goto_offset(offset);
+            continue;
+        };
+        let bytes = &bytes[offset..parser.offset()];
+        let line = Line { addr, bytes, ins, mode, endian: args.endian, symbol: None };
+        let formatter = SymbolFormatter::new(StringFormatter::new(&options), &symbols)
+            .with_literals(data, args.base, args.endian);
+        out.write_line(line, formatter, None)?;
+    }
+    Ok(())
+}
+
+struct Output<W> {
+    out: W,
+    json: bool,
+}
+
+struct Line<'a> {
+    addr: u32,
+    bytes: &'a [u8],
+    ins: Ins,
+    mode: ParseMode,
+    endian: ParseEndian,
+    /// Symbol starting at this address
+    symbol: Option<&'a str>,
+}
+
+impl<W: Write> Output<W> {
+    fn write_header(&mut self, args: &Args, format: &str) -> io::Result<()> {
+        if self.json {
+            return Ok(());
+        }
+        writeln!(self.out, "\n{}:     file format {format}", args.path.display())
+    }
+
+    fn write_section(&mut self, name: &str) -> io::Result<()> {
+        if self.json {
+            return Ok(());
+        }
+        writeln!(self.out, "\nDisassembly of section {name}:")
+    }
+
+    /// Writes a line like `objdump -d`, with the address, the encoded instruction and the
+    /// disassembly. With `--json`, the line is a [`JsonIns`] instead.
+    fn write_line<'a>(
+        &mut self,
+        line: Line,
+        mut formatter: SymbolFormatter<'a, StringFormatter, BTreeMap<u32, &str>>,
+        relocation: Option<Relocation<'a>>,
+    ) -> io::Result<()> {
+        let Line { addr, bytes, ins, mode, endian, symbol } = line;
+        let result = match relocation {
+            Some(relocation) => formatter.write_relocated_ins_at(&ins, addr, mode, relocation),
+            None => formatter.write_ins_at(&ins, addr, mode),
+        };
+        result.map_err(io::Error::other)?;
+        let formatter = formatter.into_inner();
+        let options = formatter.options().clone();
+        let text = formatter.into_string();
+
+        if self.json {
+            let mut json = JsonIns::new(&options, addr, bytes, mode, &ins, text)?;
+            json.symbol = symbol;
+            serde_json::to_writer(&mut self.out, &json)?;
+            return writeln!(self.out);
+        }
+        if let Some(name) = symbol {
+            writeln!(self.out, "\n{addr:08x} <{name}>:")?;
+        }
+        write!(self.out, "{addr:8x}:\t")?;
+        let read_u16 = |bytes: &[u8]| match endian {
+            ParseEndian::Little => u16::from_le_bytes([bytes[0], bytes[1]]),
+            ParseEndian::Big => u16::from_be_bytes([bytes[0], bytes[1]]),
+        };
+        match (mode, bytes.len()) {
+            (ParseMode::Thumb, 4) => {
+                write!(self.out, "{:04x} {:04x}", read_u16(&bytes[..2]), read_u16(&bytes[2..]))?
+            }
+            (_, 4) => {
+                let word = [bytes[0], bytes[1], bytes[2], bytes[3]];
+                let word = match endian {
+                    ParseEndian::Little => u32::from_le_bytes(word),
+                    ParseEndian::Big => u32::from_be_bytes(word),
+                };
+                write!(self.out, "{word:08x}")?
+            }
+            (_, 2) => write!(self.out, "{:04x}", read_u16(bytes))?,
+            _ => write!(self.out, "{:02x}", bytes[0])?,
+        }
+        writeln!(self.out, " \t{text}")
+    }
+}
+
+fn is_broken_pipe(error: &(dyn Error + 'static)) -> bool {
+    error.downcast_ref::<io::Error>().is_some_and(|error| error.kind() == io::ErrorKind::BrokenPipe)
+}
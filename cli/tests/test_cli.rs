@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+
+    fn unarm(args: &[&str]) -> (bool, String) {
+        let output = Command::new(env!("CARGO_BIN_EXE_unarm")).args(args).output().unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        (output.status.success(), stdout)
+    }
+
+    fn elf_path(name: &str) -> String {
+        format!("{}/../disasm/tests/elf/{name}", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn test_elf() {
+        let path = elf_path("test.o");
+        let (success, stdout) = unarm(&[&path]);
+        assert!(success);
+        assert!(stdout.contains("file format elf32-littlearm"));
+        assert!(stdout.contains("\nDisassembly of section .text:\n"));
+        assert!(stdout.contains("\n00000000 <arm_func>:\n"));
+        assert!(stdout.contains("       0:\te59f0004 \tldr r0, [pc, #0x4] ; =0x02001234\n"));
+        assert!(stdout.contains("       c:\t02001234 \t.word 0x2001234\n"));
+        assert!(stdout.contains("      10:\t2001 \tmovs r0, #0x1\n"));
+    }
+
+    #[test]
+    fn test_relocations() {
+        let path = elf_path("reloc.o");
+        let (success, stdout) = unarm(&[&path]);
+        assert!(success);
+        assert!(stdout.contains("       4:\tebfffffe \tbl printf\n"));
+        assert!(stdout.contains("      1c:\tf7ff fffe \tbl printf\n"));
+        assert!(stdout.contains("      20:\t00000004 \t.word .data+0x4\n"));
+        assert!(stdout.contains("      10:\te59f000c \tldr r0, [pc, #0xc] ; =.data\n"));
+    }
+
+    #[test]
+    fn test_json() {
+        let path = elf_path("reloc.o");
+        let (success, stdout) = unarm(&["--json", &path]);
+        assert!(success);
+        let lines = stdout.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[0],
+            r#"{"address":0,"size":4,"bytes":"10402de9","mode":"arm","symbol":"caller","mnemonic":"push","condition":"Al","operands":[{"RegList":["R4","Lr"]}],"text":"push {r4, lr}","ins":{"Push":{"cond":"Al","regs":["R4","Lr"]}},"defs":[{"Reg":"Sp"}],"uses":[{"Reg":"Sp"},{"RegList":["R4","Lr"]}]}"#
+        );
+        assert_eq!(
+            lines[1],
+            r#"{"address":4,"size":4,"bytes":"feffffeb","mode":"arm","symbol":null,"mnemonic":"bl","condition":"Al","operands":[{"BranchTarget":{"addr":4}}],"text":"bl printf","ins":{"Bl":{"cond":"Al","target":{"addr":4}}},"defs":[{"Reg":"Pc"},{"Reg":"Lr"}],"uses":[]}"#
+        );
+        assert!(lines.iter().all(|line| line.starts_with('{')));
+
+        let path = elf_path("test.o");
+        let (success, stdout) = unarm(&["--json", &path]);
+        assert!(success);
+        let movs = stdout.lines().find(|line| line.starts_with(r#"{"address":16,"#)).unwrap();
+        assert!(movs.contains(r#""mnemonic":"movs","#));
+    }
+
+    #[test]
+    fn test_raw() {
+        let path = std::env::temp_dir().join("unarm_test_raw.bin");
+        #[rustfmt::skip]
+        std::fs::write(&path, [
+            0x00, 0x00, 0x9f, 0xe5, // ldr r0, [pc, #0x0]
+            0x1e, 0xff, 0x2f, 0xe1, // bx lr
+            0x00, 0xb5, 0x00, 0xbd, // push {lr}; pop {pc}
+        ])
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let (success, stdout) = unarm(&["--base", "0x02000000", "--end", "0x2000008", path]);
+        assert!(success);
+        assert!(stdout.contains("file format binary"));
+        assert!(stdout.contains(" 2000000:\te59f0000 \tldr r0, [pc, #0x0] ; =0xbd00b500\n"));
+        assert!(stdout.contains(" 2000004:\te12fff1e \tbx lr\n"));
+        assert!(!stdout.contains(" 2000008:"));
+
+        let args = ["--arch", "thumb", "--base", "0x02000000", "--start", "0x2000008", path];
+        let (success, stdout) = unarm(&args);
+        assert!(success);
+        assert!(stdout.contains(" 2000008:\tb500 \tpush {lr}\n"));
+        assert!(stdout.contains(" 200000a:\tbd00 \tpop {pc}\n"));
+        assert!(!stdout.contains(" 2000000:"));
+
+        let (success, stdout) = unarm(&["--no-ual", "--fp", "--base", "0x02000000", path]);
+        assert!(success);
+        assert!(stdout.contains(" 2000004:\te12fff1e \tbx lr\n"));
+
+        // A base where the file would wrap around the address space
+        let (success, stdout) = unarm(&["--base", "0xfffffffe", path]);
+        assert!(!success);
+        assert!(stdout.is_empty());
+    }
+
+    #[test]
+    fn test_raw_trailing_bytes() {
+        let path = std::env::temp_dir().join("unarm_test_raw_trailing_bytes.bin");
+        #[rustfmt::skip]
+        std::fs::write(&path, [
+            0x1e, 0xff, 0x2f, 0xe1, // bx lr
+            0x00, 0xf0, 0x01, // first half of bl; one byte
+        ])
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let (success, stdout) = unarm(&[path]);
+        assert!(success);
+        assert!(stdout.contains("       0:\te12fff1e \tbx lr\n"));
+        assert!(stdout.contains("       4:\tf000 \t.hword 0xf000\n"));
+        assert!(stdout.contains("       6:\t01 \t.byte 0x1\n"));
+
+        let (success, stdout) = unarm(&["--arch", "thumb", "--start", "0x4", path]);
+        assert!(success);
+        assert!(stdout.contains("       4:\tf000 \t.hword 0xf000\n"));
+        assert!(stdout.contains("       6:\t01 \t.byte 0x1\n"));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(!unarm(&["--arch", "mips", "file"]).0);
+        assert!(!unarm(&["--version"]).0);
+        assert!(!unarm(&[]).0);
+        assert!(!unarm(&["/nonexistent/file"]).0);
+        assert!(unarm(&["--help"]).0);
+    }
+}
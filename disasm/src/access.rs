@@ -0,0 +1,330 @@
+use crate::*;
+
+/// A memory access made by an instruction, see [`Ins::memory_access`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MemAccess {
+    pub kind: AccessKind,
+    /// Size of each element in bytes
+    pub size: u8,
+    /// Number of consecutive elements, such as the number of registers in LDM/STM. LDC/STC are
+    /// counted as one word since the coprocessor decides how many words to transfer.
+    pub count: u8,
+    /// If true, loaded elements are sign-extended
+    pub signed: bool,
+    /// If true, this is an exclusive access such as LDREX/STREX
+    pub exclusive: bool,
+    /// If true, the access is performed with user mode permissions like LDRT/STRT, or LDM/STM
+    /// transfers the user mode registers
+    pub user_mode: bool,
+    pub address: AccessAddress,
+}
+
+/// Direction of a memory access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessKind {
+    Load,
+    Store,
+    /// Loads a value and stores another to the same address, like SWP
+    Swap,
+}
+
+/// How a memory access computes its address from a base register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AccessAddress {
+    pub base: Reg,
+    pub offset: AccessOffset,
+    pub indexing: Indexing,
+    /// If true, the base register is updated after the access. Always true for post-indexing.
+    pub writeback: bool,
+}
+
+/// Offset from the base register of an [`AccessAddress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessOffset {
+    Imm(i32),
+    Reg {
+        /// If true, subtract Rm from the base register instead of adding
+        subtract: bool,
+        /// Index register
+        rm: Reg,
+        /// Shift operation to apply to Rm
+        shift_op: ShiftOp,
+        /// Immediate to shift by
+        imm: u32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Indexing {
+    /// The offset is added to the base register before the access
+    Pre,
+    /// The base register is the address, and the offset is added to it afterwards
+    Post,
+    /// Consecutive elements above or below the base register, like LDM/STM. The offset is zero.
+    Block(LdmStmMode),
+}
+
+/// Register state used to compute the addresses of memory accesses, see
+/// [`AccessAddress::compute_address`].
+pub trait RegisterFile {
+    /// Returns the value of `reg`. Reading the PC should give the address of the instruction plus
+    /// 8 in ARM mode or plus 4 in Thumb mode.
+    fn reg(&self, reg: Reg) -> u32;
+
+    /// Returns the carry flag, which is shifted into RRX offsets.
+    fn carry(&self) -> bool;
+}
+
+/// The bytes accessed by a memory access and the new value of its base register, see
+/// [`AccessAddress::compute_address`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AccessRange {
+    /// Lowest address accessed
+    pub start: u32,
+    /// Number of bytes accessed
+    pub size: u32,
+    /// Value written back to the base register, if any
+    pub writeback: Option<u32>,
+}
+
+impl AccessRange {
+    /// Address after the last byte accessed.
+    pub fn end(&self) -> u32 {
+        self.start.wrapping_add(self.size)
+    }
+
+    pub fn contains(&self, addr: u32) -> bool {
+        addr.wrapping_sub(self.start) < self.size
+    }
+}
+
+impl MemAccess {
+    /// Returns the bytes accessed and the written-back base value, given the register values
+    /// before the instruction. LDC/STC are assumed to transfer one word.
+    pub fn compute_address(&self, regs: &impl RegisterFile) -> AccessRange {
+        self.address.compute_address(self.size as u32 * self.count as u32, regs)
+    }
+}
+
+impl AccessAddress {
+    /// Returns the `size` bytes accessed and the written-back base value, given the register
+    /// values before the instruction. A PC base is word-aligned like in Thumb literal loads, and
+    /// IB/DA blocks are stepped by one word like LDM/STM.
+    pub fn compute_address(&self, size: u32, regs: &impl RegisterFile) -> AccessRange {
+        let mut base = regs.reg(self.base);
+        if self.base == Reg::Pc {
+            base &= !3;
+        }
+        let offset = self.offset.compute(regs);
+        let (start, new_base) = match self.indexing {
+            Indexing::Pre => (base.wrapping_add(offset), base.wrapping_add(offset)),
+            Indexing::Post => (base, base.wrapping_add(offset)),
+            Indexing::Block(LdmStmMode::Ia) => (base, base.wrapping_add(size)),
+            Indexing::Block(LdmStmMode::Ib) => (base.wrapping_add(4), base.wrapping_add(size)),
+            Indexing::Block(LdmStmMode::Da) => {
+                (base.wrapping_sub(size).wrapping_add(4), base.wrapping_sub(size))
+            }
+            Indexing::Block(LdmStmMode::Db) => (base.wrapping_sub(size), base.wrapping_sub(size)),
+        };
+        AccessRange { start, size, writeback: self.writeback.then_some(new_base) }
+    }
+}
+
+impl AccessOffset {
+    /// Returns the value to add to the base register, which wraps around for negative offsets.
+    pub fn compute(&self, regs: &impl RegisterFile) -> u32 {
+        match *self {
+            AccessOffset::Imm(imm) => imm as u32,
+            AccessOffset::Reg { subtract, rm, shift_op, imm } => {
+                let value = regs.reg(rm);
+                let value = match (shift_op, imm) {
+                    (ShiftOp::Lsl, _) => shift(value, shift_op, imm),
+                    (ShiftOp::Ror, 0) => ((regs.carry() as u32) << 31) | (value >> 1),
+                    (_, 0) => shift(value, shift_op, 32),
+                    _ => shift(value, shift_op, imm),
+                };
+                if subtract { value.wrapping_neg() } else { value }
+            }
+        }
+    }
+}
+
+impl AddrLdrStr {
+    /// Returns the `size` bytes accessed and the written-back base value, see
+    /// [`AccessAddress::compute_address`].
+    pub fn compute_address(&self, size: u32, regs: &impl RegisterFile) -> AccessRange {
+        AccessAddress::from(*self).compute_address(size, regs)
+    }
+}
+
+impl AddrLdrStrPost {
+    /// Returns the `size` bytes accessed and the written-back base value, see
+    /// [`AccessAddress::compute_address`].
+    pub fn compute_address(&self, size: u32, regs: &impl RegisterFile) -> AccessRange {
+        AccessAddress::from(*self).compute_address(size, regs)
+    }
+}
+
+impl AddrMiscLoad {
+    /// Returns the `size` bytes accessed and the written-back base value, see
+    /// [`AccessAddress::compute_address`].
+    pub fn compute_address(&self, size: u32, regs: &impl RegisterFile) -> AccessRange {
+        AccessAddress::from(*self).compute_address(size, regs)
+    }
+}
+
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+impl AddrLdcStc {
+    /// Returns the `size` bytes accessed and the written-back base value, see
+    /// [`AccessAddress::compute_address`].
+    pub fn compute_address(&self, size: u32, regs: &impl RegisterFile) -> AccessRange {
+        AccessAddress::from(*self).compute_address(size, regs)
+    }
+}
+
+impl LdmStmMode {
+    /// Returns the bytes accessed by an LDM/STM transferring `count` registers from the base
+    /// register `rn`, and the written-back base value.
+    pub fn compute_address(
+        &self,
+        rn: Reg,
+        count: u32,
+        writeback: bool,
+        regs: &impl RegisterFile,
+    ) -> AccessRange {
+        let address = AccessAddress {
+            base: rn,
+            offset: AccessOffset::Imm(0),
+            indexing: Indexing::Block(*self),
+            writeback,
+        };
+        address.compute_address(count * 4, regs)
+    }
+}
+
+impl From<LdrStrOffset> for AccessOffset {
+    fn from(value: LdrStrOffset) -> Self {
+        match value {
+            LdrStrOffset::Imm(imm) => Self::Imm(imm),
+            LdrStrOffset::Reg { subtract, rm, shift_op, imm } => {
+                Self::Reg { subtract, rm, shift_op, imm }
+            }
+        }
+    }
+}
+
+impl From<MiscLoadOffset> for AccessOffset {
+    fn from(value: MiscLoadOffset) -> Self {
+        match value {
+            MiscLoadOffset::Imm(imm) => Self::Imm(imm),
+            MiscLoadOffset::Reg { subtract, rm, imm } => {
+                Self::Reg { subtract, rm, shift_op: ShiftOp::Lsl, imm }
+            }
+        }
+    }
+}
+
+impl From<AddrLdrStr> for AccessAddress {
+    fn from(value: AddrLdrStr) -> Self {
+        match value {
+            AddrLdrStr::Pre { rn, offset, writeback } => {
+                Self { base: rn, offset: offset.into(), indexing: Indexing::Pre, writeback }
+            }
+            AddrLdrStr::Post(post) => post.into(),
+        }
+    }
+}
+
+impl From<AddrLdrStrPost> for AccessAddress {
+    fn from(value: AddrLdrStrPost) -> Self {
+        let AddrLdrStrPost { rn, offset } = value;
+        Self {
+            base: rn,
+            offset: offset.into(),
+            indexing: Indexing::Post,
+            writeback: true,
+        }
+    }
+}
+
+impl From<AddrMiscLoad> for AccessAddress {
+    fn from(value: AddrMiscLoad) -> Self {
+        match value {
+            AddrMiscLoad::Pre { rn, offset, writeback } => {
+                Self { base: rn, offset: offset.into(), indexing: Indexing::Pre, writeback }
+            }
+            AddrMiscLoad::Post { rn, offset } => Self {
+                base: rn,
+                offset: offset.into(),
+                indexing: Indexing::Post,
+                writeback: true,
+            },
+        }
+    }
+}
+
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+impl From<AddrLdcStc> for AccessAddress {
+    fn from(value: AddrLdcStc) -> Self {
+        let (base, offset, indexing, writeback) = match value {
+            AddrLdcStc::Pre { rn, offset, writeback } => (rn, offset, Indexing::Pre, writeback),
+            AddrLdcStc::Post { rn, offset } => (rn, offset, Indexing::Post, true),
+            AddrLdcStc::Unidx { rn, .. } => (rn, 0, Indexing::Pre, false),
+        };
+        Self { base, offset: AccessOffset::Imm(offset), indexing, writeback }
+    }
+}
+
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl AccessAddress {
+    /// Converts a VLD/VST address, where writeback adds `transfer_size` bytes.
+    pub(crate) fn neon(addr: NeonAddr, transfer_size: u32) -> Self {
+        let NeonAddr { rn: base, post, .. } = addr;
+        let (offset, indexing, writeback) = match post {
+            NeonPost::None => (AccessOffset::Imm(0), Indexing::Pre, false),
+            NeonPost::Writeback => (AccessOffset::Imm(transfer_size as i32), Indexing::Post, true),
+            NeonPost::Reg(rm) => {
+                let offset =
+                    AccessOffset::Reg { subtract: false, rm, shift_op: ShiftOp::Lsl, imm: 0 };
+                (offset, Indexing::Post, true)
+            }
+        };
+        Self { base, offset, indexing, writeback }
+    }
+}
+
+#[cfg(any(
+    all(
+        feature = "arm",
+        any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+    ),
+    all(
+        feature = "thumb",
+        any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+        feature = "v7"
+    )
+))]
+impl From<VldmVstmMode> for LdmStmMode {
+    fn from(value: VldmVstmMode) -> Self {
+        match value {
+            VldmVstmMode::Ia => Self::Ia,
+            VldmVstmMode::Db => Self::Db,
+        }
+    }
+}
+
+#[cfg(any(
+    all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+    all(feature = "thumb", feature = "v7")
+))]
+impl From<SrsRfeMode> for LdmStmMode {
+    fn from(value: SrsRfeMode) -> Self {
+        match value {
+            SrsRfeMode::Da => Self::Da,
+            SrsRfeMode::Ia => Self::Ia,
+            SrsRfeMode::Db => Self::Db,
+            SrsRfeMode::Ib => Self::Ib,
+        }
+    }
+}
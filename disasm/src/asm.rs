@@ -0,0 +1,255 @@
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use crate::{FormatIns, Ins, Options, ParseMode, StringFormatter, parse_asm_ins};
+
+/// Error returned by [`parse_asm`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AsmError {
+    /// No instruction has the given mnemonic with the given options
+    UnknownMnemonic,
+    /// The mnemonic is known, but the operands are invalid for it
+    InvalidOperands,
+}
+
+impl core::fmt::Display for AsmError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AsmError::UnknownMnemonic => f.write_str("unknown mnemonic"),
+            AsmError::InvalidOperands => f.write_str("invalid operands"),
+        }
+    }
+}
+
+impl core::error::Error for AsmError {}
+
+/// Parses one instruction from assembly text. Accepts the syntax that [`Ins::display`] prints with
+/// the same `options`, meaning UAL or divided syntax depending on `options.ual`, and register
+/// aliases depending on `av`, `r9_use`, `sl`, `fp` and `ip`. Letter case, whitespace and number
+/// formatting (hexadecimal or decimal) may differ from the printed text.
+///
+/// The parser prefers an instruction which can be encoded at `pc` in `mode`, since ARM and Thumb
+/// instructions can display identically and some syntaxes leave out operands, such as the second
+/// register of `ldrd` in divided syntax. [`ParseMode::Data`] only accepts the `.word`, `.hword`
+/// and `.byte` directives. Branch targets are written as absolute addresses, just like
+/// [`parse_arm`](crate::parse_arm) and [`parse_thumb`](crate::parse_thumb) store them.
+pub fn parse_asm(text: &str, pc: u32, mode: ParseMode, options: &Options) -> Result<Ins, AsmError> {
+    let text = text.trim();
+    if let Some(ins) = parse_data(text) {
+        return ins;
+    }
+    if mode == ParseMode::Data {
+        return Err(AsmError::UnknownMnemonic);
+    }
+    parse_asm_ins(text, pc, mode, options)
+}
+
+impl FromStr for Ins {
+    type Err = AsmError;
+
+    /// Parses one ARM instruction with the default [`Options`], see [`parse_asm`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[cfg(feature = "arm")]
+        let mode = ParseMode::Arm;
+        #[cfg(not(feature = "arm"))]
+        let mode = ParseMode::Thumb;
+        parse_asm(s, 0, mode, &Options::default())
+    }
+}
+
+fn parse_data(text: &str) -> Option<Result<Ins, AsmError>> {
+    let (directive, value) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let value = match asm_uimm(value) {
+        Some((value, rest)) if rest.trim_start().is_empty() => Some(value),
+        _ => None,
+    };
+    let ins = if directive.eq_ignore_ascii_case(".word") {
+        value.map(Ins::Word)
+    } else if directive.eq_ignore_ascii_case(".hword") {
+        value.and_then(|v| v.try_into().ok()).map(Ins::HalfWord)
+    } else if directive.eq_ignore_ascii_case(".byte") {
+        value.and_then(|v| v.try_into().ok()).map(Ins::Byte)
+    } else {
+        return None;
+    };
+    Some(ins.ok_or(AsmError::InvalidOperands))
+}
+
+/// Returns true if `text` is the same instruction as `ins` displayed with `options`.
+pub(crate) fn asm_matches(text: &str, ins: &Ins, options: &Options) -> bool {
+    let mut formatter = StringFormatter::new(options);
+    if formatter.write_ins(ins).is_err() {
+        return false;
+    }
+    let display = formatter.into_string();
+    AsmTokens(text).eq(AsmTokens(&display))
+}
+
+/// Picks the instruction to parse among those which display as the same text. Prefers the
+/// smallest encoding at `pc` in `mode`, such as a 16-bit Thumb instruction over a 32-bit one, and
+/// otherwise the first instruction offered.
+pub(crate) struct AsmCandidates<'a> {
+    pc: u32,
+    mode: ParseMode,
+    options: &'a Options,
+    best: Option<(u32, Ins)>,
+    fallback: Option<Ins>,
+}
+
+impl<'a> AsmCandidates<'a> {
+    pub(crate) fn new(pc: u32, mode: ParseMode, options: &'a Options) -> Self {
+        Self { pc, mode, options, best: None, fallback: None }
+    }
+
+    /// Offers `ins` as a candidate, and returns true if no other instruction can be better.
+    pub(crate) fn offer(&mut self, ins: Ins) -> bool {
+        let (size, min_size) = match self.mode {
+            #[cfg(feature = "arm")]
+            ParseMode::Arm => (ins.encode_arm(self.pc, self.options).ok().map(|_| 4), 4),
+            #[cfg(feature = "thumb")]
+            ParseMode::Thumb => {
+                (ins.encode_thumb(self.pc, self.options).ok().map(|(_, size)| size), 2)
+            }
+            ParseMode::Data => (None, 0),
+        };
+        match size {
+            Some(size) => {
+                if self.best.as_ref().is_none_or(|(best_size, _)| size < *best_size) {
+                    self.best = Some((size, ins));
+                }
+                size <= min_size
+            }
+            None => {
+                self.fallback.get_or_insert(ins);
+                false
+            }
+        }
+    }
+
+    pub(crate) fn into_best(self) -> Option<Ins> {
+        self.best.map(|(_, ins)| ins).or(self.fallback)
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '.'
+}
+
+/// Matches `text` at the start of `s`, ignoring case. Whitespace is allowed around punctuation.
+pub(crate) fn asm_text<'a>(s: &'a str, text: &str) -> Option<&'a str> {
+    let s = if text.starts_with(is_word_char) {
+        s
+    } else {
+        s.trim_start()
+    };
+    let (head, rest) = s.split_at_checked(text.len())?;
+    if !head.eq_ignore_ascii_case(text) {
+        return None;
+    }
+    if text.ends_with(is_word_char) {
+        Some(rest)
+    } else {
+        Some(rest.trim_start())
+    }
+}
+
+pub(crate) fn asm_space(s: &str) -> &str {
+    s.trim_start()
+}
+
+pub(crate) fn asm_separator(s: &str) -> Option<&str> {
+    s.trim_start().strip_prefix(',').map(str::trim_start)
+}
+
+/// Returns true if the mnemonic ends at the start of `s`.
+pub(crate) fn asm_mnemonic_end(s: &str) -> bool {
+    s.is_empty() || s.starts_with(char::is_whitespace)
+}
+
+/// Parses an unsigned hexadecimal (with a `0x` prefix) or decimal number.
+pub(crate) fn asm_uimm(s: &str) -> Option<(u32, &str)> {
+    let s = s.trim_start();
+    let (digits, radix) = match s.get(..2) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("0x") => (&s[2..], 16),
+        _ => (s, 10),
+    };
+    let end = digits.find(|c: char| !c.is_digit(radix)).unwrap_or(digits.len());
+    let value = u32::from_str_radix(&digits[..end], radix).ok()?;
+    Some((value, &digits[end..]))
+}
+
+/// Parses a signed number, see [`asm_uimm`].
+pub(crate) fn asm_simm(s: &str) -> Option<(i32, &str)> {
+    let s = s.trim_start();
+    if let Some(s) = s.strip_prefix('-') {
+        let (value, rest) = asm_uimm(s)?;
+        let value = 0i32.checked_sub_unsigned(value)?;
+        Some((value, rest))
+    } else {
+        let (value, rest) = asm_uimm(s)?;
+        Some((value.try_into().ok()?, rest))
+    }
+}
+
+/// Assigns `value` to `field` unless it already has a different value.
+pub(crate) fn asm_assign<T: PartialEq>(field: &mut Option<T>, value: T) -> bool {
+    match field {
+        Some(field) => *field == value,
+        None => {
+            *field = Some(value);
+            true
+        }
+    }
+}
+
+/// Returns the parsed value of a field, or the values to try if it was not parsed.
+pub(crate) fn asm_field<T: Copy>(field: Option<T>, missing: &[T]) -> Vec<T> {
+    match field {
+        Some(value) => alloc::vec![value],
+        None => missing.to_vec(),
+    }
+}
+
+/// Splits assembly text into tokens for comparison, such that case, whitespace and number
+/// formatting are ignored.
+struct AsmTokens<'a>(&'a str);
+
+#[derive(Debug)]
+enum AsmToken<'a> {
+    Word(&'a str),
+    Number(u32),
+    Punct(char),
+}
+
+impl PartialEq for AsmToken<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AsmToken::Word(a), AsmToken::Word(b)) => a.eq_ignore_ascii_case(b),
+            (AsmToken::Number(a), AsmToken::Number(b)) => a == b,
+            (AsmToken::Punct(a), AsmToken::Punct(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Iterator for AsmTokens<'a> {
+    type Item = AsmToken<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.0.trim_start();
+        let c = s.chars().next()?;
+        if !is_word_char(c) {
+            self.0 = &s[c.len_utf8()..];
+            return Some(AsmToken::Punct(c));
+        }
+        let end = s.find(|c| !is_word_char(c)).unwrap_or(s.len());
+        let (word, rest) = s.split_at(end);
+        self.0 = rest;
+        if c.is_ascii_digit()
+            && let Some((value, "")) = asm_uimm(word)
+        {
+            return Some(AsmToken::Number(value));
+        }
+        Some(AsmToken::Word(word))
+    }
+}
@@ -0,0 +1,344 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec,
+    vec::Vec,
+};
+
+use crate::*;
+
+/// Control-flow graph of the code reachable from one or more entry points, made of basic blocks
+/// connected by edges. Branch and call targets are followed, switching between ARM and Thumb where
+/// the target is known to be in the other instruction set.
+pub struct Cfg<'a> {
+    bytes: &'a [u8],
+    base: u32,
+    parser: Parser<'a>,
+    blocks: BTreeMap<u32, BasicBlock>,
+    edges: BTreeMap<u32, Vec<Edge>>,
+    unresolved: Vec<u32>,
+    /// Functions which never return to their caller
+    noreturn: BTreeSet<u32>,
+    /// IT block state before each decoded instruction inside an IT block
+    #[cfg(feature = "v7")]
+    it_states: BTreeMap<u32, ItState>,
+}
+
+/// A sequence of instructions which can only be entered at the first instruction and only exits
+/// after the last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasicBlock {
+    mode: ParseMode,
+    instructions: Vec<(u32, Ins)>,
+    end: u32,
+}
+
+/// A possible transfer of control from the last instruction of a basic block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge {
+    /// Address of the instruction transferring control
+    pub from: u32,
+    /// Destination address
+    pub to: u32,
+    /// Instruction set at the destination
+    pub mode: ParseMode,
+    pub kind: EdgeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// Execution continues with the next instruction
+    Fallthrough,
+    /// Branch which is always taken
+    Branch,
+    /// Branch which is taken if a condition passes
+    ConditionalBranch,
+    /// Subroutine call
+    Call,
+}
+
+impl<'a> Cfg<'a> {
+    /// Creates an empty graph of `bytes` loaded at the address `base`. Use [`Self::add_entry`] to
+    /// discover code.
+    pub fn new(bytes: &'a [u8], base: u32, endian: ParseEndian, options: Options) -> Self {
+        let mut parser = Parser::new(bytes, ParseMode::Arm, endian, options);
+        parser.set_pc(base);
+        Self {
+            bytes,
+            base,
+            parser,
+            blocks: BTreeMap::new(),
+            edges: BTreeMap::new(),
+            unresolved: Vec::new(),
+            noreturn: BTreeSet::new(),
+            #[cfg(feature = "v7")]
+            it_states: BTreeMap::new(),
+        }
+    }
+
+    /// Builds the graph of all code reachable from `start`, which is also the address of the first
+    /// byte.
+    pub fn build(
+        bytes: &'a [u8],
+        start: u32,
+        mode: ParseMode,
+        endian: ParseEndian,
+        options: Options,
+    ) -> Self {
+        let mut cfg = Self::new(bytes, start, endian, options);
+        cfg.add_entry(start, mode);
+        cfg
+    }
+
+    /// Adds all code reachable from `addr` to the graph. Existing blocks are split if `addr` or any
+    /// new branch target is in the middle of them.
+    pub fn add_entry(&mut self, addr: u32, mode: ParseMode) {
+        let mut queue = vec![(addr, mode)];
+        while let Some((addr, mode)) = queue.pop() {
+            if !self.is_code_addr(addr, mode) || self.blocks.contains_key(&addr) {
+                continue;
+            }
+            if self.split_block(addr, mode) {
+                continue;
+            }
+            let block = self.decode_block(addr, mode, &mut queue);
+            self.blocks.insert(addr, block);
+        }
+    }
+
+    /// Marks the function at `addr` as never returning, like `abort` or `exit`. Calls to it found
+    /// after this have no fallthrough edge, since the bytes after them are often a literal pool.
+    pub fn add_noreturn(&mut self, addr: u32) {
+        self.noreturn.insert(addr);
+    }
+
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns all basic blocks in order of address.
+    pub fn blocks(&self) -> impl Iterator<Item = &BasicBlock> {
+        self.blocks.values()
+    }
+
+    /// Returns the basic block starting at `addr`.
+    pub fn block(&self, addr: u32) -> Option<&BasicBlock> {
+        self.blocks.get(&addr)
+    }
+
+    /// Returns the basic block which contains `addr`.
+    pub fn block_containing(&self, addr: u32) -> Option<&BasicBlock> {
+        self.blocks.range(..=addr).next_back().map(|(_, block)| block).filter(|b| addr < b.end)
+    }
+
+    /// Returns all edges in order of the address they come from.
+    pub fn edges(&self) -> impl Iterator<Item = &Edge> {
+        self.edges.values().flatten()
+    }
+
+    /// Returns the edges leaving `block`.
+    pub fn successors(&self, block: &BasicBlock) -> &[Edge] {
+        self.edges.get(&block.last_addr()).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns the edges entering the block starting at `addr`.
+    pub fn predecessors(&self, addr: u32) -> impl Iterator<Item = &Edge> {
+        self.edges().filter(move |edge| edge.to == addr)
+    }
+
+    /// Returns the known register values before each instruction of the block starting at `addr`,
+    /// assuming all registers are unknown at the start of the block. See [`RegValues::track`].
+    pub fn reg_values(&mut self, addr: u32) -> Option<Vec<RegValues>> {
+        let block = self.blocks.get(&addr)?;
+        self.parser.set_mode(block.mode);
+        Some(RegValues::new().track(&block.instructions, &self.parser))
+    }
+
+    /// Returns the addresses of indirect jumps and calls whose targets could not be resolved.
+    pub fn unresolved(&self) -> &[u32] {
+        &self.unresolved
+    }
+
+    fn is_code_addr(&self, addr: u32, mode: ParseMode) -> bool {
+        let size = match mode {
+            ParseMode::Arm => 4,
+            ParseMode::Thumb => 2,
+            ParseMode::Data => return false,
+        };
+        let offset = addr.wrapping_sub(self.base) as usize;
+        addr.is_multiple_of(size)
+            && offset.checked_add(size as usize).is_some_and(|end| end <= self.bytes.len())
+    }
+
+    fn split_block(&mut self, addr: u32, mode: ParseMode) -> bool {
+        let Some((_, block)) = self.blocks.range_mut(..addr).next_back() else {
+            return false;
+        };
+        if addr >= block.end || block.mode != mode {
+            return false;
+        }
+        // Jumping between instructions is treated as a separate, overlapping block
+        let Ok(index) = block.instructions.binary_search_by_key(&addr, |(addr, _)| *addr) else {
+            return false;
+        };
+
+        let instructions = block.instructions.split_off(index);
+        let end = core::mem::replace(&mut block.end, addr);
+        let from = block.last_addr();
+        self.add_edge(Edge { from, to: addr, mode, kind: EdgeKind::Fallthrough });
+        self.blocks.insert(addr, BasicBlock { mode, instructions, end });
+        true
+    }
+
+    fn decode_block(
+        &mut self,
+        start: u32,
+        mode: ParseMode,
+        queue: &mut Vec<(u32, ParseMode)>,
+    ) -> BasicBlock {
+        self.parser.set_mode(mode);
+        self.parser.goto_offset(start.wrapping_sub(self.base) as usize);
+        // Blocks starting inside an IT block continue it
+        #[cfg(feature = "v7")]
+        self.parser.set_it_state(self.it_states.get(&start).copied().unwrap_or(ItState::none()));
+
+        let mut instructions = Vec::new();
+        let mut values = RegValues::new();
+        loop {
+            let addr = self.parser.pc();
+            #[cfg(feature = "v7")]
+            let it_state = self.parser.it_state();
+            if !instructions.is_empty() && self.blocks.contains_key(&addr) {
+                let (from, _) = instructions[instructions.len() - 1];
+                self.add_edge(Edge { from, to: addr, mode, kind: EdgeKind::Fallthrough });
+                // A block inside this IT block which was decoded before the IT instruction lacks
+                // its conditions, so it's decoded again
+                #[cfg(feature = "v7")]
+                if it_state.in_block() && !self.it_states.contains_key(&addr) {
+                    let block = self.blocks.remove(&addr).unwrap();
+                    self.edges.remove(&block.last_addr());
+                    self.it_states.insert(addr, it_state);
+                    queue.push((addr, mode));
+                }
+                break;
+            }
+            #[cfg(feature = "v7")]
+            if it_state.in_block() {
+                self.it_states.insert(addr, it_state);
+            }
+            let Some(ins) = self.parser.next() else {
+                break;
+            };
+            if ins == Ins::Illegal {
+                instructions.push((addr, ins));
+                break;
+            }
+
+            let next = self.parser.pc();
+            let mut edges = Vec::new();
+            let flow = ins.flow();
+            let mut falls_through = flow.falls_through();
+            match flow {
+                FlowKind::Fallthrough => {}
+                FlowKind::Branch { target } => {
+                    edges.push(Edge { from: addr, to: target, mode, kind: EdgeKind::Branch });
+                }
+                FlowKind::ConditionalBranch { target } => {
+                    let kind = EdgeKind::ConditionalBranch;
+                    edges.push(Edge { from: addr, to: target, mode, kind });
+                }
+                FlowKind::Call { target: Some(target), exchange } => {
+                    let mode = if exchange { exchange_mode(mode) } else { mode };
+                    edges.push(Edge { from: addr, to: target, mode, kind: EdgeKind::Call });
+                    falls_through &= !self.noreturn.contains(&target);
+                }
+                FlowKind::Call { target: None, .. } | FlowKind::IndirectJump { .. } => {
+                    match values.branch_target(&ins) {
+                        Some(target) => {
+                            let kind = match flow {
+                                FlowKind::Call { .. } => EdgeKind::Call,
+                                FlowKind::IndirectJump { conditional: true } => {
+                                    EdgeKind::ConditionalBranch
+                                }
+                                _ => EdgeKind::Branch,
+                            };
+                            let to = target & !1;
+                            edges.push(Edge { from: addr, to, mode: target_mode(target), kind });
+                            if kind == EdgeKind::Call {
+                                falls_through &= !self.noreturn.contains(&to);
+                            }
+                        }
+                        None => self.unresolved.push(addr),
+                    }
+                }
+                FlowKind::Return { .. } | FlowKind::Exception { .. } => {}
+            }
+            if flow == FlowKind::Fallthrough {
+                values.update(&ins, addr, &self.parser);
+                instructions.push((addr, ins));
+                continue;
+            }
+            instructions.push((addr, ins));
+
+            if falls_through {
+                edges.push(Edge { from: addr, to: next, mode, kind: EdgeKind::Fallthrough });
+            }
+            for edge in edges {
+                queue.push((edge.to, edge.mode));
+                self.add_edge(edge);
+            }
+            break;
+        }
+
+        BasicBlock { mode, instructions, end: self.parser.pc() }
+    }
+
+    fn add_edge(&mut self, edge: Edge) {
+        self.edges.entry(edge.from).or_default().push(edge);
+    }
+}
+
+impl BasicBlock {
+    /// Address of the first instruction.
+    pub fn start(&self) -> u32 {
+        self.instructions[0].0
+    }
+
+    /// Address after the last instruction.
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    pub fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Returns the instructions in this block along with their addresses.
+    pub fn instructions(&self) -> &[(u32, Ins)] {
+        &self.instructions
+    }
+
+    /// Address of the last instruction.
+    pub fn last_addr(&self) -> u32 {
+        self.instructions[self.instructions.len() - 1].0
+    }
+}
+
+fn exchange_mode(mode: ParseMode) -> ParseMode {
+    match mode {
+        ParseMode::Arm => ParseMode::Thumb,
+        ParseMode::Thumb => ParseMode::Arm,
+        ParseMode::Data => ParseMode::Data,
+    }
+}
+
+fn target_mode(target: u32) -> ParseMode {
+    if target & 1 != 0 {
+        ParseMode::Thumb
+    } else {
+        ParseMode::Arm
+    }
+}
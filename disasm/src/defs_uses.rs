@@ -1,47 +1,166 @@
 use crate::{
-    CoReg, Dreg, DregIndex, DregList, Fpscr, Reg, RegList, Sreg, SregList, StatusFields, StatusReg,
+    CoReg, Cond, MAX_DEFS_USES, Op2, Op2Shift, Reg, RegList, ShiftImm, ShiftOp, StatusFields,
+    StatusReg,
 };
+#[cfg(all(
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+))]
+use crate::Dreg;
+#[cfg(all(
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+))]
+use crate::{DregIndex, DregList, Fpscr, Sreg, SregList};
+#[cfg(all(feature = "neon", feature = "v7"))]
+use crate::{NeonReg, NeonRegList, Qreg};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DefUseArgument {
     Reg(Reg),
+    #[cfg(all(
+        any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+    ))]
     Sreg(Sreg),
+    #[cfg(all(
+        any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"),
+        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+    ))]
     Dreg(Dreg),
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    Qreg(Qreg),
     RegList(RegList),
+    #[cfg(all(
+        any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+    ))]
     SregList(SregList),
+    #[cfg(all(
+        any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+    ))]
     DregList(DregList),
+    #[cfg(all(
+        any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+    ))]
     DregIndex(DregIndex),
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    NeonRegList(NeonRegList),
     CoReg(CoReg),
     StatusReg(StatusReg),
     StatusFields(StatusFields),
+    #[cfg(all(
+        any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+    ))]
     Fpscr(Fpscr),
+    Flag(Flag),
+}
+
+/// Status flag in the CPSR, which instructions define or use implicitly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Flag {
+    /// Negative
+    N,
+    /// Zero
+    Z,
+    /// Carry. Logical instructions with an S suffix only define it if the shifter produces a
+    /// carry-out, and also use it if shifting by a register which may be zero.
+    C,
+    /// Overflow
+    V,
+    /// Sticky saturation/overflow
+    Q,
+    /// Greater than or equal, the four SIMD flags set by parallel add/subtract instructions
+    Ge,
+}
+
+impl Op2 {
+    /// Returns whether the shifter produces a carry-out, which is the case for rotated immediates
+    /// and every shift except `lsl #0`.
+    pub(crate) fn defines_carry(&self) -> bool {
+        match *self {
+            Op2::Imm(imm) => {
+                // Thumb-2 also has replicated byte patterns which keep the carry unchanged
+                let (low, high) = (imm & 0xff, (imm >> 8) & 0xff);
+                imm > 0xff && ![low * 0x00010001, high * 0x01000100, low * 0x01010101].contains(&imm)
+            }
+            Op2::ShiftReg(_) => true,
+            Op2::ShiftImm(ShiftImm { shift_op, imm, .. }) => shift_op != ShiftOp::Lsl || imm != 0,
+        }
+    }
+
+    /// Returns whether the carry is unchanged if the shift amount is zero, which can only be known
+    /// at runtime when shifting by a register.
+    pub(crate) fn uses_carry(&self) -> bool {
+        matches!(self, Op2::ShiftReg(_))
+    }
 }
 
-const MAX_ARGS: usize = 4;
+impl Op2Shift {
+    /// Returns whether the shift produces a carry-out, which is the case for all but `lsl #0`.
+    pub(crate) fn defines_carry(&self) -> bool {
+        *self != Op2Shift::Imm(0)
+    }
+
+    /// Returns whether the carry is unchanged if the shift amount is zero, which can only be known
+    /// at runtime when shifting by a register.
+    pub(crate) fn uses_carry(&self) -> bool {
+        matches!(self, Op2Shift::Reg(_))
+    }
+}
+
+impl Cond {
+    /// Returns the flags which this condition tests.
+    pub fn flags(self) -> &'static [Flag] {
+        match self {
+            Cond::Eq | Cond::Ne => &[Flag::Z],
+            Cond::Hs | Cond::Lo => &[Flag::C],
+            Cond::Mi | Cond::Pl => &[Flag::N],
+            Cond::Vs | Cond::Vc => &[Flag::V],
+            Cond::Hi | Cond::Ls => &[Flag::C, Flag::Z],
+            Cond::Ge | Cond::Lt => &[Flag::N, Flag::V],
+            Cond::Gt | Cond::Le => &[Flag::Z, Flag::N, Flag::V],
+            Cond::Al => &[],
+        }
+    }
+}
 
 /// List of registers/arguments that an instruction either defines or uses, see [`crate::Ins::defs`]
 /// and [`crate::Ins::uses`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DefsUses {
-    args: [DefUseArgument; MAX_ARGS],
+    args: [DefUseArgument; MAX_DEFS_USES],
     len: usize,
 }
 
 impl DefsUses {
     pub(crate) fn new() -> Self {
-        Self { args: [DefUseArgument::Reg(Reg::R0); MAX_ARGS], len: 0 }
+        Self { args: [DefUseArgument::Reg(Reg::R0); MAX_DEFS_USES], len: 0 }
     }
 
     pub(crate) fn push<T>(&mut self, arg: T)
     where
         T: Into<DefUseArgument>,
     {
-        // Sanity check, tested with fuzzer to verify it never occurs
-        assert!(self.len < self.args.len(), "DefsUses args limit reached");
+        // The generator sizes the list for the instruction with the most arguments
         self.args[self.len] = arg.into();
         self.len += 1;
     }
 
+    /// Pushes the flags tested by `cond` which haven't been pushed yet.
+    pub(crate) fn push_cond(&mut self, cond: Cond) {
+        for &flag in cond.flags() {
+            if !self.as_slice().contains(&DefUseArgument::Flag(flag)) {
+                self.push(flag);
+            }
+        }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &DefUseArgument> {
         self.args.iter().take(self.len)
     }
@@ -52,7 +171,7 @@ impl DefsUses {
 }
 
 pub struct DefsUsesIntoIter {
-    args: [DefUseArgument; MAX_ARGS],
+    args: [DefUseArgument; MAX_DEFS_USES],
     len: usize,
     pos: usize,
 }
@@ -92,16 +211,29 @@ macro_rules! into_def_use_impl {
     };
 }
 
-into_def_use_impl!(
-    Reg,
-    Sreg,
-    Dreg,
-    RegList,
-    SregList,
-    DregList,
-    DregIndex,
-    CoReg,
-    StatusReg,
-    StatusFields,
-    Fpscr
-);
+into_def_use_impl!(Reg, RegList, CoReg, StatusReg, StatusFields, Flag);
+
+#[cfg(all(
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+))]
+into_def_use_impl!(Dreg);
+
+#[cfg(all(
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+))]
+into_def_use_impl!(Sreg, SregList, DregList, DregIndex, Fpscr);
+
+#[cfg(all(feature = "neon", feature = "v7"))]
+into_def_use_impl!(Qreg, NeonRegList);
+
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl From<NeonReg> for DefUseArgument {
+    fn from(value: NeonReg) -> DefUseArgument {
+        match value {
+            NeonReg::D(dreg) => DefUseArgument::Dreg(dreg),
+            NeonReg::Q(qreg) => DefUseArgument::Qreg(qreg),
+        }
+    }
+}
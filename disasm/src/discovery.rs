@@ -0,0 +1,125 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use crate::{ins::LiteralKind, *};
+
+/// Recursive-descent disassembler for raw images without symbols, such as Nintendo DS binaries.
+/// Starting from entry points, it follows all reachable code and marks the literal pools
+/// referenced by PC-relative loads as data. The result can be turned into mapping symbols.
+pub struct Discovery<'a> {
+    cfg: Cfg<'a>,
+    functions: BTreeMap<u32, ParseMode>,
+    literals: BTreeSet<(u32, u32)>,
+}
+
+/// Range of bytes which are either ARM code, Thumb code or data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region {
+    pub start: u32,
+    pub end: u32,
+    pub mode: ParseMode,
+}
+
+impl<'a> Discovery<'a> {
+    /// Creates an empty discovery of `bytes` loaded at the address `base`.
+    pub fn new(bytes: &'a [u8], base: u32, endian: ParseEndian, options: Options) -> Self {
+        Self {
+            cfg: Cfg::new(bytes, base, endian, options),
+            functions: BTreeMap::new(),
+            literals: BTreeSet::new(),
+        }
+    }
+
+    /// Disassembles all code reachable from the function at `addr`. Call targets are discovered
+    /// as functions as well.
+    pub fn add_entry(&mut self, addr: u32, mode: ParseMode) {
+        self.cfg.add_entry(addr, mode);
+        self.functions.insert(addr, mode);
+
+        let base = self.cfg.base();
+        let end = base.wrapping_add(self.cfg.bytes().len() as u32);
+        for edge in self.cfg.edges() {
+            if edge.kind == EdgeKind::Call && (base..end).contains(&edge.to) {
+                self.functions.insert(edge.to, edge.mode);
+            }
+        }
+        for block in self.cfg.blocks() {
+            for (addr, ins) in block.instructions() {
+                let Some((literal, kind)) = ins.pc_relative_access(*addr, block.mode()) else {
+                    continue;
+                };
+                if kind != LiteralKind::Address && (base..end).contains(&literal) {
+                    self.literals.insert((literal, kind.size()));
+                }
+            }
+        }
+    }
+
+    /// Marks the function at `addr` as never returning, see [`Cfg::add_noreturn`].
+    pub fn add_noreturn(&mut self, addr: u32) {
+        self.cfg.add_noreturn(addr);
+    }
+
+    /// Returns the control-flow graph of all discovered code.
+    pub fn cfg(&self) -> &Cfg<'a> {
+        &self.cfg
+    }
+
+    /// Returns the entry points and call targets in order of address.
+    pub fn functions(&self) -> impl Iterator<Item = (u32, ParseMode)> {
+        self.functions.iter().map(|(addr, mode)| (*addr, *mode))
+    }
+
+    /// Returns the address and size of each literal loaded by a PC-relative load.
+    pub fn literals(&self) -> impl Iterator<Item = (u32, u32)> {
+        self.literals.iter().copied()
+    }
+
+    /// Splits the whole image into code and data regions in order of address. Literals and bytes
+    /// which were not reached as code are data, and adjacent regions of the same kind are merged.
+    /// Blocks which overlap a literal are dropped, since falling through into a literal pool is
+    /// more likely than loading a literal from code.
+    pub fn regions(&self) -> Vec<Region> {
+        let base = self.cfg.base();
+        let end = base.wrapping_add(self.cfg.bytes().len() as u32);
+
+        let mut regions: Vec<Region> = Vec::new();
+        let mut push = |start: u32, end: u32, mode: ParseMode| {
+            if start >= end {
+                return;
+            }
+            match regions.last_mut() {
+                Some(last) if last.mode == mode && last.end == start => last.end = end,
+                _ => regions.push(Region { start, end, mode }),
+            }
+        };
+
+        let mut addr = base;
+        for block in self.cfg.blocks().filter(|block| !self.overlaps_literal(block)) {
+            // Overlapping blocks start inside the previous block
+            let start = block.start().max(addr);
+            let block_end = block.end().min(end);
+            push(addr, start, ParseMode::Data);
+            push(start, block_end, block.mode());
+            addr = addr.max(block_end);
+        }
+        push(addr, end, ParseMode::Data);
+        regions
+    }
+
+    fn overlaps_literal(&self, block: &BasicBlock) -> bool {
+        // Doubleword literals can start before the block
+        let first_literal = block.start().saturating_sub(8);
+        self.literals
+            .range((first_literal, 0)..(block.end(), 0))
+            .any(|&(literal, size)| literal.wrapping_add(size) > block.start())
+    }
+
+    /// Returns the address and name of the mapping symbols (`$a`, `$t` or `$d`) for the start of
+    /// each region, see [`ParseMode::from_mapping_symbol`].
+    pub fn mapping_symbols(&self) -> impl Iterator<Item = (u32, &'static str)> {
+        self.regions().into_iter().map(|region| (region.start, region.mode.mapping_symbol()))
+    }
+}
@@ -0,0 +1,381 @@
+use alloc::vec::Vec;
+
+use object::{
+    Endianness, Object, ObjectKind, ObjectSection, ObjectSymbol, RelocationFlags, RelocationTarget,
+    SectionFlags, SectionIndex, SymbolKind,
+    elf::{EF_ARM_BE8, EM_ARM, SHF_EXECINSTR, SHT_ARM_ATTRIBUTES},
+    read::elf::{ElfFile32, FileHeader, SectionHeader},
+};
+
+use crate::*;
+
+const TAG_FILE: u8 = 1;
+const TAG_CPU_RAW_NAME: u64 = 4;
+const TAG_CPU_NAME: u64 = 5;
+const TAG_CPU_ARCH: u64 = 6;
+const TAG_COMPATIBILITY: u64 = 32;
+
+/// Error returned by [`ElfFile::parse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElfError {
+    /// The file is not a valid ELF32 file
+    Invalid(object::read::Error),
+    /// The file is not for the ARM architecture
+    NotArm,
+}
+
+impl core::fmt::Display for ElfError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ElfError::Invalid(error) => write!(f, "invalid ELF file: {error}"),
+            ElfError::NotArm => f.write_str("ELF file is not for ARM"),
+        }
+    }
+}
+
+impl core::error::Error for ElfError {}
+
+impl From<object::read::Error> for ElfError {
+    fn from(error: object::read::Error) -> Self {
+        ElfError::Invalid(error)
+    }
+}
+
+/// ELF32 ARM object file or executable.
+pub struct ElfFile<'a> {
+    file: ElfFile32<'a, Endianness>,
+    version: Option<Version>,
+}
+
+/// Executable section of an [`ElfFile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfSection<'a> {
+    pub name: &'a str,
+    pub address: u32,
+    pub data: &'a [u8],
+    endian: ParseEndian,
+    /// Address and mode of each mapping symbol, in order of address
+    mapping_symbols: Vec<(u32, ParseMode)>,
+    /// Address and relocation of each relocated instruction, in order of address
+    relocations: Vec<(u32, Relocation<'a>)>,
+    /// Address and name of each symbol other than mapping symbols, in order of address
+    symbols: Vec<(u32, &'a str)>,
+}
+
+/// Parses an [`ElfSection`], switching modes at each mapping symbol.
+pub struct SectionParser<'a> {
+    section: &'a ElfSection<'a>,
+    options: Options,
+    parser: Parser<'a>,
+    /// Index of the next mapping symbol
+    next_symbol: usize,
+    /// Relocation of the last parsed instruction
+    relocation: Option<Relocation<'a>>,
+}
+
+impl<'a> ElfFile<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Self, ElfError> {
+        let file = ElfFile32::<Endianness>::parse(data)?;
+        let endian = file.endian();
+        if file.elf_header().e_machine(endian) != EM_ARM {
+            return Err(ElfError::NotArm);
+        }
+        let version = cpu_arch(&file)?.and_then(version_from_cpu_arch);
+        Ok(Self { file, version })
+    }
+
+    /// Returns the byte order of instructions. In BE-8 images, instructions are little-endian even
+    /// though data is big-endian.
+    pub fn endian(&self) -> ParseEndian {
+        let flags = self.file.elf_header().e_flags(self.file.endian());
+        if flags & EF_ARM_BE8 != 0 {
+            return ParseEndian::Little;
+        }
+        match self.file.endian() {
+            Endianness::Little => ParseEndian::Little,
+            Endianness::Big => ParseEndian::Big,
+        }
+    }
+
+    /// Returns the architecture version of the `Tag_CPU_arch` attribute in `.ARM.attributes`, if
+    /// it's present. Architectures which unarm doesn't support map to the closest version.
+    pub fn version(&self) -> Option<Version> {
+        self.version
+    }
+
+    /// Returns the default options, using [`Self::version`] if it's known.
+    pub fn options(&self) -> Options {
+        let mut options = Options::default();
+        if let Some(version) = self.version {
+            options.version = version;
+        }
+        options
+    }
+
+    /// Returns all sections containing executable code.
+    pub fn code_sections(&self) -> impl Iterator<Item = ElfSection<'a>> + '_ {
+        self.file.sections().filter_map(|section| {
+            let SectionFlags::Elf { sh_flags } = section.flags() else {
+                return None;
+            };
+            if sh_flags & SHF_EXECINSTR as u64 == 0 {
+                return None;
+            }
+            Some(ElfSection {
+                name: section.name().ok()?,
+                address: section.address() as u32,
+                data: section.data().ok()?,
+                endian: self.endian(),
+                mapping_symbols: self.mapping_symbols(section.index()),
+                relocations: self.relocations(&section),
+                symbols: self.symbols(section.index()),
+            })
+        })
+    }
+
+    fn mapping_symbols(&self, index: SectionIndex) -> Vec<(u32, ParseMode)> {
+        let mut symbols = self
+            .file
+            .symbols()
+            .filter(|symbol| symbol.section_index() == Some(index))
+            .filter_map(|symbol| {
+                let name = symbol.name().ok()?;
+                // Mapping symbols may have a suffix, such as `$t.1`
+                let name = name.split_once('.').map_or(name, |(name, _)| name);
+                let mode = ParseMode::from_mapping_symbol(name)?;
+                Some((symbol.address() as u32, mode))
+            })
+            .collect::<Vec<_>>();
+        symbols.sort_by_key(|(addr, _)| *addr);
+        symbols
+    }
+
+    fn symbols(&self, index: SectionIndex) -> Vec<(u32, &'a str)> {
+        let mut symbols = self
+            .file
+            .symbols()
+            .filter(|symbol| symbol.section_index() == Some(index))
+            .filter(|symbol| !matches!(symbol.kind(), SymbolKind::Section | SymbolKind::File))
+            .filter_map(|symbol| {
+                let name = symbol.name().ok()?;
+                if name.is_empty() || name.starts_with('$') {
+                    return None;
+                }
+                // Thumb functions have the lowest bit set
+                let address = match symbol.kind() {
+                    SymbolKind::Text => symbol.address() as u32 & !1,
+                    _ => symbol.address() as u32,
+                };
+                Some((address, name))
+            })
+            .collect::<Vec<_>>();
+        symbols.sort_by_key(|(addr, _)| *addr);
+        symbols
+    }
+
+    fn relocations(&self, section: &impl ObjectSection<'a>) -> Vec<(u32, Relocation<'a>)> {
+        let mut relocations = section
+            .relocations()
+            .filter_map(|(offset, relocation)| {
+                let RelocationFlags::Elf { r_type } = relocation.flags() else {
+                    return None;
+                };
+                let RelocationTarget::Symbol(index) = relocation.target() else {
+                    return None;
+                };
+                let symbol = self.file.symbol_by_index(index).ok()?;
+                let name = match symbol.kind() {
+                    SymbolKind::Section => {
+                        self.file.section_by_index(symbol.section_index()?).ok()?.name().ok()?
+                    }
+                    _ => symbol.name().ok()?,
+                };
+                let kind = RelocationKind::from_elf(r_type);
+                // Offsets are relative to the section in relocatable files
+                let address = match self.file.kind() {
+                    ObjectKind::Relocatable => section.address().wrapping_add(offset) as u32,
+                    _ => offset as u32,
+                };
+                Some((address, Relocation { kind, symbol: name }))
+            })
+            .collect::<Vec<_>>();
+        relocations.sort_by_key(|(addr, _)| *addr);
+        relocations
+    }
+}
+
+impl<'a> ElfSection<'a> {
+    /// Returns the address and mode of each mapping symbol in order of address.
+    pub fn mapping_symbols(&self) -> &[(u32, ParseMode)] {
+        &self.mapping_symbols
+    }
+
+    /// Returns the address and relocation of each relocated instruction in order of address.
+    pub fn relocations(&self) -> &[(u32, Relocation<'a>)] {
+        &self.relocations
+    }
+
+    /// Returns the address and name of each symbol in this section, except for mapping symbols,
+    /// in order of address.
+    pub fn symbols(&self) -> &[(u32, &'a str)] {
+        &self.symbols
+    }
+
+    /// Returns the relocation of the instruction at `addr`.
+    pub fn relocation(&self, addr: u32) -> Option<Relocation<'a>> {
+        let index = self.relocations.binary_search_by_key(&addr, |(addr, _)| *addr).ok()?;
+        Some(self.relocations[index].1)
+    }
+
+    /// Returns the mode at `addr` according to the mapping symbols. Code before the first mapping
+    /// symbol is assumed to be ARM.
+    pub fn mode_at(&self, addr: u32) -> ParseMode {
+        let index = self.mapping_symbols.partition_point(|(symbol, _)| *symbol <= addr);
+        match index.checked_sub(1) {
+            Some(index) => self.mapping_symbols[index].1,
+            None => default_mode(),
+        }
+    }
+
+    /// Parses the whole section, see [`SectionParser`].
+    pub fn parser(&self, options: Options) -> SectionParser<'_> {
+        let mode = self.mode_at(self.address);
+        let next_symbol = self.mapping_symbols.partition_point(|(addr, _)| *addr <= self.address);
+        let mut parser = SectionParser {
+            section: self,
+            options: options.clone(),
+            parser: Parser::new(&[], mode, self.endian, options),
+            next_symbol,
+            relocation: None,
+        };
+        parser.start_region(self.address, mode);
+        parser
+    }
+}
+
+impl<'a> SectionParser<'a> {
+    /// Mode of the last parsed instruction.
+    pub fn mode(&self) -> ParseMode {
+        self.parser.mode()
+    }
+
+    /// Relocation of the last parsed instruction.
+    pub fn relocation(&self) -> Option<Relocation<'a>> {
+        self.relocation
+    }
+
+    /// Address of the next instruction.
+    pub fn pc(&self) -> u32 {
+        self.parser.pc()
+    }
+
+    fn start_region(&mut self, start: u32, mode: ParseMode) {
+        let section = self.section;
+        let end = match section.mapping_symbols.get(self.next_symbol) {
+            Some((addr, _)) => *addr,
+            None => section.address.wrapping_add(section.data.len() as u32),
+        };
+        let start_offset = start.wrapping_sub(section.address) as usize;
+        let end_offset = (end.wrapping_sub(section.address) as usize).min(section.data.len());
+        let bytes = section.data.get(start_offset..end_offset).unwrap_or_default();
+        self.parser = Parser::new(bytes, mode, section.endian, self.options.clone());
+        self.parser.set_pc(start);
+    }
+}
+
+impl Iterator for SectionParser<'_> {
+    type Item = (u32, Ins);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let pc = self.parser.pc();
+            if let Some(ins) = self.parser.next() {
+                self.relocation = self.section.relocation(pc);
+                return Some((pc, ins));
+            }
+            let (addr, mode) = *self.section.mapping_symbols.get(self.next_symbol)?;
+            self.next_symbol += 1;
+            self.start_region(addr, mode);
+        }
+    }
+}
+
+fn default_mode() -> ParseMode {
+    #[cfg(feature = "arm")]
+    return ParseMode::Arm;
+    #[cfg(not(feature = "arm"))]
+    return ParseMode::Thumb;
+}
+
+/// Reads the `Tag_CPU_arch` attribute from the `aeabi` subsection of `.ARM.attributes`.
+fn cpu_arch(file: &ElfFile32<'_, Endianness>) -> Result<Option<u64>, ElfError> {
+    let endian = file.endian();
+    let Some(section) = file
+        .elf_section_table()
+        .iter()
+        .find(|section| section.sh_type(endian) == SHT_ARM_ATTRIBUTES)
+    else {
+        return Ok(None);
+    };
+    let attributes = section.attributes(endian, file.data())?;
+    let mut subsections = attributes.subsections()?;
+    while let Some(subsection) = subsections.next()? {
+        if subsection.vendor() != b"aeabi" {
+            continue;
+        }
+        let mut subsubsections = subsection.subsubsections();
+        while let Some(subsubsection) = subsubsections.next()? {
+            if subsubsection.tag() != TAG_FILE {
+                continue;
+            }
+            let mut reader = subsubsection.attributes();
+            while let Some(tag) = reader.read_tag()? {
+                match tag {
+                    TAG_CPU_ARCH => return Ok(Some(reader.read_integer()?)),
+                    TAG_CPU_RAW_NAME | TAG_CPU_NAME => {
+                        reader.read_string()?;
+                    }
+                    TAG_COMPATIBILITY => {
+                        reader.read_integer()?;
+                        reader.read_string()?;
+                    }
+                    // Tags above 32 are strings if odd and integers if even
+                    tag if tag > 32 && tag % 2 == 1 => {
+                        reader.read_string()?;
+                    }
+                    _ => {
+                        reader.read_integer()?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn version_from_cpu_arch(cpu_arch: u64) -> Option<Version> {
+    match cpu_arch {
+        // Pre-v4
+        #[cfg(feature = "v4")]
+        0 | 1 => Some(Version::V4),
+        #[cfg(feature = "v4t")]
+        2 => Some(Version::V4T),
+        #[cfg(feature = "v5t")]
+        3 => Some(Version::V5T),
+        #[cfg(feature = "v5te")]
+        4 => Some(Version::V5Te),
+        #[cfg(feature = "v5tej")]
+        5 => Some(Version::V5Tej),
+        #[cfg(feature = "v6")]
+        6 => Some(Version::V6),
+        // v6KZ, v6-M and v6S-M
+        #[cfg(feature = "v6k")]
+        7 | 9 | 11 | 12 => Some(Version::V6K),
+        // v6T2 is the first version with Thumb-2, and v7E-M and v8 are the closest to v7
+        #[cfg(feature = "v7")]
+        8 | 10 | 13.. => Some(Version::V7),
+        // Reachable when versions are disabled
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
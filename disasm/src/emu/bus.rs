@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+
+/// Memory and I/O of an [`Emu`]. Only the byte accesses are required, and the wider accesses
+/// default to combining bytes in little-endian order. Halfword and word addresses are always
+/// aligned by the emulator, such as unaligned LDRs which load an aligned word and rotate it.
+pub trait Bus {
+    /// Returns the byte at `addr`, or `None` if it can't be read.
+    fn read_u8(&mut self, addr: u32) -> Option<u8>;
+
+    /// Writes `value` to `addr`, or returns `None` if it can't be written.
+    fn write_u8(&mut self, addr: u32, value: u8) -> Option<()>;
+
+    fn read_u16(&mut self, addr: u32) -> Option<u16> {
+        Some(u16::from_le_bytes([self.read_u8(addr)?, self.read_u8(addr.wrapping_add(1))?]))
+    }
+
+    fn read_u32(&mut self, addr: u32) -> Option<u32> {
+        let low = self.read_u16(addr)? as u32;
+        let high = self.read_u16(addr.wrapping_add(2))? as u32;
+        Some(low | (high << 16))
+    }
+
+    fn write_u16(&mut self, addr: u32, value: u16) -> Option<()> {
+        let [low, high] = value.to_le_bytes();
+        self.write_u8(addr, low)?;
+        self.write_u8(addr.wrapping_add(1), high)
+    }
+
+    fn write_u32(&mut self, addr: u32, value: u32) -> Option<()> {
+        self.write_u16(addr, value as u16)?;
+        self.write_u16(addr.wrapping_add(2), (value >> 16) as u16)
+    }
+}
+
+/// Memory starting at address 0.
+impl Bus for [u8] {
+    fn read_u8(&mut self, addr: u32) -> Option<u8> {
+        self.get(addr as usize).copied()
+    }
+
+    fn write_u8(&mut self, addr: u32, value: u8) -> Option<()> {
+        *self.get_mut(addr as usize)? = value;
+        Some(())
+    }
+}
+
+/// Memory starting at address 0.
+impl Bus for Vec<u8> {
+    fn read_u8(&mut self, addr: u32) -> Option<u8> {
+        self.as_mut_slice().read_u8(addr)
+    }
+
+    fn write_u8(&mut self, addr: u32, value: u8) -> Option<()> {
+        self.as_mut_slice().write_u8(addr, value)
+    }
+}
+
+impl<B: Bus + ?Sized> Bus for &mut B {
+    fn read_u8(&mut self, addr: u32) -> Option<u8> {
+        (**self).read_u8(addr)
+    }
+
+    fn write_u8(&mut self, addr: u32, value: u8) -> Option<()> {
+        (**self).write_u8(addr, value)
+    }
+
+    fn read_u16(&mut self, addr: u32) -> Option<u16> {
+        (**self).read_u16(addr)
+    }
+
+    fn read_u32(&mut self, addr: u32) -> Option<u32> {
+        (**self).read_u32(addr)
+    }
+
+    fn write_u16(&mut self, addr: u32, value: u16) -> Option<()> {
+        (**self).write_u16(addr, value)
+    }
+
+    fn write_u32(&mut self, addr: u32, value: u32) -> Option<()> {
+        (**self).write_u32(addr, value)
+    }
+}
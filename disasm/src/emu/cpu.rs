@@ -0,0 +1,347 @@
+use crate::*;
+
+const FLAG_N: u32 = 1 << 31;
+const FLAG_Z: u32 = 1 << 30;
+const FLAG_C: u32 = 1 << 29;
+const FLAG_V: u32 = 1 << 28;
+const FLAG_Q: u32 = 1 << 27;
+const FLAGS_GE: u32 = 0xf << 16;
+pub(crate) const CPSR_I: u32 = 1 << 7;
+pub(crate) const CPSR_F: u32 = 1 << 6;
+pub(crate) const CPSR_T: u32 = 1 << 5;
+const CPSR_MODE: u32 = 0x1f;
+
+/// Processor mode, selects which registers are banked and whether the code is privileged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CpuMode {
+    User,
+    Fiq,
+    Irq,
+    Supervisor,
+    Abort,
+    Undefined,
+    /// Privileged mode which uses the user mode registers
+    System,
+}
+
+impl CpuMode {
+    /// Returns the mode bits of the CPSR for this mode.
+    pub const fn bits(self) -> u32 {
+        match self {
+            CpuMode::User => 0x10,
+            CpuMode::Fiq => 0x11,
+            CpuMode::Irq => 0x12,
+            CpuMode::Supervisor => 0x13,
+            CpuMode::Abort => 0x17,
+            CpuMode::Undefined => 0x1b,
+            CpuMode::System => 0x1f,
+        }
+    }
+
+    /// Returns the mode of the given CPSR mode bits, or `None` if they are reserved.
+    pub const fn from_bits(bits: u32) -> Option<Self> {
+        match bits & CPSR_MODE {
+            0x10 => Some(CpuMode::User),
+            0x11 => Some(CpuMode::Fiq),
+            0x12 => Some(CpuMode::Irq),
+            0x13 => Some(CpuMode::Supervisor),
+            0x17 => Some(CpuMode::Abort),
+            0x1b => Some(CpuMode::Undefined),
+            0x1f => Some(CpuMode::System),
+            _ => None,
+        }
+    }
+
+    /// Index of the banked SP, LR and SPSR of this mode
+    const fn bank(self) -> usize {
+        match self {
+            CpuMode::User | CpuMode::System => 0,
+            CpuMode::Fiq => 1,
+            CpuMode::Irq => 2,
+            CpuMode::Supervisor => 3,
+            CpuMode::Abort => 4,
+            CpuMode::Undefined => 5,
+        }
+    }
+}
+
+/// Register state of an ARMv4T-ARMv5TE processor with a VFP coprocessor, see [`Emu`]. The
+/// registers of the current mode are active, and the banked registers of the other modes are
+/// swapped in when the mode changes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cpu {
+    /// Active registers, where R15 is the address of the next instruction to execute
+    regs: [u32; 16],
+    cpsr: u32,
+    /// R8-R12 of FIQ mode and of the other modes. The entry of the current mode is stale.
+    r8_r12: [[u32; 5]; 2],
+    /// SP and LR of each mode bank. The entry of the current mode is stale.
+    sp_lr: [[u32; 2]; 6],
+    /// SPSR of each mode bank, where user and system mode have none
+    spsr: [u32; 6],
+    #[cfg(all(
+        any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+    ))]
+    vfp: VfpRegs,
+}
+
+#[cfg(all(
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct VfpRegs {
+    /// D0-D31, where S0-S31 are the low and high halves of D0-D15
+    dregs: [u64; 32],
+    fpscr: u32,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cpu {
+    /// Creates the state after a reset, which is ARM code in supervisor mode with interrupts
+    /// disabled. All registers are zero.
+    pub const fn new() -> Self {
+        Self {
+            regs: [0; 16],
+            cpsr: CpuMode::Supervisor.bits() | CPSR_I | CPSR_F,
+            r8_r12: [[0; 5]; 2],
+            sp_lr: [[0; 2]; 6],
+            spsr: [0; 6],
+            #[cfg(all(
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ))]
+            vfp: VfpRegs { dregs: [0; 32], fpscr: 0 },
+        }
+    }
+
+    /// Returns the value of `reg` in the current mode. The PC is the address of the next
+    /// instruction to execute, see [`RegisterFile::reg`] for the value instructions read.
+    pub fn get(&self, reg: Reg) -> u32 {
+        self.regs[reg as usize]
+    }
+
+    /// Sets `reg` in the current mode. Setting the PC does not change between ARM and Thumb.
+    pub fn set(&mut self, reg: Reg, value: u32) {
+        self.regs[reg as usize] = value;
+    }
+
+    /// Address of the next instruction to execute.
+    pub fn pc(&self) -> u32 {
+        self.regs[Reg::Pc as usize]
+    }
+
+    pub fn set_pc(&mut self, pc: u32) {
+        self.regs[Reg::Pc as usize] = pc;
+    }
+
+    /// Returns the value of `reg` in `mode`, which may be banked and inactive.
+    pub fn banked(&self, reg: Reg, mode: CpuMode) -> u32 {
+        let current = self.mode();
+        match reg {
+            Reg::R8 | Reg::R9 | Reg::R10 | Reg::R11 | Reg::R12
+                if (mode == CpuMode::Fiq) != (current == CpuMode::Fiq) =>
+            {
+                self.r8_r12[(mode == CpuMode::Fiq) as usize][reg as usize - 8]
+            }
+            Reg::Sp | Reg::Lr if mode.bank() != current.bank() => {
+                self.sp_lr[mode.bank()][reg as usize - Reg::Sp as usize]
+            }
+            _ => self.get(reg),
+        }
+    }
+
+    /// Sets the value of `reg` in `mode`, which may be banked and inactive.
+    pub fn set_banked(&mut self, reg: Reg, mode: CpuMode, value: u32) {
+        let current = self.mode();
+        match reg {
+            Reg::R8 | Reg::R9 | Reg::R10 | Reg::R11 | Reg::R12
+                if (mode == CpuMode::Fiq) != (current == CpuMode::Fiq) =>
+            {
+                self.r8_r12[(mode == CpuMode::Fiq) as usize][reg as usize - 8] = value;
+            }
+            Reg::Sp | Reg::Lr if mode.bank() != current.bank() => {
+                self.sp_lr[mode.bank()][reg as usize - Reg::Sp as usize] = value;
+            }
+            _ => self.set(reg, value),
+        }
+    }
+
+    pub fn cpsr(&self) -> u32 {
+        self.cpsr
+    }
+
+    /// Sets the CPSR and swaps in the banked registers if the mode changes. Reserved mode bits
+    /// leave the mode unchanged.
+    pub fn set_cpsr(&mut self, value: u32) {
+        let mode = CpuMode::from_bits(value).unwrap_or(self.mode());
+        self.set_mode(mode);
+        self.cpsr = (value & !CPSR_MODE) | mode.bits();
+    }
+
+    /// Returns the SPSR of the current mode, or `None` in user and system mode.
+    pub fn spsr(&self) -> Option<u32> {
+        match self.mode().bank() {
+            0 => None,
+            bank => Some(self.spsr[bank]),
+        }
+    }
+
+    /// Sets the SPSR of the current mode. Does nothing in user and system mode.
+    pub fn set_spsr(&mut self, value: u32) {
+        match self.mode().bank() {
+            0 => {}
+            bank => self.spsr[bank] = value,
+        }
+    }
+
+    pub fn mode(&self) -> CpuMode {
+        CpuMode::from_bits(self.cpsr).unwrap_or(CpuMode::User)
+    }
+
+    /// Switches to `mode` and swaps in its banked registers.
+    pub fn set_mode(&mut self, mode: CpuMode) {
+        let current = self.mode();
+        if (mode == CpuMode::Fiq) != (current == CpuMode::Fiq) {
+            let regs = &mut self.regs[Reg::R8 as usize..=Reg::R12 as usize];
+            self.r8_r12[(current == CpuMode::Fiq) as usize].copy_from_slice(regs);
+            regs.copy_from_slice(&self.r8_r12[(mode == CpuMode::Fiq) as usize]);
+        }
+        if mode.bank() != current.bank() {
+            let regs = &mut self.regs[Reg::Sp as usize..=Reg::Lr as usize];
+            self.sp_lr[current.bank()].copy_from_slice(regs);
+            regs.copy_from_slice(&self.sp_lr[mode.bank()]);
+        }
+        self.cpsr = (self.cpsr & !CPSR_MODE) | mode.bits();
+    }
+
+    /// Returns true if the processor is executing Thumb code.
+    pub fn thumb(&self) -> bool {
+        self.cpsr & CPSR_T != 0
+    }
+
+    pub fn set_thumb(&mut self, thumb: bool) {
+        self.set_cpsr_bits(CPSR_T, thumb);
+    }
+
+    /// Returns a status flag of the CPSR. [`Flag::Ge`] is set if any of the four GE bits are set.
+    pub fn flag(&self, flag: Flag) -> bool {
+        self.cpsr & Self::flag_bits(flag) != 0
+    }
+
+    /// Sets or clears a status flag of the CPSR. [`Flag::Ge`] changes all four GE bits.
+    pub fn set_flag(&mut self, flag: Flag, value: bool) {
+        self.set_cpsr_bits(Self::flag_bits(flag), value);
+    }
+
+    fn flag_bits(flag: Flag) -> u32 {
+        match flag {
+            Flag::N => FLAG_N,
+            Flag::Z => FLAG_Z,
+            Flag::C => FLAG_C,
+            Flag::V => FLAG_V,
+            Flag::Q => FLAG_Q,
+            Flag::Ge => FLAGS_GE,
+        }
+    }
+
+    pub(crate) fn set_cpsr_bits(&mut self, bits: u32, value: bool) {
+        if value {
+            self.cpsr |= bits;
+        } else {
+            self.cpsr &= !bits;
+        }
+    }
+
+    /// Sets the N and Z flags from `result`.
+    pub(crate) fn set_nz(&mut self, result: u32) {
+        self.set_flag(Flag::N, result & 0x8000_0000 != 0);
+        self.set_flag(Flag::Z, result == 0);
+    }
+
+    /// Returns true if the flags pass `cond`.
+    pub fn cond_passed(&self, cond: Cond) -> bool {
+        let (n, z, c, v) =
+            (self.flag(Flag::N), self.flag(Flag::Z), self.flag(Flag::C), self.flag(Flag::V));
+        match cond {
+            Cond::Eq => z,
+            Cond::Ne => !z,
+            Cond::Hs => c,
+            Cond::Lo => !c,
+            Cond::Mi => n,
+            Cond::Pl => !n,
+            Cond::Vs => v,
+            Cond::Vc => !v,
+            Cond::Hi => c && !z,
+            Cond::Ls => !c || z,
+            Cond::Ge => n == v,
+            Cond::Lt => n != v,
+            Cond::Gt => !z && n == v,
+            Cond::Le => z || n != v,
+            Cond::Al => true,
+        }
+    }
+}
+
+#[cfg(all(
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+))]
+impl Cpu {
+    /// Returns the bits of a single-precision register.
+    pub fn sreg(&self, sreg: Sreg) -> u32 {
+        let sreg = sreg as usize;
+        (self.vfp.dregs[sreg / 2] >> (32 * (sreg % 2))) as u32
+    }
+
+    pub fn set_sreg(&mut self, sreg: Sreg, value: u32) {
+        let sreg = sreg as usize;
+        let shift = 32 * (sreg % 2);
+        let dreg = &mut self.vfp.dregs[sreg / 2];
+        *dreg = (*dreg & !(0xffff_ffff << shift)) | ((value as u64) << shift);
+    }
+
+    /// Returns the bits of a double-precision register.
+    pub fn dreg(&self, dreg: Dreg) -> u64 {
+        self.vfp.dregs[dreg as usize]
+    }
+
+    pub fn set_dreg(&mut self, dreg: Dreg, value: u64) {
+        self.vfp.dregs[dreg as usize] = value;
+    }
+
+    /// Floating-Point Status and Control Register
+    pub fn fpscr(&self) -> u32 {
+        self.vfp.fpscr
+    }
+
+    pub fn set_fpscr(&mut self, value: u32) {
+        self.vfp.fpscr = value;
+    }
+}
+
+impl RegisterFile for Cpu {
+    fn reg(&self, reg: Reg) -> u32 {
+        match reg {
+            Reg::Pc if self.thumb() => self.pc().wrapping_add(4),
+            Reg::Pc => self.pc().wrapping_add(8),
+            _ => self.get(reg),
+        }
+    }
+
+    fn carry(&self) -> bool {
+        self.flag(Flag::C)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::*;
+
+impl<B: Bus> Emu<B> {
+    /// Executes the saturating arithmetic, halfword multiplies and doubleword transfers added in
+    /// ARMv5TE, or returns `None` if `ins` is not one of them.
+    pub(super) fn execute_dsp(&mut self, ins: &Ins) -> Option<Result<(), EmuError>> {
+        let result = match *ins {
+            Ins::Qadd { rd, rm, rn, .. } => {
+                let result =
+                    self.saturate(self.cpu.reg(rm) as i32 as i64 + self.cpu.reg(rn) as i32 as i64);
+                self.write_reg(rd, result as u32);
+                Ok(())
+            }
+            Ins::Qsub { rd, rm, rn, .. } => {
+                let result =
+                    self.saturate(self.cpu.reg(rm) as i32 as i64 - self.cpu.reg(rn) as i32 as i64);
+                self.write_reg(rd, result as u32);
+                Ok(())
+            }
+            Ins::Qdadd { rd, rm, rn, .. } => {
+                let double = self.saturate(self.cpu.reg(rn) as i32 as i64 * 2);
+                let result = self.saturate(self.cpu.reg(rm) as i32 as i64 + double as i64);
+                self.write_reg(rd, result as u32);
+                Ok(())
+            }
+            Ins::Qdsub { rd, rm, rn, .. } => {
+                let double = self.saturate(self.cpu.reg(rn) as i32 as i64 * 2);
+                let result = self.saturate(self.cpu.reg(rm) as i32 as i64 - double as i64);
+                self.write_reg(rd, result as u32);
+                Ok(())
+            }
+            Ins::Smla { rd, rn, rn_side, rm, rm_side, ra, .. } => {
+                let product = self.half(rn, rn_side) * self.half(rm, rm_side);
+                let result = self.add_q(product, self.cpu.reg(ra) as i32);
+                self.write_reg(rd, result as u32);
+                Ok(())
+            }
+            Ins::Smul { rd, rn, rn_side, rm, rm_side, .. } => {
+                let product = self.half(rn, rn_side) * self.half(rm, rm_side);
+                self.write_reg(rd, product as u32);
+                Ok(())
+            }
+            Ins::Smlaw { rd, rn, rm, rm_side, ra, .. } => {
+                let product = self.word_by_half(rn, rm, rm_side);
+                let result = self.add_q(product, self.cpu.reg(ra) as i32);
+                self.write_reg(rd, result as u32);
+                Ok(())
+            }
+            Ins::Smulw { rd, rn, rm, rm_side, .. } => {
+                let product = self.word_by_half(rn, rm, rm_side);
+                self.write_reg(rd, product as u32);
+                Ok(())
+            }
+            Ins::SmlalHalf { rd_lo, rd_hi, rn, rn_side, rm, rm_side, .. } => {
+                let product = self.half(rn, rn_side) * self.half(rm, rm_side);
+                let acc = self.cpu.reg(rd_lo) as u64 | ((self.cpu.reg(rd_hi) as u64) << 32);
+                let result = acc.wrapping_add(product as i64 as u64);
+                self.write_reg(rd_lo, result as u32);
+                self.write_reg(rd_hi, (result >> 32) as u32);
+                Ok(())
+            }
+            Ins::Ldrd { rd, rd2, addr, .. } => self.load_dual(rd, rd2, addr),
+            Ins::Strd { rd, rd2, addr, .. } => self.store_dual(rd, rd2, addr),
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    /// Saturates `value` to a signed 32-bit integer, and sets the Q flag if it was out of range.
+    fn saturate(&mut self, value: i64) -> i32 {
+        let result = value.clamp(i32::MIN as i64, i32::MAX as i64);
+        if result != value {
+            self.cpu.set_flag(Flag::Q, true);
+        }
+        result as i32
+    }
+
+    /// Adds the accumulator of a halfword multiply, and sets the Q flag if it overflows.
+    fn add_q(&mut self, a: i32, b: i32) -> i32 {
+        let (result, overflow) = a.overflowing_add(b);
+        if overflow {
+            self.cpu.set_flag(Flag::Q, true);
+        }
+        result
+    }
+
+    /// Returns the signed halfword of `reg` selected by `side`.
+    fn half(&self, reg: Reg, side: RegSide) -> i32 {
+        let value = self.cpu.reg(reg);
+        match side {
+            RegSide::Bottom => value as i16 as i32,
+            RegSide::Top => (value >> 16) as i16 as i32,
+        }
+    }
+
+    /// Returns the top 32 bits of the 48-bit product of `rn` and a halfword of `rm`.
+    fn word_by_half(&self, rn: Reg, rm: Reg, rm_side: RegSide) -> i32 {
+        let product = self.cpu.reg(rn) as i32 as i64 * self.half(rm, rm_side) as i64;
+        (product >> 16) as i32
+    }
+
+    fn load_dual(&mut self, rd: Reg, rd2: Reg, addr: AddrMiscLoad) -> Result<(), EmuError> {
+        let (addr, writeback) = self.address(addr, 8);
+        let low = self.read_u32(addr)?;
+        let high = self.read_u32(addr.wrapping_add(4))?;
+        self.writeback(writeback);
+        self.write_reg(rd, low);
+        self.write_reg(rd2, high);
+        Ok(())
+    }
+
+    fn store_dual(&mut self, rd: Reg, rd2: Reg, addr: AddrMiscLoad) -> Result<(), EmuError> {
+        let (low, high) = (self.cpu.reg(rd), self.cpu.reg(rd2));
+        let (addr, writeback) = self.address(addr, 8);
+        self.write_u32(addr, low)?;
+        self.write_u32(addr.wrapping_add(4), high)?;
+        self.writeback(writeback);
+        Ok(())
+    }
+}
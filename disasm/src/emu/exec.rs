@@ -0,0 +1,586 @@
+use super::{Exception, cpu::CPSR_T};
+use crate::*;
+
+impl<B: Bus> Emu<B> {
+    /// Executes `ins` after its condition has passed.
+    pub(super) fn execute_ins(&mut self, ins: &Ins, size: u32) -> Result<(), EmuError> {
+        let pc = self.cpu.pc();
+        let next = pc.wrapping_add(size);
+        let thumb = self.cpu.thumb();
+        let carry = self.cpu.flag(Flag::C);
+
+        match *ins {
+            Ins::And { s, rd, rn, op2, .. } => {
+                let (a, b, c) = self.operands(rn, op2);
+                self.write_result(s, rd, a & b, c, None);
+            }
+            Ins::Eor { s, rd, rn, op2, .. } => {
+                let (a, b, c) = self.operands(rn, op2);
+                self.write_result(s, rd, a ^ b, c, None);
+            }
+            Ins::Orr { s, rd, rn, op2, .. } => {
+                let (a, b, c) = self.operands(rn, op2);
+                self.write_result(s, rd, a | b, c, None);
+            }
+            Ins::Bic { s, rd, rn, op2, .. } => {
+                let (a, b, c) = self.operands(rn, op2);
+                self.write_result(s, rd, a & !b, c, None);
+            }
+            Ins::Mov { s, rd, op2, .. } => {
+                let (value, c) = self.op2(op2);
+                self.write_result(s, rd, value, c, None);
+            }
+            Ins::Mvn { s, rd, op2, .. } => {
+                let (value, c) = self.op2(op2);
+                self.write_result(s, rd, !value, c, None);
+            }
+            Ins::Tst { rn, op2, .. } => {
+                let (a, b, c) = self.operands(rn, op2);
+                self.set_flags(a & b, c, None);
+            }
+            Ins::Teq { rn, op2, .. } => {
+                let (a, b, c) = self.operands(rn, op2);
+                self.set_flags(a ^ b, c, None);
+            }
+            Ins::Add { s, rd, rn, op2, .. } => {
+                let (a, b, _) = self.operands(rn, op2);
+                let (result, c, v) = add_with_carry(a, b, false);
+                self.write_result(s, rd, result, c, Some(v));
+            }
+            Ins::Adc { s, rd, rn, op2, .. } => {
+                let (a, b, _) = self.operands(rn, op2);
+                let (result, c, v) = add_with_carry(a, b, carry);
+                self.write_result(s, rd, result, c, Some(v));
+            }
+            Ins::Sub { s, rd, rn, op2, .. } => {
+                let (a, b, _) = self.operands(rn, op2);
+                let (result, c, v) = add_with_carry(a, !b, true);
+                self.write_result(s, rd, result, c, Some(v));
+            }
+            Ins::Sbc { s, rd, rn, op2, .. } => {
+                let (a, b, _) = self.operands(rn, op2);
+                let (result, c, v) = add_with_carry(a, !b, carry);
+                self.write_result(s, rd, result, c, Some(v));
+            }
+            Ins::Rsb { s, rd, rn, op2, .. } => {
+                let (a, b, _) = self.operands(rn, op2);
+                let (result, c, v) = add_with_carry(b, !a, true);
+                self.write_result(s, rd, result, c, Some(v));
+            }
+            Ins::Rsc { s, rd, rn, op2, .. } => {
+                let (a, b, _) = self.operands(rn, op2);
+                let (result, c, v) = add_with_carry(b, !a, carry);
+                self.write_result(s, rd, result, c, Some(v));
+            }
+            Ins::Cmp { rn, op2, .. } => {
+                let (a, b, _) = self.operands(rn, op2);
+                let (result, c, v) = add_with_carry(a, !b, true);
+                self.set_flags(result, c, Some(v));
+            }
+            Ins::Cmn { rn, op2, .. } => {
+                let (a, b, _) = self.operands(rn, op2);
+                let (result, c, v) = add_with_carry(a, b, false);
+                self.set_flags(result, c, Some(v));
+            }
+            Ins::Neg { rd, rm } => {
+                let (result, c, v) = add_with_carry(0, !self.cpu.reg(rm), true);
+                self.write_result(true, rd, result, c, Some(v));
+            }
+            Ins::Lsl { s, rd, rn, op2, .. } => self.shift(s, rd, rn, ShiftOp::Lsl, op2),
+            Ins::Lsr { s, rd, rn, op2, .. } => self.shift(s, rd, rn, ShiftOp::Lsr, op2),
+            Ins::Asr { s, rd, rn, op2, .. } => self.shift(s, rd, rn, ShiftOp::Asr, op2),
+            Ins::Ror { s, rd, rn, op2, .. } => self.shift(s, rd, rn, ShiftOp::Ror, op2),
+            Ins::Rrx { s, rd, rm, .. } => {
+                let value = self.cpu.reg(rm);
+                let result = ((carry as u32) << 31) | (value >> 1);
+                self.write_result(s, rd, result, value & 1 != 0, None);
+            }
+
+            Ins::Mul { s, rd, rn, rm, .. } => {
+                let result = self.cpu.reg(rn).wrapping_mul(self.cpu.reg(rm));
+                self.write_result(s, rd, result, carry, None);
+            }
+            Ins::Mla { s, rd, rn, rm, ra, .. } => {
+                let result =
+                    self.cpu.reg(rn).wrapping_mul(self.cpu.reg(rm)).wrapping_add(self.cpu.reg(ra));
+                self.write_result(s, rd, result, carry, None);
+            }
+            Ins::Umull { s, rd_lo, rd_hi, rn, rm, .. } => {
+                let result = self.cpu.reg(rn) as u64 * self.cpu.reg(rm) as u64;
+                self.write_long(s, rd_lo, rd_hi, result);
+            }
+            Ins::Umlal { s, rd_lo, rd_hi, rn, rm, .. } => {
+                let product = self.cpu.reg(rn) as u64 * self.cpu.reg(rm) as u64;
+                let result = self.read_long(rd_lo, rd_hi).wrapping_add(product);
+                self.write_long(s, rd_lo, rd_hi, result);
+            }
+            Ins::Smull { s, rd_lo, rd_hi, rn, rm, .. } => {
+                let result = self.cpu.reg(rn) as i32 as i64 * self.cpu.reg(rm) as i32 as i64;
+                self.write_long(s, rd_lo, rd_hi, result as u64);
+            }
+            Ins::Smlal { s, rd_lo, rd_hi, rn, rm, .. } => {
+                let product = self.cpu.reg(rn) as i32 as i64 * self.cpu.reg(rm) as i32 as i64;
+                let result = self.read_long(rd_lo, rd_hi).wrapping_add(product as u64);
+                self.write_long(s, rd_lo, rd_hi, result);
+            }
+            #[cfg(any(
+                feature = "v5t",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            ))]
+            Ins::Clz { rd, rm, .. } => self.write_reg(rd, self.cpu.reg(rm).leading_zeros()),
+
+            Ins::Ldr { rd, addr, .. } => self.load(rd, addr, 4, false)?,
+            Ins::Ldrb { rd, addr, .. } => self.load(rd, addr, 1, false)?,
+            Ins::Ldrt { rd, addr, .. } => self.load(rd, addr, 4, false)?,
+            Ins::Ldrbt { rd, addr, .. } => self.load(rd, addr, 1, false)?,
+            Ins::Ldrh { rd, addr, .. } => self.load(rd, addr, 2, false)?,
+            Ins::Ldrsb { rd, addr, .. } => self.load(rd, addr, 1, true)?,
+            Ins::Ldrsh { rd, addr, .. } => self.load(rd, addr, 2, true)?,
+            Ins::Str { rd, addr, .. } => self.store(rd, addr, 4)?,
+            Ins::Strb { rd, addr, .. } => self.store(rd, addr, 1)?,
+            Ins::Strt { rd, addr, .. } => self.store(rd, addr, 4)?,
+            Ins::Strbt { rd, addr, .. } => self.store(rd, addr, 1)?,
+            Ins::Strh { rd, addr, .. } => self.store(rd, addr, 2)?,
+            Ins::Swp { rd, rd2, rn, .. } => {
+                let addr = self.cpu.reg(rn);
+                let value = self.read_u32(addr)?.rotate_right((addr & 3) * 8);
+                self.write_u32(addr, self.cpu.reg(rd2))?;
+                self.write_reg(rd, value);
+            }
+            Ins::Swpb { rd, rd2, rn, .. } => {
+                let addr = self.cpu.reg(rn);
+                let value = self.read_u8(addr)?;
+                self.write_u8(addr, self.cpu.reg(rd2) as u8)?;
+                self.write_reg(rd, value as u32);
+            }
+            Ins::Ldm { mode, rn, writeback, regs, user_mode, .. } => {
+                self.load_multiple(mode, rn, writeback, regs, user_mode)?
+            }
+            Ins::Stm { mode, rn, writeback, regs, user_mode, .. } => {
+                self.store_multiple(mode, rn, writeback, regs, user_mode)?
+            }
+            Ins::Push { regs, .. } => {
+                self.store_multiple(LdmStmMode::Db, Reg::Sp, true, regs, false)?
+            }
+            Ins::Pop { regs, .. } => {
+                self.load_multiple(LdmStmMode::Ia, Reg::Sp, true, regs, false)?
+            }
+
+            Ins::B { target, .. } => self.branch = Some((target.addr, thumb)),
+            Ins::Bl { target, .. } => {
+                self.cpu.set(Reg::Lr, next | thumb as u32);
+                self.branch = Some((target.addr, thumb));
+            }
+            #[cfg(any(
+                feature = "v4t",
+                feature = "v5t",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            ))]
+            Ins::Bx { rm, .. } => self.branch_exchange(self.cpu.reg(rm)),
+            #[cfg(any(
+                feature = "v5t",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            ))]
+            Ins::Blx { target, .. } => {
+                let target = match target {
+                    // Switches between ARM and Thumb
+                    BlxTarget::Direct(target) => target.addr | !thumb as u32,
+                    BlxTarget::Indirect(rm) => self.cpu.reg(rm),
+                };
+                self.cpu.set(Reg::Lr, next | thumb as u32);
+                self.branch_exchange(target);
+            }
+
+            Ins::Mrs { rd, status_reg, .. } => {
+                let value = match status_reg {
+                    StatusReg::Cpsr => self.cpu.cpsr(),
+                    StatusReg::Spsr => self.cpu.spsr().unwrap_or(self.cpu.cpsr()),
+                };
+                self.write_reg(rd, value);
+            }
+            Ins::Msr { status_fields, op2, .. } => {
+                let value = match op2 {
+                    MsrOp2::Imm(imm) => imm,
+                    MsrOp2::Reg(reg) => self.cpu.reg(reg),
+                };
+                let StatusFields { reg, c, x, s, f } = status_fields;
+                let mut mask = (c as u32 * 0xff)
+                    | (x as u32 * 0xff00)
+                    | (s as u32 * 0xff0000)
+                    | (f as u32 * 0xff000000);
+                match reg {
+                    StatusReg::Cpsr => {
+                        if self.cpu.mode() == CpuMode::User {
+                            mask &= 0xff000000;
+                        }
+                        // Only branches can switch between ARM and Thumb
+                        mask &= !CPSR_T;
+                        self.cpu.set_cpsr((self.cpu.cpsr() & !mask) | (value & mask));
+                    }
+                    StatusReg::Spsr => {
+                        if let Some(spsr) = self.cpu.spsr() {
+                            self.cpu.set_spsr((spsr & !mask) | (value & mask));
+                        }
+                    }
+                }
+            }
+
+            Ins::Svc { .. } => self.exception(Exception::Svc, next),
+            #[cfg(any(
+                feature = "v4t",
+                feature = "v5t",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            ))]
+            Ins::Udf { .. } => self.exception(Exception::Undefined, next),
+            Ins::Illegal => self.exception(Exception::Undefined, next),
+            #[cfg(any(
+                feature = "v5t",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            ))]
+            Ins::Bkpt { .. } => self.exception(Exception::PrefetchAbort, pc.wrapping_add(4)),
+
+            _ => {
+                #[cfg(any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                ))]
+                if let Some(result) = self.execute_dsp(ins) {
+                    return result;
+                }
+                #[cfg(all(
+                    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                    any(
+                        feature = "v5te",
+                        feature = "v5tej",
+                        feature = "v6",
+                        feature = "v6k",
+                        feature = "v7"
+                    )
+                ))]
+                if let Some(result) = self.execute_vfp(ins) {
+                    return result;
+                }
+                return Err(EmuError::Unimplemented(ins.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the values of `rn` and `op2` and the carry out of the shifter.
+    fn operands(&self, rn: Reg, op2: Op2) -> (u32, u32, bool) {
+        let a = match op2 {
+            // Like Thumb ADR, which adds to the word-aligned PC
+            Op2::Imm(_) if rn == Reg::Pc => self.cpu.reg(rn) & !3,
+            Op2::ShiftReg(_) => self.read_shifted(rn),
+            _ => self.cpu.reg(rn),
+        };
+        let (b, carry) = self.op2(op2);
+        (a, b, carry)
+    }
+
+    /// Returns the value of `op2` and the carry out of the shifter.
+    fn op2(&self, op2: Op2) -> (u32, bool) {
+        let carry = self.cpu.flag(Flag::C);
+        match op2 {
+            // The carry is bit 31 if the immediate was rotated, which is always the case for
+            // values that don't fit in 8 bits
+            Op2::Imm(imm) if imm > 0xff => (imm, imm & 0x8000_0000 != 0),
+            Op2::Imm(imm) => (imm, carry),
+            Op2::ShiftImm(ShiftImm { rm, shift_op, imm }) => {
+                let value = self.cpu.reg(rm);
+                match (shift_op, imm) {
+                    (ShiftOp::Lsl, _) => shift_carry(value, shift_op, imm, carry),
+                    // RRX
+                    (ShiftOp::Ror, 0) => (((carry as u32) << 31) | (value >> 1), value & 1 != 0),
+                    (_, 0) => shift_carry(value, shift_op, 32, carry),
+                    _ => shift_carry(value, shift_op, imm, carry),
+                }
+            }
+            Op2::ShiftReg(ShiftReg { rm, shift_op, rs }) => {
+                let amount = self.cpu.reg(rs) & 0xff;
+                shift_carry(self.read_shifted(rm), shift_op, amount, carry)
+            }
+        }
+    }
+
+    /// Reads a register of an instruction with a register-specified shift, which reads the PC
+    /// one word further ahead in ARM mode.
+    fn read_shifted(&self, reg: Reg) -> u32 {
+        let value = self.cpu.reg(reg);
+        if reg == Reg::Pc && !self.cpu.thumb() {
+            value.wrapping_add(4)
+        } else {
+            value
+        }
+    }
+
+    fn shift(&mut self, s: bool, rd: Reg, rn: Reg, shift_op: ShiftOp, op2: Op2Shift) {
+        let (value, amount) = match op2 {
+            Op2Shift::Imm(imm) => (self.cpu.reg(rn), imm),
+            Op2Shift::Reg(rs) => (self.read_shifted(rn), self.cpu.reg(rs) & 0xff),
+        };
+        let (result, carry) = shift_carry(value, shift_op, amount, self.cpu.flag(Flag::C));
+        self.write_result(s, rd, result, carry, None);
+    }
+
+    /// Writes the result of a data-processing instruction to `rd`, and sets the flags if `s` is
+    /// true. Setting the flags while writing to the PC restores the CPSR from the SPSR instead.
+    fn write_result(&mut self, s: bool, rd: Reg, result: u32, carry: bool, overflow: Option<bool>) {
+        if s && rd == Reg::Pc {
+            if let Some(spsr) = self.cpu.spsr() {
+                self.cpu.set_cpsr(spsr);
+            }
+        } else if s {
+            self.set_flags(result, carry, overflow);
+        }
+        self.write_reg(rd, result);
+    }
+
+    /// Sets N and Z from `result`, C to `carry`, and V to `overflow` if it's not `None`.
+    fn set_flags(&mut self, result: u32, carry: bool, overflow: Option<bool>) {
+        self.cpu.set_nz(result);
+        self.cpu.set_flag(Flag::C, carry);
+        if let Some(overflow) = overflow {
+            self.cpu.set_flag(Flag::V, overflow);
+        }
+    }
+
+    fn read_long(&self, rd_lo: Reg, rd_hi: Reg) -> u64 {
+        self.cpu.reg(rd_lo) as u64 | ((self.cpu.reg(rd_hi) as u64) << 32)
+    }
+
+    /// Writes the result of a long multiply, and sets N and Z if `s` is true.
+    fn write_long(&mut self, s: bool, rd_lo: Reg, rd_hi: Reg, result: u64) {
+        if s {
+            self.cpu.set_flag(Flag::N, result & (1 << 63) != 0);
+            self.cpu.set_flag(Flag::Z, result == 0);
+        }
+        self.write_reg(rd_lo, result as u32);
+        self.write_reg(rd_hi, (result >> 32) as u32);
+    }
+
+    /// Writes to a register, where writing to the PC branches without switching between ARM and
+    /// Thumb.
+    pub(super) fn write_reg(&mut self, reg: Reg, value: u32) {
+        if reg == Reg::Pc {
+            let thumb = self.cpu.thumb();
+            let align = if thumb { !1 } else { !3 };
+            self.branch = Some((value & align, thumb));
+        } else {
+            self.cpu.set(reg, value);
+        }
+    }
+
+    /// Branches to `addr`, which is Thumb code if bit 0 is set.
+    fn branch_exchange(&mut self, addr: u32) {
+        let thumb = addr & 1 != 0;
+        let align = if thumb { !1 } else { !3 };
+        self.branch = Some((addr & align, thumb));
+    }
+
+    /// Writes a loaded value to `reg`, where loads to the PC switch between ARM and Thumb in
+    /// ARMv5T and later.
+    pub(super) fn write_loaded(&mut self, reg: Reg, value: u32) {
+        let interworking = match self.options.version {
+            #[cfg(feature = "v4")]
+            Version::V4 => false,
+            #[cfg(feature = "v4t")]
+            Version::V4T => false,
+            #[allow(unreachable_patterns)]
+            _ => true,
+        };
+        if reg == Reg::Pc && interworking {
+            self.branch_exchange(value);
+        } else {
+            self.write_reg(reg, value);
+        }
+    }
+
+    /// Returns the value that a store reads from `reg`, where the PC is 12 bytes ahead in ARM
+    /// mode like on the ARM7TDMI and ARM9.
+    fn store_value(&self, reg: Reg) -> u32 {
+        let value = self.cpu.reg(reg);
+        if reg == Reg::Pc && !self.cpu.thumb() {
+            value.wrapping_add(4)
+        } else {
+            value
+        }
+    }
+
+    /// Returns the address of a load or store of `size` bytes, and the base register with its
+    /// written-back value.
+    pub(super) fn address(
+        &self,
+        address: impl Into<AccessAddress>,
+        size: u32,
+    ) -> (u32, Option<(Reg, u32)>) {
+        let address = address.into();
+        let range = address.compute_address(size, &self.cpu);
+        (range.start, range.writeback.map(|value| (address.base, value)))
+    }
+
+    pub(super) fn writeback(&mut self, writeback: Option<(Reg, u32)>) {
+        if let Some((base, value)) = writeback {
+            self.write_reg(base, value);
+        }
+    }
+
+    /// Loads `size` bytes into `rd`, where unaligned words are rotated like in ARMv5 and earlier.
+    fn load(
+        &mut self,
+        rd: Reg,
+        address: impl Into<AccessAddress>,
+        size: u32,
+        signed: bool,
+    ) -> Result<(), EmuError> {
+        let (addr, writeback) = self.address(address, size);
+        let value = match (size, signed) {
+            (1, false) => self.read_u8(addr)? as u32,
+            (1, true) => self.read_u8(addr)? as i8 as u32,
+            (2, false) => self.read_u16(addr)? as u32,
+            (2, true) => self.read_u16(addr)? as i16 as u32,
+            _ => self.read_u32(addr)?.rotate_right((addr & 3) * 8),
+        };
+        self.writeback(writeback);
+        self.write_loaded(rd, value);
+        Ok(())
+    }
+
+    /// Stores the lowest `size` bytes of `rd`.
+    fn store(
+        &mut self,
+        rd: Reg,
+        address: impl Into<AccessAddress>,
+        size: u32,
+    ) -> Result<(), EmuError> {
+        let value = self.store_value(rd);
+        let (addr, writeback) = self.address(address, size);
+        match size {
+            1 => self.write_u8(addr, value as u8)?,
+            2 => self.write_u16(addr, value as u16)?,
+            _ => self.write_u32(addr, value)?,
+        }
+        self.writeback(writeback);
+        Ok(())
+    }
+
+    /// Executes an LDM. If `user_mode` is true, the user mode registers are loaded, or the CPSR
+    /// is restored from the SPSR if the PC is loaded.
+    fn load_multiple(
+        &mut self,
+        mode: LdmStmMode,
+        rn: Reg,
+        writeback: bool,
+        regs: RegList,
+        user_mode: bool,
+    ) -> Result<(), EmuError> {
+        let count = regs.iter().count() as u32;
+        let range = mode.compute_address(rn, count, writeback, &self.cpu);
+        let mut values = [0; 16];
+        for (i, value) in values.iter_mut().take(count as usize).enumerate() {
+            *value = self.read_u32(range.start.wrapping_add(i as u32 * 4))?;
+        }
+        if let Some(value) = range.writeback {
+            self.write_reg(rn, value);
+        }
+        let exception_return = user_mode && regs.contains(Reg::Pc);
+        for (reg, value) in regs.iter().zip(values) {
+            match reg {
+                Reg::Pc if exception_return => {
+                    if let Some(spsr) = self.cpu.spsr() {
+                        self.cpu.set_cpsr(spsr);
+                    }
+                    self.write_reg(reg, value);
+                }
+                _ if user_mode && !exception_return => {
+                    self.cpu.set_banked(reg, CpuMode::User, value)
+                }
+                _ => self.write_loaded(reg, value),
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes an STM. If `user_mode` is true, the user mode registers are stored.
+    fn store_multiple(
+        &mut self,
+        mode: LdmStmMode,
+        rn: Reg,
+        writeback: bool,
+        regs: RegList,
+        user_mode: bool,
+    ) -> Result<(), EmuError> {
+        let count = regs.iter().count() as u32;
+        let range = mode.compute_address(rn, count, writeback, &self.cpu);
+        for (i, reg) in regs.iter().enumerate() {
+            let value = match reg {
+                Reg::Pc => self.store_value(reg),
+                _ if user_mode => self.cpu.banked(reg, CpuMode::User),
+                _ => self.cpu.get(reg),
+            };
+            self.write_u32(range.start.wrapping_add(i as u32 * 4), value)?;
+        }
+        if let Some(value) = range.writeback {
+            self.write_reg(rn, value);
+        }
+        Ok(())
+    }
+
+    /// Takes `exception` with LR set to `return_addr`.
+    fn exception(&mut self, exception: Exception, return_addr: u32) {
+        let vector = self.enter_exception(exception, return_addr);
+        self.branch = Some((vector, false));
+    }
+}
+
+/// Returns the sum of `a`, `b` and `carry`, and the carry and overflow flags.
+fn add_with_carry(a: u32, b: u32, carry: bool) -> (u32, bool, bool) {
+    let (sum, carry1) = a.overflowing_add(b);
+    let (sum, carry2) = sum.overflowing_add(carry as u32);
+    let overflow = ((a ^ sum) & (b ^ sum)) & 0x8000_0000 != 0;
+    (sum, carry1 || carry2, overflow)
+}
+
+/// Shifts `value` by `amount` bits like a register-specified shift, and returns the result and
+/// the carry out. The carry is unchanged if `amount` is zero.
+fn shift_carry(value: u32, shift_op: ShiftOp, amount: u32, carry: bool) -> (u32, bool) {
+    let bit = |n: u32| (value >> n) & 1 != 0;
+    match (shift_op, amount) {
+        (_, 0) => (value, carry),
+        (ShiftOp::Lsl, 1..32) => (value << amount, bit(32 - amount)),
+        (ShiftOp::Lsl, 32) => (0, bit(0)),
+        (ShiftOp::Lsl, _) => (0, false),
+        (ShiftOp::Lsr, 1..32) => (value >> amount, bit(amount - 1)),
+        (ShiftOp::Lsr, 32) => (0, bit(31)),
+        (ShiftOp::Lsr, _) => (0, false),
+        (ShiftOp::Asr, 1..32) => (((value as i32) >> amount) as u32, bit(amount - 1)),
+        (ShiftOp::Asr, _) => (((value as i32) >> 31) as u32, bit(31)),
+        (ShiftOp::Ror, _) => match amount & 31 {
+            0 => (value, bit(31)),
+            amount => (value.rotate_right(amount), bit(amount - 1)),
+        },
+    }
+}
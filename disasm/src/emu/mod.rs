@@ -0,0 +1,269 @@
+mod bus;
+mod cpu;
+#[cfg(any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7"))]
+mod dsp;
+mod exec;
+#[cfg(all(
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+))]
+mod vfp;
+
+pub use bus::*;
+pub use cpu::*;
+
+use crate::*;
+
+/// Error returned when an [`Emu`] can't execute an instruction. The PC is left at the
+/// instruction, but registers and memory it wrote before failing are not restored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EmuError {
+    /// The bus failed to read or write `addr`
+    BusFault { addr: u32 },
+    /// The instruction is not supported by the emulator, such as coprocessor instructions other
+    /// than VFP and instructions newer than ARMv5TE
+    Unimplemented(Ins),
+    /// The maximum number of steps was executed without reaching the target address
+    StepLimit,
+}
+
+impl core::fmt::Display for EmuError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EmuError::BusFault { addr } => write!(f, "bus fault at {addr:#010x}"),
+            EmuError::Unimplemented(ins) => write!(f, "unimplemented instruction {ins:?}"),
+            EmuError::StepLimit => f.write_str("step limit reached"),
+        }
+    }
+}
+
+impl core::error::Error for EmuError {}
+
+/// Exceptions taken by the emulator, which switch mode and jump to a vector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Exception {
+    Undefined,
+    Svc,
+    /// Raised by BKPT
+    #[cfg(any(
+        feature = "v5t",
+        feature = "v5te",
+        feature = "v5tej",
+        feature = "v6",
+        feature = "v6k",
+        feature = "v7"
+    ))]
+    PrefetchAbort,
+    Irq,
+    Fiq,
+}
+
+impl Exception {
+    fn vector(self) -> u32 {
+        match self {
+            Exception::Undefined => 0x04,
+            Exception::Svc => 0x08,
+            #[cfg(any(
+                feature = "v5t",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            ))]
+            Exception::PrefetchAbort => 0x0c,
+            Exception::Irq => 0x18,
+            Exception::Fiq => 0x1c,
+        }
+    }
+
+    fn mode(self) -> CpuMode {
+        match self {
+            Exception::Undefined => CpuMode::Undefined,
+            Exception::Svc => CpuMode::Supervisor,
+            #[cfg(any(
+                feature = "v5t",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            ))]
+            Exception::PrefetchAbort => CpuMode::Abort,
+            Exception::Irq => CpuMode::Irq,
+            Exception::Fiq => CpuMode::Fiq,
+        }
+    }
+}
+
+/// Executes instructions decoded by [`parse_arm`] and [`parse_thumb`] on a [`Cpu`], reading and
+/// writing memory through a [`Bus`]. Implements the ARMv4T-ARMv5TE instruction sets and VFPv2
+/// arithmetic, and returns [`EmuError::Unimplemented`] for anything else.
+pub struct Emu<B> {
+    pub cpu: Cpu,
+    pub bus: B,
+    /// Base address of the exception vectors, such as 0xffff0000 for high vectors
+    pub vector_base: u32,
+    options: Options,
+    /// Address and Thumb state to continue at, if the current instruction branches
+    branch: Option<(u32, bool)>,
+}
+
+impl<B: Bus> Emu<B> {
+    /// Return address passed to functions by [`Self::call`], which is never executed.
+    pub const RETURN_ADDRESS: u32 = 0xffff_fffc;
+
+    /// Creates an emulator in the reset state of [`Cpu::new`]. The `options` decide which
+    /// instructions are decoded, and the version also decides whether loads to the PC can switch
+    /// between ARM and Thumb like in ARMv5T and later.
+    pub fn new(bus: B, options: Options) -> Self {
+        Self { cpu: Cpu::new(), bus, vector_base: 0, options, branch: None }
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Decodes the instruction at the PC and returns it with its size in bytes.
+    pub fn fetch(&mut self) -> Result<(Ins, u32), EmuError> {
+        let pc = self.cpu.pc();
+        if self.cpu.thumb() {
+            let first = self.read_u16(pc)? as u32;
+            // 32-bit Thumb instructions start with 0b11101, 0b11110 or 0b11111
+            let second = if first >> 11 >= 0b11101 {
+                self.read_u16(pc.wrapping_add(2))? as u32
+            } else {
+                0
+            };
+            Ok(parse_thumb(first | (second << 16), pc, &self.options))
+        } else {
+            let code = self.read_u32(pc)?;
+            Ok((parse_arm(code, pc, &self.options), 4))
+        }
+    }
+
+    /// Fetches and executes one instruction, and returns it.
+    pub fn step(&mut self) -> Result<Ins, EmuError> {
+        let (ins, size) = self.fetch()?;
+        self.execute(&ins, size)?;
+        Ok(ins)
+    }
+
+    /// Executes `ins` as the instruction at the PC whose encoding is `size` bytes long, then
+    /// moves the PC to the next instruction or the branch target. Instructions whose condition
+    /// fails do nothing.
+    pub fn execute(&mut self, ins: &Ins, size: u32) -> Result<(), EmuError> {
+        self.branch = None;
+        if self.cpu.cond_passed(ins.cond()) {
+            self.execute_ins(ins, size)?;
+        }
+        match self.branch.take() {
+            Some((addr, thumb)) => {
+                self.cpu.set_thumb(thumb);
+                self.cpu.set_pc(addr);
+            }
+            None => self.cpu.set_pc(self.cpu.pc().wrapping_add(size)),
+        }
+        Ok(())
+    }
+
+    /// Executes instructions until the PC is `addr`, and returns the number of instructions
+    /// executed. Fails with [`EmuError::StepLimit`] after `max_steps` instructions.
+    pub fn run_until(&mut self, addr: u32, max_steps: usize) -> Result<usize, EmuError> {
+        let mut steps = 0;
+        while self.cpu.pc() != addr {
+            if steps == max_steps {
+                return Err(EmuError::StepLimit);
+            }
+            self.step()?;
+            steps += 1;
+        }
+        Ok(steps)
+    }
+
+    /// Calls the function at `addr` with `args` according to the AAPCS, and returns R0 when it
+    /// returns to [`Self::RETURN_ADDRESS`]. Bit 0 of `addr` selects Thumb code. Arguments after
+    /// the fourth are passed on the stack, which must already be set up in SP.
+    pub fn call(&mut self, addr: u32, args: &[u32], max_steps: usize) -> Result<u32, EmuError> {
+        let sp = self.cpu.get(Reg::Sp);
+        for (i, &arg) in args.iter().take(4).enumerate() {
+            self.cpu.set(Reg::parse(i as u32, 0), arg);
+        }
+        if args.len() > 4 {
+            let stack_args = &args[4..];
+            let args_sp = sp.wrapping_sub(stack_args.len() as u32 * 4) & !7;
+            for (i, &arg) in stack_args.iter().enumerate() {
+                self.write_u32(args_sp.wrapping_add(i as u32 * 4), arg)?;
+            }
+            self.cpu.set(Reg::Sp, args_sp);
+        }
+        self.cpu.set(Reg::Lr, Self::RETURN_ADDRESS);
+        self.cpu.set_thumb(addr & 1 != 0);
+        self.cpu.set_pc(addr & !1);
+
+        self.run_until(Self::RETURN_ADDRESS, max_steps)?;
+        self.cpu.set(Reg::Sp, sp);
+        Ok(self.cpu.get(Reg::R0))
+    }
+
+    /// Takes an IRQ, or an FIQ if `fiq` is true, before the instruction at the PC. Returns false
+    /// and does nothing if the interrupt is disabled in the CPSR.
+    pub fn interrupt(&mut self, fiq: bool) -> bool {
+        let (exception, mask) = if fiq {
+            (Exception::Fiq, CPSR_F)
+        } else {
+            (Exception::Irq, CPSR_I)
+        };
+        if self.cpu.cpsr() & mask != 0 {
+            return false;
+        }
+        // Handlers return with `subs pc, lr, #4`
+        let vector = self.enter_exception(exception, self.cpu.pc().wrapping_add(4));
+        self.cpu.set_pc(vector);
+        true
+    }
+
+    /// Switches to the mode of `exception` in ARM code with LR set to `return_addr`, and returns
+    /// the address of its vector.
+    fn enter_exception(&mut self, exception: Exception, return_addr: u32) -> u32 {
+        let cpsr = self.cpu.cpsr();
+        let mode = exception.mode();
+        self.cpu.set_mode(mode);
+        self.cpu.set_spsr(cpsr);
+        self.cpu.set(Reg::Lr, return_addr);
+        self.cpu.set_thumb(false);
+        self.cpu.set_cpsr_bits(CPSR_I, true);
+        if mode == CpuMode::Fiq {
+            self.cpu.set_cpsr_bits(CPSR_F, true);
+        }
+        self.vector_base.wrapping_add(exception.vector())
+    }
+
+    fn read_u8(&mut self, addr: u32) -> Result<u8, EmuError> {
+        self.bus.read_u8(addr).ok_or(EmuError::BusFault { addr })
+    }
+
+    fn read_u16(&mut self, addr: u32) -> Result<u16, EmuError> {
+        let addr = addr & !1;
+        self.bus.read_u16(addr).ok_or(EmuError::BusFault { addr })
+    }
+
+    fn read_u32(&mut self, addr: u32) -> Result<u32, EmuError> {
+        let addr = addr & !3;
+        self.bus.read_u32(addr).ok_or(EmuError::BusFault { addr })
+    }
+
+    fn write_u8(&mut self, addr: u32, value: u8) -> Result<(), EmuError> {
+        self.bus.write_u8(addr, value).ok_or(EmuError::BusFault { addr })
+    }
+
+    fn write_u16(&mut self, addr: u32, value: u16) -> Result<(), EmuError> {
+        let addr = addr & !1;
+        self.bus.write_u16(addr, value).ok_or(EmuError::BusFault { addr })
+    }
+
+    fn write_u32(&mut self, addr: u32, value: u32) -> Result<(), EmuError> {
+        let addr = addr & !3;
+        self.bus.write_u32(addr, value).ok_or(EmuError::BusFault { addr })
+    }
+}
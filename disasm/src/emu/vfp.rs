@@ -0,0 +1,310 @@
+use core::cmp::Ordering;
+
+use crate::*;
+
+/// Values of at least 2^52 have no fractional bits
+const MIN_INTEGRAL_F64: f64 = 4503599627370496.0;
+
+impl<B: Bus> Emu<B> {
+    /// Executes the VFPv2 instructions, or returns `None` if `ins` is not one of them. Arithmetic
+    /// rounds to nearest regardless of the FPSCR, and floating-point exceptions are not raised.
+    pub(super) fn execute_vfp(&mut self, ins: &Ins) -> Option<Result<(), EmuError>> {
+        match *ins {
+            Ins::VaddF32 { sd, sn, sm, .. } => self.set_f32(sd, self.f32(sn) + self.f32(sm)),
+            Ins::VaddF64 { dd, dn, dm, .. } => self.set_f64(dd, self.f64(dn) + self.f64(dm)),
+            Ins::VsubF32 { sd, sn, sm, .. } => self.set_f32(sd, self.f32(sn) - self.f32(sm)),
+            Ins::VsubF64 { dd, dn, dm, .. } => self.set_f64(dd, self.f64(dn) - self.f64(dm)),
+            Ins::VmulF32 { sd, sn, sm, .. } => self.set_f32(sd, self.f32(sn) * self.f32(sm)),
+            Ins::VmulF64 { dd, dn, dm, .. } => self.set_f64(dd, self.f64(dn) * self.f64(dm)),
+            Ins::VnmulF32 { sd, sn, sm, .. } => self.set_f32(sd, -(self.f32(sn) * self.f32(sm))),
+            Ins::VnmulF64 { dd, dn, dm, .. } => self.set_f64(dd, -(self.f64(dn) * self.f64(dm))),
+            Ins::VdivF32 { sd, sn, sm, .. } => self.set_f32(sd, self.f32(sn) / self.f32(sm)),
+            Ins::VdivF64 { dd, dn, dm, .. } => self.set_f64(dd, self.f64(dn) / self.f64(dm)),
+            Ins::VmlaF32 { sd, sn, sm, .. } => {
+                self.set_f32(sd, self.f32(sd) + self.f32(sn) * self.f32(sm))
+            }
+            Ins::VmlaF64 { dd, dn, dm, .. } => {
+                self.set_f64(dd, self.f64(dd) + self.f64(dn) * self.f64(dm))
+            }
+            Ins::VmlsF32 { sd, sn, sm, .. } => {
+                self.set_f32(sd, self.f32(sd) - self.f32(sn) * self.f32(sm))
+            }
+            Ins::VmlsF64 { dd, dn, dm, .. } => {
+                self.set_f64(dd, self.f64(dd) - self.f64(dn) * self.f64(dm))
+            }
+            Ins::VnmlaF32 { sd, sn, sm, .. } => {
+                self.set_f32(sd, -self.f32(sd) - self.f32(sn) * self.f32(sm))
+            }
+            Ins::VnmlaF64 { dd, dn, dm, .. } => {
+                self.set_f64(dd, -self.f64(dd) - self.f64(dn) * self.f64(dm))
+            }
+            Ins::VnmlsF32 { sd, sn, sm, .. } => {
+                self.set_f32(sd, -self.f32(sd) + self.f32(sn) * self.f32(sm))
+            }
+            Ins::VnmlsF64 { dd, dn, dm, .. } => {
+                self.set_f64(dd, -self.f64(dd) + self.f64(dn) * self.f64(dm))
+            }
+            #[cfg(feature = "std")]
+            Ins::VsqrtF32 { sd, sm, .. } => self.set_f32(sd, self.f32(sm).sqrt()),
+            #[cfg(feature = "std")]
+            Ins::VsqrtF64 { dd, dm, .. } => self.set_f64(dd, self.f64(dm).sqrt()),
+            // Sign changes don't round, so they are done on the bits
+            Ins::VnegF32 { sd, sm, .. } => self.cpu.set_sreg(sd, self.cpu.sreg(sm) ^ (1 << 31)),
+            Ins::VnegF64 { dd, dm, .. } => self.cpu.set_dreg(dd, self.cpu.dreg(dm) ^ (1 << 63)),
+            Ins::VabsF32 { sd, sm, .. } => self.cpu.set_sreg(sd, self.cpu.sreg(sm) & !(1 << 31)),
+            Ins::VabsF64 { dd, dm, .. } => self.cpu.set_dreg(dd, self.cpu.dreg(dm) & !(1 << 63)),
+            Ins::VmovF32 { sd, sm, .. } => self.cpu.set_sreg(sd, self.cpu.sreg(sm)),
+            Ins::VmovF64 { dd, dm, .. } => self.cpu.set_dreg(dd, self.cpu.dreg(dm)),
+            Ins::VcmpF32 { sd, op2, .. } => {
+                let b = match op2 {
+                    VcmpF32Op2::Zero => 0.0,
+                    VcmpF32Op2::Reg(sm) => self.f32(sm),
+                };
+                self.compare(self.f32(sd).partial_cmp(&b));
+            }
+            Ins::VcmpF64 { dd, op2, .. } => {
+                let b = match op2 {
+                    VcmpF64Op2::Zero => 0.0,
+                    VcmpF64Op2::Reg(dm) => self.f64(dm),
+                };
+                self.compare(self.f64(dd).partial_cmp(&b));
+            }
+
+            Ins::VcvtF64F32 { dd, sm, .. } => self.set_f64(dd, self.f32(sm) as f64),
+            Ins::VcvtF32F64 { sd, dm, .. } => self.set_f32(sd, self.f64(dm) as f32),
+            Ins::VcvtF32S32 { sd, sm, .. } => self.set_f32(sd, self.cpu.sreg(sm) as i32 as f32),
+            Ins::VcvtF32U32 { sd, sm, .. } => self.set_f32(sd, self.cpu.sreg(sm) as f32),
+            Ins::VcvtF64S32 { dd, sm, .. } => self.set_f64(dd, self.cpu.sreg(sm) as i32 as f64),
+            Ins::VcvtF64U32 { dd, sm, .. } => self.set_f64(dd, self.cpu.sreg(sm) as f64),
+            // Casts saturate like VCVT, and convert NaN to zero
+            Ins::VcvtS32F32 { round_zero, sd, sm, .. } => {
+                let value = self.round(self.f32(sm) as f64, round_zero);
+                self.cpu.set_sreg(sd, value as i32 as u32);
+            }
+            Ins::VcvtU32F32 { round_zero, sd, sm, .. } => {
+                let value = self.round(self.f32(sm) as f64, round_zero);
+                self.cpu.set_sreg(sd, value as u32);
+            }
+            Ins::VcvtS32F64 { round_zero, sd, dm, .. } => {
+                let value = self.round(self.f64(dm), round_zero);
+                self.cpu.set_sreg(sd, value as i32 as u32);
+            }
+            Ins::VcvtU32F64 { round_zero, sd, dm, .. } => {
+                let value = self.round(self.f64(dm), round_zero);
+                self.cpu.set_sreg(sd, value as u32);
+            }
+
+            Ins::VmovF32Reg { sn, rt, .. } => self.cpu.set_sreg(sn, self.cpu.reg(rt)),
+            Ins::VmovRegF32 { rt, sn, .. } => self.write_reg(rt, self.cpu.sreg(sn)),
+            Ins::VmovF32RegDual { sm, sm2, rt, rt2, .. } => {
+                self.cpu.set_sreg(sm, self.cpu.reg(rt));
+                self.cpu.set_sreg(sm2, self.cpu.reg(rt2));
+            }
+            Ins::VmovRegF32Dual { rt, rt2, sm, sm2, .. } => {
+                self.write_reg(rt, self.cpu.sreg(sm));
+                self.write_reg(rt2, self.cpu.sreg(sm2));
+            }
+            Ins::VmovF64Reg { dm, rt, rt2, .. } => {
+                let value = self.cpu.reg(rt) as u64 | ((self.cpu.reg(rt2) as u64) << 32);
+                self.cpu.set_dreg(dm, value);
+            }
+            Ins::VmovRegF64 { rt, rt2, dm, .. } => {
+                let value = self.cpu.dreg(dm);
+                self.write_reg(rt, value as u32);
+                self.write_reg(rt2, (value >> 32) as u32);
+            }
+            Ins::Vmov32Reg { dd: DregIndex { dreg, index }, rt, .. } => {
+                let shift = index * 32;
+                let value = self.cpu.dreg(dreg) & !(0xffff_ffff << shift);
+                self.cpu.set_dreg(dreg, value | ((self.cpu.reg(rt) as u64) << shift));
+            }
+            Ins::VmovReg32 { rt, dn: DregIndex { dreg, index }, .. } => {
+                self.write_reg(rt, (self.cpu.dreg(dreg) >> (index * 32)) as u32)
+            }
+            Ins::Vmrs { rd: Reg::Pc, .. } => {
+                // Copies the comparison flags to the CPSR
+                let flags = self.cpu.fpscr() & 0xf000_0000;
+                self.cpu.set_cpsr((self.cpu.cpsr() & 0x0fff_ffff) | flags);
+            }
+            Ins::Vmrs { rd, .. } => self.cpu.set(rd, self.cpu.fpscr()),
+            Ins::Vmsr { rd, .. } => self.cpu.set_fpscr(self.cpu.reg(rd)),
+
+            Ins::VldrF32 { sd, addr, .. } => return Some(self.load_sreg(sd, addr)),
+            Ins::VldrF64 { dd, addr, .. } => return Some(self.load_dreg(dd, addr)),
+            Ins::VstrF32 { sd, addr, .. } => return Some(self.store_sreg(sd, addr)),
+            Ins::VstrF64 { dd, addr, .. } => return Some(self.store_dreg(dd, addr)),
+            Ins::VldmF32 { mode, rn, writeback, regs, .. } => {
+                return Some(self.load_sregs(mode.into(), rn, writeback, regs));
+            }
+            Ins::VldmF64 { mode, rn, writeback, regs, .. } => {
+                return Some(self.load_dregs(mode.into(), rn, writeback, regs));
+            }
+            Ins::VstmF32 { mode, rn, writeback, regs, .. } => {
+                return Some(self.store_sregs(mode.into(), rn, writeback, regs));
+            }
+            Ins::VstmF64 { mode, rn, writeback, regs, .. } => {
+                return Some(self.store_dregs(mode.into(), rn, writeback, regs));
+            }
+            Ins::VpushF32 { regs, .. } => {
+                return Some(self.store_sregs(LdmStmMode::Db, Reg::Sp, true, regs));
+            }
+            Ins::VpushF64 { regs, .. } => {
+                return Some(self.store_dregs(LdmStmMode::Db, Reg::Sp, true, regs));
+            }
+            Ins::VpopF32 { regs, .. } => {
+                return Some(self.load_sregs(LdmStmMode::Ia, Reg::Sp, true, regs));
+            }
+            Ins::VpopF64 { regs, .. } => {
+                return Some(self.load_dregs(LdmStmMode::Ia, Reg::Sp, true, regs));
+            }
+            _ => return None,
+        }
+        Some(Ok(()))
+    }
+
+    fn f32(&self, sreg: Sreg) -> f32 {
+        f32::from_bits(self.cpu.sreg(sreg))
+    }
+
+    fn set_f32(&mut self, sreg: Sreg, value: f32) {
+        self.cpu.set_sreg(sreg, value.to_bits());
+    }
+
+    fn f64(&self, dreg: Dreg) -> f64 {
+        f64::from_bits(self.cpu.dreg(dreg))
+    }
+
+    fn set_f64(&mut self, dreg: Dreg, value: f64) {
+        self.cpu.set_dreg(dreg, value.to_bits());
+    }
+
+    /// Sets the N, Z, C and V flags of the FPSCR from a comparison, where `None` is unordered.
+    fn compare(&mut self, ordering: Option<Ordering>) {
+        let flags = match ordering {
+            Some(Ordering::Less) => 0b1000,
+            Some(Ordering::Equal) => 0b0110,
+            Some(Ordering::Greater) => 0b0010,
+            None => 0b0011,
+        };
+        self.cpu.set_fpscr((self.cpu.fpscr() & 0x0fff_ffff) | (flags << 28));
+    }
+
+    /// Rounds `value` to an integer. The `round_zero` field of VCVT is set for VCVTR, which uses
+    /// the rounding mode of the FPSCR, and VCVT rounds towards zero otherwise.
+    fn round(&self, value: f64, round_zero: bool) -> f64 {
+        let mode = if round_zero { (self.cpu.fpscr() >> 22) & 3 } else { 3 };
+        // Also passes NaN through
+        if !(value > -MIN_INTEGRAL_F64 && value < MIN_INTEGRAL_F64) {
+            return value;
+        }
+        let trunc = value as i64;
+        let frac = value - trunc as f64;
+        let adjust = match mode {
+            // Round to nearest, ties to even
+            0 if frac > 0.5 || (frac == 0.5 && trunc & 1 != 0) => 1,
+            0 if frac < -0.5 || (frac == -0.5 && trunc & 1 != 0) => -1,
+            // Round towards plus infinity
+            1 if frac > 0.0 => 1,
+            // Round towards minus infinity
+            2 if frac < 0.0 => -1,
+            _ => 0,
+        };
+        (trunc + adjust) as f64
+    }
+
+    fn load_sreg(&mut self, sd: Sreg, addr: AddrLdrStr) -> Result<(), EmuError> {
+        let (addr, _) = self.address(addr, 4);
+        let value = self.read_u32(addr)?;
+        self.cpu.set_sreg(sd, value);
+        Ok(())
+    }
+
+    fn load_dreg(&mut self, dd: Dreg, addr: AddrLdrStr) -> Result<(), EmuError> {
+        let (addr, _) = self.address(addr, 8);
+        let low = self.read_u32(addr)?;
+        let high = self.read_u32(addr.wrapping_add(4))?;
+        self.cpu.set_dreg(dd, low as u64 | ((high as u64) << 32));
+        Ok(())
+    }
+
+    fn store_sreg(&mut self, sd: Sreg, addr: AddrLdrStr) -> Result<(), EmuError> {
+        let (addr, _) = self.address(addr, 4);
+        self.write_u32(addr, self.cpu.sreg(sd))
+    }
+
+    fn store_dreg(&mut self, dd: Dreg, addr: AddrLdrStr) -> Result<(), EmuError> {
+        let (addr, _) = self.address(addr, 8);
+        let value = self.cpu.dreg(dd);
+        self.write_u32(addr, value as u32)?;
+        self.write_u32(addr.wrapping_add(4), (value >> 32) as u32)
+    }
+
+    fn load_sregs(
+        &mut self,
+        mode: LdmStmMode,
+        rn: Reg,
+        writeback: bool,
+        regs: SregList,
+    ) -> Result<(), EmuError> {
+        let range = mode.compute_address(rn, regs.iter().count() as u32, writeback, &self.cpu);
+        for (i, sreg) in regs.iter().enumerate() {
+            let value = self.read_u32(range.start.wrapping_add(i as u32 * 4))?;
+            self.cpu.set_sreg(sreg, value);
+        }
+        self.writeback(range.writeback.map(|value| (rn, value)));
+        Ok(())
+    }
+
+    fn load_dregs(
+        &mut self,
+        mode: LdmStmMode,
+        rn: Reg,
+        writeback: bool,
+        regs: DregList,
+    ) -> Result<(), EmuError> {
+        let count = regs.iter().count() as u32 * 2;
+        let range = mode.compute_address(rn, count, writeback, &self.cpu);
+        for (i, dreg) in regs.iter().enumerate() {
+            let addr = range.start.wrapping_add(i as u32 * 8);
+            let low = self.read_u32(addr)?;
+            let high = self.read_u32(addr.wrapping_add(4))?;
+            self.cpu.set_dreg(dreg, low as u64 | ((high as u64) << 32));
+        }
+        self.writeback(range.writeback.map(|value| (rn, value)));
+        Ok(())
+    }
+
+    fn store_sregs(
+        &mut self,
+        mode: LdmStmMode,
+        rn: Reg,
+        writeback: bool,
+        regs: SregList,
+    ) -> Result<(), EmuError> {
+        let range = mode.compute_address(rn, regs.iter().count() as u32, writeback, &self.cpu);
+        for (i, sreg) in regs.iter().enumerate() {
+            self.write_u32(range.start.wrapping_add(i as u32 * 4), self.cpu.sreg(sreg))?;
+        }
+        self.writeback(range.writeback.map(|value| (rn, value)));
+        Ok(())
+    }
+
+    fn store_dregs(
+        &mut self,
+        mode: LdmStmMode,
+        rn: Reg,
+        writeback: bool,
+        regs: DregList,
+    ) -> Result<(), EmuError> {
+        let count = regs.iter().count() as u32 * 2;
+        let range = mode.compute_address(rn, count, writeback, &self.cpu);
+        for (i, dreg) in regs.iter().enumerate() {
+            let addr = range.start.wrapping_add(i as u32 * 8);
+            let value = self.cpu.dreg(dreg);
+            self.write_u32(addr, value as u32)?;
+            self.write_u32(addr.wrapping_add(4), (value >> 32) as u32)?;
+        }
+        self.writeback(range.writeback.map(|value| (rn, value)));
+        Ok(())
+    }
+}
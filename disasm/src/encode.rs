@@ -0,0 +1,19 @@
+/// Error returned by [`crate::Ins::encode_arm`] and [`crate::Ins::encode_thumb`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncodeError {
+    /// The instruction has no encoding in the requested instruction set and version
+    NoEncoding,
+    /// None of the instruction's encodings can represent its operands with the given options
+    InvalidOperands,
+}
+
+impl core::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EncodeError::NoEncoding => f.write_str("instruction has no encoding"),
+            EncodeError::InvalidOperands => f.write_str("operands can't be encoded"),
+        }
+    }
+}
+
+impl core::error::Error for EncodeError {}
@@ -0,0 +1,92 @@
+use crate::*;
+
+/// How an instruction affects the control flow, see [`Ins::flow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlowKind {
+    /// Execution continues with the next instruction.
+    Fallthrough,
+    /// Direct branch which is only taken if a condition passes, otherwise execution continues
+    /// with the next instruction.
+    ConditionalBranch { target: u32 },
+    /// Direct branch which is always taken.
+    Branch { target: u32 },
+    /// Subroutine call, execution continues with the next instruction once the callee returns.
+    /// The target is unknown for calls to a register. If `exchange` is true, the callee at the
+    /// target runs in the other instruction set (ARM/Thumb).
+    Call { target: Option<u32>, exchange: bool },
+    /// Return from a subroutine or exception, such as `bx lr` or `pop {.., pc}`.
+    Return { conditional: bool },
+    /// Jump to an address not known until runtime, such as `bx r0`, `ldr pc, [..]` or a table
+    /// branch.
+    IndirectJump { conditional: bool },
+    /// Generates an exception, such as `svc`, `bkpt` or `udf`. The exception handler normally
+    /// returns to the next instruction.
+    Exception { conditional: bool },
+}
+
+impl FlowKind {
+    pub(crate) fn branch(target: u32, conditional: bool) -> Self {
+        if conditional {
+            Self::ConditionalBranch { target }
+        } else {
+            Self::Branch { target }
+        }
+    }
+
+    /// Returns the direct target address of a branch or call, if known.
+    pub fn target(&self) -> Option<u32> {
+        match self {
+            Self::ConditionalBranch { target } | Self::Branch { target } => Some(*target),
+            Self::Call { target, .. } => *target,
+            _ => None,
+        }
+    }
+
+    /// Returns true if execution may continue with the next instruction, possibly after a call or
+    /// exception handler has returned.
+    pub fn falls_through(&self) -> bool {
+        match self {
+            Self::Fallthrough
+            | Self::ConditionalBranch { .. }
+            | Self::Call { .. }
+            | Self::Exception { .. } => true,
+            Self::Branch { .. } => false,
+            Self::Return { conditional } | Self::IndirectJump { conditional } => *conditional,
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "v5t",
+    feature = "v5te",
+    feature = "v5tej",
+    feature = "v6",
+    feature = "v6k",
+    feature = "v7"
+))]
+impl BlxTarget {
+    /// Returns the destination address if this is a direct target.
+    pub fn direct_addr(&self) -> Option<u32> {
+        match self {
+            BlxTarget::Direct(target) => Some(target.addr),
+            BlxTarget::Indirect(_) => None,
+        }
+    }
+}
+
+impl Op2 {
+    /// Returns true if this operand is the given register without any shift applied.
+    pub fn is_reg(&self, reg: Reg) -> bool {
+        matches!(self, Op2::ShiftImm(ShiftImm { rm, shift_op: ShiftOp::Lsl, imm: 0 }) if *rm == reg)
+    }
+}
+
+impl AddrLdrStr {
+    /// Returns true if this address pops a word off the stack, i.e. `[sp], #0x4`.
+    pub fn is_pop(&self) -> bool {
+        matches!(
+            self,
+            AddrLdrStr::Post(AddrLdrStrPost { rn: Reg::Sp, offset: LdrStrOffset::Imm(4) })
+        )
+    }
+}
@@ -0,0 +1,1391 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_variables)]
+#![allow(clippy::explicit_auto_deref)]
+#![allow(clippy::useless_conversion)]
+#![allow(clippy::unnecessary_cast)]
+use crate::*;
+impl Ins {
+    /// Returns the memory accessed by this instruction, see [`MemAccess`]. Preload
+    /// hints such as PLD are not considered memory accesses.
+    pub fn memory_access(&self) -> Option<MemAccess> {
+        match self {
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldc { l, cond, coproc, crd, dest } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*dest),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldc2 { l, coproc, crd, dest } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*dest),
+                })
+            }
+            Ins::Ldm { mode, cond, rn, writeback, regs, user_mode } => {
+                let (size, count): (u8, u8) = (4, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: *user_mode,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(*mode)),
+                        writeback: *writeback,
+                    },
+                })
+            }
+            Ins::Ldr { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            Ins::Ldrb { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (1, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldrbt { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (1, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: true,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrd { cond, rd, rd2, addr } => {
+                let (size, count): (u8, u8) = (4, (2) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrex { cond, rd, rn, offset } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: true,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm((*offset) as i32),
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexb { cond, rd, rn } => {
+                let (size, count): (u8, u8) = (1, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: true,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexd { cond, rd, rd2, rn } => {
+                let (size, count): (u8, u8) = (4, (2) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: true,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexh { cond, rd, rn } => {
+                let (size, count): (u8, u8) = (2, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: true,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            Ins::Ldrh { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (2, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(feature = "v7")]
+            Ins::Ldrht { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (2, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: true,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            Ins::Ldrsb { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (1, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: true,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(feature = "v7")]
+            Ins::Ldrsbt { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (1, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: true,
+                    exclusive: false,
+                    user_mode: true,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            Ins::Ldrsh { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (2, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: true,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(feature = "v7")]
+            Ins::Ldrsht { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (2, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: true,
+                    exclusive: false,
+                    user_mode: true,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldrt { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: true,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            Ins::Pop { cond, regs } => {
+                let (size, count): (u8, u8) = (4, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: Reg::Sp,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(LdmStmMode::Ia)),
+                        writeback: true,
+                    },
+                })
+            }
+            Ins::Push { cond, regs } => {
+                let (size, count): (u8, u8) = (4, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: Reg::Sp,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(LdmStmMode::Db)),
+                        writeback: true,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Rfe { addr_mode, rn, writeback } => {
+                let (size, count): (u8, u8) = (4, (2) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(*addr_mode)),
+                        writeback: *writeback,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Srs { addr_mode, rn, writeback, mode } => {
+                let (size, count): (u8, u8) = (4, (2) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(*addr_mode)),
+                        writeback: *writeback,
+                    },
+                })
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Stc { l, cond, coproc, crd, dest } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*dest),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Stc2 { l, coproc, crd, dest } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*dest),
+                })
+            }
+            Ins::Stm { mode, cond, rn, writeback, regs, user_mode } => {
+                let (size, count): (u8, u8) = (4, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: *user_mode,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(*mode)),
+                        writeback: *writeback,
+                    },
+                })
+            }
+            Ins::Str { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            Ins::Strb { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (1, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Strbt { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (1, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: true,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strd { cond, rd, rd2, addr } => {
+                let (size, count): (u8, u8) = (4, (2) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strex { cond, rd, rm, rn, offset } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: true,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm((*offset) as i32),
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexb { cond, rd, rm, rn } => {
+                let (size, count): (u8, u8) = (1, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: true,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexd { cond, rd, rm, rm2, rn } => {
+                let (size, count): (u8, u8) = (4, (2) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: true,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexh { cond, rd, rm, rn } => {
+                let (size, count): (u8, u8) = (2, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: true,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            Ins::Strh { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (2, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(feature = "v7")]
+            Ins::Strht { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (2, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: true,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Strt { cond, rd, addr } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: true,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(feature = "arm")]
+            Ins::Swp { cond, rd, rd2, rn } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Swap,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            #[cfg(feature = "arm")]
+            Ins::Swpb { cond, rd, rd2, rn } => {
+                let (size, count): (u8, u8) = (1, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Swap,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbb { cond, rn, rm } => {
+                let (size, count): (u8, u8) = (1, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Reg {
+                            subtract: false,
+                            rm: *rm,
+                            shift_op: ShiftOp::Lsl,
+                            imm: 0u32,
+                        },
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbh { cond, rn, rm } => {
+                let (size, count): (u8, u8) = (2, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Reg {
+                            subtract: false,
+                            rm: *rm,
+                            shift_op: ShiftOp::Lsl,
+                            imm: 1u32,
+                        },
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    },
+                })
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld1 { cond, size, regs, addr } => {
+                let (size, count): (u8, u8) = (
+                    1 << *size as u8,
+                    ((*regs).element_count(*size, 1)) as u8,
+                );
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::neon(*addr, size as u32 * count as u32),
+                })
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld2 { cond, size, regs, addr } => {
+                let (size, count): (u8, u8) = (
+                    1 << *size as u8,
+                    ((*regs).element_count(*size, 2)) as u8,
+                );
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::neon(*addr, size as u32 * count as u32),
+                })
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld3 { cond, size, regs, addr } => {
+                let (size, count): (u8, u8) = (
+                    1 << *size as u8,
+                    ((*regs).element_count(*size, 3)) as u8,
+                );
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::neon(*addr, size as u32 * count as u32),
+                })
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld4 { cond, size, regs, addr } => {
+                let (size, count): (u8, u8) = (
+                    1 << *size as u8,
+                    ((*regs).element_count(*size, 4)) as u8,
+                );
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::neon(*addr, size as u32 * count as u32),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldmF32 { mode, cond, rn, writeback, regs } => {
+                let (size, count): (u8, u8) = (4, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(*mode)),
+                        writeback: *writeback,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldmF64 { mode, cond, rn, writeback, regs } => {
+                let (size, count): (u8, u8) = (8, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(*mode)),
+                        writeback: *writeback,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldrF32 { cond, sd, addr } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldrF64 { cond, dd, addr } => {
+                let (size, count): (u8, u8) = (8, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpopF32 { cond, regs } => {
+                let (size, count): (u8, u8) = (4, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: Reg::Sp,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(LdmStmMode::Ia)),
+                        writeback: true,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpopF64 { cond, regs } => {
+                let (size, count): (u8, u8) = (8, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Load,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: Reg::Sp,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(LdmStmMode::Ia)),
+                        writeback: true,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpushF32 { cond, regs } => {
+                let (size, count): (u8, u8) = (4, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: Reg::Sp,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(LdmStmMode::Db)),
+                        writeback: true,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpushF64 { cond, regs } => {
+                let (size, count): (u8, u8) = (8, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: Reg::Sp,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(LdmStmMode::Db)),
+                        writeback: true,
+                    },
+                })
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst1 { cond, size, regs, addr } => {
+                let (size, count): (u8, u8) = (
+                    1 << *size as u8,
+                    ((*regs).element_count(*size, 1)) as u8,
+                );
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::neon(*addr, size as u32 * count as u32),
+                })
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst2 { cond, size, regs, addr } => {
+                let (size, count): (u8, u8) = (
+                    1 << *size as u8,
+                    ((*regs).element_count(*size, 2)) as u8,
+                );
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::neon(*addr, size as u32 * count as u32),
+                })
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst3 { cond, size, regs, addr } => {
+                let (size, count): (u8, u8) = (
+                    1 << *size as u8,
+                    ((*regs).element_count(*size, 3)) as u8,
+                );
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::neon(*addr, size as u32 * count as u32),
+                })
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst4 { cond, size, regs, addr } => {
+                let (size, count): (u8, u8) = (
+                    1 << *size as u8,
+                    ((*regs).element_count(*size, 4)) as u8,
+                );
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::neon(*addr, size as u32 * count as u32),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstmF32 { mode, cond, rn, writeback, regs } => {
+                let (size, count): (u8, u8) = (4, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(*mode)),
+                        writeback: *writeback,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstmF64 { mode, cond, rn, writeback, regs } => {
+                let (size, count): (u8, u8) = (8, ((*regs).iter().count()) as u8);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress {
+                        base: *rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(*mode)),
+                        writeback: *writeback,
+                    },
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstrF32 { cond, sd, addr } => {
+                let (size, count): (u8, u8) = (4, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstrF64 { cond, dd, addr } => {
+                let (size, count): (u8, u8) = (8, 1);
+                Some(MemAccess {
+                    kind: AccessKind::Store,
+                    size,
+                    count,
+                    signed: false,
+                    exclusive: false,
+                    user_mode: false,
+                    address: AccessAddress::from(*addr),
+                })
+            }
+            _ => None,
+        }
+    }
+}
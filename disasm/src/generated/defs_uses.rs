@@ -2,7 +2,10 @@
 #![allow(unused_variables)]
 #![allow(unreachable_patterns)]
 #![allow(clippy::single_match)]
+#![allow(clippy::collapsible_match)]
 use crate::*;
+/// Most arguments that any instruction defines or uses
+pub(crate) const MAX_DEFS_USES: usize = 7;
 impl Ins {
     /// Returns a [`DefsUses`] object containing all the registers this instruction
     /// defines, in no particular order.
@@ -11,50 +14,225 @@ impl Ins {
         match self {
             Ins::Adc { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s {
+                    defs.push(Flag::C);
+                }
+                if *s {
+                    defs.push(Flag::V);
+                }
             }
             Ins::Add { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s {
+                    defs.push(Flag::C);
+                }
+                if *s {
+                    defs.push(Flag::V);
+                }
             }
             Ins::And { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
             Ins::Asr { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
             Ins::B { cond, target } => {
                 defs.push(Reg::Pc);
             }
+            #[cfg(feature = "v7")]
+            Ins::Bfc { cond, rd, lsb, width } => {
+                defs.push(*rd);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Bfi { cond, rd, rn, lsb, width } => {
+                defs.push(*rd);
+            }
             Ins::Bic { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
             Ins::Bl { cond, target } => {
                 defs.push(Reg::Pc);
-            }
+                defs.push(Reg::Lr);
+            }
+            #[cfg(
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
             Ins::Blx { cond, target } => {
                 defs.push(Reg::Pc);
-            }
+                defs.push(Reg::Lr);
+            }
+            #[cfg(
+                any(
+                    feature = "v4t",
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
             Ins::Bx { cond, rm } => {
                 defs.push(Reg::Pc);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Bxj { cond, rm } => {
                 defs.push(Reg::Pc);
             }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbnz { rn, target } => {
+                defs.push(Reg::Pc);
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbz { rn, target } => {
+                defs.push(Reg::Pc);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Cdp { cond, coproc, opc1, crd, crn, crm, opc2 } => {
                 defs.push(*crd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Cdp2 { coproc, opc1, crd, crn, crm, opc2 } => {
                 defs.push(*crd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Clz { cond, rd, rm } => {
                 defs.push(*rd);
             }
+            Ins::Cmn { cond, rn, op2 } => {
+                defs.push(Flag::N);
+                defs.push(Flag::Z);
+                defs.push(Flag::C);
+                defs.push(Flag::V);
+            }
+            Ins::Cmp { cond, rn, op2 } => {
+                defs.push(Flag::N);
+                defs.push(Flag::Z);
+                defs.push(Flag::C);
+                defs.push(Flag::V);
+            }
             Ins::Eor { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldc { l, cond, coproc, crd, dest } => {
                 defs.push(*crd);
                 dest.defs(&mut defs);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldc2 { l, coproc, crd, dest } => {
                 defs.push(*crd);
                 dest.defs(&mut defs);
@@ -73,24 +251,68 @@ impl Ins {
                 defs.push(*rd);
                 addr.defs(&mut defs);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldrbt { cond, rd, addr } => {
                 defs.push(*rd);
+                addr.defs(&mut defs);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrd { cond, rd, rd2, addr } => {
                 defs.push(*rd);
                 defs.push(*rd2);
                 addr.defs(&mut defs);
             }
-            Ins::Ldrex { cond, rd, rn } => {
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrex { cond, rd, rn, offset } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexb { cond, rd, rn } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexd { cond, rd, rd2, rn } => {
                 defs.push(*rd);
                 defs.push(*rd2);
             }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexh { cond, rd, rn } => {
                 defs.push(*rd);
             }
@@ -98,86 +320,312 @@ impl Ins {
                 defs.push(*rd);
                 addr.defs(&mut defs);
             }
+            #[cfg(feature = "v7")]
+            Ins::Ldrht { cond, rd, addr } => {
+                defs.push(*rd);
+                addr.defs(&mut defs);
+            }
             Ins::Ldrsb { cond, rd, addr } => {
                 defs.push(*rd);
                 addr.defs(&mut defs);
             }
+            #[cfg(feature = "v7")]
+            Ins::Ldrsbt { cond, rd, addr } => {
+                defs.push(*rd);
+                addr.defs(&mut defs);
+            }
             Ins::Ldrsh { cond, rd, addr } => {
                 defs.push(*rd);
                 addr.defs(&mut defs);
             }
+            #[cfg(feature = "v7")]
+            Ins::Ldrsht { cond, rd, addr } => {
+                defs.push(*rd);
+                addr.defs(&mut defs);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldrt { cond, rd, addr } => {
                 defs.push(*rd);
+                addr.defs(&mut defs);
             }
             Ins::Lsl { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
             Ins::Lsr { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mcr { cond, coproc, opc1, rd, crn, crm, opc2 } => {
                 defs.push(*crn);
                 defs.push(*crm);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mcr2 { coproc, opc1, rd, crn, crm, opc2 } => {
                 defs.push(*crn);
                 defs.push(*crm);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mcrr { cond, coproc, opc, rd, rd2, crm } => {
                 defs.push(*crm);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mcrr2 { coproc, opc, rd, rd2, crm } => {
                 defs.push(*crm);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mla { s, cond, rd, rn, rm, ra } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+            }
+            #[cfg(feature = "v7")]
+            Ins::Mls { cond, rd, rn, rm, ra } => {
+                defs.push(*rd);
             }
             Ins::Mov { s, thumb, cond, rd, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
+            #[cfg(feature = "v7")]
+            Ins::Movt { cond, rd, imm } => {
+                defs.push(*rd);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Movw { cond, rd, imm } => {
+                defs.push(*rd);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mrc { cond, coproc, opc1, rd, crn, crm, opc2 } => {
                 defs.push(*rd);
                 defs.push(*crm);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mrc2 { coproc, opc1, rd, crn, crm, opc2 } => {
                 defs.push(*rd);
                 defs.push(*crm);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mrrc { cond, coproc, opc, rd, rd2, crm } => {
                 defs.push(*rd);
                 defs.push(*rd2);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mrrc2 { coproc, opc, rd, rd2, crm } => {
                 defs.push(*rd);
                 defs.push(*rd2);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mrs { cond, rd, status_reg } => {
                 defs.push(*rd);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Msr { cond, status_fields, op2 } => {
                 defs.push(*status_fields);
             }
             Ins::Mul { s, thumb, cond, rd, rn, rm } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
             }
             Ins::Mvn { s, thumb, cond, rd, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
+            #[cfg(feature = "thumb")]
             Ins::Neg { rd, rm } => {
                 defs.push(*rd);
+                defs.push(Flag::N);
+                defs.push(Flag::Z);
+                defs.push(Flag::C);
+                defs.push(Flag::V);
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Orn { s, cond, rd, rn, op2 } => {
+                defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
             Ins::Orr { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Pkhbt { cond, rd, rn, rm, shift_op, shift } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Pkhtb { cond, rd, rn, rm, shift_op, shift } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Pld { addr } => {
                 addr.defs(&mut defs);
             }
+            #[cfg(feature = "v7")]
+            Ins::Pli { addr } => {
+                addr.defs(&mut defs);
+            }
             Ins::Pop { cond, regs } => {
                 defs.push(Reg::Sp);
                 defs.push(*regs);
@@ -185,45 +633,179 @@ impl Ins {
             Ins::Push { cond, regs } => {
                 defs.push(Reg::Sp);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qadd { cond, rd, rm, rn } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qadd16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qadd8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qasx { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qdadd { cond, rd, rm, rn } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qdsub { cond, rd, rm, rn } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsax { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsub { cond, rd, rm, rn } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsub16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsub8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(feature = "v7")]
+            Ins::Rbit { cond, rd, rm } => {
+                defs.push(*rd);
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Rev { cond, rd, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Rev16 { cond, rd, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Revsh { cond, rd, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Rfe { addr_mode, rn, writeback } => {
                 if *writeback {
                     defs.push(*rn);
@@ -231,125 +813,533 @@ impl Ins {
             }
             Ins::Ror { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s && (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Rrx { s, cond, rd, rm } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s {
+                    defs.push(Flag::C);
+                }
             }
             Ins::Rsb { s, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s {
+                    defs.push(Flag::C);
+                }
+                if *s {
+                    defs.push(Flag::V);
+                }
             }
+            #[cfg(feature = "arm")]
             Ins::Rsc { s, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s {
+                    defs.push(Flag::C);
+                }
+                if *s {
+                    defs.push(Flag::V);
+                }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sadd16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Ge);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sadd8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Ge);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sasx { cond, rd, rn, rm } => {
                 defs.push(*rd);
+                defs.push(Flag::Ge);
             }
             Ins::Sbc { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s {
+                    defs.push(Flag::C);
+                }
+                if *s {
+                    defs.push(Flag::V);
+                }
+            }
+            #[cfg(feature = "v7")]
+            Ins::Sbfx { cond, rd, rn, lsb, width } => {
+                defs.push(*rd);
             }
+            #[cfg(feature = "v7")]
+            Ins::Sdiv { cond, rd, rn, rm } => {
+                defs.push(*rd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sel { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shadd16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shadd8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shasx { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsax { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsub16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsub8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smla { cond, rd, rn, rn_side, rm, rm_side, ra } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlad { cond, rd, rn, rm, swap_rm, ra } => {
                 defs.push(*rd);
+                defs.push(Flag::Q);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Smlal { s, cond, rd_lo, rd_hi, rn, rm } => {
                 defs.push(*rd_lo);
                 defs.push(*rd_hi);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::SmlalHalf { cond, rd_lo, rd_hi, rn, rn_side, rm, rm_side } => {
                 defs.push(*rd_lo);
                 defs.push(*rd_hi);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlald { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
                 defs.push(*rd_lo);
                 defs.push(*rd_hi);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlaw { cond, rd, rn, rm, rm_side, ra } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlsd { cond, rd, rn, rm, swap_rm, ra } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlsld { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
                 defs.push(*rd_lo);
                 defs.push(*rd_hi);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmla { round, cond, rd, rn, rm, ra } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmls { round, cond, rd, rn, rm, ra } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmul { round, cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smuad { cond, rd, rn, rm, swap_rm } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smul { cond, rd, rn, rn_side, rm, rm_side } => {
                 defs.push(*rd);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Smull { s, cond, rd_lo, rd_hi, rn, rm } => {
                 defs.push(*rd_lo);
                 defs.push(*rd_hi);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smulw { cond, rd, rn, rm, rm_side } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smusd { cond, rd, rn, rm, swap_rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Srs { addr_mode, rn, writeback, mode } => {
                 if *writeback {
                     defs.push(*rn);
                 }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssat { cond, rd, imm, op2 } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssat16 { cond, rd, imm, rn } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssax { cond, rd, rn, rm } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Ge);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssub16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Ge);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssub8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
+                defs.push(Flag::Ge);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Stc { l, cond, coproc, crd, dest } => {
                 dest.defs(&mut defs);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Stc2 { l, coproc, crd, dest } => {
                 dest.defs(&mut defs);
             }
@@ -364,339 +1354,3063 @@ impl Ins {
             Ins::Strb { cond, rd, addr } => {
                 addr.defs(&mut defs);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Strbt { cond, rd, addr } => {
+                addr.defs(&mut defs);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strd { cond, rd, rd2, addr } => {
                 addr.defs(&mut defs);
             }
-            Ins::Strex { cond, rd, rm, rn } => {
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strex { cond, rd, rm, rn, offset } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexb { cond, rd, rm, rn } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexd { cond, rd, rm, rm2, rn } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexh { cond, rd, rm, rn } => {
                 defs.push(*rd);
             }
             Ins::Strh { cond, rd, addr } => {
-                defs.push(*rd);
                 addr.defs(&mut defs);
             }
+            #[cfg(feature = "v7")]
+            Ins::Strht { cond, rd, addr } => {
+                addr.defs(&mut defs);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Strt { cond, rd, addr } => {
-                defs.push(*rd);
+                addr.defs(&mut defs);
             }
             Ins::Sub { s, thumb, cond, rd, rn, op2 } => {
                 defs.push(*rd);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
+                if *s {
+                    defs.push(Flag::C);
+                }
+                if *s {
+                    defs.push(Flag::V);
+                }
             }
+            #[cfg(feature = "arm")]
             Ins::Swp { cond, rd, rd2, rn } => {
                 defs.push(*rd);
             }
+            #[cfg(feature = "arm")]
             Ins::Swpb { cond, rd, rd2, rn } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtab { cond, rd, rn, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtab16 { cond, rd, rn, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtah { cond, rd, rn, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Sxtb { cond, rd, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtb16 { cond, rd, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Sxth { cond, rd, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbb { cond, rn, rm } => {
+                defs.push(Reg::Pc);
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbh { cond, rn, rm } => {
+                defs.push(Reg::Pc);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Teq { cond, rn, op2 } => {
+                defs.push(Flag::N);
+                defs.push(Flag::Z);
+                if (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
+            }
+            Ins::Tst { cond, rn, op2 } => {
+                defs.push(Flag::N);
+                defs.push(Flag::Z);
+                if (*op2).defines_carry() {
+                    defs.push(Flag::C);
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uadd16 { cond, rd, rn, rm } => {
+                defs.push(Flag::Ge);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uadd8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Ge);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uasx { cond, rd, rn, rm } => {
                 defs.push(*rd);
+                defs.push(Flag::Ge);
             }
+            #[cfg(feature = "v7")]
+            Ins::Ubfx { cond, rd, rn, lsb, width } => {
+                defs.push(*rd);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Udiv { cond, rd, rn, rm } => {
+                defs.push(*rd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhadd16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhadd8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhasx { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsax { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsub16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsub8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Umaal { cond, rd_lo, rd_hi, rn, rm } => {
                 defs.push(*rd_lo);
                 defs.push(*rd_hi);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Umlal { s, cond, rd_lo, rd_hi, rn, rm } => {
                 defs.push(*rd_lo);
                 defs.push(*rd_hi);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Umull { s, cond, rd_lo, rd_hi, rn, rm } => {
                 defs.push(*rd_lo);
                 defs.push(*rd_hi);
+                if *s {
+                    defs.push(Flag::N);
+                }
+                if *s {
+                    defs.push(Flag::Z);
+                }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqadd16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqadd8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqasx { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsax { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsub16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsub8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usad8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usada8 { cond, rd, rn, rm, ra } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usat { cond, rd, imm, op2 } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usat16 { cond, rd, imm, rn } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Q);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usax { cond, rd, rn, rm } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Ge);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usub16 { cond, rd, rn, rm } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Ge);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usub8 { cond, rd, rn, rm } => {
                 defs.push(*rd);
-            }
+                defs.push(Flag::Ge);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtab { cond, rd, rn, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtab16 { cond, rd, rn, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtah { cond, rd, rn, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Uxtb { cond, rd, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtb16 { cond, rd, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Uxth { cond, rd, rm, rotate } => {
                 defs.push(*rd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaba { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabal { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabd { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabdF32 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabdl { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabs { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VabsF32 { cond, sd, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabsF32Neon { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VabsF64 { cond, dd, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacge { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacgt { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VaddF32 { cond, sd, sn, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddF32Neon { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VaddF64 { cond, dd, dn, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddI { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddhn { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddl { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddw { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vand { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbic { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VbicIImm { cond, dt, vd, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbif { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbit { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbsl { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vceq { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqF32 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZero { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZeroF32 { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcge { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeF32 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZero { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZeroF32 { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcgt { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtF32 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZero { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZeroF32 { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZero { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZeroF32 { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcls { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZero { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZeroF32 { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vclz { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcnt { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF16F32 { half, cond, sd, sm } => {
+                defs.push(*sd);
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32F16 { half, cond, sd, sm } => {
+                defs.push(*sd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF32F64 { cond, sd, dm } => {
                 defs.push(*sd);
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32Fixed { cond, fixed, sd, fbits } => {
+                defs.push(*sd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF32S32 { cond, sd, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF32U32 { cond, sd, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF64F32 { cond, dd, sm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF64Fixed { cond, fixed, dd, fbits } => {
+                defs.push(*dd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF64S32 { cond, dd, sm } => {
                 defs.push(*dd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF64U32 { cond, dd, sm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF32 { cond, fixed, sd, fbits } => {
+                defs.push(*sd);
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF64 { cond, fixed, dd, fbits } => {
+                defs.push(*dd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtFixedNeon { cond, dt, vd, vm, fbits } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtNeon { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtS32F32 { round_zero, cond, sd, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtS32F64 { round_zero, cond, sd, dm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtU32F32 { round_zero, cond, sd, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtU32F64 { round_zero, cond, sd, dm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VdivF32 { cond, sd, sn, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VdivF64 { cond, dd, dn, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupReg { cond, size, vd, rt } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupScalar { cond, size, vd, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Veor { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vext { cond, vd, vn, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhadd { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhsub { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld1 { cond, size, regs, addr } => {
+                defs.push(*regs);
+                addr.defs(&mut defs);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld2 { cond, size, regs, addr } => {
+                defs.push(*regs);
+                addr.defs(&mut defs);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld3 { cond, size, regs, addr } => {
+                defs.push(*regs);
+                addr.defs(&mut defs);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld4 { cond, size, regs, addr } => {
+                defs.push(*regs);
+                addr.defs(&mut defs);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VldmF32 { mode, cond, rn, writeback, regs } => {
                 defs.push(*regs);
                 if *writeback {
                     defs.push(*rn);
                 }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VldmF64 { mode, cond, rn, writeback, regs } => {
                 defs.push(*regs);
                 if *writeback {
                     defs.push(*rn);
                 }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VldrF32 { cond, sd, addr } => {
                 defs.push(*sd);
                 addr.defs(&mut defs);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VldrF64 { cond, dd, addr } => {
                 defs.push(*dd);
                 addr.defs(&mut defs);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmax { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmaxF32 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmin { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VminF32 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmlaF32 { cond, sd, sn, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Neon { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Scalar { cond, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmlaF64 { cond, dd, dn, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaI { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaIScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlal { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlalScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmlsF32 { cond, sd, sn, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Neon { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Scalar { cond, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmlsF64 { cond, dd, dn, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsI { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsIScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlsl { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlslScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::Vmov32Reg { cond, dd, rt } => {
                 defs.push(*dd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovF32 { cond, sd, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF32Imm { cond, sd, imm } => {
+                defs.push(*sd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovF32ImmNeon { cond, vd, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovF32Reg { cond, sn, rt } => {
                 defs.push(*sn);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovF64 { cond, dd, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF64Imm { cond, dd, imm } => {
+                defs.push(*dd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovI64Imm { cond, vd, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovIImm { cond, dt, vd, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovReg32 { cond, rt, dn } => {
                 defs.push(*rt);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovRegScalar { cond, dt, rt, dn, index } => {
+                defs.push(*rt);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovRegF32 { cond, rt, sn } => {
                 defs.push(*rt);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovRegF32Dual { cond, rt, rt2, sm, sm2 } => {
                 defs.push(*rt);
                 defs.push(*rt2);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovScalarReg { cond, size, dd, index, rt } => {
+                defs.push(*dd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovF32RegDual { cond, sm, sm2, rt, rt2 } => {
                 defs.push(*sm);
                 defs.push(*sm2);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovRegF64 { cond, rt, rt2, dm } => {
                 defs.push(*rt);
                 defs.push(*rt2);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovF64Reg { cond, dm, rt, rt2 } => {
                 defs.push(*dm);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmovn { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::Vmrs { cond, rd, fpscr } => {
-                defs.push(*rd);
+                if *rd != Reg::Pc {
+                    defs.push(*rd);
+                }
+                if *rd == Reg::Pc {
+                    defs.push(Flag::N);
+                }
+                if *rd == Reg::Pc {
+                    defs.push(Flag::Z);
+                }
+                if *rd == Reg::Pc {
+                    defs.push(Flag::C);
+                }
+                if *rd == Reg::Pc {
+                    defs.push(Flag::V);
+                }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::Vmsr { cond, fpscr, rd } => {
                 defs.push(*fpscr);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmulF32 { cond, sd, sn, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Neon { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Scalar { cond, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmulF64 { cond, dd, dn, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulI { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulIScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulP8 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmull { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullP8 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmvn { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmvnIImm { cond, dt, vd, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vneg { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnegF32 { cond, sd, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VnegF32Neon { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnegF64 { cond, dd, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmlaF32 { cond, sd, sn, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmlaF64 { cond, dd, dn, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmlsF32 { cond, sd, sn, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmlsF64 { cond, dd, dn, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmulF32 { cond, sd, sn, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmulF64 { cond, dd, dn, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorn { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorr { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VorrIImm { cond, dt, vd, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpadal { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddF32 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddI { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpaddl { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmax { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpmaxF32 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmin { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpminF32 { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VpopF32 { cond, regs } => {
                 defs.push(Reg::Sp);
                 defs.push(*regs);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VpopF64 { cond, regs } => {
                 defs.push(Reg::Sp);
                 defs.push(*regs);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VpushF32 { cond, regs } => {
                 defs.push(Reg::Sp);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VpushF64 { cond, regs } => {
                 defs.push(Reg::Sp);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqabs { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqadd { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlal { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlalScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlsl { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlslScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmulh { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmull { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmullScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovn { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovun { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqneg { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrdmulh { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqrdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshl { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrn { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrun { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshl { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqshlImm { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshlu { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrn { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrun { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqsub { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vraddhn { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecpe { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrecpeF32 { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecps { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev16 { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev32 { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev64 { cond, dt, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrhadd { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshl { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshr { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshrn { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrte { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrsqrteF32 { cond, vd, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrts { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsra { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsubhn { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshl { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshlImm { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshll { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshllMax { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshr { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshrn { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsli { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VsqrtF32 { cond, sd, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VsqrtF64 { cond, dd, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsra { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsri { cond, dt, vd, vm, imm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst1 { cond, size, regs, addr } => {
+                addr.defs(&mut defs);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst2 { cond, size, regs, addr } => {
+                addr.defs(&mut defs);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst3 { cond, size, regs, addr } => {
+                addr.defs(&mut defs);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst4 { cond, size, regs, addr } => {
+                addr.defs(&mut defs);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VstmF32 { mode, cond, rn, writeback, regs } => {
                 if *writeback {
                     defs.push(*rn);
                 }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VstmF64 { mode, cond, rn, writeback, regs } => {
                 if *writeback {
                     defs.push(*rn);
                 }
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VstrF32 { cond, sd, addr } => {
                 addr.defs(&mut defs);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VstrF64 { cond, dd, addr } => {
                 addr.defs(&mut defs);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VsubF32 { cond, sd, sn, sm } => {
                 defs.push(*sd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubF32Neon { cond, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VsubF64 { cond, dd, dn, dm } => {
                 defs.push(*dd);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubI { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubhn { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubl { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubw { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vswp { cond, vd, vm } => {
+                defs.push(*vd);
+                defs.push(*vm);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbl { cond, dd, regs, dm } => {
+                defs.push(*dd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbx { cond, dd, regs, dm } => {
+                defs.push(*dd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtrn { cond, dt, vd, vm } => {
+                defs.push(*vd);
+                defs.push(*vm);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtst { cond, dt, vd, vn, vm } => {
+                defs.push(*vd);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vuzp { cond, dt, vd, vm } => {
+                defs.push(*vd);
+                defs.push(*vm);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vzip { cond, dt, vd, vm } => {
+                defs.push(*vd);
+                defs.push(*vm);
+            }
             _ => {}
         }
         defs
@@ -708,388 +4422,1350 @@ impl Ins {
         match self {
             Ins::Adc { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                uses.push(Flag::C);
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Add { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::And { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Asr { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            Ins::B { cond, target } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Bfc { cond, rd, lsb, width } => {
+                uses.push(*rd);
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Bfi { cond, rd, rn, lsb, width } => {
+                uses.push(*rd);
+                uses.push(*rn);
+                uses.push_cond(*cond);
             }
             Ins::Bic { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            Ins::Bl { cond, target } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
             Ins::Blx { cond, target } => {
                 target.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    feature = "v4t",
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
             Ins::Bx { cond, rm } => {
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Bxj { cond, rm } => {
                 uses.push(*rm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbnz { rn, target } => {
+                uses.push(*rn);
             }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbz { rn, target } => {
+                uses.push(*rn);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Cdp { cond, coproc, opc1, crd, crn, crm, opc2 } => {
                 uses.push(*crn);
                 uses.push(*crm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Cdp2 { coproc, opc1, crd, crn, crm, opc2 } => {
                 uses.push(*crn);
                 uses.push(*crm);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Clz { cond, rd, rm } => {
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
             Ins::Cmn { cond, rn, op2 } => {
                 uses.push(*rn);
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Cmp { cond, rn, op2 } => {
                 uses.push(*rn);
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Csdb { cond } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Dbg { cond, option } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Dmb { cond, option } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Dsb { cond, option } => {
+                uses.push_cond(*cond);
             }
             Ins::Eor { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(feature = "v7")]
+            Ins::Isb { cond, option } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldc { l, cond, coproc, crd, dest } => {
                 dest.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldc2 { l, coproc, crd, dest } => {
                 dest.uses(&mut uses);
             }
             Ins::Ldm { mode, cond, rn, writeback, regs, user_mode } => {
                 uses.push(*rn);
+                uses.push_cond(*cond);
             }
             Ins::Ldr { cond, rd, addr } => {
                 addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Ldrb { cond, rd, addr } => {
                 addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldrbt { cond, rd, addr } => {
                 addr.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrd { cond, rd, rd2, addr } => {
                 addr.uses(&mut uses);
-            }
-            Ins::Ldrex { cond, rd, rn } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrex { cond, rd, rn, offset } => {
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexb { cond, rd, rn } => {
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexd { cond, rd, rd2, rn } => {
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexh { cond, rd, rn } => {
                 uses.push(*rn);
+                uses.push_cond(*cond);
             }
             Ins::Ldrh { cond, rd, addr } => {
                 addr.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Ldrht { cond, rd, addr } => {
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Ldrsb { cond, rd, addr } => {
                 addr.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Ldrsbt { cond, rd, addr } => {
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Ldrsh { cond, rd, addr } => {
                 addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(feature = "v7")]
+            Ins::Ldrsht { cond, rd, addr } => {
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldrt { cond, rd, addr } => {
                 addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Lsl { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Lsr { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mcr { cond, coproc, opc1, rd, crn, crm, opc2 } => {
                 uses.push(*rd);
                 uses.push(*crm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mcr2 { coproc, opc1, rd, crn, crm, opc2 } => {
                 uses.push(*rd);
                 uses.push(*crm);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mcrr { cond, coproc, opc, rd, rd2, crm } => {
                 uses.push(*rd);
                 uses.push(*rd2);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mcrr2 { coproc, opc, rd, rd2, crm } => {
                 uses.push(*rd);
                 uses.push(*rd2);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mla { s, cond, rd, rn, rm, ra } => {
                 uses.push(*rn);
                 uses.push(*rm);
                 uses.push(*ra);
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Mls { cond, rd, rn, rm, ra } => {
+                uses.push(*rn);
+                uses.push(*rm);
+                uses.push(*ra);
+                uses.push_cond(*cond);
             }
             Ins::Mov { s, thumb, cond, rd, op2 } => {
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Movt { cond, rd, imm } => {
+                uses.push(*rd);
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Movw { cond, rd, imm } => {
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mrc { cond, coproc, opc1, rd, crn, crm, opc2 } => {
                 uses.push(*crn);
                 uses.push(*crm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mrc2 { coproc, opc1, rd, crn, crm, opc2 } => {
                 uses.push(*crn);
                 uses.push(*crm);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mrrc { cond, coproc, opc, rd, rd2, crm } => {
                 uses.push(*crm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mrrc2 { coproc, opc, rd, rd2, crm } => {
                 uses.push(*crm);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mrs { cond, rd, status_reg } => {
                 uses.push(*status_reg);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Msr { cond, status_fields, op2 } => {
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Mul { s, thumb, cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
             Ins::Mvn { s, thumb, cond, rd, op2 } => {
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(feature = "thumb")]
             Ins::Neg { rd, rm } => {
                 uses.push(*rm);
             }
-            Ins::Orr { s, thumb, cond, rd, rn, op2 } => {
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Nop { cond } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Orn { s, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            Ins::Orr { s, thumb, cond, rd, rn, op2 } => {
+                uses.push(*rn);
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
+                op2.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Pkhbt { cond, rd, rn, rm, shift_op, shift } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Pkhtb { cond, rd, rn, rm, shift_op, shift } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Pld { addr } => {
                 addr.uses(&mut uses);
             }
+            #[cfg(feature = "v7")]
+            Ins::Pli { addr } => {
+                addr.uses(&mut uses);
+            }
             Ins::Pop { cond, regs } => {
                 uses.push(Reg::Sp);
+                uses.push_cond(*cond);
             }
             Ins::Push { cond, regs } => {
                 uses.push(Reg::Sp);
                 uses.push(*regs);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qadd { cond, rd, rm, rn } => {
                 uses.push(*rm);
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qadd16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qadd8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qasx { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qdadd { cond, rd, rm, rn } => {
                 uses.push(*rm);
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qdsub { cond, rd, rm, rn } => {
                 uses.push(*rm);
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsax { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsub { cond, rd, rm, rn } => {
                 uses.push(*rm);
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsub16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsub8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Rbit { cond, rd, rm } => {
+                uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Rev { cond, rd, rm } => {
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Rev16 { cond, rd, rm } => {
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Revsh { cond, rd, rm } => {
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Rfe { addr_mode, rn, writeback } => {
                 uses.push(*rn);
             }
             Ins::Ror { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                if *s && (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Rrx { s, cond, rd, rm } => {
                 uses.push(*rm);
+                uses.push(Flag::C);
+                uses.push_cond(*cond);
             }
             Ins::Rsb { s, cond, rd, rn, op2 } => {
                 uses.push(*rn);
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(feature = "arm")]
             Ins::Rsc { s, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                uses.push(Flag::C);
                 op2.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sadd16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sadd8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sasx { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
             Ins::Sbc { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
+                uses.push(Flag::C);
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(feature = "v7")]
+            Ins::Sbfx { cond, rd, rn, lsb, width } => {
+                uses.push(*rn);
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Sdiv { cond, rd, rn, rm } => {
+                uses.push(*rn);
+                uses.push(*rm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sel { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push(Flag::Ge);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sev { cond } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shadd16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shadd8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shasx { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsax { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsub16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsub8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smla { cond, rd, rn, rn_side, rm, rm_side, ra } => {
                 uses.push(*rn);
                 uses.push(*rm);
                 uses.push(*ra);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlad { cond, rd, rn, rm, swap_rm, ra } => {
                 uses.push(*rn);
                 uses.push(*rm);
                 uses.push(*ra);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Smlal { s, cond, rd_lo, rd_hi, rn, rm } => {
+                uses.push(*rd_lo);
+                uses.push(*rd_hi);
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::SmlalHalf { cond, rd_lo, rd_hi, rn, rn_side, rm, rm_side } => {
+                uses.push(*rd_lo);
+                uses.push(*rd_hi);
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlald { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
+                uses.push(*rd_lo);
+                uses.push(*rd_hi);
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlaw { cond, rd, rn, rm, rm_side, ra } => {
                 uses.push(*rn);
                 uses.push(*rm);
                 uses.push(*ra);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlsd { cond, rd, rn, rm, swap_rm, ra } => {
                 uses.push(*rn);
                 uses.push(*rm);
                 uses.push(*ra);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlsld { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
+                uses.push(*rd_lo);
+                uses.push(*rd_hi);
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmla { round, cond, rd, rn, rm, ra } => {
                 uses.push(*rn);
                 uses.push(*rm);
                 uses.push(*ra);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmls { round, cond, rd, rn, rm, ra } => {
                 uses.push(*rn);
                 uses.push(*rm);
                 uses.push(*ra);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmul { round, cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smuad { cond, rd, rn, rm, swap_rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smul { cond, rd, rn, rn_side, rm, rm_side } => {
                 uses.push(*rn);
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Smull { s, cond, rd_lo, rd_hi, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smulw { cond, rd, rn, rm, rm_side } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smusd { cond, rd, rn, rm, swap_rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Srs { addr_mode, rn, writeback, mode } => {
                 uses.push(*rn);
             }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssat { cond, rd, imm, op2 } => {
                 op2.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssat16 { cond, rd, imm, rn } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssax { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssub16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssub8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Stc { l, cond, coproc, crd, dest } => {
                 uses.push(*crd);
                 dest.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Stc2 { l, coproc, crd, dest } => {
                 uses.push(*crd);
                 dest.uses(&mut uses);
@@ -1097,421 +5773,3589 @@ impl Ins {
             Ins::Stm { mode, cond, rn, writeback, regs, user_mode } => {
                 uses.push(*regs);
                 uses.push(*rn);
+                uses.push_cond(*cond);
             }
             Ins::Str { cond, rd, addr } => {
                 uses.push(*rd);
                 addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Strb { cond, rd, addr } => {
                 uses.push(*rd);
                 addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Strbt { cond, rd, addr } => {
                 uses.push(*rd);
                 addr.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strd { cond, rd, rd2, addr } => {
                 uses.push(*rd);
                 uses.push(*rd2);
                 addr.uses(&mut uses);
-            }
-            Ins::Strex { cond, rd, rm, rn } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strex { cond, rd, rm, rn, offset } => {
                 uses.push(*rm);
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexb { cond, rd, rm, rn } => {
                 uses.push(*rm);
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexd { cond, rd, rm, rm2, rn } => {
                 uses.push(*rn);
                 uses.push(*rm);
                 uses.push(*rm2);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexh { cond, rd, rm, rn } => {
                 uses.push(*rm);
                 uses.push(*rn);
+                uses.push_cond(*cond);
             }
             Ins::Strh { cond, rd, addr } => {
+                uses.push(*rd);
                 addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(feature = "v7")]
+            Ins::Strht { cond, rd, addr } => {
+                uses.push(*rd);
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Strt { cond, rd, addr } => {
+                uses.push(*rd);
                 addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Sub { s, thumb, cond, rd, rn, op2 } => {
                 uses.push(*rn);
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            Ins::Svc { cond, imm } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "arm")]
             Ins::Swp { cond, rd, rd2, rn } => {
                 uses.push(*rd2);
                 uses.push(*rn);
+                uses.push_cond(*cond);
             }
+            #[cfg(feature = "arm")]
             Ins::Swpb { cond, rd, rd2, rn } => {
                 uses.push(*rd2);
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtab { cond, rd, rn, rm, rotate } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtab16 { cond, rd, rn, rm, rotate } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtah { cond, rd, rn, rm, rotate } => {
                 uses.push(*rn);
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Sxtb { cond, rd, rm, rotate } => {
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtb16 { cond, rd, rm, rotate } => {
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Sxth { cond, rd, rm, rotate } => {
                 uses.push(*rm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbb { cond, rn, rm } => {
+                uses.push(*rn);
+                uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbh { cond, rn, rm } => {
+                uses.push(*rn);
+                uses.push(*rm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Teq { cond, rn, op2 } => {
                 uses.push(*rn);
+                if (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
             }
             Ins::Tst { cond, rn, op2 } => {
                 uses.push(*rn);
+                if (*op2).uses_carry() {
+                    uses.push(Flag::C);
+                }
                 op2.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uadd16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uadd8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uasx { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(feature = "v7")]
+            Ins::Ubfx { cond, rd, rn, lsb, width } => {
+                uses.push(*rn);
+                uses.push_cond(*cond);
+            }
+            #[cfg(feature = "v7")]
+            Ins::Udiv { cond, rd, rn, rm } => {
+                uses.push(*rn);
+                uses.push(*rm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhadd16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhadd8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhasx { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsax { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsub16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsub8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Umaal { cond, rd_lo, rd_hi, rn, rm } => {
+                uses.push(*rd_lo);
+                uses.push(*rd_hi);
                 uses.push(*rn);
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Umlal { s, cond, rd_lo, rd_hi, rn, rm } => {
+                uses.push(*rd_lo);
+                uses.push(*rd_hi);
                 uses.push(*rn);
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Umull { s, cond, rd_lo, rd_hi, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqadd16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqadd8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqasx { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsax { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsub16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsub8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usad8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usada8 { cond, rd, rn, rm, ra } => {
                 uses.push(*rn);
                 uses.push(*rm);
                 uses.push(*ra);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usat { cond, rd, imm, op2 } => {
                 op2.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usat16 { cond, rd, imm, rn } => {
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usax { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usub16 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usub8 { cond, rd, rn, rm } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtab { cond, rd, rn, rm, rotate } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtab16 { cond, rd, rn, rm, rotate } => {
                 uses.push(*rn);
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtah { cond, rd, rn, rm, rotate } => {
                 uses.push(*rn);
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Uxtb { cond, rd, rm, rotate } => {
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtb16 { cond, rd, rm, rotate } => {
                 uses.push(*rm);
+                uses.push_cond(*cond);
             }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Uxth { cond, rd, rm, rotate } => {
                 uses.push(*rm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaba { cond, dt, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabal { cond, dt, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabd { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabdF32 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabdl { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabs { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VabsF32 { cond, sd, sm } => {
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabsF32Neon { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VabsF64 { cond, dd, dm } => {
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacge { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacgt { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VaddF32 { cond, sd, sn, sm } => {
                 uses.push(*sn);
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddF32Neon { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VaddF64 { cond, dd, dn, dm } => {
                 uses.push(*dn);
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddI { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddhn { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddl { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddw { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vand { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbic { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VbicIImm { cond, dt, vd, imm } => {
+                uses.push(*vd);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbif { cond, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbit { cond, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbsl { cond, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vceq { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqF32 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZero { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZeroF32 { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcge { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeF32 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZero { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZeroF32 { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcgt { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtF32 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZero { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZeroF32 { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZero { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZeroF32 { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcls { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZero { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZeroF32 { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vclz { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcmpF32 { nan_exc, cond, sd, op2 } => {
                 uses.push(*sd);
                 op2.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcmpF64 { nan_exc, cond, dd, op2 } => {
                 uses.push(*dd);
                 op2.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcnt { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF16F32 { half, cond, sd, sm } => {
+                uses.push(*sm);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32F16 { half, cond, sd, sm } => {
+                uses.push(*sm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF32F64 { cond, sd, dm } => {
                 uses.push(*dm);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32Fixed { cond, fixed, sd, fbits } => {
+                uses.push(*sd);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF32S32 { cond, sd, sm } => {
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF32U32 { cond, sd, sm } => {
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF64F32 { cond, dd, sm } => {
                 uses.push(*sm);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF64Fixed { cond, fixed, dd, fbits } => {
+                uses.push(*dd);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF64S32 { cond, dd, sm } => {
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtF64U32 { cond, dd, sm } => {
                 uses.push(*sm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF32 { cond, fixed, sd, fbits } => {
+                uses.push(*sd);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF64 { cond, fixed, dd, fbits } => {
+                uses.push(*dd);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtFixedNeon { cond, dt, vd, vm, fbits } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtNeon { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtS32F32 { round_zero, cond, sd, sm } => {
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtS32F64 { round_zero, cond, sd, dm } => {
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtU32F32 { round_zero, cond, sd, sm } => {
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VcvtU32F64 { round_zero, cond, sd, dm } => {
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VdivF32 { cond, sd, sn, sm } => {
                 uses.push(*sn);
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VdivF64 { cond, dd, dn, dm } => {
                 uses.push(*dn);
                 uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupReg { cond, size, vd, rt } => {
+                uses.push(*rt);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupScalar { cond, size, vd, dm, index } => {
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Veor { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vext { cond, vd, vn, vm, imm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhadd { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhsub { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld1 { cond, size, regs, addr } => {
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld2 { cond, size, regs, addr } => {
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld3 { cond, size, regs, addr } => {
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld4 { cond, size, regs, addr } => {
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VldmF32 { mode, cond, rn, writeback, regs } => {
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VldmF64 { mode, cond, rn, writeback, regs } => {
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VldrF32 { cond, sd, addr } => {
                 addr.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VldrF64 { cond, dd, addr } => {
                 addr.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmax { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmaxF32 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmin { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VminF32 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmlaF32 { cond, sd, sn, sm } => {
                 uses.push(*sn);
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Neon { cond, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Scalar { cond, vd, vn, dm, index } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmlaF64 { cond, dd, dn, dm } => {
                 uses.push(*dn);
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaI { cond, dt, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaIScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlal { cond, dt, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlalScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmlsF32 { cond, sd, sn, sm } => {
                 uses.push(*sn);
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Neon { cond, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Scalar { cond, vd, vn, dm, index } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmlsF64 { cond, dd, dn, dm } => {
                 uses.push(*dn);
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsI { cond, dt, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsIScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlsl { cond, dt, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlslScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::Vmov32Reg { cond, dd, rt } => {
                 uses.push(*rt);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovF32 { cond, sd, sm } => {
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF32Imm { cond, sd, imm } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovF32ImmNeon { cond, vd, imm } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovF32Reg { cond, sn, rt } => {
                 uses.push(*rt);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovF64 { cond, dd, dm } => {
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF64Imm { cond, dd, imm } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovI64Imm { cond, vd, imm } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovIImm { cond, dt, vd, imm } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovReg32 { cond, rt, dn } => {
                 uses.push(*dn);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovRegScalar { cond, dt, rt, dn, index } => {
+                uses.push(*dn);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovRegF32 { cond, rt, sn } => {
                 uses.push(*sn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovRegF32Dual { cond, rt, rt2, sm, sm2 } => {
                 uses.push(*sm);
                 uses.push(*sm2);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovScalarReg { cond, size, dd, index, rt } => {
+                uses.push(*rt);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovF32RegDual { cond, sm, sm2, rt, rt2 } => {
                 uses.push(*rt);
                 uses.push(*rt2);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovRegF64 { cond, rt, rt2, dm } => {
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmovF64Reg { cond, dm, rt, rt2 } => {
                 uses.push(*rt);
                 uses.push(*rt2);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmovn { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::Vmrs { cond, rd, fpscr } => {
                 uses.push(*fpscr);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::Vmsr { cond, fpscr, rd } => {
                 uses.push(*rd);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmulF32 { cond, sd, sn, sm } => {
                 uses.push(*sn);
                 uses.push(*sm);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Neon { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Scalar { cond, vd, vn, dm, index } => {
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VmulF64 { cond, dd, dn, dm } => {
                 uses.push(*dn);
                 uses.push(*dm);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulI { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulIScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulP8 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmull { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullP8 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmvn { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmvnIImm { cond, dt, vd, imm } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vneg { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnegF32 { cond, sd, sm } => {
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VnegF32Neon { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnegF64 { cond, dd, dm } => {
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmlaF32 { cond, sd, sn, sm } => {
                 uses.push(*sn);
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmlaF64 { cond, dd, dn, dm } => {
                 uses.push(*dn);
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmlsF32 { cond, sd, sn, sm } => {
                 uses.push(*sn);
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmlsF64 { cond, dd, dn, dm } => {
                 uses.push(*dn);
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmulF32 { cond, sd, sn, sm } => {
                 uses.push(*sn);
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VnmulF64 { cond, dd, dn, dm } => {
                 uses.push(*dn);
                 uses.push(*dm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorn { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorr { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VorrIImm { cond, dt, vd, imm } => {
+                uses.push(*vd);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpadal { cond, dt, vd, vm } => {
+                uses.push(*vd);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddF32 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddI { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpaddl { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmax { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpmaxF32 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmin { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpminF32 { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VpopF32 { cond, regs } => {
                 uses.push(Reg::Sp);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VpopF64 { cond, regs } => {
                 uses.push(Reg::Sp);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VpushF32 { cond, regs } => {
                 uses.push(Reg::Sp);
                 uses.push(*regs);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VpushF64 { cond, regs } => {
                 uses.push(Reg::Sp);
                 uses.push(*regs);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqabs { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqadd { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlal { cond, dt, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlalScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlsl { cond, dt, vd, vn, vm } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlslScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vd);
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmulh { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmull { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmullScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovn { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovun { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqneg { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrdmulh { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqrdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                uses.push(*vn);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshl { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrn { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrun { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshl { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqshlImm { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshlu { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrn { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrun { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqsub { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vraddhn { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecpe { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrecpeF32 { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecps { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev16 { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev32 { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev64 { cond, dt, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrhadd { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshl { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshr { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshrn { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrte { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrsqrteF32 { cond, vd, vm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrts { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsra { cond, dt, vd, vm, imm } => {
+                uses.push(*vd);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsubhn { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshl { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshlImm { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshll { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshllMax { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshr { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshrn { cond, dt, vd, vm, imm } => {
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsli { cond, dt, vd, vm, imm } => {
+                uses.push(*vd);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VsqrtF32 { cond, sd, sm } => {
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VsqrtF64 { cond, dd, dm } => {
                 uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsra { cond, dt, vd, vm, imm } => {
+                uses.push(*vd);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsri { cond, dt, vd, vm, imm } => {
+                uses.push(*vd);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst1 { cond, size, regs, addr } => {
+                uses.push(*regs);
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst2 { cond, size, regs, addr } => {
+                uses.push(*regs);
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst3 { cond, size, regs, addr } => {
+                uses.push(*regs);
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst4 { cond, size, regs, addr } => {
+                uses.push(*regs);
+                addr.uses(&mut uses);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VstmF32 { mode, cond, rn, writeback, regs } => {
                 uses.push(*regs);
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VstmF64 { mode, cond, rn, writeback, regs } => {
                 uses.push(*regs);
                 uses.push(*rn);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VstrF32 { cond, sd, addr } => {
                 uses.push(*sd);
                 addr.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VstrF64 { cond, dd, addr } => {
                 uses.push(*dd);
                 addr.uses(&mut uses);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VsubF32 { cond, sd, sn, sm } => {
                 uses.push(*sn);
                 uses.push(*sm);
-            }
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubF32Neon { cond, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
             Ins::VsubF64 { cond, dd, dn, dm } => {
                 uses.push(*dn);
                 uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubI { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubhn { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubl { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubw { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vswp { cond, vd, vm } => {
+                uses.push(*vd);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbl { cond, dd, regs, dm } => {
+                uses.push(*regs);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbx { cond, dd, regs, dm } => {
+                uses.push(*dd);
+                uses.push(*regs);
+                uses.push(*dm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtrn { cond, dt, vd, vm } => {
+                uses.push(*vd);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtst { cond, dt, vd, vn, vm } => {
+                uses.push(*vn);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vuzp { cond, dt, vd, vm } => {
+                uses.push(*vd);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vzip { cond, dt, vd, vm } => {
+                uses.push(*vd);
+                uses.push(*vm);
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Wfe { cond } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Wfi { cond } => {
+                uses.push_cond(*cond);
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Yield { cond } => {
+                uses.push_cond(*cond);
             }
             _ => {}
         }
         uses
     }
 }
+#[cfg(
+    any(
+        feature = "v5t",
+        feature = "v5te",
+        feature = "v5tej",
+        feature = "v6",
+        feature = "v6k",
+        feature = "v7"
+    )
+)]
 impl BlxTarget {
     fn uses(&self, uses: &mut DefsUses) {
         match self {
@@ -1522,6 +9366,7 @@ impl BlxTarget {
         }
     }
 }
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 impl MsrOp2 {
     fn uses(&self, uses: &mut DefsUses) {
         match self {
@@ -1556,6 +9401,9 @@ impl ShiftImm {
     fn uses(&self, uses: &mut DefsUses) {
         let Self { rm, shift_op, imm } = self;
         uses.push(*rm);
+        if *imm == 0 && *shift_op == ShiftOp::Ror {
+            uses.push(Flag::C);
+        }
     }
 }
 impl Op2Shift {
@@ -1568,6 +9416,7 @@ impl Op2Shift {
         }
     }
 }
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 impl AddrLdcStc {
     fn defs(&self, defs: &mut DefsUses) {
         match self {
@@ -1602,6 +9451,9 @@ impl AddrLdrStr {
                     defs.push(*rn);
                 }
             }
+            Self::Post(addr_ldr_str_post) => {
+                addr_ldr_str_post.defs(defs);
+            }
             _ => {}
         }
     }
@@ -1619,6 +9471,10 @@ impl AddrLdrStr {
     }
 }
 impl AddrLdrStrPost {
+    fn defs(&self, defs: &mut DefsUses) {
+        let Self { rn, offset } = self;
+        defs.push(*rn);
+    }
     fn uses(&self, uses: &mut DefsUses) {
         let Self { rn, offset } = self;
         uses.push(*rn);
@@ -1630,6 +9486,9 @@ impl LdrStrOffset {
         match self {
             Self::Reg { subtract, rm, shift_op, imm } => {
                 uses.push(*rm);
+                if *imm == 0 && *shift_op == ShiftOp::Ror {
+                    uses.push(Flag::C);
+                }
             }
             _ => {}
         }
@@ -1643,6 +9502,9 @@ impl AddrMiscLoad {
                     defs.push(*rn);
                 }
             }
+            Self::Post { rn, offset } => {
+                defs.push(*rn);
+            }
             _ => {}
         }
     }
@@ -1663,13 +9525,33 @@ impl AddrMiscLoad {
 impl MiscLoadOffset {
     fn uses(&self, uses: &mut DefsUses) {
         match self {
-            Self::Reg { subtract, rm } => {
+            Self::Reg { subtract, rm, imm } => {
                 uses.push(*rm);
             }
             _ => {}
         }
     }
 }
+#[cfg(
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
+    )
+)]
 impl VcmpF32Op2 {
     fn uses(&self, uses: &mut DefsUses) {
         match self {
@@ -1680,6 +9562,26 @@ impl VcmpF32Op2 {
         }
     }
 }
+#[cfg(
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
+    )
+)]
 impl VcmpF64Op2 {
     fn uses(&self, uses: &mut DefsUses) {
         match self {
@@ -1690,3 +9592,28 @@ impl VcmpF64Op2 {
         }
     }
 }
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl NeonPost {
+    fn uses(&self, uses: &mut DefsUses) {
+        match self {
+            Self::Reg(rm) => {
+                uses.push(*rm);
+            }
+            _ => {}
+        }
+    }
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl NeonAddr {
+    fn defs(&self, defs: &mut DefsUses) {
+        let Self { rn, align, post } = self;
+        if *post != NeonPost::None {
+            defs.push(*rn);
+        }
+    }
+    fn uses(&self, uses: &mut DefsUses) {
+        let Self { rn, align, post } = self;
+        uses.push(*rn);
+        post.uses(uses);
+    }
+}
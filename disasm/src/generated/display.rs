@@ -19,7 +19,7 @@ pub trait FormatIns: core::fmt::Write {
         }
         Ok(())
     }
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Mnemonic suffix, specifies a long load/store for LDC/STC instructions
     fn write_l(&mut self, l: bool) -> core::fmt::Result {
         if l {
@@ -73,7 +73,8 @@ pub trait FormatIns: core::fmt::Write {
             feature = "v5te",
             feature = "v5tej",
             feature = "v6",
-            feature = "v6k"
+            feature = "v6k",
+            feature = "v7"
         )
     )]
     ///The destination of a BLX instruction, which can be direct (immediate) or indirect (register)
@@ -96,19 +97,19 @@ pub trait FormatIns: core::fmt::Write {
         reg_list.write(self)?;
         Ok(())
     }
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Status register
     fn write_status_reg(&mut self, status_reg: StatusReg) -> core::fmt::Result {
         status_reg.write(self)?;
         Ok(())
     }
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Status register with field masks
     fn write_status_fields(&mut self, status_fields: StatusFields) -> core::fmt::Result {
         status_fields.write(self)?;
         Ok(())
     }
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Second operand of the MSR instruction, can be an immediate or a register
     fn write_msr_op2(&mut self, msr_op2: MsrOp2) -> core::fmt::Result {
         msr_op2.write(self)?;
@@ -119,13 +120,13 @@ pub trait FormatIns: core::fmt::Write {
         shift_op.write(self)?;
         Ok(())
     }
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Coprocessor
     fn write_coproc(&mut self, coproc: Coproc) -> core::fmt::Result {
         coproc.write(self)?;
         Ok(())
     }
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Coprocessor register
     fn write_co_reg(&mut self, co_reg: CoReg) -> core::fmt::Result {
         co_reg.write(self)?;
@@ -151,19 +152,19 @@ pub trait FormatIns: core::fmt::Write {
         op2_shift.write(self)?;
         Ok(())
     }
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Mnemonic suffix for CPS, specifies whether to enable/disable interrupt bits or just set the processor mode
     fn write_cps_effect(&mut self, cps_effect: CpsEffect) -> core::fmt::Result {
         cps_effect.write(self)?;
         Ok(())
     }
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///In a CPS instruction, specifies which interrupt bits to enable or disable
     fn write_aif_flags(&mut self, aif_flags: AifFlags) -> core::fmt::Result {
         aif_flags.write(self)?;
         Ok(())
     }
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///The memory address of an LDC/STC instruction
     fn write_addr_ldc_stc(&mut self, addr_ldc_stc: AddrLdcStc) -> core::fmt::Result {
         addr_ldc_stc.write(self)?;
@@ -174,7 +175,7 @@ pub trait FormatIns: core::fmt::Write {
         ldm_stm_mode.write(self)?;
         Ok(())
     }
-    ///The memory address of an LDR(B)/STR(B)/PLD instruction
+    ///The memory address of an LDR(B)(T)/STR(B)(T)/PLD instruction
     fn write_addr_ldr_str(&mut self, addr_ldr_str: AddrLdrStr) -> core::fmt::Result {
         addr_ldr_str.write(self)?;
         Ok(())
@@ -211,22 +212,36 @@ pub trait FormatIns: core::fmt::Write {
         misc_load_offset.write(self)?;
         Ok(())
     }
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Mnemonic suffix for SRS/RFE, specifies how to step the stack pointer
     fn write_srs_rfe_mode(&mut self, srs_rfe_mode: SrsRfeMode) -> core::fmt::Result {
         srs_rfe_mode.write(self)?;
         Ok(())
     }
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Used by SETEND, specifies the endianness for data accesses
     fn write_endianness(&mut self, endianness: Endianness) -> core::fmt::Result {
         endianness.write(self)?;
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Mnemonic suffix, specifies which half of a register to use as an operand
@@ -234,7 +249,12 @@ pub trait FormatIns: core::fmt::Write {
         reg_side.write(self)?;
         Ok(())
     }
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Mnemonic suffix, when performing two 16x16 bit multiplications, swap the two halfwords of Rm
     fn write_swap_rm(&mut self, swap_rm: bool) -> core::fmt::Result {
         if swap_rm {
@@ -242,7 +262,12 @@ pub trait FormatIns: core::fmt::Write {
         }
         Ok(())
     }
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Mnemonic suffix, round the multiplication result instead of truncating
     fn write_round(&mut self, round: bool) -> core::fmt::Result {
         if round {
@@ -251,10 +276,23 @@ pub trait FormatIns: core::fmt::Write {
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Mnemonic suffix, when converting a floating-point number to an integer, round the result towards zero
@@ -269,10 +307,23 @@ pub trait FormatIns: core::fmt::Write {
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///General-purpose register for single-precision floating-point numbers
@@ -281,10 +332,33 @@ pub trait FormatIns: core::fmt::Write {
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "neon",
+                    feature = "vfp_v2",
+                    feature = "vfp_v3",
+                    feature = "vfp_v3_d16"
+                ),
+                any(
+                    feature = "v7",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(
+                    feature = "neon",
+                    feature = "vfp_v2",
+                    feature = "vfp_v3",
+                    feature = "vfp_v3_d16"
+                ),
+                feature = "v7"
+            )
         )
     )]
     ///General-purpose register for double-precision floating-point numbers
@@ -293,10 +367,23 @@ pub trait FormatIns: core::fmt::Write {
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Mnemonic suffix, specifies that a comparison operation should cause an exception if any operand is NaN
@@ -307,10 +394,23 @@ pub trait FormatIns: core::fmt::Write {
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Second operand of a VCMP.F32 instruction, can be zero or a register
@@ -319,10 +419,23 @@ pub trait FormatIns: core::fmt::Write {
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Second operand of a VCMP.F64 instruction, can be zero or a register
@@ -331,10 +444,23 @@ pub trait FormatIns: core::fmt::Write {
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///List of general-purpose single-precision floation-point registers, used by VLDM/VSTM
@@ -343,10 +469,23 @@ pub trait FormatIns: core::fmt::Write {
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///List of general-purpose double-precision floation-point registers, used by VLDM/VSTM
@@ -355,10 +494,23 @@ pub trait FormatIns: core::fmt::Write {
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///A double-precision floating-point register and index (0 or 1) to move to/from
@@ -368,9 +520,45 @@ pub trait FormatIns: core::fmt::Write {
     }
     #[cfg(
         all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+            any(feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"),
+            feature = "v7"
+        )
+    )]
+    ///Floating-point immediate of VMOV, an 8-bit value expanded to a sign, 3-bit exponent and 4-bit fraction
+    fn write_vfp_imm(&mut self, vfp_imm: VfpImm) -> core::fmt::Result {
+        vfp_imm.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    ///Fixed-point data type of a VCVT instruction
+    fn write_fixed_type(&mut self, fixed_type: FixedType) -> core::fmt::Result {
+        fixed_type.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    ///Mnemonic suffix for VCVTB/VCVTT, specifies which half of the single-precision register holds the half-precision value
+    fn write_half(&mut self, half: Half) -> core::fmt::Result {
+        half.write(self)?;
+        Ok(())
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Floating-Point Status and Control Register
@@ -379,10 +567,23 @@ pub trait FormatIns: core::fmt::Write {
         Ok(())
     }
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Mnemonic suffix for VLDM/VSTM, specifies how to step the base address
@@ -393,6 +594,90 @@ pub trait FormatIns: core::fmt::Write {
         vldm_vstm_mode.write(self)?;
         Ok(())
     }
+    #[cfg(feature = "v7")]
+    ///Limits which memory accesses a DMB/DSB/ISB instruction applies to
+    fn write_barrier_option(
+        &mut self,
+        barrier_option: BarrierOption,
+    ) -> core::fmt::Result {
+        barrier_option.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    ///Mnemonic suffix for IT, specifies which of the following instructions use the condition or its inverse
+    fn write_it_mask(&mut self, it_mask: ItMask) -> core::fmt::Result {
+        it_mask.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Quadword register of the Advanced SIMD extension, overlaps two doubleword registers
+    fn write_qreg(&mut self, qreg: Qreg) -> core::fmt::Result {
+        qreg.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Doubleword or quadword register of an Advanced SIMD instruction, selected by the highest bit
+    fn write_neon_reg(&mut self, neon_reg: NeonReg) -> core::fmt::Result {
+        neon_reg.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Data type suffix of an Advanced SIMD instruction which works on integers of any signedness
+    fn write_neon_int_type(&mut self, neon_int_type: NeonIntType) -> core::fmt::Result {
+        neon_int_type.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Data type suffix of an Advanced SIMD instruction which works on signed or unsigned integers
+    fn write_neon_sign_type(
+        &mut self,
+        neon_sign_type: NeonSignType,
+    ) -> core::fmt::Result {
+        neon_sign_type.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Data type suffix of an Advanced SIMD instruction which only depends on the element size
+    fn write_neon_size(&mut self, neon_size: NeonSize) -> core::fmt::Result {
+        neon_size.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Source and destination data types of an Advanced SIMD VCVT instruction
+    fn write_neon_cvt_type(&mut self, neon_cvt_type: NeonCvtType) -> core::fmt::Result {
+        neon_cvt_type.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///64-bit immediate of VMOV, an 8-bit value where each bit is expanded to a byte of ones or zeros
+    fn write_neon_imm64(&mut self, neon_imm64: NeonImm64) -> core::fmt::Result {
+        neon_imm64.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///List of doubleword registers used by VLD1-VLD4/VST1-VST4 and VTBL/VTBX, either whole registers, one lane or all lanes
+    fn write_neon_reg_list(&mut self, neon_reg_list: NeonRegList) -> core::fmt::Result {
+        neon_reg_list.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Alignment of the base address of VLD1-VLD4/VST1-VST4, in bits
+    fn write_neon_align(&mut self, neon_align: NeonAlign) -> core::fmt::Result {
+        neon_align.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Base register update of VLD1-VLD4/VST1-VST4
+    fn write_neon_post(&mut self, neon_post: NeonPost) -> core::fmt::Result {
+        neon_post.write(self)?;
+        Ok(())
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///The memory address of VLD1-VLD4/VST1-VST4
+    fn write_neon_addr(&mut self, neon_addr: NeonAddr) -> core::fmt::Result {
+        neon_addr.write(self)?;
+        Ok(())
+    }
     fn write_ins(&mut self, ins: &Ins) -> core::fmt::Result {
         ins.write_opcode(self)?;
         ins.write_params(self)?;
@@ -416,7 +701,8 @@ impl FormatValue for BranchTarget {
         feature = "v5te",
         feature = "v5tej",
         feature = "v6",
-        feature = "v6k"
+        feature = "v6k",
+        feature = "v7"
     )
 )]
 impl FormatValue for BlxTarget {
@@ -616,7 +902,7 @@ impl FormatValue for Reg {
         Ok(())
     }
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 impl FormatValue for StatusReg {
     fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
@@ -633,7 +919,7 @@ impl FormatValue for StatusReg {
         Ok(())
     }
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 impl FormatValue for StatusFields {
     fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
@@ -657,7 +943,7 @@ impl FormatValue for StatusFields {
         Ok(())
     }
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 impl FormatValue for MsrOp2 {
     fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
@@ -697,7 +983,7 @@ impl FormatValue for ShiftOp {
         Ok(())
     }
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 impl FormatValue for Coproc {
     fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
@@ -756,7 +1042,7 @@ impl FormatValue for Coproc {
         Ok(())
     }
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 impl FormatValue for CoReg {
     fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
@@ -891,7 +1177,7 @@ impl FormatValue for Op2Shift {
         Ok(())
     }
 }
-#[cfg(any(feature = "v6", feature = "v6k"))]
+#[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
 impl FormatValue for CpsEffect {
     fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
@@ -909,7 +1195,7 @@ impl FormatValue for CpsEffect {
         Ok(())
     }
 }
-#[cfg(any(feature = "v6", feature = "v6k"))]
+#[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
 impl FormatValue for AifFlags {
     fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
@@ -928,7 +1214,7 @@ impl FormatValue for AifFlags {
         Ok(())
     }
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 impl FormatValue for AddrLdcStc {
     fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
@@ -1094,15 +1380,30 @@ impl FormatValue for MiscLoadOffset {
                 formatter.write_str("#")?;
                 formatter.write_simm(*offset)?;
             }
-            Self::Reg { subtract, rm } => {
-                formatter.write_subtract(*subtract)?;
-                formatter.write_reg(*rm)?;
+            Self::Reg { subtract, rm, imm } => {
+                if *imm == 0 {
+                    formatter.write_subtract(*subtract)?;
+                    formatter.write_reg(*rm)?;
+                } else {
+                    formatter.write_subtract(*subtract)?;
+                    formatter.write_reg(*rm)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("lsl")?;
+                    formatter.write_space()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*imm)?;
+                }
             }
         }
         Ok(())
     }
 }
-#[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+#[cfg(
+    any(
+        all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+        all(feature = "thumb", feature = "v7")
+    )
+)]
 impl FormatValue for SrsRfeMode {
     fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
@@ -1125,7 +1426,7 @@ impl FormatValue for SrsRfeMode {
         Ok(())
     }
 }
-#[cfg(any(feature = "v6", feature = "v6k"))]
+#[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
 impl FormatValue for Endianness {
     fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
@@ -1143,9 +1444,18 @@ impl FormatValue for Endianness {
     }
 }
 #[cfg(
-    all(
-        feature = "arm",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(feature = "thumb", feature = "v7")
     )
 )]
 impl FormatValue for RegSide {
@@ -1165,10 +1475,23 @@ impl FormatValue for RegSide {
     }
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 impl FormatValue for Sreg {
@@ -1278,10 +1601,33 @@ impl FormatValue for Sreg {
     }
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(
+                feature = "neon",
+                feature = "vfp_v2",
+                feature = "vfp_v3",
+                feature = "vfp_v3_d16"
+            ),
+            any(
+                feature = "v7",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(
+                feature = "neon",
+                feature = "vfp_v2",
+                feature = "vfp_v3",
+                feature = "vfp_v3_d16"
+            ),
+            feature = "v7"
+        )
     )
 )]
 impl FormatValue for Dreg {
@@ -1391,10 +1737,23 @@ impl FormatValue for Dreg {
     }
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 impl FormatValue for VcmpF32Op2 {
@@ -1414,10 +1773,23 @@ impl FormatValue for VcmpF32Op2 {
     }
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 impl FormatValue for VcmpF64Op2 {
@@ -1437,10 +1809,23 @@ impl FormatValue for VcmpF64Op2 {
     }
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 impl FormatValue for DregIndex {
@@ -1460,11 +1845,80 @@ impl FormatValue for DregIndex {
         Ok(())
     }
 }
+#[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+impl FormatValue for FixedType {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Self::S16 => {
+                if formatter.options().ual {
+                    formatter.write_str("s16")?;
+                } else {
+                    formatter.write_str("sh")?;
+                }
+            }
+            Self::S32 => {
+                if formatter.options().ual {
+                    formatter.write_str("s32")?;
+                } else {
+                    formatter.write_str("sl")?;
+                }
+            }
+            Self::U16 => {
+                if formatter.options().ual {
+                    formatter.write_str("u16")?;
+                } else {
+                    formatter.write_str("uh")?;
+                }
+            }
+            Self::U32 => {
+                if formatter.options().ual {
+                    formatter.write_str("u32")?;
+                } else {
+                    formatter.write_str("ul")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+impl FormatValue for Half {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Self::B => {
+                formatter.write_str("b")?;
+            }
+            Self::T => {
+                formatter.write_str("t")?;
+            }
+        }
+        Ok(())
+    }
+}
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 impl FormatValue for Fpscr {
@@ -1478,10 +1932,23 @@ impl FormatValue for Fpscr {
     }
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 impl FormatValue for VldmVstmMode {
@@ -1500,31 +1967,331 @@ impl FormatValue for VldmVstmMode {
         Ok(())
     }
 }
-impl Ins {
-    pub fn write_opcode<F>(&self, formatter: &mut F) -> core::fmt::Result
+#[cfg(feature = "v7")]
+impl FormatValue for BarrierOption {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
     where
         F: FormatIns + ?Sized,
     {
         match self {
-            Ins::Adc { s, thumb, cond, rd, rn, op2 } => {
-                if formatter.options().ual {
-                    formatter.write_str("adc")?;
-                    formatter.write_s(*s)?;
-                    formatter.write_cond(*cond)?;
-                } else {
-                    formatter.write_str("adc")?;
-                    formatter.write_cond(*cond)?;
-                    if !*thumb {
-                        formatter.write_s(*s)?;
-                    } else {}
-                }
+            Self::Reserved0 => {
+                formatter.write_str("#0x0")?;
             }
-            Ins::Add { s, thumb, cond, rd, rn, op2 } => {
-                if formatter.options().ual {
-                    formatter.write_str("add")?;
-                    formatter.write_s(*s)?;
-                    formatter.write_cond(*cond)?;
-                } else {
+            Self::Reserved1 => {
+                formatter.write_str("#0x1")?;
+            }
+            Self::Oshst => {
+                formatter.write_str("oshst")?;
+            }
+            Self::Osh => {
+                formatter.write_str("osh")?;
+            }
+            Self::Reserved4 => {
+                formatter.write_str("#0x4")?;
+            }
+            Self::Reserved5 => {
+                formatter.write_str("#0x5")?;
+            }
+            Self::Nshst => {
+                formatter.write_str("nshst")?;
+            }
+            Self::Nsh => {
+                formatter.write_str("nsh")?;
+            }
+            Self::Reserved8 => {
+                formatter.write_str("#0x8")?;
+            }
+            Self::Reserved9 => {
+                formatter.write_str("#0x9")?;
+            }
+            Self::Ishst => {
+                formatter.write_str("ishst")?;
+            }
+            Self::Ish => {
+                formatter.write_str("ish")?;
+            }
+            Self::Reserved12 => {
+                formatter.write_str("#0xc")?;
+            }
+            Self::Reserved13 => {
+                formatter.write_str("#0xd")?;
+            }
+            Self::St => {
+                formatter.write_str("st")?;
+            }
+            Self::Sy => {
+                formatter.write_str("sy")?;
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl FormatValue for Qreg {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Self::Q0 => {
+                formatter.write_str("q0")?;
+            }
+            Self::Q1 => {
+                formatter.write_str("q1")?;
+            }
+            Self::Q2 => {
+                formatter.write_str("q2")?;
+            }
+            Self::Q3 => {
+                formatter.write_str("q3")?;
+            }
+            Self::Q4 => {
+                formatter.write_str("q4")?;
+            }
+            Self::Q5 => {
+                formatter.write_str("q5")?;
+            }
+            Self::Q6 => {
+                formatter.write_str("q6")?;
+            }
+            Self::Q7 => {
+                formatter.write_str("q7")?;
+            }
+            Self::Q8 => {
+                formatter.write_str("q8")?;
+            }
+            Self::Q9 => {
+                formatter.write_str("q9")?;
+            }
+            Self::Q10 => {
+                formatter.write_str("q10")?;
+            }
+            Self::Q11 => {
+                formatter.write_str("q11")?;
+            }
+            Self::Q12 => {
+                formatter.write_str("q12")?;
+            }
+            Self::Q13 => {
+                formatter.write_str("q13")?;
+            }
+            Self::Q14 => {
+                formatter.write_str("q14")?;
+            }
+            Self::Q15 => {
+                formatter.write_str("q15")?;
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl FormatValue for NeonReg {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Self::D(dreg) => {
+                formatter.write_dreg(*dreg)?;
+            }
+            Self::Q(qreg) => {
+                formatter.write_qreg(*qreg)?;
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl FormatValue for NeonIntType {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Self::I8 => {
+                formatter.write_str("i8")?;
+            }
+            Self::I16 => {
+                formatter.write_str("i16")?;
+            }
+            Self::I32 => {
+                formatter.write_str("i32")?;
+            }
+            Self::I64 => {
+                formatter.write_str("i64")?;
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl FormatValue for NeonSignType {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Self::S8 => {
+                formatter.write_str("s8")?;
+            }
+            Self::S16 => {
+                formatter.write_str("s16")?;
+            }
+            Self::S32 => {
+                formatter.write_str("s32")?;
+            }
+            Self::S64 => {
+                formatter.write_str("s64")?;
+            }
+            Self::U8 => {
+                formatter.write_str("u8")?;
+            }
+            Self::U16 => {
+                formatter.write_str("u16")?;
+            }
+            Self::U32 => {
+                formatter.write_str("u32")?;
+            }
+            Self::U64 => {
+                formatter.write_str("u64")?;
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl FormatValue for NeonSize {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Self::Size8 => {
+                formatter.write_str("8")?;
+            }
+            Self::Size16 => {
+                formatter.write_str("16")?;
+            }
+            Self::Size32 => {
+                formatter.write_str("32")?;
+            }
+            Self::Size64 => {
+                formatter.write_str("64")?;
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl FormatValue for NeonCvtType {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Self::F32S32 => {
+                formatter.write_str("f32.s32")?;
+            }
+            Self::F32U32 => {
+                formatter.write_str("f32.u32")?;
+            }
+            Self::S32F32 => {
+                formatter.write_str("s32.f32")?;
+            }
+            Self::U32F32 => {
+                formatter.write_str("u32.f32")?;
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl FormatValue for NeonAlign {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Self::None => {}
+            Self::Align16 => {
+                formatter.write_str(":16")?;
+            }
+            Self::Align32 => {
+                formatter.write_str(":32")?;
+            }
+            Self::Align64 => {
+                formatter.write_str(":64")?;
+            }
+            Self::Align128 => {
+                formatter.write_str(":128")?;
+            }
+            Self::Align256 => {
+                formatter.write_str(":256")?;
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl FormatValue for NeonPost {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Self::None => {}
+            Self::Writeback => {
+                formatter.write_str("!")?;
+            }
+            Self::Reg(rm) => {
+                formatter.write_separator()?;
+                formatter.write_reg(*rm)?;
+            }
+        }
+        Ok(())
+    }
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl FormatValue for NeonAddr {
+    fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        let Self { rn, align, post } = self;
+        formatter.write_str("[")?;
+        formatter.write_reg(*rn)?;
+        formatter.write_neon_align(*align)?;
+        formatter.write_str("]")?;
+        formatter.write_neon_post(*post)?;
+        Ok(())
+    }
+}
+impl Ins {
+    pub fn write_opcode<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        match self {
+            Ins::Adc { s, thumb, cond, rd, rn, op2 } => {
+                if formatter.options().ual {
+                    formatter.write_str("adc")?;
+                    formatter.write_s(*s)?;
+                    formatter.write_cond(*cond)?;
+                } else {
+                    formatter.write_str("adc")?;
+                    formatter.write_cond(*cond)?;
+                    if !*thumb {
+                        formatter.write_s(*s)?;
+                    } else {}
+                }
+            }
+            Ins::Add { s, thumb, cond, rd, rn, op2 } => {
+                if formatter.options().ual {
+                    formatter.write_str("add")?;
+                    formatter.write_s(*s)?;
+                    formatter.write_cond(*cond)?;
+                } else {
                     formatter.write_str("add")?;
                     formatter.write_cond(*cond)?;
                     if !*thumb {
@@ -1559,6 +2326,16 @@ impl Ins {
                 formatter.write_str("b")?;
                 formatter.write_cond(*cond)?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Bfc { cond, rd, lsb, width } => {
+                formatter.write_str("bfc")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(feature = "v7")]
+            Ins::Bfi { cond, rd, rn, lsb, width } => {
+                formatter.write_str("bfi")?;
+                formatter.write_cond(*cond)?;
+            }
             Ins::Bic { s, thumb, cond, rd, rn, op2 } => {
                 if formatter.options().ual {
                     formatter.write_str("bic")?;
@@ -1578,7 +2355,8 @@ impl Ins {
                     feature = "v5te",
                     feature = "v5tej",
                     feature = "v6",
-                    feature = "v6k"
+                    feature = "v6k",
+                    feature = "v7"
                 )
             )]
             Ins::Bkpt { imm } => {
@@ -1594,7 +2372,8 @@ impl Ins {
                     feature = "v5te",
                     feature = "v5tej",
                     feature = "v6",
-                    feature = "v6k"
+                    feature = "v6k",
+                    feature = "v7"
                 )
             )]
             Ins::Blx { cond, target } => {
@@ -1608,7 +2387,8 @@ impl Ins {
                     feature = "v5te",
                     feature = "v5tej",
                     feature = "v6",
-                    feature = "v6k"
+                    feature = "v6k",
+                    feature = "v7"
                 )
             )]
             Ins::Bx { cond, rm } => {
@@ -1616,49 +2396,78 @@ impl Ins {
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(feature = "v5tej", feature = "v6", feature = "v6k")
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Bxj { cond, rm } => {
                 formatter.write_str("bxj")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbnz { rn, target } => {
+                formatter.write_str("cbnz")?;
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbz { rn, target } => {
+                formatter.write_str("cbz")?;
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Cdp { cond, coproc, opc1, crd, crn, crm, opc2 } => {
                 formatter.write_str("cdp")?;
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Cdp2 { coproc, opc1, crd, crn, crm, opc2 } => {
                 formatter.write_str("cdp2")?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Clrex {} => {
                 formatter.write_str("clrex")?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Clz { cond, rd, rm } => {
@@ -1673,21 +2482,36 @@ impl Ins {
                 formatter.write_str("cmp")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Cps { effect, aif, mode } => {
                 formatter.write_str("cps")?;
                 formatter.write_cps_effect(*effect)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Csdb { cond } => {
                 formatter.write_str("csdb")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Dbg { cond, option } => {
                 formatter.write_str("dbg")?;
                 formatter.write_cond(*cond)?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Dmb { cond, option } => {
+                formatter.write_str("dmb")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(feature = "v7")]
+            Ins::Dsb { cond, option } => {
+                formatter.write_str("dsb")?;
+                formatter.write_cond(*cond)?;
+            }
             Ins::Eor { s, thumb, cond, rd, rn, op2 } => {
                 if formatter.options().ual {
                     formatter.write_str("eor")?;
@@ -1701,7 +2525,17 @@ impl Ins {
                     } else {}
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(feature = "v7")]
+            Ins::Isb { cond, option } => {
+                formatter.write_str("isb")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::It { firstcond, mask } => {
+                formatter.write_str("it")?;
+                formatter.write_it_mask(*mask)?;
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldc { l, cond, coproc, crd, dest } => {
                 if formatter.options().ual {
                     formatter.write_str("ldc")?;
@@ -1714,15 +2548,19 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Ldc2 { l, coproc, crd, dest } => {
@@ -1754,7 +2592,7 @@ impl Ins {
                     formatter.write_str("b")?;
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldrbt { cond, rd, addr } => {
                 if formatter.options().ual {
                     formatter.write_str("ldrbt")?;
@@ -1766,14 +2604,18 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Ldrd { cond, rd, rd2, addr } => {
@@ -1786,22 +2628,45 @@ impl Ins {
                     formatter.write_str("d")?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
-            Ins::Ldrex { cond, rd, rn } => {
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrex { cond, rd, rn, offset } => {
                 formatter.write_str("ldrex")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexb { cond, rd, rn } => {
                 formatter.write_str("ldrexb")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexd { cond, rd, rd2, rn } => {
                 formatter.write_str("ldrexd")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexh { cond, rd, rn } => {
                 formatter.write_str("ldrexh")?;
                 formatter.write_cond(*cond)?;
@@ -1816,6 +2681,17 @@ impl Ins {
                     formatter.write_str("h")?;
                 }
             }
+            #[cfg(feature = "v7")]
+            Ins::Ldrht { cond, rd, addr } => {
+                if formatter.options().ual {
+                    formatter.write_str("ldrht")?;
+                    formatter.write_cond(*cond)?;
+                } else {
+                    formatter.write_str("ldr")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str("ht")?;
+                }
+            }
             Ins::Ldrsb { cond, rd, addr } => {
                 if formatter.options().ual {
                     formatter.write_str("ldrsb")?;
@@ -1826,6 +2702,17 @@ impl Ins {
                     formatter.write_str("sb")?;
                 }
             }
+            #[cfg(feature = "v7")]
+            Ins::Ldrsbt { cond, rd, addr } => {
+                if formatter.options().ual {
+                    formatter.write_str("ldrsbt")?;
+                    formatter.write_cond(*cond)?;
+                } else {
+                    formatter.write_str("ldr")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str("sbt")?;
+                }
+            }
             Ins::Ldrsh { cond, rd, addr } => {
                 if formatter.options().ual {
                     formatter.write_str("ldrsh")?;
@@ -1836,7 +2723,18 @@ impl Ins {
                     formatter.write_str("sh")?;
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(feature = "v7")]
+            Ins::Ldrsht { cond, rd, addr } => {
+                if formatter.options().ual {
+                    formatter.write_str("ldrsht")?;
+                    formatter.write_cond(*cond)?;
+                } else {
+                    formatter.write_str("ldr")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str("sht")?;
+                }
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldrt { cond, rd, addr } => {
                 if formatter.options().ual {
                     formatter.write_str("ldrt")?;
@@ -1867,46 +2765,62 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mcr { cond, coproc, opc1, rd, crn, crm, opc2 } => {
                 formatter.write_str("mcr")?;
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Mcr2 { coproc, opc1, rd, crn, crm, opc2 } => {
                 formatter.write_str("mcr2")?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Mcrr { cond, coproc, opc, rd, rd2, crm } => {
                 formatter.write_str("mcrr")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mcrr2 { coproc, opc, rd, rd2, crm } => {
                 formatter.write_str("mcrr2")?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mla { s, cond, rd, rn, rm, ra } => {
                 if formatter.options().ual {
                     formatter.write_str("mla")?;
@@ -1918,6 +2832,11 @@ impl Ins {
                     formatter.write_s(*s)?;
                 }
             }
+            #[cfg(feature = "v7")]
+            Ins::Mls { cond, rd, rn, rm, ra } => {
+                formatter.write_str("mls")?;
+                formatter.write_cond(*cond)?;
+            }
             Ins::Mov { s, thumb, cond, rd, op2 } => {
                 if formatter.options().ual {
                     formatter.write_str("mov")?;
@@ -1931,51 +2850,77 @@ impl Ins {
                     } else {}
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(feature = "v7")]
+            Ins::Movt { cond, rd, imm } => {
+                formatter.write_str("movt")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(feature = "v7")]
+            Ins::Movw { cond, rd, imm } => {
+                formatter.write_str("movw")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mrc { cond, coproc, opc1, rd, crn, crm, opc2 } => {
                 formatter.write_str("mrc")?;
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Mrc2 { coproc, opc1, rd, crn, crm, opc2 } => {
                 formatter.write_str("mrc2")?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Mrrc { cond, coproc, opc, rd, rd2, crm } => {
                 formatter.write_str("mrrc")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mrrc2 { coproc, opc, rd, rd2, crm } => {
                 formatter.write_str("mrrc2")?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mrs { cond, rd, status_reg } => {
                 formatter.write_str("mrs")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Msr { cond, status_fields, op2 } => {
                 formatter.write_str("msr")?;
                 formatter.write_cond(*cond)?;
@@ -2010,11 +2955,28 @@ impl Ins {
             Ins::Neg { rd, rm } => {
                 formatter.write_str("neg")?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Nop { cond } => {
                 formatter.write_str("nop")?;
                 formatter.write_cond(*cond)?;
             }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Orn { s, cond, rd, rn, op2 } => {
+                if formatter.options().ual {
+                    formatter.write_str("orn")?;
+                    formatter.write_s(*s)?;
+                    formatter.write_cond(*cond)?;
+                } else {
+                    formatter.write_str("orn")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_s(*s)?;
+                }
+            }
             Ins::Orr { s, thumb, cond, rd, rn, op2 } => {
                 if formatter.options().ual {
                     formatter.write_str("orr")?;
@@ -2028,30 +2990,54 @@ impl Ins {
                     } else {}
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Pkhbt { cond, rd, rn, rm, shift_op, shift } => {
                 formatter.write_str("pkhbt")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Pkhtb { cond, rd, rn, rm, shift_op, shift } => {
                 formatter.write_str("pkhtb")?;
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Pld { addr } => {
                 formatter.write_str("pld")?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Pli { addr } => {
+                formatter.write_str("pli")?;
+            }
             Ins::Pop { cond, regs } => {
                 formatter.write_str("pop")?;
                 formatter.write_cond(*cond)?;
@@ -2061,31 +3047,59 @@ impl Ins {
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Qadd { cond, rd, rm, rn } => {
                 formatter.write_str("qadd")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qadd16 { cond, rd, rn, rm } => {
                 formatter.write_str("qadd16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qadd8 { cond, rd, rn, rm } => {
                 formatter.write_str("qadd8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qasx { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("qasx")?;
@@ -2096,14 +3110,18 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Qdadd { cond, rd, rm, rn } => {
@@ -2111,21 +3129,33 @@ impl Ins {
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Qdsub { cond, rd, rm, rn } => {
                 formatter.write_str("qdsub")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsax { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("qsax")?;
@@ -2136,46 +3166,79 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Qsub { cond, rd, rm, rn } => {
                 formatter.write_str("qsub")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsub16 { cond, rd, rn, rm } => {
                 formatter.write_str("qsub16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsub8 { cond, rd, rn, rm } => {
                 formatter.write_str("qsub8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(feature = "v7")]
+            Ins::Rbit { cond, rd, rm } => {
+                formatter.write_str("rbit")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Rev { cond, rd, rm } => {
                 formatter.write_str("rev")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Rev16 { cond, rd, rm } => {
                 formatter.write_str("rev16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Revsh { cond, rd, rm } => {
                 formatter.write_str("revsh")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Rfe { addr_mode, rn, writeback } => {
                 formatter.write_str("rfe")?;
                 formatter.write_srs_rfe_mode(*addr_mode)?;
@@ -2190,7 +3253,7 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Rrx { s, cond, rd, rm } => {
                 formatter.write_str("rrx")?;
                 formatter.write_s(*s)?;
@@ -2219,17 +3282,41 @@ impl Ins {
                     formatter.write_s(*s)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sadd16 { cond, rd, rn, rm } => {
                 formatter.write_str("sadd16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sadd8 { cond, rd, rn, rm } => {
                 formatter.write_str("sadd8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sasx { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("sasx")?;
@@ -2252,31 +3339,78 @@ impl Ins {
                     } else {}
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(feature = "v7")]
+            Ins::Sbfx { cond, rd, rn, lsb, width } => {
+                formatter.write_str("sbfx")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(feature = "v7")]
+            Ins::Sdiv { cond, rd, rn, rm } => {
+                formatter.write_str("sdiv")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sel { cond, rd, rn, rm } => {
                 formatter.write_str("sel")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Setend { endian } => {
                 formatter.write_str("setend")?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sev { cond } => {
                 formatter.write_str("sev")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shadd16 { cond, rd, rn, rm } => {
                 formatter.write_str("shadd16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shadd8 { cond, rd, rn, rm } => {
                 formatter.write_str("shadd8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shasx { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("shasx")?;
@@ -2286,7 +3420,15 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsax { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("shsax")?;
@@ -2296,25 +3438,45 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsub16 { cond, rd, rn, rm } => {
                 formatter.write_str("shsub16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsub8 { cond, rd, rn, rm } => {
                 formatter.write_str("shsub8")?;
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Smla { cond, rd, rn, rn_side, rm, rm_side, ra } => {
@@ -2323,13 +3485,21 @@ impl Ins {
                 formatter.write_reg_side(*rm_side)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlad { cond, rd, rn, rm, swap_rm, ra } => {
                 formatter.write_str("smlad")?;
                 formatter.write_swap_rm(*swap_rm)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Smlal { s, cond, rd_lo, rd_hi, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("smlal")?;
@@ -2342,14 +3512,18 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::SmlalHalf { cond, rd_lo, rd_hi, rn, rn_side, rm, rm_side } => {
@@ -2358,21 +3532,33 @@ impl Ins {
                 formatter.write_reg_side(*rm_side)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlald { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
                 formatter.write_str("smlald")?;
                 formatter.write_swap_rm(*swap_rm)?;
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Smlaw { cond, rd, rn, rm, rm_side, ra } => {
@@ -2380,51 +3566,103 @@ impl Ins {
                 formatter.write_reg_side(*rm_side)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlsd { cond, rd, rn, rm, swap_rm, ra } => {
                 formatter.write_str("smlsd")?;
                 formatter.write_swap_rm(*swap_rm)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlsld { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
                 formatter.write_str("smlsld")?;
                 formatter.write_swap_rm(*swap_rm)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmla { round, cond, rd, rn, rm, ra } => {
                 formatter.write_str("smmla")?;
                 formatter.write_round(*round)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmls { round, cond, rd, rn, rm, ra } => {
                 formatter.write_str("smmls")?;
                 formatter.write_round(*round)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmul { round, cond, rd, rn, rm } => {
                 formatter.write_str("smmul")?;
                 formatter.write_round(*round)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smuad { cond, rd, rn, rm, swap_rm } => {
                 formatter.write_str("smuad")?;
                 formatter.write_swap_rm(*swap_rm)?;
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Smul { cond, rd, rn, rn_side, rm, rm_side } => {
@@ -2433,7 +3671,7 @@ impl Ins {
                 formatter.write_reg_side(*rm_side)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Smull { s, cond, rd_lo, rd_hi, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("smull")?;
@@ -2446,14 +3684,18 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Smulw { cond, rd, rn, rm, rm_side } => {
@@ -2461,28 +3703,68 @@ impl Ins {
                 formatter.write_reg_side(*rm_side)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smusd { cond, rd, rn, rm, swap_rm } => {
                 formatter.write_str("smusd")?;
                 formatter.write_swap_rm(*swap_rm)?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Srs { addr_mode, rn, writeback, mode } => {
                 formatter.write_str("srs")?;
                 formatter.write_srs_rfe_mode(*addr_mode)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssat { cond, rd, imm, op2 } => {
                 formatter.write_str("ssat")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssat16 { cond, rd, imm, rn } => {
                 formatter.write_str("ssat16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssax { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("ssax")?;
@@ -2492,17 +3774,33 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssub16 { cond, rd, rn, rm } => {
                 formatter.write_str("ssub16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssub8 { cond, rd, rn, rm } => {
                 formatter.write_str("ssub8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Stc { l, cond, coproc, crd, dest } => {
                 if formatter.options().ual {
                     formatter.write_str("stc")?;
@@ -2515,15 +3813,19 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Stc2 { l, coproc, crd, dest } => {
@@ -2555,7 +3857,7 @@ impl Ins {
                     formatter.write_str("b")?;
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Strbt { cond, rd, addr } => {
                 if formatter.options().ual {
                     formatter.write_str("strbt")?;
@@ -2567,14 +3869,18 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Strd { cond, rd, rd2, addr } => {
@@ -2587,22 +3893,45 @@ impl Ins {
                     formatter.write_str("d")?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
-            Ins::Strex { cond, rd, rm, rn } => {
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strex { cond, rd, rm, rn, offset } => {
                 formatter.write_str("strex")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexb { cond, rd, rm, rn } => {
                 formatter.write_str("strexb")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexd { cond, rd, rm, rm2, rn } => {
                 formatter.write_str("strexd")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexh { cond, rd, rm, rn } => {
                 formatter.write_str("strexh")?;
                 formatter.write_cond(*cond)?;
@@ -2617,7 +3946,18 @@ impl Ins {
                     formatter.write_str("h")?;
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(feature = "v7")]
+            Ins::Strht { cond, rd, addr } => {
+                if formatter.options().ual {
+                    formatter.write_str("strht")?;
+                    formatter.write_cond(*cond)?;
+                } else {
+                    formatter.write_str("str")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str("ht")?;
+                }
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Strt { cond, rd, addr } => {
                 if formatter.options().ual {
                     formatter.write_str("strt")?;
@@ -2660,37 +4000,79 @@ impl Ins {
                 formatter.write_str("swpb")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtab { cond, rd, rn, rm, rotate } => {
                 formatter.write_str("sxtab")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtab16 { cond, rd, rn, rm, rotate } => {
                 formatter.write_str("sxtab16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtah { cond, rd, rn, rm, rotate } => {
                 formatter.write_str("sxtah")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Sxtb { cond, rd, rm, rotate } => {
                 formatter.write_str("sxtb")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtb16 { cond, rd, rm, rotate } => {
                 formatter.write_str("sxtb16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Sxth { cond, rd, rm, rotate } => {
                 formatter.write_str("sxth")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbb { cond, rn, rm } => {
+                formatter.write_str("tbb")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbh { cond, rn, rm } => {
+                formatter.write_str("tbh")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Teq { cond, rn, op2 } => {
                 formatter.write_str("teq")?;
                 formatter.write_cond(*cond)?;
@@ -2699,21 +4081,50 @@ impl Ins {
                 formatter.write_str("tst")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uadd16 { cond, rd, rn, rm } => {
                 formatter.write_str("uadd16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uadd8 { cond, rd, rn, rm } => {
                 formatter.write_str("uadd8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uasx { cond, rd, rn, rm } => {
                 formatter.write_str("uasx")?;
                 formatter.write_cond(*cond)?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Ubfx { cond, rd, rn, lsb, width } => {
+                formatter.write_str("ubfx")?;
+                formatter.write_cond(*cond)?;
+            }
             #[cfg(
                 any(
                     feature = "v4t",
@@ -2721,23 +4132,53 @@ impl Ins {
                     feature = "v5te",
                     feature = "v5tej",
                     feature = "v6",
-                    feature = "v6k"
+                    feature = "v6k",
+                    feature = "v7"
                 )
             )]
             Ins::Udf { imm } => {
                 formatter.write_str("udf")?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(feature = "v7")]
+            Ins::Udiv { cond, rd, rn, rm } => {
+                formatter.write_str("udiv")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhadd16 { cond, rd, rn, rm } => {
                 formatter.write_str("uhadd16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhadd8 { cond, rd, rn, rm } => {
                 formatter.write_str("uhadd8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhasx { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("uhasx")?;
@@ -2747,7 +4188,15 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsax { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("uhsax")?;
@@ -2757,22 +4206,46 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsub16 { cond, rd, rn, rm } => {
                 formatter.write_str("uhsub16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsub8 { cond, rd, rn, rm } => {
                 formatter.write_str("uhsub8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Umaal { cond, rd_lo, rd_hi, rn, rm } => {
                 formatter.write_str("umaal")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Umlal { s, cond, rd_lo, rd_hi, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("umlal")?;
@@ -2784,7 +4257,7 @@ impl Ins {
                     formatter.write_s(*s)?;
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Umull { s, cond, rd_lo, rd_hi, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("umull")?;
@@ -2796,17 +4269,41 @@ impl Ins {
                     formatter.write_s(*s)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqadd16 { cond, rd, rn, rm } => {
                 formatter.write_str("uqadd16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqadd8 { cond, rd, rn, rm } => {
                 formatter.write_str("uqadd8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqasx { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("uqasx")?;
@@ -2816,7 +4313,15 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsax { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("uqsax")?;
@@ -2826,37 +4331,93 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsub16 { cond, rd, rn, rm } => {
                 formatter.write_str("uqsub16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsub8 { cond, rd, rn, rm } => {
                 formatter.write_str("uqsub8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usad8 { cond, rd, rn, rm } => {
                 formatter.write_str("usad8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usada8 { cond, rd, rn, rm, ra } => {
                 formatter.write_str("usada8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usat { cond, rd, imm, op2 } => {
                 formatter.write_str("usat")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usat16 { cond, rd, imm, rn } => {
                 formatter.write_str("usat16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usax { cond, rd, rn, rm } => {
                 if formatter.options().ual {
                     formatter.write_str("usax")?;
@@ -2866,55 +4427,160 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usub16 { cond, rd, rn, rm } => {
                 formatter.write_str("usub16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usub8 { cond, rd, rn, rm } => {
                 formatter.write_str("usub8")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtab { cond, rd, rn, rm, rotate } => {
                 formatter.write_str("uxtab")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtab16 { cond, rd, rn, rm, rotate } => {
                 formatter.write_str("uxtab16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtah { cond, rd, rn, rm, rotate } => {
                 formatter.write_str("uxtah")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Uxtb { cond, rd, rm, rotate } => {
                 formatter.write_str("uxtb")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtb16 { cond, rd, rm, rotate } => {
                 formatter.write_str("uxtb16")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Uxth { cond, rd, rm, rotate } => {
                 formatter.write_str("uxth")?;
                 formatter.write_cond(*cond)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaba { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vaba")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabal { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vabal")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabd { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vabd")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabdF32 { cond, vd, vn, vm } => {
+                formatter.write_str("vabd")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabdl { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vabdl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabs { cond, dt, vd, vm } => {
+                formatter.write_str("vabs")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -2928,15 +4594,37 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabsF32Neon { cond, vd, vm } => {
+                formatter.write_str("vabs")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -2950,15 +4638,43 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacge { cond, vd, vn, vm } => {
+                formatter.write_str("vacge")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacgt { cond, vd, vn, vm } => {
+                formatter.write_str("vacgt")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -2972,15 +4688,37 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddF32Neon { cond, vd, vn, vm } => {
+                formatter.write_str("vadd")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -2994,15 +4732,209 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddI { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vadd")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddhn { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vaddhn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddl { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vaddl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddw { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vaddw")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vand { cond, vd, vn, vm } => {
+                formatter.write_str("vand")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbic { cond, vd, vn, vm } => {
+                formatter.write_str("vbic")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VbicIImm { cond, dt, vd, imm } => {
+                formatter.write_str("vbic")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbif { cond, vd, vn, vm } => {
+                formatter.write_str("vbif")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbit { cond, vd, vn, vm } => {
+                formatter.write_str("vbit")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbsl { cond, vd, vn, vm } => {
+                formatter.write_str("vbsl")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vceq { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vceq")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqF32 { cond, vd, vn, vm } => {
+                formatter.write_str("vceq")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZero { cond, dt, vd, vm } => {
+                formatter.write_str("vceq")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZeroF32 { cond, vd, vm } => {
+                formatter.write_str("vceq")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcge { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vcge")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeF32 { cond, vd, vn, vm } => {
+                formatter.write_str("vcge")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZero { cond, dt, vd, vm } => {
+                formatter.write_str("vcge")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZeroF32 { cond, vd, vm } => {
+                formatter.write_str("vcge")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcgt { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vcgt")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtF32 { cond, vd, vn, vm } => {
+                formatter.write_str("vcgt")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZero { cond, dt, vd, vm } => {
+                formatter.write_str("vcgt")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZeroF32 { cond, vd, vm } => {
+                formatter.write_str("vcgt")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZero { cond, dt, vd, vm } => {
+                formatter.write_str("vcle")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZeroF32 { cond, vd, vm } => {
+                formatter.write_str("vcle")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcls { cond, dt, vd, vm } => {
+                formatter.write_str("vcls")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZero { cond, dt, vd, vm } => {
+                formatter.write_str("vclt")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZeroF32 { cond, vd, vm } => {
+                formatter.write_str("vclt")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vclz { cond, dt, vd, vm } => {
+                formatter.write_str("vclz")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3023,14 +4955,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3050,15 +4998,51 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcnt { cond, vd, vm } => {
+                formatter.write_str("vcnt")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".8")?;
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF16F32 { half, cond, sd, sm } => {
+                formatter.write_str("vcvt")?;
+                formatter.write_half(*half)?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f16.f32")?;
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32F16 { half, cond, sd, sm } => {
+                formatter.write_str("vcvt")?;
+                formatter.write_half(*half)?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32.f16")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3072,15 +5056,45 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32Fixed { cond, fixed, sd, fbits } => {
+                if formatter.options().ual {
+                    formatter.write_str("vcvt")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str(".f32.")?;
+                    formatter.write_fixed_type(*fixed)?;
+                } else {
+                    formatter.write_str("f")?;
+                    formatter.write_fixed_type(*fixed)?;
+                    formatter.write_str("tos")?;
+                    formatter.write_cond(*cond)?;
+                }
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3095,14 +5109,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3117,14 +5147,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3138,15 +5184,45 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF64Fixed { cond, fixed, dd, fbits } => {
+                if formatter.options().ual {
+                    formatter.write_str("vcvt")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str(".f64.")?;
+                    formatter.write_fixed_type(*fixed)?;
+                } else {
+                    formatter.write_str("f")?;
+                    formatter.write_fixed_type(*fixed)?;
+                    formatter.write_str("tod")?;
+                    formatter.write_cond(*cond)?;
+                }
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3161,14 +5237,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3182,65 +5274,157 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VcvtS32F32 { round_zero, cond, sd, sm } => {
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF32 { cond, fixed, sd, fbits } => {
                 if formatter.options().ual {
                     formatter.write_str("vcvt")?;
-                    formatter.write_round_zero(*round_zero)?;
                     formatter.write_cond(*cond)?;
-                    formatter.write_str(".s32.f32")?;
+                    formatter.write_str(".")?;
+                    formatter.write_fixed_type(*fixed)?;
+                    formatter.write_str(".f32")?;
                 } else {
-                    formatter.write_str("ftosi")?;
-                    formatter.write_round_zero(*round_zero)?;
+                    formatter.write_str("fto")?;
+                    formatter.write_fixed_type(*fixed)?;
                     formatter.write_str("s")?;
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VcvtS32F64 { round_zero, cond, sd, dm } => {
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF64 { cond, fixed, dd, fbits } => {
                 if formatter.options().ual {
                     formatter.write_str("vcvt")?;
-                    formatter.write_round_zero(*round_zero)?;
                     formatter.write_cond(*cond)?;
-                    formatter.write_str(".s32.f64")?;
+                    formatter.write_str(".")?;
+                    formatter.write_fixed_type(*fixed)?;
+                    formatter.write_str(".f64")?;
                 } else {
-                    formatter.write_str("ftosi")?;
+                    formatter.write_str("fto")?;
+                    formatter.write_fixed_type(*fixed)?;
+                    formatter.write_str("d")?;
+                    formatter.write_cond(*cond)?;
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtFixedNeon { cond, dt, vd, vm, fbits } => {
+                formatter.write_str("vcvt")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_cvt_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtNeon { cond, dt, vd, vm } => {
+                formatter.write_str("vcvt")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_cvt_type(*dt)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtS32F32 { round_zero, cond, sd, sm } => {
+                if formatter.options().ual {
+                    formatter.write_str("vcvt")?;
+                    formatter.write_round_zero(*round_zero)?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str(".s32.f32")?;
+                } else {
+                    formatter.write_str("ftosi")?;
+                    formatter.write_round_zero(*round_zero)?;
+                    formatter.write_str("s")?;
+                    formatter.write_cond(*cond)?;
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtS32F64 { round_zero, cond, sd, dm } => {
+                if formatter.options().ual {
+                    formatter.write_str("vcvt")?;
+                    formatter.write_round_zero(*round_zero)?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str(".s32.f64")?;
+                } else {
+                    formatter.write_str("ftosi")?;
                     formatter.write_round_zero(*round_zero)?;
                     formatter.write_str("d")?;
                     formatter.write_cond(*cond)?;
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3258,14 +5442,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3283,14 +5483,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3305,14 +5521,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3326,15 +5558,98 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupReg { cond, size, vd, rt } => {
+                formatter.write_str("vdup")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupScalar { cond, size, vd, dm, index } => {
+                formatter.write_str("vdup")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Veor { cond, vd, vn, vm } => {
+                formatter.write_str("veor")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vext { cond, vd, vn, vm, imm } => {
+                formatter.write_str("vext")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".8")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhadd { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vhadd")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhsub { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vhsub")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld1 { cond, size, regs, addr } => {
+                formatter.write_str("vld1")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld2 { cond, size, regs, addr } => {
+                formatter.write_str("vld2")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld3 { cond, size, regs, addr } => {
+                formatter.write_str("vld3")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld4 { cond, size, regs, addr } => {
+                formatter.write_str("vld4")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3351,14 +5666,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3375,14 +5706,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3396,14 +5743,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3416,15 +5779,57 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmax { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vmax")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmaxF32 { cond, vd, vn, vm } => {
+                formatter.write_str("vmax")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmin { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vmin")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VminF32 { cond, vd, vn, vm } => {
+                formatter.write_str("vmin")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3438,15 +5843,43 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Neon { cond, vd, vn, vm } => {
+                formatter.write_str("vmla")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Scalar { cond, vd, vn, dm, index } => {
+                formatter.write_str("vmla")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3460,15 +5893,59 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaI { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vmla")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaIScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vmla")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlal { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vmlal")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlalScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vmlal")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3482,15 +5959,43 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Neon { cond, vd, vn, vm } => {
+                formatter.write_str("vmls")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Scalar { cond, vd, vn, dm, index } => {
+                formatter.write_str("vmls")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3504,15 +6009,59 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsI { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vmls")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsIScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vmls")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlsl { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vmlsl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlslScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vmlsl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3532,14 +6081,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3553,15 +6118,48 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF32Imm { cond, sd, imm } => {
+                if formatter.options().ual {
+                    formatter.write_str("vmov")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str(".f32")?;
+                } else {
+                    formatter.write_str("fconsts")?;
+                    formatter.write_cond(*cond)?;
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovF32ImmNeon { cond, vd, imm } => {
+                formatter.write_str("vmov")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3575,14 +6173,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3596,15 +6210,55 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF64Imm { cond, dd, imm } => {
+                if formatter.options().ual {
+                    formatter.write_str("vmov")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str(".f64")?;
+                } else {
+                    formatter.write_str("fconstd")?;
+                    formatter.write_cond(*cond)?;
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovI64Imm { cond, vd, imm } => {
+                formatter.write_str("vmov")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".i64")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovIImm { cond, dt, vd, imm } => {
+                formatter.write_str("vmov")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3623,15 +6277,38 @@ impl Ins {
                     }
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovRegScalar { cond, dt, rt, dn, index } => {
+                formatter.write_str("vmov")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3645,14 +6322,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3665,15 +6358,38 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovScalarReg { cond, size, dd, index, rt } => {
+                formatter.write_str("vmov")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3687,14 +6403,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3708,14 +6440,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3728,15 +6476,38 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmovn { cond, dt, vd, vm } => {
+                formatter.write_str("vmovn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3750,14 +6521,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3771,14 +6558,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3792,15 +6595,43 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Neon { cond, vd, vn, vm } => {
+                formatter.write_str("vmul")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Scalar { cond, vd, vn, dm, index } => {
+                formatter.write_str("vmul")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3814,15 +6645,90 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulI { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vmul")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulIScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vmul")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulP8 { cond, vd, vn, vm } => {
+                formatter.write_str("vmul")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".p8")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmull { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vmull")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullP8 { cond, vd, vn, vm } => {
+                formatter.write_str("vmull")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".p8")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vmull")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmvn { cond, vd, vm } => {
+                formatter.write_str("vmvn")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmvnIImm { cond, dt, vd, imm } => {
+                formatter.write_str("vmvn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vneg { cond, dt, vd, vm } => {
+                formatter.write_str("vneg")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3836,15 +6742,37 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VnegF32Neon { cond, vd, vm } => {
+                formatter.write_str("vneg")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3859,14 +6787,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3881,14 +6825,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3903,14 +6863,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3925,14 +6901,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3947,14 +6939,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3969,14 +6977,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -3990,15 +7014,106 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorn { cond, vd, vn, vm } => {
+                formatter.write_str("vorn")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorr { cond, vd, vn, vm } => {
+                if *vn == *vm {
+                    formatter.write_str("vmov")?;
+                    formatter.write_cond(*cond)?;
+                } else {
+                    formatter.write_str("vorr")?;
+                    formatter.write_cond(*cond)?;
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VorrIImm { cond, dt, vd, imm } => {
+                formatter.write_str("vorr")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpadal { cond, dt, vd, vm } => {
+                formatter.write_str("vpadal")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddF32 { cond, vd, vn, vm } => {
+                formatter.write_str("vpadd")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddI { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vpadd")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpaddl { cond, dt, vd, vm } => {
+                formatter.write_str("vpaddl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmax { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vpmax")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpmaxF32 { cond, vd, vn, vm } => {
+                formatter.write_str("vpmax")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmin { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vpmin")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpminF32 { cond, vd, vn, vm } => {
+                formatter.write_str("vpmin")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4007,14 +7122,30 @@ impl Ins {
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4023,14 +7154,30 @@ impl Ins {
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4039,14 +7186,30 @@ impl Ins {
                 formatter.write_cond(*cond)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4054,37 +7217,399 @@ impl Ins {
                 formatter.write_str("vpush")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VsqrtF32 { cond, sd, sm } => {
-                if formatter.options().ual {
-                    formatter.write_str("vsqrt")?;
-                    formatter.write_cond(*cond)?;
-                    formatter.write_str(".f32")?;
-                } else {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqabs { cond, dt, vd, vm } => {
+                formatter.write_str("vqabs")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqadd { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vqadd")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlal { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vqdmlal")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlalScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vqdmlal")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlsl { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vqdmlsl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlslScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vqdmlsl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmulh { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vqdmulh")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vqdmulh")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmull { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vqdmull")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmullScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vqdmull")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovn { cond, dt, vd, vm } => {
+                formatter.write_str("vqmovn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovun { cond, dt, vd, vm } => {
+                formatter.write_str("vqmovun")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqneg { cond, dt, vd, vm } => {
+                formatter.write_str("vqneg")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrdmulh { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vqrdmulh")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqrdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_str("vqrdmulh")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshl { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vqrshl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrn { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vqrshrn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrun { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vqrshrun")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshl { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vqshl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqshlImm { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vqshl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshlu { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vqshlu")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrn { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vqshrn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrun { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vqshrun")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqsub { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vqsub")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vraddhn { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vraddhn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecpe { cond, vd, vm } => {
+                formatter.write_str("vrecpe")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".u32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrecpeF32 { cond, vd, vm } => {
+                formatter.write_str("vrecpe")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecps { cond, vd, vn, vm } => {
+                formatter.write_str("vrecps")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev16 { cond, dt, vd, vm } => {
+                formatter.write_str("vrev16")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev32 { cond, dt, vd, vm } => {
+                formatter.write_str("vrev32")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev64 { cond, dt, vd, vm } => {
+                formatter.write_str("vrev64")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrhadd { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vrhadd")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshl { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vrshl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshr { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vrshr")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshrn { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vrshrn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrte { cond, vd, vm } => {
+                formatter.write_str("vrsqrte")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".u32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrsqrteF32 { cond, vd, vm } => {
+                formatter.write_str("vrsqrte")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrts { cond, vd, vn, vm } => {
+                formatter.write_str("vrsqrts")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsra { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vrsra")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsubhn { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vrsubhn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshl { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vshl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshlImm { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vshl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshll { cond, dt, vd, vm, imm } => {
+                if *imm == 0 {
+                    formatter.write_str("vmovl")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str(".")?;
+                    formatter.write_neon_sign_type(*dt)?;
+                } else {
+                    formatter.write_str("vshll")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str(".")?;
+                    formatter.write_neon_sign_type(*dt)?;
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshllMax { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vshll")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshr { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vshr")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshrn { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vshrn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsli { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vsli")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*dt)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsqrtF32 { cond, sd, sm } => {
+                if formatter.options().ual {
+                    formatter.write_str("vsqrt")?;
+                    formatter.write_cond(*cond)?;
+                    formatter.write_str(".f32")?;
+                } else {
                     formatter.write_str("fsqrts")?;
                     formatter.write_cond(*cond)?;
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4098,15 +7623,73 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsra { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vsra")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsri { cond, dt, vd, vm, imm } => {
+                formatter.write_str("vsri")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst1 { cond, size, regs, addr } => {
+                formatter.write_str("vst1")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst2 { cond, size, regs, addr } => {
+                formatter.write_str("vst2")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst3 { cond, size, regs, addr } => {
+                formatter.write_str("vst3")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst4 { cond, size, regs, addr } => {
+                formatter.write_str("vst4")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*size)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4123,14 +7706,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4147,14 +7746,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4168,14 +7783,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4189,14 +7820,30 @@ impl Ins {
                 }
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4210,15 +7857,37 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubF32Neon { cond, vd, vn, vm } => {
+                formatter.write_str("vsub")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".f32")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -4232,17 +7901,105 @@ impl Ins {
                     formatter.write_cond(*cond)?;
                 }
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubI { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vsub")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubhn { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vsubhn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_int_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubl { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vsubl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubw { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vsubw")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_sign_type(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vswp { cond, vd, vm } => {
+                formatter.write_str("vswp")?;
+                formatter.write_cond(*cond)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbl { cond, dd, regs, dm } => {
+                formatter.write_str("vtbl")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".8")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbx { cond, dd, regs, dm } => {
+                formatter.write_str("vtbx")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".8")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtrn { cond, dt, vd, vm } => {
+                formatter.write_str("vtrn")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtst { cond, dt, vd, vn, vm } => {
+                formatter.write_str("vtst")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vuzp { cond, dt, vd, vm } => {
+                formatter.write_str("vuzp")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*dt)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vzip { cond, dt, vd, vm } => {
+                formatter.write_str("vzip")?;
+                formatter.write_cond(*cond)?;
+                formatter.write_str(".")?;
+                formatter.write_neon_size(*dt)?;
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Wfe { cond } => {
                 formatter.write_str("wfe")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Wfi { cond } => {
                 formatter.write_str("wfi")?;
                 formatter.write_cond(*cond)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Yield { cond } => {
                 formatter.write_str("yield")?;
                 formatter.write_cond(*cond)?;
@@ -4261,7 +8018,7 @@ impl Ins {
         match self {
             Ins::Adc { s, thumb, cond, rd, rn, op2 } => {
                 formatter.write_space()?;
-                if formatter.options().ual || !*thumb {
+                if formatter.options().ual || !*thumb || *rd != *rn {
                     formatter.write_reg(*rd)?;
                     formatter.write_separator()?;
                     formatter.write_reg(*rn)?;
@@ -4289,7 +8046,7 @@ impl Ins {
             }
             Ins::And { s, thumb, cond, rd, rn, op2 } => {
                 formatter.write_space()?;
-                if formatter.options().ual || !*thumb {
+                if formatter.options().ual || !*thumb || *rd != *rn {
                     formatter.write_reg(*rd)?;
                     formatter.write_separator()?;
                     formatter.write_reg(*rn)?;
@@ -4319,9 +8076,33 @@ impl Ins {
                 formatter.write_space()?;
                 formatter.write_branch_target(*target)?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Bfc { cond, rd, lsb, width } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*lsb)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*width)?;
+            }
+            #[cfg(feature = "v7")]
+            Ins::Bfi { cond, rd, rn, lsb, width } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*lsb)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*width)?;
+            }
             Ins::Bic { s, thumb, cond, rd, rn, op2 } => {
                 formatter.write_space()?;
-                if formatter.options().ual || !*thumb {
+                if formatter.options().ual || !*thumb || *rd != *rn {
                     formatter.write_reg(*rd)?;
                     formatter.write_separator()?;
                     formatter.write_reg(*rn)?;
@@ -4339,7 +8120,8 @@ impl Ins {
                     feature = "v5te",
                     feature = "v5tej",
                     feature = "v6",
-                    feature = "v6k"
+                    feature = "v6k",
+                    feature = "v7"
                 )
             )]
             Ins::Bkpt { imm } => {
@@ -4357,7 +8139,8 @@ impl Ins {
                     feature = "v5te",
                     feature = "v5tej",
                     feature = "v6",
-                    feature = "v6k"
+                    feature = "v6k",
+                    feature = "v7"
                 )
             )]
             Ins::Blx { cond, target } => {
@@ -4371,7 +8154,8 @@ impl Ins {
                     feature = "v5te",
                     feature = "v5tej",
                     feature = "v6",
-                    feature = "v6k"
+                    feature = "v6k",
+                    feature = "v7"
                 )
             )]
             Ins::Bx { cond, rm } => {
@@ -4379,16 +8163,38 @@ impl Ins {
                 formatter.write_reg(*rm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(feature = "v5tej", feature = "v6", feature = "v6k")
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Bxj { cond, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbnz { rn, target } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_branch_target(*target)?;
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbz { rn, target } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_branch_target(*target)?;
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Cdp { cond, coproc, opc1, crd, crn, crm, opc2 } => {
                 formatter.write_space()?;
                 formatter.write_coproc(*coproc)?;
@@ -4406,15 +8212,19 @@ impl Ins {
                 formatter.write_uimm(*opc2)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Cdp2 { coproc, opc1, crd, crn, crm, opc2 } => {
@@ -4433,18 +8243,27 @@ impl Ins {
                 formatter.write_str("#")?;
                 formatter.write_uimm(*opc2)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Clrex {} => {}
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Clz { cond, rd, rm } => {
@@ -4465,7 +8284,7 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_op2(*op2)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Cps { effect, aif, mode } => {
                 formatter.write_space()?;
                 if *effect == CpsEffect::SetMode {
@@ -4482,17 +8301,32 @@ impl Ins {
                     }
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Csdb { cond } => {}
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Dbg { cond, option } => {
                 formatter.write_space()?;
                 formatter.write_str("#")?;
                 formatter.write_uimm(*option)?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Dmb { cond, option } => {
+                formatter.write_space()?;
+                formatter.write_barrier_option(*option)?;
+            }
+            #[cfg(feature = "v7")]
+            Ins::Dsb { cond, option } => {
+                formatter.write_space()?;
+                formatter.write_barrier_option(*option)?;
+            }
             Ins::Eor { s, thumb, cond, rd, rn, op2 } => {
                 formatter.write_space()?;
-                if formatter.options().ual || !*thumb {
+                if formatter.options().ual || !*thumb || *rd != *rn {
                     formatter.write_reg(*rd)?;
                     formatter.write_separator()?;
                     formatter.write_reg(*rn)?;
@@ -4504,7 +8338,17 @@ impl Ins {
                     formatter.write_op2(*op2)?;
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(feature = "v7")]
+            Ins::Isb { cond, option } => {
+                formatter.write_space()?;
+                formatter.write_barrier_option(*option)?;
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::It { firstcond, mask } => {
+                formatter.write_space()?;
+                formatter.write_cond(*firstcond)?;
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldc { l, cond, coproc, crd, dest } => {
                 formatter.write_space()?;
                 formatter.write_coproc(*coproc)?;
@@ -4514,15 +8358,19 @@ impl Ins {
                 formatter.write_addr_ldc_stc(*dest)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Ldc2 { l, coproc, crd, dest } => {
@@ -4553,22 +8401,26 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_addr_ldr_str(*addr)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldrbt { cond, rd, addr } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
-                formatter.write_addr_ldr_str_post(*addr)?;
+                formatter.write_addr_ldr_str(*addr)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Ldrd { cond, rd, rd2, addr } => {
@@ -4585,16 +8437,40 @@ impl Ins {
                     formatter.write_addr_misc_load(*addr)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
-            Ins::Ldrex { cond, rd, rn } => {
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrex { cond, rd, rn, offset } => {
                 formatter.write_space()?;
-                formatter.write_reg(*rd)?;
-                formatter.write_separator()?;
-                formatter.write_str("[")?;
-                formatter.write_reg(*rn)?;
-                formatter.write_str("]")?;
+                if *offset == 0 {
+                    formatter.write_reg(*rd)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("[")?;
+                    formatter.write_reg(*rn)?;
+                    formatter.write_str("]")?;
+                } else {
+                    formatter.write_reg(*rd)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("[")?;
+                    formatter.write_reg(*rn)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*offset)?;
+                    formatter.write_str("]")?;
+                }
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexb { cond, rd, rn } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -4603,7 +8479,12 @@ impl Ins {
                 formatter.write_reg(*rn)?;
                 formatter.write_str("]")?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexd { cond, rd, rd2, rn } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -4614,7 +8495,12 @@ impl Ins {
                 formatter.write_reg(*rn)?;
                 formatter.write_str("]")?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ldrexh { cond, rd, rn } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -4629,24 +8515,45 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_addr_misc_load(*addr)?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Ldrht { cond, rd, addr } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_addr_misc_load(*addr)?;
+            }
             Ins::Ldrsb { cond, rd, addr } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
                 formatter.write_addr_misc_load(*addr)?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Ldrsbt { cond, rd, addr } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_addr_misc_load(*addr)?;
+            }
             Ins::Ldrsh { cond, rd, addr } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
                 formatter.write_addr_misc_load(*addr)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(feature = "v7")]
+            Ins::Ldrsht { cond, rd, addr } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_addr_misc_load(*addr)?;
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Ldrt { cond, rd, addr } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
-                formatter.write_addr_ldr_str_post(*addr)?;
+                formatter.write_addr_ldr_str(*addr)?;
             }
             Ins::Lsl { s, thumb, cond, rd, rn, op2 } => {
                 formatter.write_space()?;
@@ -4676,7 +8583,7 @@ impl Ins {
                     formatter.write_op2_shift(*op2)?;
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mcr { cond, coproc, opc1, rd, crn, crm, opc2 } => {
                 formatter.write_space()?;
                 formatter.write_coproc(*coproc)?;
@@ -4694,15 +8601,19 @@ impl Ins {
                 formatter.write_uimm(*opc2)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Mcr2 { coproc, opc1, rd, crn, crm, opc2 } => {
@@ -4722,14 +8633,18 @@ impl Ins {
                 formatter.write_uimm(*opc2)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Mcrr { cond, coproc, opc, rd, rd2, crm } => {
@@ -4745,7 +8660,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_co_reg(*crm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mcrr2 { coproc, opc, rd, rd2, crm } => {
                 formatter.write_space()?;
                 formatter.write_coproc(*coproc)?;
@@ -4759,7 +8682,7 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_co_reg(*crm)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mla { s, cond, rd, rn, rm, ra } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -4770,13 +8693,40 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*ra)?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Mls { cond, rd, rn, rm, ra } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rm)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*ra)?;
+            }
             Ins::Mov { s, thumb, cond, rd, op2 } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
                 formatter.write_op2(*op2)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(feature = "v7")]
+            Ins::Movt { cond, rd, imm } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(feature = "v7")]
+            Ins::Movw { cond, rd, imm } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mrc { cond, coproc, opc1, rd, crn, crm, opc2 } => {
                 formatter.write_space()?;
                 formatter.write_coproc(*coproc)?;
@@ -4794,15 +8744,19 @@ impl Ins {
                 formatter.write_uimm(*opc2)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Mrc2 { coproc, opc1, rd, crn, crm, opc2 } => {
@@ -4822,14 +8776,18 @@ impl Ins {
                 formatter.write_uimm(*opc2)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Mrrc { cond, coproc, opc, rd, rd2, crm } => {
@@ -4845,7 +8803,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_co_reg(*crm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Mrrc2 { coproc, opc, rd, rd2, crm } => {
                 formatter.write_space()?;
                 formatter.write_coproc(*coproc)?;
@@ -4859,14 +8825,14 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_co_reg(*crm)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Mrs { cond, rd, status_reg } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
                 formatter.write_status_reg(*status_reg)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Msr { cond, status_fields, op2 } => {
                 formatter.write_space()?;
                 formatter.write_status_fields(*status_fields)?;
@@ -4875,7 +8841,7 @@ impl Ins {
             }
             Ins::Mul { s, thumb, cond, rd, rn, rm } => {
                 formatter.write_space()?;
-                if formatter.options().ual || !*thumb {
+                if formatter.options().ual || !*thumb || *rd != *rm {
                     formatter.write_reg(*rd)?;
                     formatter.write_separator()?;
                     formatter.write_reg(*rn)?;
@@ -4900,11 +8866,25 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Nop { cond } => {}
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Orn { s, cond, rd, rn, op2 } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_op2(*op2)?;
+            }
             Ins::Orr { s, thumb, cond, rd, rn, op2 } => {
                 formatter.write_space()?;
-                if formatter.options().ual || !*thumb {
+                if formatter.options().ual || !*thumb || *rd != *rn {
                     formatter.write_reg(*rd)?;
                     formatter.write_separator()?;
                     formatter.write_reg(*rn)?;
@@ -4916,7 +8896,15 @@ impl Ins {
                     formatter.write_op2(*op2)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Pkhbt { cond, rd, rn, rm, shift_op, shift } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -4932,7 +8920,15 @@ impl Ins {
                     formatter.write_uimm(*shift)?;
                 } else {}
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Pkhtb { cond, rd, rn, rm, shift_op, shift } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -4947,20 +8943,29 @@ impl Ins {
                 formatter.write_uimm(*shift)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Pld { addr } => {
                 formatter.write_space()?;
                 formatter.write_addr_ldr_str(*addr)?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Pli { addr } => {
+                formatter.write_space()?;
+                formatter.write_addr_ldr_str(*addr)?;
+            }
             Ins::Pop { cond, regs } => {
                 formatter.write_space()?;
                 formatter.write_reg_list(*regs)?;
@@ -4970,14 +8975,18 @@ impl Ins {
                 formatter.write_reg_list(*regs)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Qadd { cond, rd, rm, rn } => {
@@ -4988,7 +8997,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rn)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qadd16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -4997,7 +9014,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qadd8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5006,7 +9031,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qasx { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5016,14 +9049,18 @@ impl Ins {
                 formatter.write_reg(*rm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Qdadd { cond, rd, rm, rn } => {
@@ -5035,14 +9072,18 @@ impl Ins {
                 formatter.write_reg(*rn)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Qdsub { cond, rd, rm, rn } => {
@@ -5053,7 +9094,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rn)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsax { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5063,14 +9112,18 @@ impl Ins {
                 formatter.write_reg(*rm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Qsub { cond, rd, rm, rn } => {
@@ -5081,7 +9134,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rn)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsub16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5090,7 +9151,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Qsub8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5099,28 +9168,43 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(feature = "v7")]
+            Ins::Rbit { cond, rd, rm } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rm)?;
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Rev { cond, rd, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Rev16 { cond, rd, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Revsh { cond, rd, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Rfe { addr_mode, rn, writeback } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rn)?;
@@ -5128,7 +9212,7 @@ impl Ins {
             }
             Ins::Ror { s, thumb, cond, rd, rn, op2 } => {
                 formatter.write_space()?;
-                if formatter.options().ual || !*thumb {
+                if formatter.options().ual || !*thumb || *rd != *rn {
                     formatter.write_reg(*rd)?;
                     formatter.write_separator()?;
                     formatter.write_reg(*rn)?;
@@ -5140,7 +9224,7 @@ impl Ins {
                     formatter.write_op2_shift(*op2)?;
                 }
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Rrx { s, cond, rd, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5164,7 +9248,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_op2(*op2)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sadd16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5173,7 +9265,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sadd8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5182,7 +9282,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sasx { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5193,7 +9301,7 @@ impl Ins {
             }
             Ins::Sbc { s, thumb, cond, rd, rn, op2 } => {
                 formatter.write_space()?;
-                if formatter.options().ual || !*thumb {
+                if formatter.options().ual || !*thumb || *rd != *rn {
                     formatter.write_reg(*rd)?;
                     formatter.write_separator()?;
                     formatter.write_reg(*rn)?;
@@ -5205,7 +9313,37 @@ impl Ins {
                     formatter.write_op2(*op2)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(feature = "v7")]
+            Ins::Sbfx { cond, rd, rn, lsb, width } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*lsb)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*width)?;
+            }
+            #[cfg(feature = "v7")]
+            Ins::Sdiv { cond, rd, rn, rm } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rm)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sel { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5214,14 +9352,27 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Setend { endian } => {
                 formatter.write_space()?;
                 formatter.write_endianness(*endian)?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sev { cond } => {}
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shadd16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5230,7 +9381,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shadd8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5239,7 +9398,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shasx { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5248,7 +9415,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsax { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5257,7 +9432,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsub16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5266,7 +9449,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Shsub8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5276,14 +9467,18 @@ impl Ins {
                 formatter.write_reg(*rm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Smla { cond, rd, rn, rn_side, rm, rm_side, ra } => {
@@ -5296,7 +9491,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*ra)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlad { cond, rd, rn, rm, swap_rm, ra } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5307,7 +9510,7 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*ra)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Smlal { s, cond, rd_lo, rd_hi, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd_lo)?;
@@ -5319,14 +9522,18 @@ impl Ins {
                 formatter.write_reg(*rm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::SmlalHalf { cond, rd_lo, rd_hi, rn, rn_side, rm, rm_side } => {
@@ -5339,7 +9546,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlald { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd_lo)?;
@@ -5351,14 +9566,18 @@ impl Ins {
                 formatter.write_reg(*rm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Smlaw { cond, rd, rn, rm, rm_side, ra } => {
@@ -5371,7 +9590,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*ra)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlsd { cond, rd, rn, rm, swap_rm, ra } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5382,7 +9609,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*ra)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smlsld { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd_lo)?;
@@ -5393,7 +9628,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmla { round, cond, rd, rn, rm, ra } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5404,7 +9647,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*ra)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmls { round, cond, rd, rn, rm, ra } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5415,7 +9666,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*ra)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smmul { round, cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5424,7 +9683,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smuad { cond, rd, rn, rm, swap_rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5434,14 +9701,18 @@ impl Ins {
                 formatter.write_reg(*rm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Smul { cond, rd, rn, rn_side, rm, rm_side } => {
@@ -5452,7 +9723,7 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Smull { s, cond, rd_lo, rd_hi, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd_lo)?;
@@ -5464,14 +9735,18 @@ impl Ins {
                 formatter.write_reg(*rm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Smulw { cond, rd, rn, rm, rm_side } => {
@@ -5482,7 +9757,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Smusd { cond, rd, rn, rm, swap_rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5491,7 +9774,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Srs { addr_mode, rn, writeback, mode } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rn)?;
@@ -5500,7 +9791,15 @@ impl Ins {
                 formatter.write_str("#")?;
                 formatter.write_uimm(*mode)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssat { cond, rd, imm, op2 } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5510,7 +9809,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_shift_imm(*op2)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssat16 { cond, rd, imm, rn } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5520,7 +9827,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rn)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssax { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5529,7 +9844,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssub16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5538,7 +9861,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Ssub8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5547,7 +9878,7 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Stc { l, cond, coproc, crd, dest } => {
                 formatter.write_space()?;
                 formatter.write_coproc(*coproc)?;
@@ -5557,15 +9888,19 @@ impl Ins {
                 formatter.write_addr_ldc_stc(*dest)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5t",
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Stc2 { l, coproc, crd, dest } => {
@@ -5596,22 +9931,26 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_addr_ldr_str(*addr)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Strbt { cond, rd, addr } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
-                formatter.write_addr_ldr_str_post(*addr)?;
+                formatter.write_addr_ldr_str(*addr)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
                 )
             )]
             Ins::Strd { cond, rd, rd2, addr } => {
@@ -5628,18 +9967,44 @@ impl Ins {
                     formatter.write_addr_misc_load(*addr)?;
                 }
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
-            Ins::Strex { cond, rd, rm, rn } => {
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strex { cond, rd, rm, rn, offset } => {
                 formatter.write_space()?;
-                formatter.write_reg(*rd)?;
-                formatter.write_separator()?;
-                formatter.write_reg(*rm)?;
-                formatter.write_separator()?;
-                formatter.write_str("[")?;
-                formatter.write_reg(*rn)?;
-                formatter.write_str("]")?;
+                if *offset == 0 {
+                    formatter.write_reg(*rd)?;
+                    formatter.write_separator()?;
+                    formatter.write_reg(*rm)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("[")?;
+                    formatter.write_reg(*rn)?;
+                    formatter.write_str("]")?;
+                } else {
+                    formatter.write_reg(*rd)?;
+                    formatter.write_separator()?;
+                    formatter.write_reg(*rm)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("[")?;
+                    formatter.write_reg(*rn)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*offset)?;
+                    formatter.write_str("]")?;
+                }
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexb { cond, rd, rm, rn } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5650,7 +10015,12 @@ impl Ins {
                 formatter.write_reg(*rn)?;
                 formatter.write_str("]")?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexd { cond, rd, rm, rm2, rn } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5663,7 +10033,12 @@ impl Ins {
                 formatter.write_reg(*rn)?;
                 formatter.write_str("]")?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Strexh { cond, rd, rm, rn } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5680,12 +10055,19 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_addr_misc_load(*addr)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(feature = "v7")]
+            Ins::Strht { cond, rd, addr } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_addr_misc_load(*addr)?;
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Strt { cond, rd, addr } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
                 formatter.write_separator()?;
-                formatter.write_addr_ldr_str_post(*addr)?;
+                formatter.write_addr_ldr_str(*addr)?;
             }
             Ins::Sub { s, thumb, cond, rd, rn, op2 } => {
                 formatter.write_space()?;
@@ -5728,7 +10110,15 @@ impl Ins {
                 formatter.write_reg(*rn)?;
                 formatter.write_str("]")?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtab { cond, rd, rn, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5744,7 +10134,15 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtab16 { cond, rd, rn, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5760,7 +10158,15 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtah { cond, rd, rn, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5776,7 +10182,7 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Sxtb { cond, rd, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5790,7 +10196,15 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Sxtb16 { cond, rd, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5804,7 +10218,7 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Sxth { cond, rd, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5818,7 +10232,28 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(feature = "arm")]
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbb { cond, rn, rm } => {
+                formatter.write_space()?;
+                formatter.write_str("[")?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rm)?;
+                formatter.write_str("]")?;
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbh { cond, rn, rm } => {
+                formatter.write_space()?;
+                formatter.write_str("[")?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rm)?;
+                formatter.write_separator()?;
+                formatter.write_str("lsl")?;
+                formatter.write_space()?;
+                formatter.write_str("#0x1]")?;
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Teq { cond, rn, op2 } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rn)?;
@@ -5831,7 +10266,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_op2(*op2)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uadd16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5840,7 +10283,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uadd8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5849,7 +10300,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uasx { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5858,6 +10317,19 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
+            #[cfg(feature = "v7")]
+            Ins::Ubfx { cond, rd, rn, lsb, width } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*lsb)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*width)?;
+            }
             #[cfg(
                 any(
                     feature = "v4t",
@@ -5865,7 +10337,8 @@ impl Ins {
                     feature = "v5te",
                     feature = "v5tej",
                     feature = "v6",
-                    feature = "v6k"
+                    feature = "v6k",
+                    feature = "v7"
                 )
             )]
             Ins::Udf { imm } => {
@@ -5873,7 +10346,24 @@ impl Ins {
                 formatter.write_str("#")?;
                 formatter.write_uimm(*imm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(feature = "v7")]
+            Ins::Udiv { cond, rd, rn, rm } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rd)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rm)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhadd16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5882,7 +10372,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhadd8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5891,7 +10389,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhasx { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5900,7 +10406,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsax { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5909,7 +10423,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsub16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5918,7 +10440,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uhsub8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5927,7 +10457,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Umaal { cond, rd_lo, rd_hi, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd_lo)?;
@@ -5938,7 +10476,7 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Umlal { s, cond, rd_lo, rd_hi, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd_lo)?;
@@ -5949,7 +10487,7 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(feature = "arm")]
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
             Ins::Umull { s, cond, rd_lo, rd_hi, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd_lo)?;
@@ -5960,7 +10498,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqadd16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5969,7 +10515,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqadd8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5978,7 +10532,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqasx { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5987,7 +10549,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsax { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -5996,7 +10566,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsub16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6005,7 +10583,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uqsub8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6014,7 +10600,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usad8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6023,7 +10617,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usada8 { cond, rd, rn, rm, ra } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6034,7 +10636,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*ra)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usat { cond, rd, imm, op2 } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6044,7 +10654,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_shift_imm(*op2)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usat16 { cond, rd, imm, rn } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6054,7 +10672,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rn)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usax { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6063,7 +10689,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usub16 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6072,7 +10706,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Usub8 { cond, rd, rn, rm } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6081,7 +10723,15 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rm)?;
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtab { cond, rd, rn, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6097,7 +10747,15 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtab16 { cond, rd, rn, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6113,7 +10771,15 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtah { cond, rd, rn, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6129,7 +10795,7 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Uxtb { cond, rd, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6143,7 +10809,15 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
             Ins::Uxtb16 { cond, rd, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6157,7 +10831,7 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
-            #[cfg(any(feature = "v6", feature = "v6k"))]
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
             Ins::Uxth { cond, rd, rm, rotate } => {
                 formatter.write_space()?;
                 formatter.write_reg(*rd)?;
@@ -6171,15 +10845,83 @@ impl Ins {
                     formatter.write_uimm(*rotate)?;
                 } else {}
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaba { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabal { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabd { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabdF32 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabdl { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabs { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6189,15 +10931,38 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_sreg(*sm)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabsF32Neon { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6207,16 +10972,50 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacge { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacgt { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
                 )
             )]
             Ins::VaddF32 { cond, sd, sn, sm } => {
@@ -6227,15 +11026,40 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_sreg(*sm)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddF32Neon { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6247,15 +11071,278 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddI { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddhn { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddl { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddw { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vand { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbic { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VbicIImm { cond, dt, vd, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbif { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbit { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbsl { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vceq { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqF32 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZero { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#0")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZeroF32 { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#0")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcge { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeF32 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZero { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#0")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZeroF32 { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#0")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcgt { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtF32 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZero { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#0")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZeroF32 { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#0")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZero { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#0")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZeroF32 { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#0")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcls { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZero { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#0")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZeroF32 { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#0")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vclz { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6268,14 +11355,30 @@ impl Ins {
                 } else {}
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6287,15 +11390,52 @@ impl Ins {
                     formatter.write_vcmp_f64_op2(*op2)?;
                 } else {}
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcnt { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF16F32 { half, cond, sd, sm } => {
+                formatter.write_space()?;
+                formatter.write_sreg(*sd)?;
+                formatter.write_separator()?;
+                formatter.write_sreg(*sm)?;
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32F16 { half, cond, sd, sm } => {
+                formatter.write_space()?;
+                formatter.write_sreg(*sd)?;
+                formatter.write_separator()?;
+                formatter.write_sreg(*sm)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6305,15 +11445,48 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32Fixed { cond, fixed, sd, fbits } => {
+                formatter.write_space()?;
+                if formatter.options().ual {
+                    formatter.write_sreg(*sd)?;
+                    formatter.write_separator()?;
+                    formatter.write_sreg(*sd)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*fbits)?;
+                } else {
+                    formatter.write_sreg(*sd)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*fbits)?;
+                }
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6324,14 +11497,30 @@ impl Ins {
                 formatter.write_sreg(*sm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6342,14 +11531,30 @@ impl Ins {
                 formatter.write_sreg(*sm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6359,15 +11564,48 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_sreg(*sm)?;
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF64Fixed { cond, fixed, dd, fbits } => {
+                formatter.write_space()?;
+                if formatter.options().ual {
+                    formatter.write_dreg(*dd)?;
+                    formatter.write_separator()?;
+                    formatter.write_dreg(*dd)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*fbits)?;
+                } else {
+                    formatter.write_dreg(*dd)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*fbits)?;
+                }
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6378,14 +11616,30 @@ impl Ins {
                 formatter.write_sreg(*sm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6395,15 +11649,82 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_sreg(*sm)?;
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF32 { cond, fixed, sd, fbits } => {
+                formatter.write_space()?;
+                if formatter.options().ual {
+                    formatter.write_sreg(*sd)?;
+                    formatter.write_separator()?;
+                    formatter.write_sreg(*sd)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*fbits)?;
+                } else {
+                    formatter.write_sreg(*sd)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*fbits)?;
+                }
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF64 { cond, fixed, dd, fbits } => {
+                formatter.write_space()?;
+                if formatter.options().ual {
+                    formatter.write_dreg(*dd)?;
+                    formatter.write_separator()?;
+                    formatter.write_dreg(*dd)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*fbits)?;
+                } else {
+                    formatter.write_dreg(*dd)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*fbits)?;
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtFixedNeon { cond, dt, vd, vm, fbits } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*fbits)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtNeon { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6414,14 +11735,30 @@ impl Ins {
                 formatter.write_sreg(*sm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6432,14 +11769,30 @@ impl Ins {
                 formatter.write_dreg(*dm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6450,14 +11803,30 @@ impl Ins {
                 formatter.write_sreg(*sm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6468,14 +11837,30 @@ impl Ins {
                 formatter.write_dreg(*dm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6488,14 +11873,30 @@ impl Ins {
                 formatter.write_sreg(*sm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6507,89 +11908,289 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VldmF32 { mode, cond, rn, writeback, regs } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupReg { cond, size, vd, rt } => {
                 formatter.write_space()?;
-                formatter.write_reg(*rn)?;
-                formatter.write_wb(*writeback)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_sreg_list(*regs)?;
+                formatter.write_reg(*rt)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VldmF64 { mode, cond, rn, writeback, regs } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupScalar { cond, size, vd, dm, index } => {
                 formatter.write_space()?;
-                formatter.write_reg(*rn)?;
-                formatter.write_wb(*writeback)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_dreg_list(*regs)?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VldrF32 { cond, sd, addr } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Veor { cond, vd, vn, vm } => {
                 formatter.write_space()?;
-                formatter.write_sreg(*sd)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_addr_ldr_str(*addr)?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VldrF64 { cond, dd, addr } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vext { cond, vd, vn, vm, imm } => {
                 formatter.write_space()?;
-                formatter.write_dreg(*dd)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_addr_ldr_str(*addr)?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhadd { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhsub { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld1 { cond, size, regs, addr } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg_list(*regs)?;
+                formatter.write_separator()?;
+                formatter.write_neon_addr(*addr)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld2 { cond, size, regs, addr } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg_list(*regs)?;
+                formatter.write_separator()?;
+                formatter.write_neon_addr(*addr)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld3 { cond, size, regs, addr } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg_list(*regs)?;
+                formatter.write_separator()?;
+                formatter.write_neon_addr(*addr)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld4 { cond, size, regs, addr } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg_list(*regs)?;
+                formatter.write_separator()?;
+                formatter.write_neon_addr(*addr)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldmF32 { mode, cond, rn, writeback, regs } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_wb(*writeback)?;
+                formatter.write_separator()?;
+                formatter.write_sreg_list(*regs)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldmF64 { mode, cond, rn, writeback, regs } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_wb(*writeback)?;
+                formatter.write_separator()?;
+                formatter.write_dreg_list(*regs)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldrF32 { cond, sd, addr } => {
+                formatter.write_space()?;
+                formatter.write_sreg(*sd)?;
+                formatter.write_separator()?;
+                formatter.write_addr_ldr_str(*addr)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldrF64 { cond, dd, addr } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*dd)?;
+                formatter.write_separator()?;
+                formatter.write_addr_ldr_str(*addr)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmax { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmaxF32 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmin { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VminF32 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6601,15 +12202,52 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_sreg(*sm)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Neon { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Scalar { cond, vd, vn, dm, index } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6621,15 +12259,73 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaI { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaIScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlal { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlalScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6641,15 +12337,52 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_sreg(*sm)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Neon { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Scalar { cond, vd, vn, dm, index } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6661,15 +12394,73 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsI { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsIScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlsl { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlslScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6680,14 +12471,30 @@ impl Ins {
                 formatter.write_reg(*rt)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6697,15 +12504,47 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_sreg(*sm)?;
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF32Imm { cond, sd, imm } => {
+                formatter.write_space()?;
+                formatter.write_sreg(*sd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_vfp_imm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovF32ImmNeon { cond, vd, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_vfp_imm(*imm)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6716,14 +12555,30 @@ impl Ins {
                 formatter.write_reg(*rt)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6733,15 +12588,55 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
             }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF64Imm { cond, dd, imm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*dd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_vfp_imm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovI64Imm { cond, vd, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_neon_imm64(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovIImm { cond, dt, vd, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6751,15 +12646,41 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_dreg_index(*dn)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovRegScalar { cond, dt, rt, dn, index } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rt)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dn)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6770,14 +12691,30 @@ impl Ins {
                 formatter.write_sreg(*sn)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6791,15 +12728,41 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_sreg(*sm2)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovScalarReg { cond, size, dd, index, rt } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*dd)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+                formatter.write_separator()?;
+                formatter.write_reg(*rt)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6814,14 +12777,30 @@ impl Ins {
                 formatter.write_reg(*rt2)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6834,14 +12813,30 @@ impl Ins {
                 formatter.write_dreg(*dm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6853,15 +12848,38 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_reg(*rt2)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmovn { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6872,14 +12890,30 @@ impl Ins {
                 formatter.write_fpscr(*fpscr)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6890,14 +12924,30 @@ impl Ins {
                 formatter.write_reg(*rd)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6909,15 +12959,52 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_sreg(*sm)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Neon { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Scalar { cond, vd, vn, dm, index } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6929,51 +13016,188 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VnegF32 { cond, sd, sm } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulI { cond, dt, vd, vn, vm } => {
                 formatter.write_space()?;
-                formatter.write_sreg(*sd)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_sreg(*sm)?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VnegF64 { cond, dd, dm } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulIScalar { cond, dt, vd, vn, dm, index } => {
                 formatter.write_space()?;
-                formatter.write_dreg(*dd)?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulP8 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmull { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullP8 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmvn { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmvnIImm { cond, dt, vd, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vneg { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnegF32 { cond, sd, sm } => {
+                formatter.write_space()?;
+                formatter.write_sreg(*sd)?;
+                formatter.write_separator()?;
+                formatter.write_sreg(*sm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VnegF32Neon { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnegF64 { cond, dd, dm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*dd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -6986,14 +13210,30 @@ impl Ins {
                 formatter.write_sreg(*sm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -7006,14 +13246,30 @@ impl Ins {
                 formatter.write_dreg(*dm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -7026,14 +13282,30 @@ impl Ins {
                 formatter.write_sreg(*sm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -7046,14 +13318,30 @@ impl Ins {
                 formatter.write_dreg(*dm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -7066,14 +13354,30 @@ impl Ins {
                 formatter.write_sreg(*sm)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -7085,15 +13389,131 @@ impl Ins {
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
             }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorn { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorr { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                if *vn == *vm {
+                    formatter.write_neon_reg(*vd)?;
+                    formatter.write_separator()?;
+                    formatter.write_neon_reg(*vm)?;
+                } else {
+                    formatter.write_neon_reg(*vd)?;
+                    formatter.write_separator()?;
+                    formatter.write_neon_reg(*vn)?;
+                    formatter.write_separator()?;
+                    formatter.write_neon_reg(*vm)?;
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VorrIImm { cond, dt, vd, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpadal { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddF32 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddI { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpaddl { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmax { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpmaxF32 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmin { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpminF32 { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -7102,14 +13522,30 @@ impl Ins {
                 formatter.write_sreg_list(*regs)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -7118,14 +13554,30 @@ impl Ins {
                 formatter.write_dreg_list(*regs)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -7134,14 +13586,30 @@ impl Ins {
                 formatter.write_sreg_list(*regs)?;
             }
             #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
                     )
                 )
             )]
@@ -7149,176 +13617,1420 @@ impl Ins {
                 formatter.write_space()?;
                 formatter.write_dreg_list(*regs)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VsqrtF32 { cond, sd, sm } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqabs { cond, dt, vd, vm } => {
                 formatter.write_space()?;
-                formatter.write_sreg(*sd)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_sreg(*sm)?;
+                formatter.write_neon_reg(*vm)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VsqrtF64 { cond, dd, dm } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqadd { cond, dt, vd, vn, vm } => {
                 formatter.write_space()?;
-                formatter.write_dreg(*dd)?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlal { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlalScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VstmF32 { mode, cond, rn, writeback, regs } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlsl { cond, dt, vd, vn, vm } => {
                 formatter.write_space()?;
-                formatter.write_reg(*rn)?;
-                formatter.write_wb(*writeback)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_sreg_list(*regs)?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VstmF64 { mode, cond, rn, writeback, regs } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlslScalar { cond, dt, vd, vn, dm, index } => {
                 formatter.write_space()?;
-                formatter.write_reg(*rn)?;
-                formatter.write_wb(*writeback)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_dreg_list(*regs)?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VstrF32 { cond, sd, addr } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmulh { cond, dt, vd, vn, vm } => {
                 formatter.write_space()?;
-                formatter.write_sreg(*sd)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_addr_ldr_str(*addr)?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VstrF64 { cond, dd, addr } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmulhScalar { cond, dt, vd, vn, dm, index } => {
                 formatter.write_space()?;
-                formatter.write_dreg(*dd)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_addr_ldr_str(*addr)?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VsubF32 { cond, sd, sn, sm } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmull { cond, dt, vd, vn, vm } => {
                 formatter.write_space()?;
-                formatter.write_sreg(*sd)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_sreg(*sn)?;
+                formatter.write_dreg(*vn)?;
                 formatter.write_separator()?;
-                formatter.write_sreg(*sm)?;
+                formatter.write_dreg(*vm)?;
             }
-            #[cfg(
-                all(
-                    feature = "arm",
-                    feature = "vfp_v2",
-                    any(
-                        feature = "v5te",
-                        feature = "v5tej",
-                        feature = "v6",
-                        feature = "v6k"
-                    )
-                )
-            )]
-            Ins::VsubF64 { cond, dd, dn, dm } => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmullScalar { cond, dt, vd, vn, dm, index } => {
                 formatter.write_space()?;
-                formatter.write_dreg(*dd)?;
+                formatter.write_neon_reg(*vd)?;
                 formatter.write_separator()?;
-                formatter.write_dreg(*dn)?;
+                formatter.write_dreg(*vn)?;
                 formatter.write_separator()?;
                 formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
             }
-            #[cfg(all(feature = "arm", feature = "v6k"))]
-            Ins::Wfe { cond } => {}
-            #[cfg(all(feature = "arm", feature = "v6k"))]
-            Ins::Wfi { cond } => {}
-            #[cfg(all(feature = "arm", feature = "v6k"))]
-            Ins::Yield { cond } => {}
-            Ins::Illegal => {}
-            Ins::Word(value) => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovn { cond, dt, vd, vm } => {
                 formatter.write_space()?;
-                formatter.write_uimm(*value)?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
             }
-            Ins::HalfWord(value) => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovun { cond, dt, vd, vm } => {
                 formatter.write_space()?;
-                formatter.write_uimm(*value as u32)?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
             }
-            Ins::Byte(value) => {
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqneg { cond, dt, vd, vm } => {
                 formatter.write_space()?;
-                formatter.write_uimm(*value as u32)?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
             }
-        }
-        Ok(())
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrdmulh { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqrdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+                formatter.write_str("[")?;
+                formatter.write_uimm(*index)?;
+                formatter.write_str("]")?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshl { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrn { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrun { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshl { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqshlImm { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshlu { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrn { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrun { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqsub { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vraddhn { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecpe { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrecpeF32 { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecps { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev16 { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev32 { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev64 { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrhadd { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshl { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshr { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshrn { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrte { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrsqrteF32 { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrts { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsra { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsubhn { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshl { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshlImm { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshll { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                if *imm == 0 {
+                    formatter.write_neon_reg(*vd)?;
+                    formatter.write_separator()?;
+                    formatter.write_dreg(*vm)?;
+                } else {
+                    formatter.write_neon_reg(*vd)?;
+                    formatter.write_separator()?;
+                    formatter.write_dreg(*vm)?;
+                    formatter.write_separator()?;
+                    formatter.write_str("#")?;
+                    formatter.write_uimm(*imm)?;
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshllMax { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshr { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshrn { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsli { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsqrtF32 { cond, sd, sm } => {
+                formatter.write_space()?;
+                formatter.write_sreg(*sd)?;
+                formatter.write_separator()?;
+                formatter.write_sreg(*sm)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsqrtF64 { cond, dd, dm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*dd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsra { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsri { cond, dt, vd, vm, imm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+                formatter.write_separator()?;
+                formatter.write_str("#")?;
+                formatter.write_uimm(*imm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst1 { cond, size, regs, addr } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg_list(*regs)?;
+                formatter.write_separator()?;
+                formatter.write_neon_addr(*addr)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst2 { cond, size, regs, addr } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg_list(*regs)?;
+                formatter.write_separator()?;
+                formatter.write_neon_addr(*addr)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst3 { cond, size, regs, addr } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg_list(*regs)?;
+                formatter.write_separator()?;
+                formatter.write_neon_addr(*addr)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst4 { cond, size, regs, addr } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg_list(*regs)?;
+                formatter.write_separator()?;
+                formatter.write_neon_addr(*addr)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstmF32 { mode, cond, rn, writeback, regs } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_wb(*writeback)?;
+                formatter.write_separator()?;
+                formatter.write_sreg_list(*regs)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstmF64 { mode, cond, rn, writeback, regs } => {
+                formatter.write_space()?;
+                formatter.write_reg(*rn)?;
+                formatter.write_wb(*writeback)?;
+                formatter.write_separator()?;
+                formatter.write_dreg_list(*regs)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstrF32 { cond, sd, addr } => {
+                formatter.write_space()?;
+                formatter.write_sreg(*sd)?;
+                formatter.write_separator()?;
+                formatter.write_addr_ldr_str(*addr)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstrF64 { cond, dd, addr } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*dd)?;
+                formatter.write_separator()?;
+                formatter.write_addr_ldr_str(*addr)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsubF32 { cond, sd, sn, sm } => {
+                formatter.write_space()?;
+                formatter.write_sreg(*sd)?;
+                formatter.write_separator()?;
+                formatter.write_sreg(*sn)?;
+                formatter.write_separator()?;
+                formatter.write_sreg(*sm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubF32Neon { cond, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsubF64 { cond, dd, dn, dm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*dd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubI { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubhn { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubl { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubw { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vswp { cond, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbl { cond, dd, regs, dm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*dd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg_list(*regs)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbx { cond, dd, regs, dm } => {
+                formatter.write_space()?;
+                formatter.write_dreg(*dd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg_list(*regs)?;
+                formatter.write_separator()?;
+                formatter.write_dreg(*dm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtrn { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtst { cond, dt, vd, vn, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vn)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vuzp { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vzip { cond, dt, vd, vm } => {
+                formatter.write_space()?;
+                formatter.write_neon_reg(*vd)?;
+                formatter.write_separator()?;
+                formatter.write_neon_reg(*vm)?;
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Wfe { cond } => {}
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Wfi { cond } => {}
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Yield { cond } => {}
+            Ins::Illegal => {}
+            Ins::Word(value) => {
+                formatter.write_space()?;
+                formatter.write_uimm(*value)?;
+            }
+            Ins::HalfWord(value) => {
+                formatter.write_space()?;
+                formatter.write_uimm(*value as u32)?;
+            }
+            Ins::Byte(value) => {
+                formatter.write_space()?;
+                formatter.write_uimm(*value as u32)?;
+            }
+        }
+        Ok(())
+    }
+}
+impl<F, R> FormatIns for SymbolFormatter<'_, F, R>
+where
+    F: FormatIns,
+    R: SymbolResolver + ?Sized,
+{
+    fn options(&self) -> &Options {
+        self.formatter.options()
+    }
+    fn write_space(&mut self) -> core::fmt::Result {
+        self.formatter.write_space()
+    }
+    fn write_separator(&mut self) -> core::fmt::Result {
+        self.formatter.write_separator()
+    }
+    fn write_s(&mut self, s: bool) -> core::fmt::Result {
+        self.formatter.write_s(s)
+    }
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    fn write_l(&mut self, l: bool) -> core::fmt::Result {
+        self.formatter.write_l(l)
+    }
+    fn write_wb(&mut self, wb: bool) -> core::fmt::Result {
+        self.formatter.write_wb(wb)
+    }
+    fn write_user_mode(&mut self, user_mode: bool) -> core::fmt::Result {
+        self.formatter.write_user_mode(user_mode)
+    }
+    fn write_subtract(&mut self, subtract: bool) -> core::fmt::Result {
+        self.formatter.write_subtract(subtract)
+    }
+    fn write_uimm(&mut self, uimm: u32) -> core::fmt::Result {
+        self.formatter.write_uimm(uimm)
+    }
+    fn write_simm(&mut self, simm: i32) -> core::fmt::Result {
+        self.formatter.write_simm(simm)
+    }
+    fn write_cond(&mut self, cond: Cond) -> core::fmt::Result {
+        self.formatter.write_cond(cond)
+    }
+    fn write_reg(&mut self, reg: Reg) -> core::fmt::Result {
+        self.formatter.write_reg(reg)
+    }
+    fn write_reg_list(&mut self, reg_list: RegList) -> core::fmt::Result {
+        self.formatter.write_reg_list(reg_list)
+    }
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    fn write_status_reg(&mut self, status_reg: StatusReg) -> core::fmt::Result {
+        self.formatter.write_status_reg(status_reg)
+    }
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    fn write_status_fields(&mut self, status_fields: StatusFields) -> core::fmt::Result {
+        self.formatter.write_status_fields(status_fields)
+    }
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    fn write_msr_op2(&mut self, msr_op2: MsrOp2) -> core::fmt::Result {
+        self.formatter.write_msr_op2(msr_op2)
+    }
+    fn write_shift_op(&mut self, shift_op: ShiftOp) -> core::fmt::Result {
+        self.formatter.write_shift_op(shift_op)
+    }
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    fn write_coproc(&mut self, coproc: Coproc) -> core::fmt::Result {
+        self.formatter.write_coproc(coproc)
+    }
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    fn write_co_reg(&mut self, co_reg: CoReg) -> core::fmt::Result {
+        self.formatter.write_co_reg(co_reg)
+    }
+    fn write_op2(&mut self, op2: Op2) -> core::fmt::Result {
+        self.formatter.write_op2(op2)
+    }
+    fn write_shift_reg(&mut self, shift_reg: ShiftReg) -> core::fmt::Result {
+        self.formatter.write_shift_reg(shift_reg)
+    }
+    fn write_shift_imm(&mut self, shift_imm: ShiftImm) -> core::fmt::Result {
+        self.formatter.write_shift_imm(shift_imm)
+    }
+    fn write_op2_shift(&mut self, op2_shift: Op2Shift) -> core::fmt::Result {
+        self.formatter.write_op2_shift(op2_shift)
+    }
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+    fn write_cps_effect(&mut self, cps_effect: CpsEffect) -> core::fmt::Result {
+        self.formatter.write_cps_effect(cps_effect)
+    }
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+    fn write_aif_flags(&mut self, aif_flags: AifFlags) -> core::fmt::Result {
+        self.formatter.write_aif_flags(aif_flags)
+    }
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    fn write_addr_ldc_stc(&mut self, addr_ldc_stc: AddrLdcStc) -> core::fmt::Result {
+        self.formatter.write_addr_ldc_stc(addr_ldc_stc)
+    }
+    fn write_ldm_stm_mode(&mut self, ldm_stm_mode: LdmStmMode) -> core::fmt::Result {
+        self.formatter.write_ldm_stm_mode(ldm_stm_mode)
+    }
+    fn write_addr_ldr_str(&mut self, addr_ldr_str: AddrLdrStr) -> core::fmt::Result {
+        self.formatter.write_addr_ldr_str(addr_ldr_str)
+    }
+    fn write_addr_ldr_str_post(
+        &mut self,
+        addr_ldr_str_post: AddrLdrStrPost,
+    ) -> core::fmt::Result {
+        self.formatter.write_addr_ldr_str_post(addr_ldr_str_post)
+    }
+    fn write_ldr_str_offset(
+        &mut self,
+        ldr_str_offset: LdrStrOffset,
+    ) -> core::fmt::Result {
+        self.formatter.write_ldr_str_offset(ldr_str_offset)
+    }
+    fn write_addr_misc_load(
+        &mut self,
+        addr_misc_load: AddrMiscLoad,
+    ) -> core::fmt::Result {
+        self.formatter.write_addr_misc_load(addr_misc_load)
+    }
+    fn write_misc_load_offset(
+        &mut self,
+        misc_load_offset: MiscLoadOffset,
+    ) -> core::fmt::Result {
+        self.formatter.write_misc_load_offset(misc_load_offset)
+    }
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
+    fn write_srs_rfe_mode(&mut self, srs_rfe_mode: SrsRfeMode) -> core::fmt::Result {
+        self.formatter.write_srs_rfe_mode(srs_rfe_mode)
+    }
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+    fn write_endianness(&mut self, endianness: Endianness) -> core::fmt::Result {
+        self.formatter.write_endianness(endianness)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
+    fn write_reg_side(&mut self, reg_side: RegSide) -> core::fmt::Result {
+        self.formatter.write_reg_side(reg_side)
+    }
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
+    fn write_swap_rm(&mut self, swap_rm: bool) -> core::fmt::Result {
+        self.formatter.write_swap_rm(swap_rm)
+    }
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
+    fn write_round(&mut self, round: bool) -> core::fmt::Result {
+        self.formatter.write_round(round)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_round_zero(&mut self, round_zero: bool) -> core::fmt::Result {
+        self.formatter.write_round_zero(round_zero)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_sreg(&mut self, sreg: Sreg) -> core::fmt::Result {
+        self.formatter.write_sreg(sreg)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "neon",
+                    feature = "vfp_v2",
+                    feature = "vfp_v3",
+                    feature = "vfp_v3_d16"
+                ),
+                any(
+                    feature = "v7",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(
+                    feature = "neon",
+                    feature = "vfp_v2",
+                    feature = "vfp_v3",
+                    feature = "vfp_v3_d16"
+                ),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_dreg(&mut self, dreg: Dreg) -> core::fmt::Result {
+        self.formatter.write_dreg(dreg)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_nan_exc(&mut self, nan_exc: bool) -> core::fmt::Result {
+        self.formatter.write_nan_exc(nan_exc)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_vcmp_f32_op2(&mut self, vcmp_f32_op2: VcmpF32Op2) -> core::fmt::Result {
+        self.formatter.write_vcmp_f32_op2(vcmp_f32_op2)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_vcmp_f64_op2(&mut self, vcmp_f64_op2: VcmpF64Op2) -> core::fmt::Result {
+        self.formatter.write_vcmp_f64_op2(vcmp_f64_op2)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_sreg_list(&mut self, sreg_list: SregList) -> core::fmt::Result {
+        self.formatter.write_sreg_list(sreg_list)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_dreg_list(&mut self, dreg_list: DregList) -> core::fmt::Result {
+        self.formatter.write_dreg_list(dreg_list)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_dreg_index(&mut self, dreg_index: DregIndex) -> core::fmt::Result {
+        self.formatter.write_dreg_index(dreg_index)
+    }
+    #[cfg(
+        all(
+            any(feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"),
+            feature = "v7"
+        )
+    )]
+    fn write_vfp_imm(&mut self, vfp_imm: VfpImm) -> core::fmt::Result {
+        self.formatter.write_vfp_imm(vfp_imm)
+    }
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    fn write_fixed_type(&mut self, fixed_type: FixedType) -> core::fmt::Result {
+        self.formatter.write_fixed_type(fixed_type)
+    }
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    fn write_half(&mut self, half: Half) -> core::fmt::Result {
+        self.formatter.write_half(half)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_fpscr(&mut self, fpscr: Fpscr) -> core::fmt::Result {
+        self.formatter.write_fpscr(fpscr)
+    }
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    fn write_vldm_vstm_mode(
+        &mut self,
+        vldm_vstm_mode: VldmVstmMode,
+    ) -> core::fmt::Result {
+        self.formatter.write_vldm_vstm_mode(vldm_vstm_mode)
+    }
+    #[cfg(feature = "v7")]
+    fn write_barrier_option(
+        &mut self,
+        barrier_option: BarrierOption,
+    ) -> core::fmt::Result {
+        self.formatter.write_barrier_option(barrier_option)
+    }
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    fn write_it_mask(&mut self, it_mask: ItMask) -> core::fmt::Result {
+        self.formatter.write_it_mask(it_mask)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_qreg(&mut self, qreg: Qreg) -> core::fmt::Result {
+        self.formatter.write_qreg(qreg)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_neon_reg(&mut self, neon_reg: NeonReg) -> core::fmt::Result {
+        self.formatter.write_neon_reg(neon_reg)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_neon_int_type(&mut self, neon_int_type: NeonIntType) -> core::fmt::Result {
+        self.formatter.write_neon_int_type(neon_int_type)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_neon_sign_type(
+        &mut self,
+        neon_sign_type: NeonSignType,
+    ) -> core::fmt::Result {
+        self.formatter.write_neon_sign_type(neon_sign_type)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_neon_size(&mut self, neon_size: NeonSize) -> core::fmt::Result {
+        self.formatter.write_neon_size(neon_size)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_neon_cvt_type(&mut self, neon_cvt_type: NeonCvtType) -> core::fmt::Result {
+        self.formatter.write_neon_cvt_type(neon_cvt_type)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_neon_imm64(&mut self, neon_imm64: NeonImm64) -> core::fmt::Result {
+        self.formatter.write_neon_imm64(neon_imm64)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_neon_reg_list(&mut self, neon_reg_list: NeonRegList) -> core::fmt::Result {
+        self.formatter.write_neon_reg_list(neon_reg_list)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_neon_align(&mut self, neon_align: NeonAlign) -> core::fmt::Result {
+        self.formatter.write_neon_align(neon_align)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_neon_post(&mut self, neon_post: NeonPost) -> core::fmt::Result {
+        self.formatter.write_neon_post(neon_post)
+    }
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    fn write_neon_addr(&mut self, neon_addr: NeonAddr) -> core::fmt::Result {
+        self.formatter.write_neon_addr(neon_addr)
+    }
+    fn write_branch_target(&mut self, branch_target: BranchTarget) -> core::fmt::Result {
+        self.write_symbol(branch_target.addr)
     }
 }
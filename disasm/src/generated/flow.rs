@@ -0,0 +1,3582 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_variables)]
+#![allow(clippy::explicit_auto_deref)]
+#![allow(clippy::useless_conversion)]
+use crate::*;
+impl Ins {
+    /// Returns the condition for executing this instruction, or [`Cond::Al`] if it
+    /// has none.
+    pub fn cond(&self) -> Cond {
+        match self {
+            Ins::Adc { cond, .. } => *cond,
+            Ins::Add { cond, .. } => *cond,
+            Ins::And { cond, .. } => *cond,
+            Ins::Asr { cond, .. } => *cond,
+            Ins::B { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Bfc { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Bfi { cond, .. } => *cond,
+            Ins::Bic { cond, .. } => *cond,
+            Ins::Bl { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Blx { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    feature = "v4t",
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Bx { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Bxj { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Cdp { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Clz { cond, .. } => *cond,
+            Ins::Cmn { cond, .. } => *cond,
+            Ins::Cmp { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Csdb { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Dbg { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Dmb { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Dsb { cond, .. } => *cond,
+            Ins::Eor { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Isb { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldc { cond, .. } => *cond,
+            Ins::Ldm { cond, .. } => *cond,
+            Ins::Ldr { cond, .. } => *cond,
+            Ins::Ldrb { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldrbt { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrd { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrex { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexb { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexd { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexh { cond, .. } => *cond,
+            Ins::Ldrh { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Ldrht { cond, .. } => *cond,
+            Ins::Ldrsb { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Ldrsbt { cond, .. } => *cond,
+            Ins::Ldrsh { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Ldrsht { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldrt { cond, .. } => *cond,
+            Ins::Lsl { cond, .. } => *cond,
+            Ins::Lsr { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mcr { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mcrr { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mla { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Mls { cond, .. } => *cond,
+            Ins::Mov { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Movt { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Movw { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mrc { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mrrc { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mrs { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Msr { cond, .. } => *cond,
+            Ins::Mul { cond, .. } => *cond,
+            Ins::Mvn { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Nop { cond, .. } => *cond,
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Orn { cond, .. } => *cond,
+            Ins::Orr { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Pkhbt { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Pkhtb { cond, .. } => *cond,
+            Ins::Pop { cond, .. } => *cond,
+            Ins::Push { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qadd { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qadd16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qadd8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qasx { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qdadd { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qdsub { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsax { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsub { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsub16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsub8 { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Rbit { cond, .. } => *cond,
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Rev { cond, .. } => *cond,
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Rev16 { cond, .. } => *cond,
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Revsh { cond, .. } => *cond,
+            Ins::Ror { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Rrx { cond, .. } => *cond,
+            Ins::Rsb { cond, .. } => *cond,
+            #[cfg(feature = "arm")]
+            Ins::Rsc { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sadd16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sadd8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sasx { cond, .. } => *cond,
+            Ins::Sbc { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Sbfx { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Sdiv { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sel { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sev { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shadd16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shadd8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shasx { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shsax { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shsub16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shsub8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smla { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlad { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Smlal { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::SmlalHalf { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlald { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlaw { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlsd { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlsld { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smmla { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smmls { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smmul { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smuad { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smul { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Smull { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smulw { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smusd { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssat { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssat16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssax { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssub16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssub8 { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Stc { cond, .. } => *cond,
+            Ins::Stm { cond, .. } => *cond,
+            Ins::Str { cond, .. } => *cond,
+            Ins::Strb { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Strbt { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strd { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strex { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexb { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexd { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexh { cond, .. } => *cond,
+            Ins::Strh { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Strht { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Strt { cond, .. } => *cond,
+            Ins::Sub { cond, .. } => *cond,
+            Ins::Svc { cond, .. } => *cond,
+            #[cfg(feature = "arm")]
+            Ins::Swp { cond, .. } => *cond,
+            #[cfg(feature = "arm")]
+            Ins::Swpb { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtab { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtab16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtah { cond, .. } => *cond,
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Sxtb { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtb16 { cond, .. } => *cond,
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Sxth { cond, .. } => *cond,
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbb { cond, .. } => *cond,
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbh { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Teq { cond, .. } => *cond,
+            Ins::Tst { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uadd16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uadd8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uasx { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Ubfx { cond, .. } => *cond,
+            #[cfg(feature = "v7")]
+            Ins::Udiv { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhadd16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhadd8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhasx { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhsax { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhsub16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhsub8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Umaal { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Umlal { cond, .. } => *cond,
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Umull { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqadd16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqadd8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqasx { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqsax { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqsub16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqsub8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usad8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usada8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usat { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usat16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usax { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usub16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usub8 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtab { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtab16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtah { cond, .. } => *cond,
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Uxtb { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtb16 { cond, .. } => *cond,
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Uxth { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaba { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabal { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabd { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabdF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabdl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabs { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VabsF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabsF32Neon { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VabsF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacge { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacgt { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VaddF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddF32Neon { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VaddF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddI { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddhn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddw { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vand { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbic { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VbicIImm { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbif { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbit { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbsl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vceq { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZero { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZeroF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcge { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZero { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZeroF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcgt { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZero { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZeroF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZero { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZeroF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcls { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZero { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZeroF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vclz { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcmpF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcmpF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcnt { cond, .. } => *cond,
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF16F32 { cond, .. } => *cond,
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32F16 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF32F64 { cond, .. } => *cond,
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32Fixed { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF32S32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF32U32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF64F32 { cond, .. } => *cond,
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF64Fixed { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF64S32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF64U32 { cond, .. } => *cond,
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF32 { cond, .. } => *cond,
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtFixedNeon { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtNeon { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtS32F32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtS32F64 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtU32F32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtU32F64 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VdivF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VdivF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupReg { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupScalar { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Veor { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vext { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhadd { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhsub { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld1 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld2 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld3 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld4 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldmF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldmF64 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldrF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldrF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmax { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmaxF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmin { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VminF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlaF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Neon { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Scalar { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlaF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaI { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaIScalar { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlal { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlalScalar { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlsF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Neon { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Scalar { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlsF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsI { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsIScalar { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlsl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlslScalar { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::Vmov32Reg { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF32 { cond, .. } => *cond,
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF32Imm { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovF32ImmNeon { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF32Reg { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF64 { cond, .. } => *cond,
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF64Imm { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovI64Imm { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovIImm { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovReg32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovRegScalar { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovRegF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovRegF32Dual { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovScalarReg { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF32RegDual { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovRegF64 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF64Reg { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmovn { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::Vmrs { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::Vmsr { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmulF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Neon { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Scalar { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmulF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulI { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulIScalar { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulP8 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmull { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullP8 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullScalar { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmvn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmvnIImm { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vneg { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnegF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VnegF32Neon { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnegF64 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlaF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlaF64 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlsF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlsF64 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmulF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmulF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorr { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VorrIImm { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpadal { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddI { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpaddl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmax { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpmaxF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmin { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpminF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpopF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpopF64 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpushF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpushF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqabs { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqadd { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlal { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlalScalar { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlsl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlslScalar { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmulh { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmulhScalar { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmull { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmullScalar { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovun { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqneg { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrdmulh { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqrdmulhScalar { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrun { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqshlImm { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshlu { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrun { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqsub { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vraddhn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecpe { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrecpeF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecps { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev16 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrhadd { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshr { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshrn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrte { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrsqrteF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrts { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsra { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsubhn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshlImm { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshll { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshllMax { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshr { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshrn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsli { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsqrtF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsqrtF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsra { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsri { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst1 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst2 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst3 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst4 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstmF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstmF64 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstrF32 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstrF64 { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsubF32 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubF32Neon { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsubF64 { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubI { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubhn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubw { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vswp { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbl { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbx { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtrn { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtst { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vuzp { cond, .. } => *cond,
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vzip { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Wfe { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Wfi { cond, .. } => *cond,
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Yield { cond, .. } => *cond,
+            _ => Cond::Al,
+        }
+    }
+    /// Returns how this instruction affects the control flow, see [`FlowKind`].
+    pub fn flow(&self) -> FlowKind {
+        match self {
+            Ins::Adc { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Add { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::And { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Asr { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::B { cond, target } => {
+                let conditional = *cond != Cond::Al;
+                FlowKind::branch((*target).addr, conditional)
+            }
+            Ins::Bic { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            #[cfg(
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Bkpt { imm } => {
+                let conditional = false;
+                FlowKind::Exception { conditional }
+            }
+            Ins::Bl { cond, target } => {
+                FlowKind::Call {
+                    target: Option::<u32>::from((*target).addr),
+                    exchange: false,
+                }
+            }
+            #[cfg(
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Blx { cond, target } => {
+                FlowKind::Call {
+                    target: Option::<u32>::from((*target).direct_addr()),
+                    exchange: true,
+                }
+            }
+            #[cfg(
+                any(
+                    feature = "v4t",
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Bx { cond, rm } => {
+                let conditional = *cond != Cond::Al;
+                if *rm == Reg::Lr {
+                    FlowKind::Return { conditional }
+                } else {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Bxj { cond, rm } => {
+                let conditional = *cond != Cond::Al;
+                FlowKind::IndirectJump {
+                    conditional,
+                }
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbnz { rn, target } => {
+                FlowKind::ConditionalBranch {
+                    target: (*target).addr,
+                }
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbz { rn, target } => {
+                FlowKind::ConditionalBranch {
+                    target: (*target).addr,
+                }
+            }
+            Ins::Eor { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Ldm { mode, cond, rn, writeback, regs, user_mode } => {
+                let conditional = *cond != Cond::Al;
+                if (*regs).contains(Reg::Pc) {
+                    if *rn == Reg::Sp {
+                        FlowKind::Return { conditional }
+                    } else {
+                        FlowKind::IndirectJump {
+                            conditional,
+                        }
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Ldr { cond, rd, addr } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    if (*addr).is_pop() {
+                        FlowKind::Return { conditional }
+                    } else {
+                        FlowKind::IndirectJump {
+                            conditional,
+                        }
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Lsl { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Lsr { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Mov { s, thumb, cond, rd, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    if (*op2).is_reg(Reg::Lr) {
+                        FlowKind::Return { conditional }
+                    } else {
+                        FlowKind::IndirectJump {
+                            conditional,
+                        }
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Mvn { s, thumb, cond, rd, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Orr { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Pop { cond, regs } => {
+                let conditional = *cond != Cond::Al;
+                if (*regs).contains(Reg::Pc) {
+                    FlowKind::Return { conditional }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Rfe { addr_mode, rn, writeback } => {
+                let conditional = false;
+                FlowKind::Return { conditional }
+            }
+            Ins::Ror { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Rrx { s, cond, rd, rm } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Rsb { s, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            #[cfg(feature = "arm")]
+            Ins::Rsc { s, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Sbc { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    FlowKind::IndirectJump {
+                        conditional,
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Sub { s, thumb, cond, rd, rn, op2 } => {
+                let conditional = *cond != Cond::Al;
+                if *rd == Reg::Pc {
+                    if *s && *rn == Reg::Lr {
+                        FlowKind::Return { conditional }
+                    } else {
+                        FlowKind::IndirectJump {
+                            conditional,
+                        }
+                    }
+                } else {
+                    FlowKind::Fallthrough
+                }
+            }
+            Ins::Svc { cond, imm } => {
+                let conditional = *cond != Cond::Al;
+                FlowKind::Exception { conditional }
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbb { cond, rn, rm } => {
+                let conditional = *cond != Cond::Al;
+                FlowKind::IndirectJump {
+                    conditional,
+                }
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbh { cond, rn, rm } => {
+                let conditional = *cond != Cond::Al;
+                FlowKind::IndirectJump {
+                    conditional,
+                }
+            }
+            #[cfg(
+                any(
+                    feature = "v4t",
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Udf { imm } => {
+                let conditional = false;
+                FlowKind::Exception { conditional }
+            }
+            _ => FlowKind::Fallthrough,
+        }
+    }
+}
@@ -1,8 +1,18 @@
+mod access;
+mod asm;
 mod defs_uses;
 mod display;
+mod encode;
+mod flow;
+mod opcode;
+mod operands;
 mod parse;
 mod types;
 
+pub(crate) use asm::*;
+pub(crate) use defs_uses::MAX_DEFS_USES;
 pub use display::*;
+pub use opcode::*;
+pub use operands::*;
 pub use parse::*;
 pub use types::*;
@@ -0,0 +1,10201 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_variables)]
+#![allow(clippy::explicit_auto_deref)]
+use crate::*;
+pub(crate) const MAX_OPERANDS: usize = 6;
+/// Operand of an instruction, see [`Ins::operands`]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Operand {
+    ///In LDM/STM, write the last accessed address back to the base register
+    Wb(bool),
+    ///In LDM/STM, access user mode registers while in a privileged mode
+    UserMode(bool),
+    ///Unsigned immediate
+    Uimm(u32),
+    ///The direct destination address of a branch instruction
+    BranchTarget(BranchTarget),
+    #[cfg(
+        any(
+            feature = "v5t",
+            feature = "v5te",
+            feature = "v5tej",
+            feature = "v6",
+            feature = "v6k",
+            feature = "v7"
+        )
+    )]
+    ///The destination of a BLX instruction, which can be direct (immediate) or indirect (register)
+    BlxTarget(BlxTarget),
+    ///Mnemonic suffix, specifies the condition for whether to execute the instruction
+    Cond(Cond),
+    ///General-purpose register
+    Reg(Reg),
+    ///List of general-purpose registers, used by LDM/STM
+    RegList(RegList),
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    ///Status register
+    StatusReg(StatusReg),
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    ///Status register with field masks
+    StatusFields(StatusFields),
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    ///Second operand of the MSR instruction, can be an immediate or a register
+    MsrOp2(MsrOp2),
+    ///Shift operation
+    ShiftOp(ShiftOp),
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    ///Coprocessor
+    Coproc(Coproc),
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    ///Coprocessor register
+    CoReg(CoReg),
+    ///Second operand of a data-processing operation, can be an immediate, an immediate-shifted register or a register-shifted register.
+    Op2(Op2),
+    ///Register shifted by an immediate
+    ShiftImm(ShiftImm),
+    ///Second operand of a shift instruction, can be an immediate or a register
+    Op2Shift(Op2Shift),
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+    ///In a CPS instruction, specifies which interrupt bits to enable or disable
+    AifFlags(AifFlags),
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+    ///The memory address of an LDC/STC instruction
+    AddrLdcStc(AddrLdcStc),
+    ///The memory address of an LDR(B)(T)/STR(B)(T)/PLD instruction
+    AddrLdrStr(AddrLdrStr),
+    ///The memory address of a miscellaneous load/store instruction
+    AddrMiscLoad(AddrMiscLoad),
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+    ///Used by SETEND, specifies the endianness for data accesses
+    Endianness(Endianness),
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    ///General-purpose register for single-precision floating-point numbers
+    Sreg(Sreg),
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "neon",
+                    feature = "vfp_v2",
+                    feature = "vfp_v3",
+                    feature = "vfp_v3_d16"
+                ),
+                any(
+                    feature = "v7",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(
+                    feature = "neon",
+                    feature = "vfp_v2",
+                    feature = "vfp_v3",
+                    feature = "vfp_v3_d16"
+                ),
+                feature = "v7"
+            )
+        )
+    )]
+    ///General-purpose register for double-precision floating-point numbers
+    Dreg(Dreg),
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    ///Second operand of a VCMP.F32 instruction, can be zero or a register
+    VcmpF32Op2(VcmpF32Op2),
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    ///Second operand of a VCMP.F64 instruction, can be zero or a register
+    VcmpF64Op2(VcmpF64Op2),
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    ///List of general-purpose single-precision floation-point registers, used by VLDM/VSTM
+    SregList(SregList),
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    ///List of general-purpose double-precision floation-point registers, used by VLDM/VSTM
+    DregList(DregList),
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    ///A double-precision floating-point register and index (0 or 1) to move to/from
+    DregIndex(DregIndex),
+    #[cfg(
+        all(
+            any(feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"),
+            feature = "v7"
+        )
+    )]
+    ///Floating-point immediate of VMOV, an 8-bit value expanded to a sign, 3-bit exponent and 4-bit fraction
+    VfpImm(VfpImm),
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
+        )
+    )]
+    ///Floating-Point Status and Control Register
+    Fpscr(Fpscr),
+    #[cfg(feature = "v7")]
+    ///Limits which memory accesses a DMB/DSB/ISB instruction applies to
+    BarrierOption(BarrierOption),
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Doubleword or quadword register of an Advanced SIMD instruction, selected by the highest bit
+    NeonReg(NeonReg),
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///64-bit immediate of VMOV, an 8-bit value where each bit is expanded to a byte of ones or zeros
+    NeonImm64(NeonImm64),
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///List of doubleword registers used by VLD1-VLD4/VST1-VST4 and VTBL/VTBX, either whole registers, one lane or all lanes
+    NeonRegList(NeonRegList),
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///The memory address of VLD1-VLD4/VST1-VST4
+    NeonAddr(NeonAddr),
+}
+impl Ins {
+    /// Returns the mnemonic of this instruction with its suffixes and data type, but
+    /// without the condition code, e.g. `ldr` for `ldrne r0, [r1]`, `adds` for
+    /// `addsne r0, r1, r2`, `ldmib` for `ldmib r0, {r1}` and `vadd.i8` for
+    /// `vadd.i8 d0, d1, d2`.
+    pub fn mnemonic(&self, options: &Options) -> &'static str {
+        match self {
+            Ins::Adc { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual {
+                    if *s { "adcs" } else { "adc" }
+                } else {
+                    if !*thumb { if *s { "adcs" } else { "adc" } } else { "adc" }
+                }
+            }
+            Ins::Add { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual {
+                    if *s { "adds" } else { "add" }
+                } else {
+                    if !*thumb { if *s { "adds" } else { "add" } } else { "add" }
+                }
+            }
+            Ins::And { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual {
+                    if *s { "ands" } else { "and" }
+                } else {
+                    if !*thumb { if *s { "ands" } else { "and" } } else { "and" }
+                }
+            }
+            Ins::Asr { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual { if *s { "asrs" } else { "asr" } } else { "asr" }
+            }
+            Ins::B { cond, target } => "b",
+            #[cfg(feature = "v7")]
+            Ins::Bfc { cond, rd, lsb, width } => "bfc",
+            #[cfg(feature = "v7")]
+            Ins::Bfi { cond, rd, rn, lsb, width } => "bfi",
+            Ins::Bic { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual {
+                    if *s { "bics" } else { "bic" }
+                } else {
+                    if !*thumb { if *s { "bics" } else { "bic" } } else { "bic" }
+                }
+            }
+            #[cfg(
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Bkpt { imm } => "bkpt",
+            Ins::Bl { cond, target } => "bl",
+            #[cfg(
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Blx { cond, target } => "blx",
+            #[cfg(
+                any(
+                    feature = "v4t",
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Bx { cond, rm } => "bx",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Bxj { cond, rm } => "bxj",
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbnz { rn, target } => "cbnz",
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbz { rn, target } => "cbz",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Cdp { cond, coproc, opc1, crd, crn, crm, opc2 } => "cdp",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Cdp2 { coproc, opc1, crd, crn, crm, opc2 } => "cdp2",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Clrex {} => "clrex",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Clz { cond, rd, rm } => "clz",
+            Ins::Cmn { cond, rn, op2 } => "cmn",
+            Ins::Cmp { cond, rn, op2 } => "cmp",
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Cps { effect, aif, mode } => {
+                match effect {
+                    CpsEffect::SetMode => "cps",
+                    CpsEffect::Ie => "cpsie",
+                    CpsEffect::Id => "cpsid",
+                }
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Csdb { cond } => "csdb",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Dbg { cond, option } => "dbg",
+            #[cfg(feature = "v7")]
+            Ins::Dmb { cond, option } => "dmb",
+            #[cfg(feature = "v7")]
+            Ins::Dsb { cond, option } => "dsb",
+            Ins::Eor { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual {
+                    if *s { "eors" } else { "eor" }
+                } else {
+                    if !*thumb { if *s { "eors" } else { "eor" } } else { "eor" }
+                }
+            }
+            #[cfg(feature = "v7")]
+            Ins::Isb { cond, option } => "isb",
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::It { firstcond, mask } => "it",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldc { l, cond, coproc, crd, dest } => if *l { "ldcl" } else { "ldc" }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldc2 { l, coproc, crd, dest } => if *l { "ldc2l" } else { "ldc2" }
+            Ins::Ldm { mode, cond, rn, writeback, regs, user_mode } => {
+                match mode {
+                    LdmStmMode::Da => "ldmda",
+                    LdmStmMode::Ia => if options.ual { "ldm" } else { "ldmia" }
+                    LdmStmMode::Db => "ldmdb",
+                    LdmStmMode::Ib => "ldmib",
+                }
+            }
+            Ins::Ldr { cond, rd, addr } => "ldr",
+            Ins::Ldrb { cond, rd, addr } => "ldrb",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldrbt { cond, rd, addr } => "ldrbt",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrd { cond, rd, rd2, addr } => "ldrd",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrex { cond, rd, rn, offset } => "ldrex",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexb { cond, rd, rn } => "ldrexb",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexd { cond, rd, rd2, rn } => "ldrexd",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexh { cond, rd, rn } => "ldrexh",
+            Ins::Ldrh { cond, rd, addr } => "ldrh",
+            #[cfg(feature = "v7")]
+            Ins::Ldrht { cond, rd, addr } => "ldrht",
+            Ins::Ldrsb { cond, rd, addr } => "ldrsb",
+            #[cfg(feature = "v7")]
+            Ins::Ldrsbt { cond, rd, addr } => "ldrsbt",
+            Ins::Ldrsh { cond, rd, addr } => "ldrsh",
+            #[cfg(feature = "v7")]
+            Ins::Ldrsht { cond, rd, addr } => "ldrsht",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldrt { cond, rd, addr } => "ldrt",
+            Ins::Lsl { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual { if *s { "lsls" } else { "lsl" } } else { "lsl" }
+            }
+            Ins::Lsr { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual { if *s { "lsrs" } else { "lsr" } } else { "lsr" }
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mcr { cond, coproc, opc1, rd, crn, crm, opc2 } => "mcr",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mcr2 { coproc, opc1, rd, crn, crm, opc2 } => "mcr2",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mcrr { cond, coproc, opc, rd, rd2, crm } => "mcrr",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mcrr2 { coproc, opc, rd, rd2, crm } => "mcrr2",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mla { s, cond, rd, rn, rm, ra } => if *s { "mlas" } else { "mla" }
+            #[cfg(feature = "v7")]
+            Ins::Mls { cond, rd, rn, rm, ra } => "mls",
+            Ins::Mov { s, thumb, cond, rd, op2 } => {
+                if options.ual {
+                    if *s { "movs" } else { "mov" }
+                } else {
+                    if !*thumb { if *s { "movs" } else { "mov" } } else { "mov" }
+                }
+            }
+            #[cfg(feature = "v7")]
+            Ins::Movt { cond, rd, imm } => "movt",
+            #[cfg(feature = "v7")]
+            Ins::Movw { cond, rd, imm } => "movw",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mrc { cond, coproc, opc1, rd, crn, crm, opc2 } => "mrc",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mrc2 { coproc, opc1, rd, crn, crm, opc2 } => "mrc2",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mrrc { cond, coproc, opc, rd, rd2, crm } => "mrrc",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mrrc2 { coproc, opc, rd, rd2, crm } => "mrrc2",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mrs { cond, rd, status_reg } => "mrs",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Msr { cond, status_fields, op2 } => "msr",
+            Ins::Mul { s, thumb, cond, rd, rn, rm } => {
+                if options.ual {
+                    if *s { "muls" } else { "mul" }
+                } else {
+                    if !*thumb { if *s { "muls" } else { "mul" } } else { "mul" }
+                }
+            }
+            Ins::Mvn { s, thumb, cond, rd, op2 } => {
+                if options.ual {
+                    if *s { "mvns" } else { "mvn" }
+                } else {
+                    if !*thumb { if *s { "mvns" } else { "mvn" } } else { "mvn" }
+                }
+            }
+            #[cfg(feature = "thumb")]
+            Ins::Neg { rd, rm } => "neg",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Nop { cond } => "nop",
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Orn { s, cond, rd, rn, op2 } => if *s { "orns" } else { "orn" }
+            Ins::Orr { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual {
+                    if *s { "orrs" } else { "orr" }
+                } else {
+                    if !*thumb { if *s { "orrs" } else { "orr" } } else { "orr" }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Pkhbt { cond, rd, rn, rm, shift_op, shift } => "pkhbt",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Pkhtb { cond, rd, rn, rm, shift_op, shift } => "pkhtb",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Pld { addr } => "pld",
+            #[cfg(feature = "v7")]
+            Ins::Pli { addr } => "pli",
+            Ins::Pop { cond, regs } => "pop",
+            Ins::Push { cond, regs } => "push",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qadd { cond, rd, rm, rn } => "qadd",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qadd16 { cond, rd, rn, rm } => "qadd16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qadd8 { cond, rd, rn, rm } => "qadd8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qasx { cond, rd, rn, rm } => {
+                if options.ual { "qasx" } else { "qaddsubx" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qdadd { cond, rd, rm, rn } => "qdadd",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qdsub { cond, rd, rm, rn } => "qdsub",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsax { cond, rd, rn, rm } => {
+                if options.ual { "qsax" } else { "qsubaddx" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsub { cond, rd, rm, rn } => "qsub",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsub16 { cond, rd, rn, rm } => "qsub16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsub8 { cond, rd, rn, rm } => "qsub8",
+            #[cfg(feature = "v7")]
+            Ins::Rbit { cond, rd, rm } => "rbit",
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Rev { cond, rd, rm } => "rev",
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Rev16 { cond, rd, rm } => "rev16",
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Revsh { cond, rd, rm } => "revsh",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Rfe { addr_mode, rn, writeback } => {
+                match addr_mode {
+                    SrsRfeMode::Da => "rfeda",
+                    SrsRfeMode::Ia => "rfeia",
+                    SrsRfeMode::Db => "rfedb",
+                    SrsRfeMode::Ib => "rfeib",
+                }
+            }
+            Ins::Ror { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual { if *s { "rors" } else { "ror" } } else { "ror" }
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Rrx { s, cond, rd, rm } => if *s { "rrxs" } else { "rrx" }
+            Ins::Rsb { s, cond, rd, rn, op2 } => if *s { "rsbs" } else { "rsb" }
+            #[cfg(feature = "arm")]
+            Ins::Rsc { s, cond, rd, rn, op2 } => if *s { "rscs" } else { "rsc" }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sadd16 { cond, rd, rn, rm } => "sadd16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sadd8 { cond, rd, rn, rm } => "sadd8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sasx { cond, rd, rn, rm } => {
+                if options.ual { "sasx" } else { "saddsubx" }
+            }
+            Ins::Sbc { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual {
+                    if *s { "sbcs" } else { "sbc" }
+                } else {
+                    if !*thumb { if *s { "sbcs" } else { "sbc" } } else { "sbc" }
+                }
+            }
+            #[cfg(feature = "v7")]
+            Ins::Sbfx { cond, rd, rn, lsb, width } => "sbfx",
+            #[cfg(feature = "v7")]
+            Ins::Sdiv { cond, rd, rn, rm } => "sdiv",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sel { cond, rd, rn, rm } => "sel",
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Setend { endian } => "setend",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sev { cond } => "sev",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shadd16 { cond, rd, rn, rm } => "shadd16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shadd8 { cond, rd, rn, rm } => "shadd8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shasx { cond, rd, rn, rm } => {
+                if options.ual { "shasx" } else { "shaddsubx" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shsax { cond, rd, rn, rm } => {
+                if options.ual { "shsax" } else { "shsubaddx" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shsub16 { cond, rd, rn, rm } => "shsub16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shsub8 { cond, rd, rn, rm } => "shsub8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smla { cond, rd, rn, rn_side, rm, rm_side, ra } => {
+                match rn_side {
+                    RegSide::Bottom => {
+                        match rm_side {
+                            RegSide::Bottom => "smlabb",
+                            RegSide::Top => "smlabt",
+                        }
+                    }
+                    RegSide::Top => {
+                        match rm_side {
+                            RegSide::Bottom => "smlatb",
+                            RegSide::Top => "smlatt",
+                        }
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlad { cond, rd, rn, rm, swap_rm, ra } => {
+                if *swap_rm { "smladx" } else { "smlad" }
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Smlal { s, cond, rd_lo, rd_hi, rn, rm } => {
+                if *s { "smlals" } else { "smlal" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::SmlalHalf { cond, rd_lo, rd_hi, rn, rn_side, rm, rm_side } => {
+                match rn_side {
+                    RegSide::Bottom => {
+                        match rm_side {
+                            RegSide::Bottom => "smlalbb",
+                            RegSide::Top => "smlalbt",
+                        }
+                    }
+                    RegSide::Top => {
+                        match rm_side {
+                            RegSide::Bottom => "smlaltb",
+                            RegSide::Top => "smlaltt",
+                        }
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlald { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
+                if *swap_rm { "smlaldx" } else { "smlald" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlaw { cond, rd, rn, rm, rm_side, ra } => {
+                match rm_side {
+                    RegSide::Bottom => "smlawb",
+                    RegSide::Top => "smlawt",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlsd { cond, rd, rn, rm, swap_rm, ra } => {
+                if *swap_rm { "smlsdx" } else { "smlsd" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlsld { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
+                if *swap_rm { "smlsldx" } else { "smlsld" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smmla { round, cond, rd, rn, rm, ra } => {
+                if *round { "smmlar" } else { "smmla" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smmls { round, cond, rd, rn, rm, ra } => {
+                if *round { "smmlsr" } else { "smmls" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smmul { round, cond, rd, rn, rm } => {
+                if *round { "smmulr" } else { "smmul" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smuad { cond, rd, rn, rm, swap_rm } => {
+                if *swap_rm { "smuadx" } else { "smuad" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smul { cond, rd, rn, rn_side, rm, rm_side } => {
+                match rn_side {
+                    RegSide::Bottom => {
+                        match rm_side {
+                            RegSide::Bottom => "smulbb",
+                            RegSide::Top => "smulbt",
+                        }
+                    }
+                    RegSide::Top => {
+                        match rm_side {
+                            RegSide::Bottom => "smultb",
+                            RegSide::Top => "smultt",
+                        }
+                    }
+                }
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Smull { s, cond, rd_lo, rd_hi, rn, rm } => {
+                if *s { "smulls" } else { "smull" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smulw { cond, rd, rn, rm, rm_side } => {
+                match rm_side {
+                    RegSide::Bottom => "smulwb",
+                    RegSide::Top => "smulwt",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smusd { cond, rd, rn, rm, swap_rm } => {
+                if *swap_rm { "smusdx" } else { "smusd" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Srs { addr_mode, rn, writeback, mode } => {
+                match addr_mode {
+                    SrsRfeMode::Da => "srsda",
+                    SrsRfeMode::Ia => "srsia",
+                    SrsRfeMode::Db => "srsdb",
+                    SrsRfeMode::Ib => "srsib",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssat { cond, rd, imm, op2 } => "ssat",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssat16 { cond, rd, imm, rn } => "ssat16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssax { cond, rd, rn, rm } => {
+                if options.ual { "ssax" } else { "ssubaddx" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssub16 { cond, rd, rn, rm } => "ssub16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssub8 { cond, rd, rn, rm } => "ssub8",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Stc { l, cond, coproc, crd, dest } => if *l { "stcl" } else { "stc" }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Stc2 { l, coproc, crd, dest } => if *l { "stc2l" } else { "stc2" }
+            Ins::Stm { mode, cond, rn, writeback, regs, user_mode } => {
+                match mode {
+                    LdmStmMode::Da => "stmda",
+                    LdmStmMode::Ia => if options.ual { "stm" } else { "stmia" }
+                    LdmStmMode::Db => "stmdb",
+                    LdmStmMode::Ib => "stmib",
+                }
+            }
+            Ins::Str { cond, rd, addr } => "str",
+            Ins::Strb { cond, rd, addr } => "strb",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Strbt { cond, rd, addr } => "strbt",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strd { cond, rd, rd2, addr } => "strd",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strex { cond, rd, rm, rn, offset } => "strex",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexb { cond, rd, rm, rn } => "strexb",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexd { cond, rd, rm, rm2, rn } => "strexd",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexh { cond, rd, rm, rn } => "strexh",
+            Ins::Strh { cond, rd, addr } => "strh",
+            #[cfg(feature = "v7")]
+            Ins::Strht { cond, rd, addr } => "strht",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Strt { cond, rd, addr } => "strt",
+            Ins::Sub { s, thumb, cond, rd, rn, op2 } => {
+                if options.ual {
+                    if *s { "subs" } else { "sub" }
+                } else {
+                    if !*thumb { if *s { "subs" } else { "sub" } } else { "sub" }
+                }
+            }
+            Ins::Svc { cond, imm } => if options.ual { "svc" } else { "swi" }
+            #[cfg(feature = "arm")]
+            Ins::Swp { cond, rd, rd2, rn } => "swp",
+            #[cfg(feature = "arm")]
+            Ins::Swpb { cond, rd, rd2, rn } => "swpb",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtab { cond, rd, rn, rm, rotate } => "sxtab",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtab16 { cond, rd, rn, rm, rotate } => "sxtab16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtah { cond, rd, rn, rm, rotate } => "sxtah",
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Sxtb { cond, rd, rm, rotate } => "sxtb",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtb16 { cond, rd, rm, rotate } => "sxtb16",
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Sxth { cond, rd, rm, rotate } => "sxth",
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbb { cond, rn, rm } => "tbb",
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbh { cond, rn, rm } => "tbh",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Teq { cond, rn, op2 } => "teq",
+            Ins::Tst { cond, rn, op2 } => "tst",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uadd16 { cond, rd, rn, rm } => "uadd16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uadd8 { cond, rd, rn, rm } => "uadd8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uasx { cond, rd, rn, rm } => "uasx",
+            #[cfg(feature = "v7")]
+            Ins::Ubfx { cond, rd, rn, lsb, width } => "ubfx",
+            #[cfg(
+                any(
+                    feature = "v4t",
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Udf { imm } => "udf",
+            #[cfg(feature = "v7")]
+            Ins::Udiv { cond, rd, rn, rm } => "udiv",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhadd16 { cond, rd, rn, rm } => "uhadd16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhadd8 { cond, rd, rn, rm } => "uhadd8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhasx { cond, rd, rn, rm } => {
+                if options.ual { "uhasx" } else { "uhaddsubx" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhsax { cond, rd, rn, rm } => {
+                if options.ual { "uhsax" } else { "uhsubaddx" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhsub16 { cond, rd, rn, rm } => "uhsub16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhsub8 { cond, rd, rn, rm } => "uhsub8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Umaal { cond, rd_lo, rd_hi, rn, rm } => "umaal",
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Umlal { s, cond, rd_lo, rd_hi, rn, rm } => {
+                if *s { "umlals" } else { "umlal" }
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Umull { s, cond, rd_lo, rd_hi, rn, rm } => {
+                if *s { "umulls" } else { "umull" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqadd16 { cond, rd, rn, rm } => "uqadd16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqadd8 { cond, rd, rn, rm } => "uqadd8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqasx { cond, rd, rn, rm } => {
+                if options.ual { "uqasx" } else { "uqaddsubx" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqsax { cond, rd, rn, rm } => {
+                if options.ual { "uqsax" } else { "uqsubaddx" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqsub16 { cond, rd, rn, rm } => "uqsub16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqsub8 { cond, rd, rn, rm } => "uqsub8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usad8 { cond, rd, rn, rm } => "usad8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usada8 { cond, rd, rn, rm, ra } => "usada8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usat { cond, rd, imm, op2 } => "usat",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usat16 { cond, rd, imm, rn } => "usat16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usax { cond, rd, rn, rm } => {
+                if options.ual { "usax" } else { "usubaddx" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usub16 { cond, rd, rn, rm } => "usub16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usub8 { cond, rd, rn, rm } => "usub8",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtab { cond, rd, rn, rm, rotate } => "uxtab",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtab16 { cond, rd, rn, rm, rotate } => "uxtab16",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtah { cond, rd, rn, rm, rotate } => "uxtah",
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Uxtb { cond, rd, rm, rotate } => "uxtb",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtb16 { cond, rd, rm, rotate } => "uxtb16",
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Uxth { cond, rd, rm, rotate } => "uxth",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaba { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vaba.s8",
+                    NeonSignType::S16 => "vaba.s16",
+                    NeonSignType::S32 => "vaba.s32",
+                    NeonSignType::S64 => "vaba.s64",
+                    NeonSignType::U8 => "vaba.u8",
+                    NeonSignType::U16 => "vaba.u16",
+                    NeonSignType::U32 => "vaba.u32",
+                    NeonSignType::U64 => "vaba.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabal { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vabal.s8",
+                    NeonSignType::S16 => "vabal.s16",
+                    NeonSignType::S32 => "vabal.s32",
+                    NeonSignType::S64 => "vabal.s64",
+                    NeonSignType::U8 => "vabal.u8",
+                    NeonSignType::U16 => "vabal.u16",
+                    NeonSignType::U32 => "vabal.u32",
+                    NeonSignType::U64 => "vabal.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabd { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vabd.s8",
+                    NeonSignType::S16 => "vabd.s16",
+                    NeonSignType::S32 => "vabd.s32",
+                    NeonSignType::S64 => "vabd.s64",
+                    NeonSignType::U8 => "vabd.u8",
+                    NeonSignType::U16 => "vabd.u16",
+                    NeonSignType::U32 => "vabd.u32",
+                    NeonSignType::U64 => "vabd.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabdF32 { cond, vd, vn, vm } => "vabd.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabdl { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vabdl.s8",
+                    NeonSignType::S16 => "vabdl.s16",
+                    NeonSignType::S32 => "vabdl.s32",
+                    NeonSignType::S64 => "vabdl.s64",
+                    NeonSignType::U8 => "vabdl.u8",
+                    NeonSignType::U16 => "vabdl.u16",
+                    NeonSignType::U32 => "vabdl.u32",
+                    NeonSignType::U64 => "vabdl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabs { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vabs.s8",
+                    NeonSignType::S16 => "vabs.s16",
+                    NeonSignType::S32 => "vabs.s32",
+                    NeonSignType::S64 => "vabs.s64",
+                    NeonSignType::U8 => "vabs.u8",
+                    NeonSignType::U16 => "vabs.u16",
+                    NeonSignType::U32 => "vabs.u32",
+                    NeonSignType::U64 => "vabs.u64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VabsF32 { cond, sd, sm } => {
+                if options.ual { "vabs.f32" } else { "fabss" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabsF32Neon { cond, vd, vm } => "vabs.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VabsF64 { cond, dd, dm } => {
+                if options.ual { "vabs.f64" } else { "fabsd" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacge { cond, vd, vn, vm } => "vacge.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacgt { cond, vd, vn, vm } => "vacgt.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VaddF32 { cond, sd, sn, sm } => {
+                if options.ual { "vadd.f32" } else { "fadds" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddF32Neon { cond, vd, vn, vm } => "vadd.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VaddF64 { cond, dd, dn, dm } => {
+                if options.ual { "vadd.f64" } else { "faddd" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddI { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vadd.i8",
+                    NeonIntType::I16 => "vadd.i16",
+                    NeonIntType::I32 => "vadd.i32",
+                    NeonIntType::I64 => "vadd.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddhn { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vaddhn.i8",
+                    NeonIntType::I16 => "vaddhn.i16",
+                    NeonIntType::I32 => "vaddhn.i32",
+                    NeonIntType::I64 => "vaddhn.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddl { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vaddl.s8",
+                    NeonSignType::S16 => "vaddl.s16",
+                    NeonSignType::S32 => "vaddl.s32",
+                    NeonSignType::S64 => "vaddl.s64",
+                    NeonSignType::U8 => "vaddl.u8",
+                    NeonSignType::U16 => "vaddl.u16",
+                    NeonSignType::U32 => "vaddl.u32",
+                    NeonSignType::U64 => "vaddl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddw { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vaddw.s8",
+                    NeonSignType::S16 => "vaddw.s16",
+                    NeonSignType::S32 => "vaddw.s32",
+                    NeonSignType::S64 => "vaddw.s64",
+                    NeonSignType::U8 => "vaddw.u8",
+                    NeonSignType::U16 => "vaddw.u16",
+                    NeonSignType::U32 => "vaddw.u32",
+                    NeonSignType::U64 => "vaddw.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vand { cond, vd, vn, vm } => "vand",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbic { cond, vd, vn, vm } => "vbic",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VbicIImm { cond, dt, vd, imm } => {
+                match dt {
+                    NeonIntType::I8 => "vbic.i8",
+                    NeonIntType::I16 => "vbic.i16",
+                    NeonIntType::I32 => "vbic.i32",
+                    NeonIntType::I64 => "vbic.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbif { cond, vd, vn, vm } => "vbif",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbit { cond, vd, vn, vm } => "vbit",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbsl { cond, vd, vn, vm } => "vbsl",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vceq { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vceq.i8",
+                    NeonIntType::I16 => "vceq.i16",
+                    NeonIntType::I32 => "vceq.i32",
+                    NeonIntType::I64 => "vceq.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqF32 { cond, vd, vn, vm } => "vceq.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZero { cond, dt, vd, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vceq.i8",
+                    NeonIntType::I16 => "vceq.i16",
+                    NeonIntType::I32 => "vceq.i32",
+                    NeonIntType::I64 => "vceq.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZeroF32 { cond, vd, vm } => "vceq.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcge { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vcge.s8",
+                    NeonSignType::S16 => "vcge.s16",
+                    NeonSignType::S32 => "vcge.s32",
+                    NeonSignType::S64 => "vcge.s64",
+                    NeonSignType::U8 => "vcge.u8",
+                    NeonSignType::U16 => "vcge.u16",
+                    NeonSignType::U32 => "vcge.u32",
+                    NeonSignType::U64 => "vcge.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeF32 { cond, vd, vn, vm } => "vcge.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZero { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vcge.s8",
+                    NeonSignType::S16 => "vcge.s16",
+                    NeonSignType::S32 => "vcge.s32",
+                    NeonSignType::S64 => "vcge.s64",
+                    NeonSignType::U8 => "vcge.u8",
+                    NeonSignType::U16 => "vcge.u16",
+                    NeonSignType::U32 => "vcge.u32",
+                    NeonSignType::U64 => "vcge.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZeroF32 { cond, vd, vm } => "vcge.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcgt { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vcgt.s8",
+                    NeonSignType::S16 => "vcgt.s16",
+                    NeonSignType::S32 => "vcgt.s32",
+                    NeonSignType::S64 => "vcgt.s64",
+                    NeonSignType::U8 => "vcgt.u8",
+                    NeonSignType::U16 => "vcgt.u16",
+                    NeonSignType::U32 => "vcgt.u32",
+                    NeonSignType::U64 => "vcgt.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtF32 { cond, vd, vn, vm } => "vcgt.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZero { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vcgt.s8",
+                    NeonSignType::S16 => "vcgt.s16",
+                    NeonSignType::S32 => "vcgt.s32",
+                    NeonSignType::S64 => "vcgt.s64",
+                    NeonSignType::U8 => "vcgt.u8",
+                    NeonSignType::U16 => "vcgt.u16",
+                    NeonSignType::U32 => "vcgt.u32",
+                    NeonSignType::U64 => "vcgt.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZeroF32 { cond, vd, vm } => "vcgt.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZero { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vcle.s8",
+                    NeonSignType::S16 => "vcle.s16",
+                    NeonSignType::S32 => "vcle.s32",
+                    NeonSignType::S64 => "vcle.s64",
+                    NeonSignType::U8 => "vcle.u8",
+                    NeonSignType::U16 => "vcle.u16",
+                    NeonSignType::U32 => "vcle.u32",
+                    NeonSignType::U64 => "vcle.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZeroF32 { cond, vd, vm } => "vcle.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcls { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vcls.s8",
+                    NeonSignType::S16 => "vcls.s16",
+                    NeonSignType::S32 => "vcls.s32",
+                    NeonSignType::S64 => "vcls.s64",
+                    NeonSignType::U8 => "vcls.u8",
+                    NeonSignType::U16 => "vcls.u16",
+                    NeonSignType::U32 => "vcls.u32",
+                    NeonSignType::U64 => "vcls.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZero { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vclt.s8",
+                    NeonSignType::S16 => "vclt.s16",
+                    NeonSignType::S32 => "vclt.s32",
+                    NeonSignType::S64 => "vclt.s64",
+                    NeonSignType::U8 => "vclt.u8",
+                    NeonSignType::U16 => "vclt.u16",
+                    NeonSignType::U32 => "vclt.u32",
+                    NeonSignType::U64 => "vclt.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZeroF32 { cond, vd, vm } => "vclt.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vclz { cond, dt, vd, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vclz.i8",
+                    NeonIntType::I16 => "vclz.i16",
+                    NeonIntType::I32 => "vclz.i32",
+                    NeonIntType::I64 => "vclz.i64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcmpF32 { nan_exc, cond, sd, op2 } => {
+                if options.ual {
+                    if *nan_exc { "vcmpe.f32" } else { "vcmp.f32" }
+                } else {
+                    if *nan_exc {
+                        if *op2 == VcmpF32Op2::Zero { "fcmpezs" } else { "fcmpes" }
+                    } else {
+                        if *op2 == VcmpF32Op2::Zero { "fcmpzs" } else { "fcmps" }
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcmpF64 { nan_exc, cond, dd, op2 } => {
+                if options.ual {
+                    if *nan_exc { "vcmpe.f64" } else { "vcmp.f64" }
+                } else {
+                    if *nan_exc {
+                        if *op2 == VcmpF64Op2::Zero { "fcmpezd" } else { "fcmped" }
+                    } else {
+                        if *op2 == VcmpF64Op2::Zero { "fcmpzd" } else { "fcmpd" }
+                    }
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcnt { cond, vd, vm } => "vcnt.8",
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF16F32 { half, cond, sd, sm } => {
+                match half {
+                    Half::B => "vcvtb.f16.f32",
+                    Half::T => "vcvtt.f16.f32",
+                }
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32F16 { half, cond, sd, sm } => {
+                match half {
+                    Half::B => "vcvtb.f32.f16",
+                    Half::T => "vcvtt.f32.f16",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF32F64 { cond, sd, dm } => {
+                if options.ual { "vcvt.f32.f64" } else { "fcvtsd" }
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32Fixed { cond, fixed, sd, fbits } => {
+                if options.ual {
+                    match fixed {
+                        FixedType::S16 => {
+                            if options.ual { "vcvt.f32.s16" } else { "vcvt.f32.sh" }
+                        }
+                        FixedType::S32 => {
+                            if options.ual { "vcvt.f32.s32" } else { "vcvt.f32.sl" }
+                        }
+                        FixedType::U16 => {
+                            if options.ual { "vcvt.f32.u16" } else { "vcvt.f32.uh" }
+                        }
+                        FixedType::U32 => {
+                            if options.ual { "vcvt.f32.u32" } else { "vcvt.f32.ul" }
+                        }
+                    }
+                } else {
+                    match fixed {
+                        FixedType::S16 => if options.ual { "fs16tos" } else { "fshtos" }
+                        FixedType::S32 => if options.ual { "fs32tos" } else { "fsltos" }
+                        FixedType::U16 => if options.ual { "fu16tos" } else { "fuhtos" }
+                        FixedType::U32 => if options.ual { "fu32tos" } else { "fultos" }
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF32S32 { cond, sd, sm } => {
+                if options.ual { "vcvt.f32.s32" } else { "fsitos" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF32U32 { cond, sd, sm } => {
+                if options.ual { "vcvt.f32.u32" } else { "fuitos" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF64F32 { cond, dd, sm } => {
+                if options.ual { "vcvt.f64.f32" } else { "fcvtds" }
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF64Fixed { cond, fixed, dd, fbits } => {
+                if options.ual {
+                    match fixed {
+                        FixedType::S16 => {
+                            if options.ual { "vcvt.f64.s16" } else { "vcvt.f64.sh" }
+                        }
+                        FixedType::S32 => {
+                            if options.ual { "vcvt.f64.s32" } else { "vcvt.f64.sl" }
+                        }
+                        FixedType::U16 => {
+                            if options.ual { "vcvt.f64.u16" } else { "vcvt.f64.uh" }
+                        }
+                        FixedType::U32 => {
+                            if options.ual { "vcvt.f64.u32" } else { "vcvt.f64.ul" }
+                        }
+                    }
+                } else {
+                    match fixed {
+                        FixedType::S16 => if options.ual { "fs16tod" } else { "fshtod" }
+                        FixedType::S32 => if options.ual { "fs32tod" } else { "fsltod" }
+                        FixedType::U16 => if options.ual { "fu16tod" } else { "fuhtod" }
+                        FixedType::U32 => if options.ual { "fu32tod" } else { "fultod" }
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF64S32 { cond, dd, sm } => {
+                if options.ual { "vcvt.f64.s32" } else { "fsitod" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF64U32 { cond, dd, sm } => {
+                if options.ual { "vcvt.f64.u32" } else { "fuitod" }
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF32 { cond, fixed, sd, fbits } => {
+                if options.ual {
+                    match fixed {
+                        FixedType::S16 => {
+                            if options.ual { "vcvt.s16.f32" } else { "vcvt.sh.f32" }
+                        }
+                        FixedType::S32 => {
+                            if options.ual { "vcvt.s32.f32" } else { "vcvt.sl.f32" }
+                        }
+                        FixedType::U16 => {
+                            if options.ual { "vcvt.u16.f32" } else { "vcvt.uh.f32" }
+                        }
+                        FixedType::U32 => {
+                            if options.ual { "vcvt.u32.f32" } else { "vcvt.ul.f32" }
+                        }
+                    }
+                } else {
+                    match fixed {
+                        FixedType::S16 => if options.ual { "ftos16s" } else { "ftoshs" }
+                        FixedType::S32 => if options.ual { "ftos32s" } else { "ftosls" }
+                        FixedType::U16 => if options.ual { "ftou16s" } else { "ftouhs" }
+                        FixedType::U32 => if options.ual { "ftou32s" } else { "ftouls" }
+                    }
+                }
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF64 { cond, fixed, dd, fbits } => {
+                if options.ual {
+                    match fixed {
+                        FixedType::S16 => {
+                            if options.ual { "vcvt.s16.f64" } else { "vcvt.sh.f64" }
+                        }
+                        FixedType::S32 => {
+                            if options.ual { "vcvt.s32.f64" } else { "vcvt.sl.f64" }
+                        }
+                        FixedType::U16 => {
+                            if options.ual { "vcvt.u16.f64" } else { "vcvt.uh.f64" }
+                        }
+                        FixedType::U32 => {
+                            if options.ual { "vcvt.u32.f64" } else { "vcvt.ul.f64" }
+                        }
+                    }
+                } else {
+                    match fixed {
+                        FixedType::S16 => if options.ual { "ftos16d" } else { "ftoshd" }
+                        FixedType::S32 => if options.ual { "ftos32d" } else { "ftosld" }
+                        FixedType::U16 => if options.ual { "ftou16d" } else { "ftouhd" }
+                        FixedType::U32 => if options.ual { "ftou32d" } else { "ftould" }
+                    }
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtFixedNeon { cond, dt, vd, vm, fbits } => {
+                match dt {
+                    NeonCvtType::F32S32 => "vcvt.f32.s32",
+                    NeonCvtType::F32U32 => "vcvt.f32.u32",
+                    NeonCvtType::S32F32 => "vcvt.s32.f32",
+                    NeonCvtType::U32F32 => "vcvt.u32.f32",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtNeon { cond, dt, vd, vm } => {
+                match dt {
+                    NeonCvtType::F32S32 => "vcvt.f32.s32",
+                    NeonCvtType::F32U32 => "vcvt.f32.u32",
+                    NeonCvtType::S32F32 => "vcvt.s32.f32",
+                    NeonCvtType::U32F32 => "vcvt.u32.f32",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtS32F32 { round_zero, cond, sd, sm } => {
+                if options.ual {
+                    if *round_zero {
+                        if options.ual { "vcvtr.s32.f32" } else { "vcvtz.s32.f32" }
+                    } else {
+                        "vcvt.s32.f32"
+                    }
+                } else {
+                    if *round_zero {
+                        if options.ual { "ftosirs" } else { "ftosizs" }
+                    } else {
+                        "ftosis"
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtS32F64 { round_zero, cond, sd, dm } => {
+                if options.ual {
+                    if *round_zero {
+                        if options.ual { "vcvtr.s32.f64" } else { "vcvtz.s32.f64" }
+                    } else {
+                        "vcvt.s32.f64"
+                    }
+                } else {
+                    if *round_zero {
+                        if options.ual { "ftosird" } else { "ftosizd" }
+                    } else {
+                        "ftosid"
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtU32F32 { round_zero, cond, sd, sm } => {
+                if options.ual {
+                    if *round_zero {
+                        if options.ual { "vcvtr.u32.f32" } else { "vcvtz.u32.f32" }
+                    } else {
+                        "vcvt.u32.f32"
+                    }
+                } else {
+                    if *round_zero {
+                        if options.ual { "ftouirs" } else { "ftouizs" }
+                    } else {
+                        "ftouis"
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtU32F64 { round_zero, cond, sd, dm } => {
+                if options.ual {
+                    if *round_zero {
+                        if options.ual { "vcvtr.u32.f64" } else { "vcvtz.u32.f64" }
+                    } else {
+                        "vcvt.u32.f64"
+                    }
+                } else {
+                    if *round_zero {
+                        if options.ual { "ftouird" } else { "ftouizd" }
+                    } else {
+                        "ftouid"
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VdivF32 { cond, sd, sn, sm } => {
+                if options.ual { "vdiv.f32" } else { "fdivs" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VdivF64 { cond, dd, dn, dm } => {
+                if options.ual { "vdiv.f64" } else { "fdivd" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupReg { cond, size, vd, rt } => {
+                match size {
+                    NeonSize::Size8 => "vdup.8",
+                    NeonSize::Size16 => "vdup.16",
+                    NeonSize::Size32 => "vdup.32",
+                    NeonSize::Size64 => "vdup.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupScalar { cond, size, vd, dm, index } => {
+                match size {
+                    NeonSize::Size8 => "vdup.8",
+                    NeonSize::Size16 => "vdup.16",
+                    NeonSize::Size32 => "vdup.32",
+                    NeonSize::Size64 => "vdup.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Veor { cond, vd, vn, vm } => "veor",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vext { cond, vd, vn, vm, imm } => "vext.8",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhadd { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vhadd.s8",
+                    NeonSignType::S16 => "vhadd.s16",
+                    NeonSignType::S32 => "vhadd.s32",
+                    NeonSignType::S64 => "vhadd.s64",
+                    NeonSignType::U8 => "vhadd.u8",
+                    NeonSignType::U16 => "vhadd.u16",
+                    NeonSignType::U32 => "vhadd.u32",
+                    NeonSignType::U64 => "vhadd.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhsub { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vhsub.s8",
+                    NeonSignType::S16 => "vhsub.s16",
+                    NeonSignType::S32 => "vhsub.s32",
+                    NeonSignType::S64 => "vhsub.s64",
+                    NeonSignType::U8 => "vhsub.u8",
+                    NeonSignType::U16 => "vhsub.u16",
+                    NeonSignType::U32 => "vhsub.u32",
+                    NeonSignType::U64 => "vhsub.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld1 { cond, size, regs, addr } => {
+                match size {
+                    NeonSize::Size8 => "vld1.8",
+                    NeonSize::Size16 => "vld1.16",
+                    NeonSize::Size32 => "vld1.32",
+                    NeonSize::Size64 => "vld1.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld2 { cond, size, regs, addr } => {
+                match size {
+                    NeonSize::Size8 => "vld2.8",
+                    NeonSize::Size16 => "vld2.16",
+                    NeonSize::Size32 => "vld2.32",
+                    NeonSize::Size64 => "vld2.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld3 { cond, size, regs, addr } => {
+                match size {
+                    NeonSize::Size8 => "vld3.8",
+                    NeonSize::Size16 => "vld3.16",
+                    NeonSize::Size32 => "vld3.32",
+                    NeonSize::Size64 => "vld3.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld4 { cond, size, regs, addr } => {
+                match size {
+                    NeonSize::Size8 => "vld4.8",
+                    NeonSize::Size16 => "vld4.16",
+                    NeonSize::Size32 => "vld4.32",
+                    NeonSize::Size64 => "vld4.64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldmF32 { mode, cond, rn, writeback, regs } => {
+                if options.ual {
+                    match mode {
+                        VldmVstmMode::Ia => "vldmia",
+                        VldmVstmMode::Db => "vldmdb",
+                    }
+                } else {
+                    match mode {
+                        VldmVstmMode::Ia => "fldmias",
+                        VldmVstmMode::Db => "fldmdbs",
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldmF64 { mode, cond, rn, writeback, regs } => {
+                if options.ual {
+                    match mode {
+                        VldmVstmMode::Ia => "vldmia",
+                        VldmVstmMode::Db => "vldmdb",
+                    }
+                } else {
+                    match mode {
+                        VldmVstmMode::Ia => "fldmiad",
+                        VldmVstmMode::Db => "fldmdbd",
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldrF32 { cond, sd, addr } => if options.ual { "vldr" } else { "flds" }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldrF64 { cond, dd, addr } => if options.ual { "vldr" } else { "fldd" }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmax { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vmax.s8",
+                    NeonSignType::S16 => "vmax.s16",
+                    NeonSignType::S32 => "vmax.s32",
+                    NeonSignType::S64 => "vmax.s64",
+                    NeonSignType::U8 => "vmax.u8",
+                    NeonSignType::U16 => "vmax.u16",
+                    NeonSignType::U32 => "vmax.u32",
+                    NeonSignType::U64 => "vmax.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmaxF32 { cond, vd, vn, vm } => "vmax.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmin { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vmin.s8",
+                    NeonSignType::S16 => "vmin.s16",
+                    NeonSignType::S32 => "vmin.s32",
+                    NeonSignType::S64 => "vmin.s64",
+                    NeonSignType::U8 => "vmin.u8",
+                    NeonSignType::U16 => "vmin.u16",
+                    NeonSignType::U32 => "vmin.u32",
+                    NeonSignType::U64 => "vmin.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VminF32 { cond, vd, vn, vm } => "vmin.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlaF32 { cond, sd, sn, sm } => {
+                if options.ual { "vmla.f32" } else { "fmacs" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Neon { cond, vd, vn, vm } => "vmla.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Scalar { cond, vd, vn, dm, index } => "vmla.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlaF64 { cond, dd, dn, dm } => {
+                if options.ual { "vmla.f64" } else { "fmacd" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaI { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vmla.i8",
+                    NeonIntType::I16 => "vmla.i16",
+                    NeonIntType::I32 => "vmla.i32",
+                    NeonIntType::I64 => "vmla.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaIScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonIntType::I8 => "vmla.i8",
+                    NeonIntType::I16 => "vmla.i16",
+                    NeonIntType::I32 => "vmla.i32",
+                    NeonIntType::I64 => "vmla.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlal { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vmlal.s8",
+                    NeonSignType::S16 => "vmlal.s16",
+                    NeonSignType::S32 => "vmlal.s32",
+                    NeonSignType::S64 => "vmlal.s64",
+                    NeonSignType::U8 => "vmlal.u8",
+                    NeonSignType::U16 => "vmlal.u16",
+                    NeonSignType::U32 => "vmlal.u32",
+                    NeonSignType::U64 => "vmlal.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlalScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonSignType::S8 => "vmlal.s8",
+                    NeonSignType::S16 => "vmlal.s16",
+                    NeonSignType::S32 => "vmlal.s32",
+                    NeonSignType::S64 => "vmlal.s64",
+                    NeonSignType::U8 => "vmlal.u8",
+                    NeonSignType::U16 => "vmlal.u16",
+                    NeonSignType::U32 => "vmlal.u32",
+                    NeonSignType::U64 => "vmlal.u64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlsF32 { cond, sd, sn, sm } => {
+                if options.ual { "vmls.f32" } else { "fmscs" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Neon { cond, vd, vn, vm } => "vmls.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Scalar { cond, vd, vn, dm, index } => "vmls.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlsF64 { cond, dd, dn, dm } => {
+                if options.ual { "vmls.f64" } else { "fmscd" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsI { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vmls.i8",
+                    NeonIntType::I16 => "vmls.i16",
+                    NeonIntType::I32 => "vmls.i32",
+                    NeonIntType::I64 => "vmls.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsIScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonIntType::I8 => "vmls.i8",
+                    NeonIntType::I16 => "vmls.i16",
+                    NeonIntType::I32 => "vmls.i32",
+                    NeonIntType::I64 => "vmls.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlsl { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vmlsl.s8",
+                    NeonSignType::S16 => "vmlsl.s16",
+                    NeonSignType::S32 => "vmlsl.s32",
+                    NeonSignType::S64 => "vmlsl.s64",
+                    NeonSignType::U8 => "vmlsl.u8",
+                    NeonSignType::U16 => "vmlsl.u16",
+                    NeonSignType::U32 => "vmlsl.u32",
+                    NeonSignType::U64 => "vmlsl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlslScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonSignType::S8 => "vmlsl.s8",
+                    NeonSignType::S16 => "vmlsl.s16",
+                    NeonSignType::S32 => "vmlsl.s32",
+                    NeonSignType::S64 => "vmlsl.s64",
+                    NeonSignType::U8 => "vmlsl.u8",
+                    NeonSignType::U16 => "vmlsl.u16",
+                    NeonSignType::U32 => "vmlsl.u32",
+                    NeonSignType::U64 => "vmlsl.u64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::Vmov32Reg { cond, dd, rt } => {
+                if options.ual {
+                    "vmov.32"
+                } else {
+                    if (*dd).index == 0 { "fmdlr" } else { "fmdhr" }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF32 { cond, sd, sm } => {
+                if options.ual { "vmov.f32" } else { "fcpys" }
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF32Imm { cond, sd, imm } => {
+                if options.ual { "vmov.f32" } else { "fconsts" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovF32ImmNeon { cond, vd, imm } => "vmov.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF32Reg { cond, sn, rt } => if options.ual { "vmov" } else { "fmsr" }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF64 { cond, dd, dm } => {
+                if options.ual { "vmov.f64" } else { "fcpyd" }
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF64Imm { cond, dd, imm } => {
+                if options.ual { "vmov.f64" } else { "fconstd" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovI64Imm { cond, vd, imm } => "vmov.i64",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovIImm { cond, dt, vd, imm } => {
+                match dt {
+                    NeonIntType::I8 => "vmov.i8",
+                    NeonIntType::I16 => "vmov.i16",
+                    NeonIntType::I32 => "vmov.i32",
+                    NeonIntType::I64 => "vmov.i64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovReg32 { cond, rt, dn } => {
+                if options.ual {
+                    "vmov.32"
+                } else {
+                    if (*dn).index == 0 { "fmrdl" } else { "fmrdh" }
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovRegScalar { cond, dt, rt, dn, index } => {
+                match dt {
+                    NeonSignType::S8 => "vmov.s8",
+                    NeonSignType::S16 => "vmov.s16",
+                    NeonSignType::S32 => "vmov.s32",
+                    NeonSignType::S64 => "vmov.s64",
+                    NeonSignType::U8 => "vmov.u8",
+                    NeonSignType::U16 => "vmov.u16",
+                    NeonSignType::U32 => "vmov.u32",
+                    NeonSignType::U64 => "vmov.u64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovRegF32 { cond, rt, sn } => if options.ual { "vmov" } else { "fmrs" }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovRegF32Dual { cond, rt, rt2, sm, sm2 } => {
+                if options.ual { "vmov" } else { "fmrrs" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovScalarReg { cond, size, dd, index, rt } => {
+                match size {
+                    NeonSize::Size8 => "vmov.8",
+                    NeonSize::Size16 => "vmov.16",
+                    NeonSize::Size32 => "vmov.32",
+                    NeonSize::Size64 => "vmov.64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF32RegDual { cond, sm, sm2, rt, rt2 } => {
+                if options.ual { "vmov" } else { "fmsrr" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovRegF64 { cond, rt, rt2, dm } => {
+                if options.ual { "vmov" } else { "fmrrd" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF64Reg { cond, dm, rt, rt2 } => {
+                if options.ual { "vmov" } else { "fmdrr" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmovn { cond, dt, vd, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vmovn.i8",
+                    NeonIntType::I16 => "vmovn.i16",
+                    NeonIntType::I32 => "vmovn.i32",
+                    NeonIntType::I64 => "vmovn.i64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::Vmrs { cond, rd, fpscr } => if options.ual { "vmrs" } else { "fmrx" }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::Vmsr { cond, fpscr, rd } => if options.ual { "vmsr" } else { "fmxr" }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmulF32 { cond, sd, sn, sm } => {
+                if options.ual { "vmul.f32" } else { "fmuls" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Neon { cond, vd, vn, vm } => "vmul.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Scalar { cond, vd, vn, dm, index } => "vmul.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmulF64 { cond, dd, dn, dm } => {
+                if options.ual { "vmul.f64" } else { "fmuld" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulI { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vmul.i8",
+                    NeonIntType::I16 => "vmul.i16",
+                    NeonIntType::I32 => "vmul.i32",
+                    NeonIntType::I64 => "vmul.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulIScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonIntType::I8 => "vmul.i8",
+                    NeonIntType::I16 => "vmul.i16",
+                    NeonIntType::I32 => "vmul.i32",
+                    NeonIntType::I64 => "vmul.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulP8 { cond, vd, vn, vm } => "vmul.p8",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmull { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vmull.s8",
+                    NeonSignType::S16 => "vmull.s16",
+                    NeonSignType::S32 => "vmull.s32",
+                    NeonSignType::S64 => "vmull.s64",
+                    NeonSignType::U8 => "vmull.u8",
+                    NeonSignType::U16 => "vmull.u16",
+                    NeonSignType::U32 => "vmull.u32",
+                    NeonSignType::U64 => "vmull.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullP8 { cond, vd, vn, vm } => "vmull.p8",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonSignType::S8 => "vmull.s8",
+                    NeonSignType::S16 => "vmull.s16",
+                    NeonSignType::S32 => "vmull.s32",
+                    NeonSignType::S64 => "vmull.s64",
+                    NeonSignType::U8 => "vmull.u8",
+                    NeonSignType::U16 => "vmull.u16",
+                    NeonSignType::U32 => "vmull.u32",
+                    NeonSignType::U64 => "vmull.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmvn { cond, vd, vm } => "vmvn",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmvnIImm { cond, dt, vd, imm } => {
+                match dt {
+                    NeonIntType::I8 => "vmvn.i8",
+                    NeonIntType::I16 => "vmvn.i16",
+                    NeonIntType::I32 => "vmvn.i32",
+                    NeonIntType::I64 => "vmvn.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vneg { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vneg.s8",
+                    NeonSignType::S16 => "vneg.s16",
+                    NeonSignType::S32 => "vneg.s32",
+                    NeonSignType::S64 => "vneg.s64",
+                    NeonSignType::U8 => "vneg.u8",
+                    NeonSignType::U16 => "vneg.u16",
+                    NeonSignType::U32 => "vneg.u32",
+                    NeonSignType::U64 => "vneg.u64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnegF32 { cond, sd, sm } => {
+                if options.ual { "vneg.f32" } else { "fnegs" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VnegF32Neon { cond, vd, vm } => "vneg.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnegF64 { cond, dd, dm } => {
+                if options.ual { "vneg.f64" } else { "fnegd" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlaF32 { cond, sd, sn, sm } => {
+                if options.ual { "vnmla.f32" } else { "fnmacs" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlaF64 { cond, dd, dn, dm } => {
+                if options.ual { "vnmla.f64" } else { "fnmacd" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlsF32 { cond, sd, sn, sm } => {
+                if options.ual { "vnmls.f32" } else { "fnmscs" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlsF64 { cond, dd, dn, dm } => {
+                if options.ual { "vnmls.f64" } else { "fnmscd" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmulF32 { cond, sd, sn, sm } => {
+                if options.ual { "vnmul.f32" } else { "fnmuls" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmulF64 { cond, dd, dn, dm } => {
+                if options.ual { "vnmul.f64" } else { "fnmuld" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorn { cond, vd, vn, vm } => "vorn",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorr { cond, vd, vn, vm } => if *vn == *vm { "vmov" } else { "vorr" }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VorrIImm { cond, dt, vd, imm } => {
+                match dt {
+                    NeonIntType::I8 => "vorr.i8",
+                    NeonIntType::I16 => "vorr.i16",
+                    NeonIntType::I32 => "vorr.i32",
+                    NeonIntType::I64 => "vorr.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpadal { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vpadal.s8",
+                    NeonSignType::S16 => "vpadal.s16",
+                    NeonSignType::S32 => "vpadal.s32",
+                    NeonSignType::S64 => "vpadal.s64",
+                    NeonSignType::U8 => "vpadal.u8",
+                    NeonSignType::U16 => "vpadal.u16",
+                    NeonSignType::U32 => "vpadal.u32",
+                    NeonSignType::U64 => "vpadal.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddF32 { cond, vd, vn, vm } => "vpadd.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddI { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vpadd.i8",
+                    NeonIntType::I16 => "vpadd.i16",
+                    NeonIntType::I32 => "vpadd.i32",
+                    NeonIntType::I64 => "vpadd.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpaddl { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vpaddl.s8",
+                    NeonSignType::S16 => "vpaddl.s16",
+                    NeonSignType::S32 => "vpaddl.s32",
+                    NeonSignType::S64 => "vpaddl.s64",
+                    NeonSignType::U8 => "vpaddl.u8",
+                    NeonSignType::U16 => "vpaddl.u16",
+                    NeonSignType::U32 => "vpaddl.u32",
+                    NeonSignType::U64 => "vpaddl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmax { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vpmax.s8",
+                    NeonSignType::S16 => "vpmax.s16",
+                    NeonSignType::S32 => "vpmax.s32",
+                    NeonSignType::S64 => "vpmax.s64",
+                    NeonSignType::U8 => "vpmax.u8",
+                    NeonSignType::U16 => "vpmax.u16",
+                    NeonSignType::U32 => "vpmax.u32",
+                    NeonSignType::U64 => "vpmax.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpmaxF32 { cond, vd, vn, vm } => "vpmax.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmin { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vpmin.s8",
+                    NeonSignType::S16 => "vpmin.s16",
+                    NeonSignType::S32 => "vpmin.s32",
+                    NeonSignType::S64 => "vpmin.s64",
+                    NeonSignType::U8 => "vpmin.u8",
+                    NeonSignType::U16 => "vpmin.u16",
+                    NeonSignType::U32 => "vpmin.u32",
+                    NeonSignType::U64 => "vpmin.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpminF32 { cond, vd, vn, vm } => "vpmin.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpopF32 { cond, regs } => "vpop",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpopF64 { cond, regs } => "vpop",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpushF32 { cond, regs } => "vpush",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpushF64 { cond, regs } => "vpush",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqabs { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqabs.s8",
+                    NeonSignType::S16 => "vqabs.s16",
+                    NeonSignType::S32 => "vqabs.s32",
+                    NeonSignType::S64 => "vqabs.s64",
+                    NeonSignType::U8 => "vqabs.u8",
+                    NeonSignType::U16 => "vqabs.u16",
+                    NeonSignType::U32 => "vqabs.u32",
+                    NeonSignType::U64 => "vqabs.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqadd { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqadd.s8",
+                    NeonSignType::S16 => "vqadd.s16",
+                    NeonSignType::S32 => "vqadd.s32",
+                    NeonSignType::S64 => "vqadd.s64",
+                    NeonSignType::U8 => "vqadd.u8",
+                    NeonSignType::U16 => "vqadd.u16",
+                    NeonSignType::U32 => "vqadd.u32",
+                    NeonSignType::U64 => "vqadd.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlal { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqdmlal.s8",
+                    NeonSignType::S16 => "vqdmlal.s16",
+                    NeonSignType::S32 => "vqdmlal.s32",
+                    NeonSignType::S64 => "vqdmlal.s64",
+                    NeonSignType::U8 => "vqdmlal.u8",
+                    NeonSignType::U16 => "vqdmlal.u16",
+                    NeonSignType::U32 => "vqdmlal.u32",
+                    NeonSignType::U64 => "vqdmlal.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlalScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonSignType::S8 => "vqdmlal.s8",
+                    NeonSignType::S16 => "vqdmlal.s16",
+                    NeonSignType::S32 => "vqdmlal.s32",
+                    NeonSignType::S64 => "vqdmlal.s64",
+                    NeonSignType::U8 => "vqdmlal.u8",
+                    NeonSignType::U16 => "vqdmlal.u16",
+                    NeonSignType::U32 => "vqdmlal.u32",
+                    NeonSignType::U64 => "vqdmlal.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlsl { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqdmlsl.s8",
+                    NeonSignType::S16 => "vqdmlsl.s16",
+                    NeonSignType::S32 => "vqdmlsl.s32",
+                    NeonSignType::S64 => "vqdmlsl.s64",
+                    NeonSignType::U8 => "vqdmlsl.u8",
+                    NeonSignType::U16 => "vqdmlsl.u16",
+                    NeonSignType::U32 => "vqdmlsl.u32",
+                    NeonSignType::U64 => "vqdmlsl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlslScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonSignType::S8 => "vqdmlsl.s8",
+                    NeonSignType::S16 => "vqdmlsl.s16",
+                    NeonSignType::S32 => "vqdmlsl.s32",
+                    NeonSignType::S64 => "vqdmlsl.s64",
+                    NeonSignType::U8 => "vqdmlsl.u8",
+                    NeonSignType::U16 => "vqdmlsl.u16",
+                    NeonSignType::U32 => "vqdmlsl.u32",
+                    NeonSignType::U64 => "vqdmlsl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmulh { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqdmulh.s8",
+                    NeonSignType::S16 => "vqdmulh.s16",
+                    NeonSignType::S32 => "vqdmulh.s32",
+                    NeonSignType::S64 => "vqdmulh.s64",
+                    NeonSignType::U8 => "vqdmulh.u8",
+                    NeonSignType::U16 => "vqdmulh.u16",
+                    NeonSignType::U32 => "vqdmulh.u32",
+                    NeonSignType::U64 => "vqdmulh.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonSignType::S8 => "vqdmulh.s8",
+                    NeonSignType::S16 => "vqdmulh.s16",
+                    NeonSignType::S32 => "vqdmulh.s32",
+                    NeonSignType::S64 => "vqdmulh.s64",
+                    NeonSignType::U8 => "vqdmulh.u8",
+                    NeonSignType::U16 => "vqdmulh.u16",
+                    NeonSignType::U32 => "vqdmulh.u32",
+                    NeonSignType::U64 => "vqdmulh.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmull { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqdmull.s8",
+                    NeonSignType::S16 => "vqdmull.s16",
+                    NeonSignType::S32 => "vqdmull.s32",
+                    NeonSignType::S64 => "vqdmull.s64",
+                    NeonSignType::U8 => "vqdmull.u8",
+                    NeonSignType::U16 => "vqdmull.u16",
+                    NeonSignType::U32 => "vqdmull.u32",
+                    NeonSignType::U64 => "vqdmull.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmullScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonSignType::S8 => "vqdmull.s8",
+                    NeonSignType::S16 => "vqdmull.s16",
+                    NeonSignType::S32 => "vqdmull.s32",
+                    NeonSignType::S64 => "vqdmull.s64",
+                    NeonSignType::U8 => "vqdmull.u8",
+                    NeonSignType::U16 => "vqdmull.u16",
+                    NeonSignType::U32 => "vqdmull.u32",
+                    NeonSignType::U64 => "vqdmull.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovn { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqmovn.s8",
+                    NeonSignType::S16 => "vqmovn.s16",
+                    NeonSignType::S32 => "vqmovn.s32",
+                    NeonSignType::S64 => "vqmovn.s64",
+                    NeonSignType::U8 => "vqmovn.u8",
+                    NeonSignType::U16 => "vqmovn.u16",
+                    NeonSignType::U32 => "vqmovn.u32",
+                    NeonSignType::U64 => "vqmovn.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovun { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqmovun.s8",
+                    NeonSignType::S16 => "vqmovun.s16",
+                    NeonSignType::S32 => "vqmovun.s32",
+                    NeonSignType::S64 => "vqmovun.s64",
+                    NeonSignType::U8 => "vqmovun.u8",
+                    NeonSignType::U16 => "vqmovun.u16",
+                    NeonSignType::U32 => "vqmovun.u32",
+                    NeonSignType::U64 => "vqmovun.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqneg { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqneg.s8",
+                    NeonSignType::S16 => "vqneg.s16",
+                    NeonSignType::S32 => "vqneg.s32",
+                    NeonSignType::S64 => "vqneg.s64",
+                    NeonSignType::U8 => "vqneg.u8",
+                    NeonSignType::U16 => "vqneg.u16",
+                    NeonSignType::U32 => "vqneg.u32",
+                    NeonSignType::U64 => "vqneg.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrdmulh { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqrdmulh.s8",
+                    NeonSignType::S16 => "vqrdmulh.s16",
+                    NeonSignType::S32 => "vqrdmulh.s32",
+                    NeonSignType::S64 => "vqrdmulh.s64",
+                    NeonSignType::U8 => "vqrdmulh.u8",
+                    NeonSignType::U16 => "vqrdmulh.u16",
+                    NeonSignType::U32 => "vqrdmulh.u32",
+                    NeonSignType::U64 => "vqrdmulh.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqrdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                match dt {
+                    NeonSignType::S8 => "vqrdmulh.s8",
+                    NeonSignType::S16 => "vqrdmulh.s16",
+                    NeonSignType::S32 => "vqrdmulh.s32",
+                    NeonSignType::S64 => "vqrdmulh.s64",
+                    NeonSignType::U8 => "vqrdmulh.u8",
+                    NeonSignType::U16 => "vqrdmulh.u16",
+                    NeonSignType::U32 => "vqrdmulh.u32",
+                    NeonSignType::U64 => "vqrdmulh.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshl { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqrshl.s8",
+                    NeonSignType::S16 => "vqrshl.s16",
+                    NeonSignType::S32 => "vqrshl.s32",
+                    NeonSignType::S64 => "vqrshl.s64",
+                    NeonSignType::U8 => "vqrshl.u8",
+                    NeonSignType::U16 => "vqrshl.u16",
+                    NeonSignType::U32 => "vqrshl.u32",
+                    NeonSignType::U64 => "vqrshl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrn { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSignType::S8 => "vqrshrn.s8",
+                    NeonSignType::S16 => "vqrshrn.s16",
+                    NeonSignType::S32 => "vqrshrn.s32",
+                    NeonSignType::S64 => "vqrshrn.s64",
+                    NeonSignType::U8 => "vqrshrn.u8",
+                    NeonSignType::U16 => "vqrshrn.u16",
+                    NeonSignType::U32 => "vqrshrn.u32",
+                    NeonSignType::U64 => "vqrshrn.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrun { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSignType::S8 => "vqrshrun.s8",
+                    NeonSignType::S16 => "vqrshrun.s16",
+                    NeonSignType::S32 => "vqrshrun.s32",
+                    NeonSignType::S64 => "vqrshrun.s64",
+                    NeonSignType::U8 => "vqrshrun.u8",
+                    NeonSignType::U16 => "vqrshrun.u16",
+                    NeonSignType::U32 => "vqrshrun.u32",
+                    NeonSignType::U64 => "vqrshrun.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshl { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqshl.s8",
+                    NeonSignType::S16 => "vqshl.s16",
+                    NeonSignType::S32 => "vqshl.s32",
+                    NeonSignType::S64 => "vqshl.s64",
+                    NeonSignType::U8 => "vqshl.u8",
+                    NeonSignType::U16 => "vqshl.u16",
+                    NeonSignType::U32 => "vqshl.u32",
+                    NeonSignType::U64 => "vqshl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqshlImm { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSignType::S8 => "vqshl.s8",
+                    NeonSignType::S16 => "vqshl.s16",
+                    NeonSignType::S32 => "vqshl.s32",
+                    NeonSignType::S64 => "vqshl.s64",
+                    NeonSignType::U8 => "vqshl.u8",
+                    NeonSignType::U16 => "vqshl.u16",
+                    NeonSignType::U32 => "vqshl.u32",
+                    NeonSignType::U64 => "vqshl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshlu { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSignType::S8 => "vqshlu.s8",
+                    NeonSignType::S16 => "vqshlu.s16",
+                    NeonSignType::S32 => "vqshlu.s32",
+                    NeonSignType::S64 => "vqshlu.s64",
+                    NeonSignType::U8 => "vqshlu.u8",
+                    NeonSignType::U16 => "vqshlu.u16",
+                    NeonSignType::U32 => "vqshlu.u32",
+                    NeonSignType::U64 => "vqshlu.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrn { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSignType::S8 => "vqshrn.s8",
+                    NeonSignType::S16 => "vqshrn.s16",
+                    NeonSignType::S32 => "vqshrn.s32",
+                    NeonSignType::S64 => "vqshrn.s64",
+                    NeonSignType::U8 => "vqshrn.u8",
+                    NeonSignType::U16 => "vqshrn.u16",
+                    NeonSignType::U32 => "vqshrn.u32",
+                    NeonSignType::U64 => "vqshrn.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrun { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSignType::S8 => "vqshrun.s8",
+                    NeonSignType::S16 => "vqshrun.s16",
+                    NeonSignType::S32 => "vqshrun.s32",
+                    NeonSignType::S64 => "vqshrun.s64",
+                    NeonSignType::U8 => "vqshrun.u8",
+                    NeonSignType::U16 => "vqshrun.u16",
+                    NeonSignType::U32 => "vqshrun.u32",
+                    NeonSignType::U64 => "vqshrun.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqsub { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vqsub.s8",
+                    NeonSignType::S16 => "vqsub.s16",
+                    NeonSignType::S32 => "vqsub.s32",
+                    NeonSignType::S64 => "vqsub.s64",
+                    NeonSignType::U8 => "vqsub.u8",
+                    NeonSignType::U16 => "vqsub.u16",
+                    NeonSignType::U32 => "vqsub.u32",
+                    NeonSignType::U64 => "vqsub.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vraddhn { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vraddhn.i8",
+                    NeonIntType::I16 => "vraddhn.i16",
+                    NeonIntType::I32 => "vraddhn.i32",
+                    NeonIntType::I64 => "vraddhn.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecpe { cond, vd, vm } => "vrecpe.u32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrecpeF32 { cond, vd, vm } => "vrecpe.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecps { cond, vd, vn, vm } => "vrecps.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev16 { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSize::Size8 => "vrev16.8",
+                    NeonSize::Size16 => "vrev16.16",
+                    NeonSize::Size32 => "vrev16.32",
+                    NeonSize::Size64 => "vrev16.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev32 { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSize::Size8 => "vrev32.8",
+                    NeonSize::Size16 => "vrev32.16",
+                    NeonSize::Size32 => "vrev32.32",
+                    NeonSize::Size64 => "vrev32.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev64 { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSize::Size8 => "vrev64.8",
+                    NeonSize::Size16 => "vrev64.16",
+                    NeonSize::Size32 => "vrev64.32",
+                    NeonSize::Size64 => "vrev64.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrhadd { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vrhadd.s8",
+                    NeonSignType::S16 => "vrhadd.s16",
+                    NeonSignType::S32 => "vrhadd.s32",
+                    NeonSignType::S64 => "vrhadd.s64",
+                    NeonSignType::U8 => "vrhadd.u8",
+                    NeonSignType::U16 => "vrhadd.u16",
+                    NeonSignType::U32 => "vrhadd.u32",
+                    NeonSignType::U64 => "vrhadd.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshl { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vrshl.s8",
+                    NeonSignType::S16 => "vrshl.s16",
+                    NeonSignType::S32 => "vrshl.s32",
+                    NeonSignType::S64 => "vrshl.s64",
+                    NeonSignType::U8 => "vrshl.u8",
+                    NeonSignType::U16 => "vrshl.u16",
+                    NeonSignType::U32 => "vrshl.u32",
+                    NeonSignType::U64 => "vrshl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshr { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSignType::S8 => "vrshr.s8",
+                    NeonSignType::S16 => "vrshr.s16",
+                    NeonSignType::S32 => "vrshr.s32",
+                    NeonSignType::S64 => "vrshr.s64",
+                    NeonSignType::U8 => "vrshr.u8",
+                    NeonSignType::U16 => "vrshr.u16",
+                    NeonSignType::U32 => "vrshr.u32",
+                    NeonSignType::U64 => "vrshr.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshrn { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonIntType::I8 => "vrshrn.i8",
+                    NeonIntType::I16 => "vrshrn.i16",
+                    NeonIntType::I32 => "vrshrn.i32",
+                    NeonIntType::I64 => "vrshrn.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrte { cond, vd, vm } => "vrsqrte.u32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrsqrteF32 { cond, vd, vm } => "vrsqrte.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrts { cond, vd, vn, vm } => "vrsqrts.f32",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsra { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSignType::S8 => "vrsra.s8",
+                    NeonSignType::S16 => "vrsra.s16",
+                    NeonSignType::S32 => "vrsra.s32",
+                    NeonSignType::S64 => "vrsra.s64",
+                    NeonSignType::U8 => "vrsra.u8",
+                    NeonSignType::U16 => "vrsra.u16",
+                    NeonSignType::U32 => "vrsra.u32",
+                    NeonSignType::U64 => "vrsra.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsubhn { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vrsubhn.i8",
+                    NeonIntType::I16 => "vrsubhn.i16",
+                    NeonIntType::I32 => "vrsubhn.i32",
+                    NeonIntType::I64 => "vrsubhn.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshl { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vshl.s8",
+                    NeonSignType::S16 => "vshl.s16",
+                    NeonSignType::S32 => "vshl.s32",
+                    NeonSignType::S64 => "vshl.s64",
+                    NeonSignType::U8 => "vshl.u8",
+                    NeonSignType::U16 => "vshl.u16",
+                    NeonSignType::U32 => "vshl.u32",
+                    NeonSignType::U64 => "vshl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshlImm { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonIntType::I8 => "vshl.i8",
+                    NeonIntType::I16 => "vshl.i16",
+                    NeonIntType::I32 => "vshl.i32",
+                    NeonIntType::I64 => "vshl.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshll { cond, dt, vd, vm, imm } => {
+                if *imm == 0 {
+                    match dt {
+                        NeonSignType::S8 => "vmovl.s8",
+                        NeonSignType::S16 => "vmovl.s16",
+                        NeonSignType::S32 => "vmovl.s32",
+                        NeonSignType::S64 => "vmovl.s64",
+                        NeonSignType::U8 => "vmovl.u8",
+                        NeonSignType::U16 => "vmovl.u16",
+                        NeonSignType::U32 => "vmovl.u32",
+                        NeonSignType::U64 => "vmovl.u64",
+                    }
+                } else {
+                    match dt {
+                        NeonSignType::S8 => "vshll.s8",
+                        NeonSignType::S16 => "vshll.s16",
+                        NeonSignType::S32 => "vshll.s32",
+                        NeonSignType::S64 => "vshll.s64",
+                        NeonSignType::U8 => "vshll.u8",
+                        NeonSignType::U16 => "vshll.u16",
+                        NeonSignType::U32 => "vshll.u32",
+                        NeonSignType::U64 => "vshll.u64",
+                    }
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshllMax { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonIntType::I8 => "vshll.i8",
+                    NeonIntType::I16 => "vshll.i16",
+                    NeonIntType::I32 => "vshll.i32",
+                    NeonIntType::I64 => "vshll.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshr { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSignType::S8 => "vshr.s8",
+                    NeonSignType::S16 => "vshr.s16",
+                    NeonSignType::S32 => "vshr.s32",
+                    NeonSignType::S64 => "vshr.s64",
+                    NeonSignType::U8 => "vshr.u8",
+                    NeonSignType::U16 => "vshr.u16",
+                    NeonSignType::U32 => "vshr.u32",
+                    NeonSignType::U64 => "vshr.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshrn { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonIntType::I8 => "vshrn.i8",
+                    NeonIntType::I16 => "vshrn.i16",
+                    NeonIntType::I32 => "vshrn.i32",
+                    NeonIntType::I64 => "vshrn.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsli { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSize::Size8 => "vsli.8",
+                    NeonSize::Size16 => "vsli.16",
+                    NeonSize::Size32 => "vsli.32",
+                    NeonSize::Size64 => "vsli.64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsqrtF32 { cond, sd, sm } => {
+                if options.ual { "vsqrt.f32" } else { "fsqrts" }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsqrtF64 { cond, dd, dm } => {
+                if options.ual { "vsqrt.f64" } else { "fsqrtd" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsra { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSignType::S8 => "vsra.s8",
+                    NeonSignType::S16 => "vsra.s16",
+                    NeonSignType::S32 => "vsra.s32",
+                    NeonSignType::S64 => "vsra.s64",
+                    NeonSignType::U8 => "vsra.u8",
+                    NeonSignType::U16 => "vsra.u16",
+                    NeonSignType::U32 => "vsra.u32",
+                    NeonSignType::U64 => "vsra.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsri { cond, dt, vd, vm, imm } => {
+                match dt {
+                    NeonSize::Size8 => "vsri.8",
+                    NeonSize::Size16 => "vsri.16",
+                    NeonSize::Size32 => "vsri.32",
+                    NeonSize::Size64 => "vsri.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst1 { cond, size, regs, addr } => {
+                match size {
+                    NeonSize::Size8 => "vst1.8",
+                    NeonSize::Size16 => "vst1.16",
+                    NeonSize::Size32 => "vst1.32",
+                    NeonSize::Size64 => "vst1.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst2 { cond, size, regs, addr } => {
+                match size {
+                    NeonSize::Size8 => "vst2.8",
+                    NeonSize::Size16 => "vst2.16",
+                    NeonSize::Size32 => "vst2.32",
+                    NeonSize::Size64 => "vst2.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst3 { cond, size, regs, addr } => {
+                match size {
+                    NeonSize::Size8 => "vst3.8",
+                    NeonSize::Size16 => "vst3.16",
+                    NeonSize::Size32 => "vst3.32",
+                    NeonSize::Size64 => "vst3.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst4 { cond, size, regs, addr } => {
+                match size {
+                    NeonSize::Size8 => "vst4.8",
+                    NeonSize::Size16 => "vst4.16",
+                    NeonSize::Size32 => "vst4.32",
+                    NeonSize::Size64 => "vst4.64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstmF32 { mode, cond, rn, writeback, regs } => {
+                if options.ual {
+                    match mode {
+                        VldmVstmMode::Ia => "vstmia",
+                        VldmVstmMode::Db => "vstmdb",
+                    }
+                } else {
+                    match mode {
+                        VldmVstmMode::Ia => "fstmias",
+                        VldmVstmMode::Db => "fstmdbs",
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstmF64 { mode, cond, rn, writeback, regs } => {
+                if options.ual {
+                    match mode {
+                        VldmVstmMode::Ia => "vstmia",
+                        VldmVstmMode::Db => "vstmdb",
+                    }
+                } else {
+                    match mode {
+                        VldmVstmMode::Ia => "fstmiad",
+                        VldmVstmMode::Db => "fstmdbd",
+                    }
+                }
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstrF32 { cond, sd, addr } => if options.ual { "vstr" } else { "fsts" }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstrF64 { cond, dd, addr } => if options.ual { "vstr" } else { "fstd" }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsubF32 { cond, sd, sn, sm } => {
+                if options.ual { "vsub.f32" } else { "fsubs" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubF32Neon { cond, vd, vn, vm } => "vsub.f32",
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsubF64 { cond, dd, dn, dm } => {
+                if options.ual { "vsub.f64" } else { "fsubd" }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubI { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vsub.i8",
+                    NeonIntType::I16 => "vsub.i16",
+                    NeonIntType::I32 => "vsub.i32",
+                    NeonIntType::I64 => "vsub.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubhn { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonIntType::I8 => "vsubhn.i8",
+                    NeonIntType::I16 => "vsubhn.i16",
+                    NeonIntType::I32 => "vsubhn.i32",
+                    NeonIntType::I64 => "vsubhn.i64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubl { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vsubl.s8",
+                    NeonSignType::S16 => "vsubl.s16",
+                    NeonSignType::S32 => "vsubl.s32",
+                    NeonSignType::S64 => "vsubl.s64",
+                    NeonSignType::U8 => "vsubl.u8",
+                    NeonSignType::U16 => "vsubl.u16",
+                    NeonSignType::U32 => "vsubl.u32",
+                    NeonSignType::U64 => "vsubl.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubw { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSignType::S8 => "vsubw.s8",
+                    NeonSignType::S16 => "vsubw.s16",
+                    NeonSignType::S32 => "vsubw.s32",
+                    NeonSignType::S64 => "vsubw.s64",
+                    NeonSignType::U8 => "vsubw.u8",
+                    NeonSignType::U16 => "vsubw.u16",
+                    NeonSignType::U32 => "vsubw.u32",
+                    NeonSignType::U64 => "vsubw.u64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vswp { cond, vd, vm } => "vswp",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbl { cond, dd, regs, dm } => "vtbl.8",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbx { cond, dd, regs, dm } => "vtbx.8",
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtrn { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSize::Size8 => "vtrn.8",
+                    NeonSize::Size16 => "vtrn.16",
+                    NeonSize::Size32 => "vtrn.32",
+                    NeonSize::Size64 => "vtrn.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtst { cond, dt, vd, vn, vm } => {
+                match dt {
+                    NeonSize::Size8 => "vtst.8",
+                    NeonSize::Size16 => "vtst.16",
+                    NeonSize::Size32 => "vtst.32",
+                    NeonSize::Size64 => "vtst.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vuzp { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSize::Size8 => "vuzp.8",
+                    NeonSize::Size16 => "vuzp.16",
+                    NeonSize::Size32 => "vuzp.32",
+                    NeonSize::Size64 => "vuzp.64",
+                }
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vzip { cond, dt, vd, vm } => {
+                match dt {
+                    NeonSize::Size8 => "vzip.8",
+                    NeonSize::Size16 => "vzip.16",
+                    NeonSize::Size32 => "vzip.32",
+                    NeonSize::Size64 => "vzip.64",
+                }
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Wfe { cond } => "wfe",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Wfi { cond } => "wfi",
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Yield { cond } => "yield",
+            Ins::Word(_) => ".word",
+            Ins::HalfWord(_) => ".hword",
+            Ins::Byte(_) => ".byte",
+            Ins::Illegal => "<illegal>",
+        }
+    }
+    /// Returns the operands of this instruction in the order of the UAL syntax. An
+    /// operand which is left out in some syntaxes, like the first source register of
+    /// a narrow Thumb instruction, is always included.
+    pub fn operands(&self) -> Operands {
+        let mut operands = Operands::new();
+        match self {
+            Ins::Adc { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            Ins::Add { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            Ins::And { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            Ins::Asr { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2Shift(*op2));
+            }
+            Ins::B { cond, target } => {
+                operands.push(Operand::BranchTarget(*target));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Bfc { cond, rd, lsb, width } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Uimm(*lsb));
+                operands.push(Operand::Uimm(*width));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Bfi { cond, rd, rn, lsb, width } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Uimm(*lsb));
+                operands.push(Operand::Uimm(*width));
+            }
+            Ins::Bic { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            #[cfg(
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Bkpt { imm } => {
+                operands.push(Operand::Uimm(*imm));
+            }
+            Ins::Bl { cond, target } => {
+                operands.push(Operand::BranchTarget(*target));
+            }
+            #[cfg(
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Blx { cond, target } => {
+                operands.push(Operand::BlxTarget(*target));
+            }
+            #[cfg(
+                any(
+                    feature = "v4t",
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Bx { cond, rm } => {
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Bxj { cond, rm } => {
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbnz { rn, target } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::BranchTarget(*target));
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Cbz { rn, target } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::BranchTarget(*target));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Cdp { cond, coproc, opc1, crd, crn, crm, opc2 } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::Uimm(*opc1));
+                operands.push(Operand::CoReg(*crd));
+                operands.push(Operand::CoReg(*crn));
+                operands.push(Operand::CoReg(*crm));
+                operands.push(Operand::Uimm(*opc2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Cdp2 { coproc, opc1, crd, crn, crm, opc2 } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::Uimm(*opc1));
+                operands.push(Operand::CoReg(*crd));
+                operands.push(Operand::CoReg(*crn));
+                operands.push(Operand::CoReg(*crm));
+                operands.push(Operand::Uimm(*opc2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Clz { cond, rd, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+            }
+            Ins::Cmn { cond, rn, op2 } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            Ins::Cmp { cond, rn, op2 } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Cps { effect, aif, mode } => {
+                operands.push(Operand::Uimm(*mode));
+                operands.push(Operand::AifFlags(*aif));
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Dbg { cond, option } => {
+                operands.push(Operand::Uimm(*option));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Dmb { cond, option } => {
+                operands.push(Operand::BarrierOption(*option));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Dsb { cond, option } => {
+                operands.push(Operand::BarrierOption(*option));
+            }
+            Ins::Eor { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Isb { cond, option } => {
+                operands.push(Operand::BarrierOption(*option));
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::It { firstcond, mask } => {
+                operands.push(Operand::Cond(*firstcond));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldc { l, cond, coproc, crd, dest } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::CoReg(*crd));
+                operands.push(Operand::AddrLdcStc(*dest));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldc2 { l, coproc, crd, dest } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::CoReg(*crd));
+                operands.push(Operand::AddrLdcStc(*dest));
+            }
+            Ins::Ldm { mode, cond, rn, writeback, regs, user_mode } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Wb(*writeback));
+                operands.push(Operand::RegList(*regs));
+                operands.push(Operand::UserMode(*user_mode));
+            }
+            Ins::Ldr { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            Ins::Ldrb { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldrbt { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrd { cond, rd, rd2, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rd2));
+                operands.push(Operand::AddrMiscLoad(*addr));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrex { cond, rd, rn, offset } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Uimm(*offset));
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexb { cond, rd, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexd { cond, rd, rd2, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rd2));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ldrexh { cond, rd, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+            }
+            Ins::Ldrh { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrMiscLoad(*addr));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Ldrht { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrMiscLoad(*addr));
+            }
+            Ins::Ldrsb { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrMiscLoad(*addr));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Ldrsbt { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrMiscLoad(*addr));
+            }
+            Ins::Ldrsh { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrMiscLoad(*addr));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Ldrsht { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrMiscLoad(*addr));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Ldrt { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            Ins::Lsl { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2Shift(*op2));
+            }
+            Ins::Lsr { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2Shift(*op2));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mcr { cond, coproc, opc1, rd, crn, crm, opc2 } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::Uimm(*opc1));
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::CoReg(*crn));
+                operands.push(Operand::CoReg(*crm));
+                operands.push(Operand::Uimm(*opc2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mcr2 { coproc, opc1, rd, crn, crm, opc2 } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::Uimm(*opc1));
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::CoReg(*crn));
+                operands.push(Operand::CoReg(*crm));
+                operands.push(Operand::Uimm(*opc2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mcrr { cond, coproc, opc, rd, rd2, crm } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::Uimm(*opc));
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rd2));
+                operands.push(Operand::CoReg(*crm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mcrr2 { coproc, opc, rd, rd2, crm } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::Uimm(*opc));
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rd2));
+                operands.push(Operand::CoReg(*crm));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mla { s, cond, rd, rn, rm, ra } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*ra));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Mls { cond, rd, rn, rm, ra } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*ra));
+            }
+            Ins::Mov { s, thumb, cond, rd, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Op2(*op2));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Movt { cond, rd, imm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Movw { cond, rd, imm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mrc { cond, coproc, opc1, rd, crn, crm, opc2 } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::Uimm(*opc1));
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::CoReg(*crn));
+                operands.push(Operand::CoReg(*crm));
+                operands.push(Operand::Uimm(*opc2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mrc2 { coproc, opc1, rd, crn, crm, opc2 } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::Uimm(*opc1));
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::CoReg(*crn));
+                operands.push(Operand::CoReg(*crm));
+                operands.push(Operand::Uimm(*opc2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mrrc { cond, coproc, opc, rd, rd2, crm } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::Uimm(*opc));
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rd2));
+                operands.push(Operand::CoReg(*crm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Mrrc2 { coproc, opc, rd, rd2, crm } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::Uimm(*opc));
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rd2));
+                operands.push(Operand::CoReg(*crm));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Mrs { cond, rd, status_reg } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::StatusReg(*status_reg));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Msr { cond, status_fields, op2 } => {
+                operands.push(Operand::StatusFields(*status_fields));
+                operands.push(Operand::MsrOp2(*op2));
+            }
+            Ins::Mul { s, thumb, cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            Ins::Mvn { s, thumb, cond, rd, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Op2(*op2));
+            }
+            #[cfg(feature = "thumb")]
+            Ins::Neg { rd, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Orn { s, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            Ins::Orr { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Pkhbt { cond, rd, rn, rm, shift_op, shift } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::ShiftOp(*shift_op));
+                operands.push(Operand::Uimm(*shift));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Pkhtb { cond, rd, rn, rm, shift_op, shift } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::ShiftOp(*shift_op));
+                operands.push(Operand::Uimm(*shift));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Pld { addr } => {
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Pli { addr } => {
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            Ins::Pop { cond, regs } => {
+                operands.push(Operand::RegList(*regs));
+            }
+            Ins::Push { cond, regs } => {
+                operands.push(Operand::RegList(*regs));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qadd { cond, rd, rm, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qadd16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qadd8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qasx { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qdadd { cond, rd, rm, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qdsub { cond, rd, rm, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsax { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsub { cond, rd, rm, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsub16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Qsub8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Rbit { cond, rd, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Rev { cond, rd, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Rev16 { cond, rd, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Revsh { cond, rd, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Rfe { addr_mode, rn, writeback } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Wb(*writeback));
+            }
+            Ins::Ror { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2Shift(*op2));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Rrx { s, cond, rd, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+            }
+            Ins::Rsb { s, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            #[cfg(feature = "arm")]
+            Ins::Rsc { s, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sadd16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sadd8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sasx { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            Ins::Sbc { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Sbfx { cond, rd, rn, lsb, width } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Uimm(*lsb));
+                operands.push(Operand::Uimm(*width));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Sdiv { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sel { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Setend { endian } => {
+                operands.push(Operand::Endianness(*endian));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shadd16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shadd8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shasx { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shsax { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shsub16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Shsub8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smla { cond, rd, rn, rn_side, rm, rm_side, ra } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*ra));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlad { cond, rd, rn, rm, swap_rm, ra } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*ra));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Smlal { s, cond, rd_lo, rd_hi, rn, rm } => {
+                operands.push(Operand::Reg(*rd_lo));
+                operands.push(Operand::Reg(*rd_hi));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::SmlalHalf { cond, rd_lo, rd_hi, rn, rn_side, rm, rm_side } => {
+                operands.push(Operand::Reg(*rd_lo));
+                operands.push(Operand::Reg(*rd_hi));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlald { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
+                operands.push(Operand::Reg(*rd_lo));
+                operands.push(Operand::Reg(*rd_hi));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlaw { cond, rd, rn, rm, rm_side, ra } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*ra));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlsd { cond, rd, rn, rm, swap_rm, ra } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*ra));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smlsld { cond, rd_lo, rd_hi, rn, rm, swap_rm } => {
+                operands.push(Operand::Reg(*rd_lo));
+                operands.push(Operand::Reg(*rd_hi));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smmla { round, cond, rd, rn, rm, ra } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*ra));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smmls { round, cond, rd, rn, rm, ra } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*ra));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smmul { round, cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smuad { cond, rd, rn, rm, swap_rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smul { cond, rd, rn, rn_side, rm, rm_side } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Smull { s, cond, rd_lo, rd_hi, rn, rm } => {
+                operands.push(Operand::Reg(*rd_lo));
+                operands.push(Operand::Reg(*rd_hi));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smulw { cond, rd, rn, rm, rm_side } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Smusd { cond, rd, rn, rm, swap_rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Srs { addr_mode, rn, writeback, mode } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Wb(*writeback));
+                operands.push(Operand::Uimm(*mode));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssat { cond, rd, imm, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Uimm(*imm));
+                operands.push(Operand::ShiftImm(*op2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssat16 { cond, rd, imm, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Uimm(*imm));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssax { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssub16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Ssub8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Stc { l, cond, coproc, crd, dest } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::CoReg(*crd));
+                operands.push(Operand::AddrLdcStc(*dest));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5t",
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Stc2 { l, coproc, crd, dest } => {
+                operands.push(Operand::Coproc(*coproc));
+                operands.push(Operand::CoReg(*crd));
+                operands.push(Operand::AddrLdcStc(*dest));
+            }
+            Ins::Stm { mode, cond, rn, writeback, regs, user_mode } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Wb(*writeback));
+                operands.push(Operand::RegList(*regs));
+                operands.push(Operand::UserMode(*user_mode));
+            }
+            Ins::Str { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            Ins::Strb { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Strbt { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strd { cond, rd, rd2, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rd2));
+                operands.push(Operand::AddrMiscLoad(*addr));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strex { cond, rd, rm, rn, offset } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Uimm(*offset));
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexb { cond, rd, rm, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexd { cond, rd, rm, rm2, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*rm2));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(feature = "arm", any(feature = "v6k", feature = "v7")),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Strexh { cond, rd, rm, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*rn));
+            }
+            Ins::Strh { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrMiscLoad(*addr));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Strht { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrMiscLoad(*addr));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Strt { cond, rd, addr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            Ins::Sub { s, thumb, cond, rd, rn, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            Ins::Svc { cond, imm } => {
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(feature = "arm")]
+            Ins::Swp { cond, rd, rd2, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rd2));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(feature = "arm")]
+            Ins::Swpb { cond, rd, rd2, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rd2));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtab { cond, rd, rn, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtab16 { cond, rd, rn, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtah { cond, rd, rn, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Sxtb { cond, rd, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Sxtb16 { cond, rd, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Sxth { cond, rd, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbb { cond, rn, rm } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            Ins::Tbh { cond, rn, rm } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Teq { cond, rn, op2 } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            Ins::Tst { cond, rn, op2 } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Op2(*op2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uadd16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uadd8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uasx { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Ubfx { cond, rd, rn, lsb, width } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Uimm(*lsb));
+                operands.push(Operand::Uimm(*width));
+            }
+            #[cfg(
+                any(
+                    feature = "v4t",
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            )]
+            Ins::Udf { imm } => {
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(feature = "v7")]
+            Ins::Udiv { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhadd16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhadd8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhasx { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhsax { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhsub16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uhsub8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Umaal { cond, rd_lo, rd_hi, rn, rm } => {
+                operands.push(Operand::Reg(*rd_lo));
+                operands.push(Operand::Reg(*rd_hi));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Umlal { s, cond, rd_lo, rd_hi, rn, rm } => {
+                operands.push(Operand::Reg(*rd_lo));
+                operands.push(Operand::Reg(*rd_hi));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
+            Ins::Umull { s, cond, rd_lo, rd_hi, rn, rm } => {
+                operands.push(Operand::Reg(*rd_lo));
+                operands.push(Operand::Reg(*rd_hi));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqadd16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqadd8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqasx { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqsax { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqsub16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uqsub8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usad8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usada8 { cond, rd, rn, rm, ra } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Reg(*ra));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usat { cond, rd, imm, op2 } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Uimm(*imm));
+                operands.push(Operand::ShiftImm(*op2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usat16 { cond, rd, imm, rn } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Uimm(*imm));
+                operands.push(Operand::Reg(*rn));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usax { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usub16 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Usub8 { cond, rd, rn, rm } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtab { cond, rd, rn, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtab16 { cond, rd, rn, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtah { cond, rd, rn, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Uxtb { cond, rd, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(feature = "v6", feature = "v6k", feature = "v7")
+                    ),
+                    all(feature = "thumb", feature = "v7")
+                )
+            )]
+            Ins::Uxtb16 { cond, rd, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
+            Ins::Uxth { cond, rd, rm, rotate } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Reg(*rm));
+                operands.push(Operand::Uimm(*rotate));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaba { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabal { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabd { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabdF32 { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabdl { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vabs { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VabsF32 { cond, sd, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VabsF32Neon { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VabsF64 { cond, dd, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacge { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vacgt { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VaddF32 { cond, sd, sn, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sn));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddF32Neon { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VaddF64 { cond, dd, dn, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dn));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VaddI { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddhn { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddl { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vaddw { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vand { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbic { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VbicIImm { cond, dt, vd, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbif { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbit { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vbsl { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vceq { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqF32 { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZero { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VceqZeroF32 { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcge { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeF32 { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZero { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgeZeroF32 { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcgt { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtF32 { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZero { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcgtZeroF32 { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZero { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcleZeroF32 { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcls { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZero { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcltZeroF32 { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vclz { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcmpF32 { nan_exc, cond, sd, op2 } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::VcmpF32Op2(*op2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcmpF64 { nan_exc, cond, dd, op2 } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::VcmpF64Op2(*op2));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vcnt { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF16F32 { half, cond, sd, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32F16 { half, cond, sd, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF32F64 { cond, sd, dm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF32Fixed { cond, fixed, sd, fbits } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Uimm(*fbits));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF32S32 { cond, sd, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF32U32 { cond, sd, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF64F32 { cond, dd, sm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtF64Fixed { cond, fixed, dd, fbits } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Uimm(*fbits));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF64S32 { cond, dd, sm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtF64U32 { cond, dd, sm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF32 { cond, fixed, sd, fbits } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Uimm(*fbits));
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VcvtFixedF64 { cond, fixed, dd, fbits } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Uimm(*fbits));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtFixedNeon { cond, dt, vd, vm, fbits } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*fbits));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VcvtNeon { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtS32F32 { round_zero, cond, sd, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtS32F64 { round_zero, cond, sd, dm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtU32F32 { round_zero, cond, sd, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VcvtU32F64 { round_zero, cond, sd, dm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VdivF32 { cond, sd, sn, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sn));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VdivF64 { cond, dd, dn, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dn));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupReg { cond, size, vd, rt } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Reg(*rt));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VdupScalar { cond, size, vd, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Veor { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vext { cond, vd, vn, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhadd { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vhsub { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld1 { cond, size, regs, addr } => {
+                operands.push(Operand::NeonRegList(*regs));
+                operands.push(Operand::NeonAddr(*addr));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld2 { cond, size, regs, addr } => {
+                operands.push(Operand::NeonRegList(*regs));
+                operands.push(Operand::NeonAddr(*addr));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld3 { cond, size, regs, addr } => {
+                operands.push(Operand::NeonRegList(*regs));
+                operands.push(Operand::NeonAddr(*addr));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vld4 { cond, size, regs, addr } => {
+                operands.push(Operand::NeonRegList(*regs));
+                operands.push(Operand::NeonAddr(*addr));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldmF32 { mode, cond, rn, writeback, regs } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Wb(*writeback));
+                operands.push(Operand::SregList(*regs));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldmF64 { mode, cond, rn, writeback, regs } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Wb(*writeback));
+                operands.push(Operand::DregList(*regs));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldrF32 { cond, sd, addr } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VldrF64 { cond, dd, addr } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmax { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmaxF32 { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmin { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VminF32 { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlaF32 { cond, sd, sn, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sn));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Neon { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaF32Scalar { cond, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlaF64 { cond, dd, dn, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dn));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaI { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlaIScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlal { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlalScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlsF32 { cond, sd, sn, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sn));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Neon { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsF32Scalar { cond, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmlsF64 { cond, dd, dn, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dn));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsI { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlsIScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmlsl { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmlslScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::Vmov32Reg { cond, dd, rt } => {
+                operands.push(Operand::DregIndex(*dd));
+                operands.push(Operand::Reg(*rt));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF32 { cond, sd, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF32Imm { cond, sd, imm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::VfpImm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovF32ImmNeon { cond, vd, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::VfpImm(*imm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF32Reg { cond, sn, rt } => {
+                operands.push(Operand::Sreg(*sn));
+                operands.push(Operand::Reg(*rt));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF64 { cond, dd, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+            Ins::VmovF64Imm { cond, dd, imm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::VfpImm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovI64Imm { cond, vd, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonImm64(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovIImm { cond, dt, vd, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovReg32 { cond, rt, dn } => {
+                operands.push(Operand::Reg(*rt));
+                operands.push(Operand::DregIndex(*dn));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovRegScalar { cond, dt, rt, dn, index } => {
+                operands.push(Operand::Reg(*rt));
+                operands.push(Operand::Dreg(*dn));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovRegF32 { cond, rt, sn } => {
+                operands.push(Operand::Reg(*rt));
+                operands.push(Operand::Sreg(*sn));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovRegF32Dual { cond, rt, rt2, sm, sm2 } => {
+                operands.push(Operand::Reg(*rt));
+                operands.push(Operand::Reg(*rt2));
+                operands.push(Operand::Sreg(*sm));
+                operands.push(Operand::Sreg(*sm2));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmovScalarReg { cond, size, dd, index, rt } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Uimm(*index));
+                operands.push(Operand::Reg(*rt));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF32RegDual { cond, sm, sm2, rt, rt2 } => {
+                operands.push(Operand::Sreg(*sm));
+                operands.push(Operand::Sreg(*sm2));
+                operands.push(Operand::Reg(*rt));
+                operands.push(Operand::Reg(*rt2));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovRegF64 { cond, rt, rt2, dm } => {
+                operands.push(Operand::Reg(*rt));
+                operands.push(Operand::Reg(*rt2));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmovF64Reg { cond, dm, rt, rt2 } => {
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Reg(*rt));
+                operands.push(Operand::Reg(*rt2));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmovn { cond, dt, vd, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::Vmrs { cond, rd, fpscr } => {
+                operands.push(Operand::Reg(*rd));
+                operands.push(Operand::Fpscr(*fpscr));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::Vmsr { cond, fpscr, rd } => {
+                operands.push(Operand::Fpscr(*fpscr));
+                operands.push(Operand::Reg(*rd));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmulF32 { cond, sd, sn, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sn));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Neon { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulF32Scalar { cond, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VmulF64 { cond, dd, dn, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dn));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulI { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulIScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmulP8 { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmull { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullP8 { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmullScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vmvn { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VmvnIImm { cond, dt, vd, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vneg { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnegF32 { cond, sd, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VnegF32Neon { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnegF64 { cond, dd, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlaF32 { cond, sd, sn, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sn));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlaF64 { cond, dd, dn, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dn));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlsF32 { cond, sd, sn, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sn));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmlsF64 { cond, dd, dn, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dn));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmulF32 { cond, sd, sn, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sn));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VnmulF64 { cond, dd, dn, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dn));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorn { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vorr { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::NeonReg(*vn));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VorrIImm { cond, dt, vd, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpadal { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddF32 { cond, vd, vn, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpaddI { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpaddl { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmax { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpmaxF32 { cond, vd, vn, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vpmin { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VpminF32 { cond, vd, vn, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpopF32 { cond, regs } => {
+                operands.push(Operand::SregList(*regs));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpopF64 { cond, regs } => {
+                operands.push(Operand::DregList(*regs));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpushF32 { cond, regs } => {
+                operands.push(Operand::SregList(*regs));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VpushF64 { cond, regs } => {
+                operands.push(Operand::DregList(*regs));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqabs { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqadd { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlal { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlalScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmlsl { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmlslScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmulh { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqdmull { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqdmullScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovn { cond, dt, vd, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqmovun { cond, dt, vd, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqneg { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrdmulh { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqrdmulhScalar { cond, dt, vd, vn, dm, index } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::Dreg(*dm));
+                operands.push(Operand::Uimm(*index));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshl { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::NeonReg(*vn));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrn { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqrshrun { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshl { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::NeonReg(*vn));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VqshlImm { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshlu { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrn { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqshrun { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vqsub { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vraddhn { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecpe { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrecpeF32 { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrecps { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev16 { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev32 { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrev64 { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrhadd { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshl { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::NeonReg(*vn));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshr { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrshrn { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrte { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VrsqrteF32 { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsqrts { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsra { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vrsubhn { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshl { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::NeonReg(*vn));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshlImm { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshll { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VshllMax { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshr { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vshrn { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsli { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsqrtF32 { cond, sd, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsqrtF64 { cond, dd, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsra { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsri { cond, dt, vd, vm, imm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+                operands.push(Operand::Uimm(*imm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst1 { cond, size, regs, addr } => {
+                operands.push(Operand::NeonRegList(*regs));
+                operands.push(Operand::NeonAddr(*addr));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst2 { cond, size, regs, addr } => {
+                operands.push(Operand::NeonRegList(*regs));
+                operands.push(Operand::NeonAddr(*addr));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst3 { cond, size, regs, addr } => {
+                operands.push(Operand::NeonRegList(*regs));
+                operands.push(Operand::NeonAddr(*addr));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vst4 { cond, size, regs, addr } => {
+                operands.push(Operand::NeonRegList(*regs));
+                operands.push(Operand::NeonAddr(*addr));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstmF32 { mode, cond, rn, writeback, regs } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Wb(*writeback));
+                operands.push(Operand::SregList(*regs));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstmF64 { mode, cond, rn, writeback, regs } => {
+                operands.push(Operand::Reg(*rn));
+                operands.push(Operand::Wb(*writeback));
+                operands.push(Operand::DregList(*regs));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstrF32 { cond, sd, addr } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VstrF64 { cond, dd, addr } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::AddrLdrStr(*addr));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsubF32 { cond, sd, sn, sm } => {
+                operands.push(Operand::Sreg(*sd));
+                operands.push(Operand::Sreg(*sn));
+                operands.push(Operand::Sreg(*sm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubF32Neon { cond, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(
+                any(
+                    all(
+                        feature = "arm",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        any(
+                            feature = "v5te",
+                            feature = "v5tej",
+                            feature = "v6",
+                            feature = "v6k",
+                            feature = "v7"
+                        )
+                    ),
+                    all(
+                        feature = "thumb",
+                        any(
+                            feature = "vfp_v2",
+                            feature = "vfp_v3",
+                            feature = "vfp_v3_d16"
+                        ),
+                        feature = "v7"
+                    )
+                )
+            )]
+            Ins::VsubF64 { cond, dd, dn, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::Dreg(*dn));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::VsubI { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubhn { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::Dreg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubl { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::Dreg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vsubw { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::Dreg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vswp { cond, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbl { cond, dd, regs, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::NeonRegList(*regs));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtbx { cond, dd, regs, dm } => {
+                operands.push(Operand::Dreg(*dd));
+                operands.push(Operand::NeonRegList(*regs));
+                operands.push(Operand::Dreg(*dm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtrn { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vtst { cond, dt, vd, vn, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vn));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vuzp { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            Ins::Vzip { cond, dt, vd, vm } => {
+                operands.push(Operand::NeonReg(*vd));
+                operands.push(Operand::NeonReg(*vm));
+            }
+            Ins::Word(value) => operands.push(Operand::Uimm(*value)),
+            Ins::HalfWord(value) => operands.push(Operand::Uimm(*value as u32)),
+            Ins::Byte(value) => operands.push(Operand::Uimm(*value as u32)),
+            _ => {}
+        }
+        operands
+    }
+}
@@ -49,20 +49,22 @@ pub enum Version {
     V6,
     #[cfg(feature = "v6k")]
     V6K,
+    #[cfg(feature = "v7")]
+    V7,
 }
 impl Version {
-    pub const fn bit(self) -> u8 {
-        1 << self as u8
+    pub const fn bit(self) -> u16 {
+        1 << self as u16
     }
 }
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct Versions(u8);
+pub struct Versions(u16);
 impl Versions {
     pub const fn none() -> Self {
         Self(0)
     }
     pub const fn all() -> Self {
-        Self(u8::MAX)
+        Self(u16::MAX)
     }
     pub fn with(self, version: Version) -> Self {
         Self(self.0 | version.bit())
@@ -86,6 +88,7 @@ impl Versions {
 impl Default for Version {
     #[allow(unreachable_code)]
     fn default() -> Self {
+        #[cfg(feature = "v7")] return Self::V7;
         #[cfg(feature = "v6k")] return Self::V6K;
         #[cfg(feature = "v6")] return Self::V6;
         #[cfg(feature = "v5tej")] return Self::V5Tej;
@@ -99,6 +102,12 @@ impl Default for Version {
 pub enum Extension {
     #[cfg(feature = "vfp_v2")]
     VfpV2,
+    #[cfg(feature = "vfp_v3")]
+    VfpV3,
+    #[cfg(feature = "vfp_v3_d16")]
+    VfpV3D16,
+    #[cfg(feature = "neon")]
+    Neon,
 }
 impl Extension {
     pub const fn bit(self) -> u8 {
@@ -132,6 +141,9 @@ impl Extensions {
     pub fn has_all(self, extensions: Extensions) -> bool {
         (self.0 & extensions.0) == self.0
     }
+    pub const fn has_any(self, extensions: Extensions) -> bool {
+        (self.0 & extensions.0) != 0
+    }
 }
 impl Default for Extensions {
     fn default() -> Self {
@@ -150,6 +162,7 @@ pub enum R9Use {
 }
 ///The direct destination address of a branch instruction
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BranchTarget {
     pub addr: u32,
 }
@@ -159,11 +172,13 @@ pub struct BranchTarget {
         feature = "v5te",
         feature = "v5tej",
         feature = "v6",
-        feature = "v6k"
+        feature = "v6k",
+        feature = "v7"
     )
 )]
 ///The destination of a BLX instruction, which can be direct (immediate) or indirect (register)
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BlxTarget {
     ///Direct target
     Direct(BranchTarget),
@@ -173,6 +188,7 @@ pub enum BlxTarget {
 ///Mnemonic suffix, specifies the condition for whether to execute the instruction
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Cond {
     ///Equal
     Eq,
@@ -208,6 +224,7 @@ pub enum Cond {
 ///General-purpose register
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Reg {
     R0,
     R1,
@@ -233,19 +250,21 @@ pub enum Reg {
     ///Program counter
     Pc,
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 ///Status register
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StatusReg {
     ///Current program status register
     Cpsr,
     ///Saved program status register
     Spsr,
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 ///Status register with field masks
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StatusFields {
     pub reg: StatusReg,
     ///Control field mask
@@ -257,9 +276,10 @@ pub struct StatusFields {
     ///Flags field mask
     pub f: bool,
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 ///Second operand of the MSR instruction, can be an immediate or a register
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MsrOp2 {
     ///Immediate
     Imm(u32),
@@ -269,6 +289,7 @@ pub enum MsrOp2 {
 ///Shift operation
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShiftOp {
     ///Logical shift left
     Lsl,
@@ -279,10 +300,11 @@ pub enum ShiftOp {
     ///Rotate right
     Ror,
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 ///Coprocessor
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Coproc {
     P0,
     P1,
@@ -301,10 +323,11 @@ pub enum Coproc {
     P14,
     P15,
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 ///Coprocessor register
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CoReg {
     C0,
     C1,
@@ -325,6 +348,7 @@ pub enum CoReg {
 }
 ///Second operand of a data-processing operation, can be an immediate, an immediate-shifted register or a register-shifted register.
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Op2 {
     ///Immediate
     Imm(u32),
@@ -335,6 +359,7 @@ pub enum Op2 {
 }
 ///Register shifted by another register
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShiftReg {
     ///Register being shifted
     pub rm: Reg,
@@ -345,6 +370,7 @@ pub struct ShiftReg {
 }
 ///Register shifted by an immediate
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShiftImm {
     ///Register being shifted
     pub rm: Reg,
@@ -355,15 +381,17 @@ pub struct ShiftImm {
 }
 ///Second operand of a shift instruction, can be an immediate or a register
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Op2Shift {
     ///Immediate
     Imm(u32),
     ///Register
     Reg(Reg),
 }
-#[cfg(any(feature = "v6", feature = "v6k"))]
+#[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
 ///Mnemonic suffix for CPS, specifies whether to enable/disable interrupt bits or just set the processor mode
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CpsEffect {
     ///Set mode
     SetMode,
@@ -372,9 +400,10 @@ pub enum CpsEffect {
     ///Interrupt disable
     Id,
 }
-#[cfg(any(feature = "v6", feature = "v6k"))]
+#[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
 ///In a CPS instruction, specifies which interrupt bits to enable or disable
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AifFlags {
     ///Imprecise data abort bit
     pub a: bool,
@@ -383,9 +412,10 @@ pub struct AifFlags {
     ///FIQ interrupt bit
     pub f: bool,
 }
-#[cfg(feature = "arm")]
+#[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
 ///The memory address of an LDC/STC instruction
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AddrLdcStc {
     ///Pre-indexed
     Pre {
@@ -412,6 +442,7 @@ pub enum AddrLdcStc {
 ///Mnemonic suffix for LDM/STM, specifies how to step the base address
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LdmStmMode {
     ///Decrement After
     Da,
@@ -422,8 +453,9 @@ pub enum LdmStmMode {
     ///Increment Before
     Ib,
 }
-///The memory address of an LDR(B)/STR(B)/PLD instruction
+///The memory address of an LDR(B)(T)/STR(B)(T)/PLD instruction
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AddrLdrStr {
     ///Pre-indexed
     Pre {
@@ -438,6 +470,7 @@ pub enum AddrLdrStr {
 }
 ///A post-indexed memory address for LDR(B)(T)/STR(B)(T)
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddrLdrStrPost {
     ///Base register
     pub rn: Reg,
@@ -445,6 +478,7 @@ pub struct AddrLdrStrPost {
 }
 ///The offset value in the memory address of a LDR(B)/STR(B) instruction, can be an immediate or a register
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LdrStrOffset {
     ///Immediate offset
     Imm(i32),
@@ -462,6 +496,7 @@ pub enum LdrStrOffset {
 }
 ///The memory address of a miscellaneous load/store instruction
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AddrMiscLoad {
     ///Pre-indexed
     Pre {
@@ -480,6 +515,7 @@ pub enum AddrMiscLoad {
 }
 ///The offset value in the memory address of a miscellaneous load/store instruction, can be an immediate or a register
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MiscLoadOffset {
     ///Immediate offset
     Imm(i32),
@@ -489,12 +525,20 @@ pub enum MiscLoadOffset {
         subtract: bool,
         ///Index register
         rm: Reg,
+        ///Immediate to shift Rm left by, only used in Thumb
+        imm: u32,
     },
 }
-#[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+#[cfg(
+    any(
+        all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+        all(feature = "thumb", feature = "v7")
+    )
+)]
 ///Mnemonic suffix for SRS/RFE, specifies how to step the stack pointer
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SrsRfeMode {
     ///Decrement After
     Da,
@@ -505,10 +549,11 @@ pub enum SrsRfeMode {
     ///Increment Before
     Ib,
 }
-#[cfg(any(feature = "v6", feature = "v6k"))]
+#[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
 ///Used by SETEND, specifies the endianness for data accesses
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Endianness {
     ///Little-endian
     Le,
@@ -516,14 +561,24 @@ pub enum Endianness {
     Be,
 }
 #[cfg(
-    all(
-        feature = "arm",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(feature = "thumb", feature = "v7")
     )
 )]
 ///Mnemonic suffix, specifies which half of a register to use as an operand
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RegSide {
     ///Bottom halfword
     Bottom,
@@ -531,15 +586,29 @@ pub enum RegSide {
     Top,
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 ///General-purpose register for single-precision floating-point numbers
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Sreg {
     S0,
     S1,
@@ -575,15 +644,39 @@ pub enum Sreg {
     S31,
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(
+                feature = "neon",
+                feature = "vfp_v2",
+                feature = "vfp_v3",
+                feature = "vfp_v3_d16"
+            ),
+            any(
+                feature = "v7",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(
+                feature = "neon",
+                feature = "vfp_v2",
+                feature = "vfp_v3",
+                feature = "vfp_v3_d16"
+            ),
+            feature = "v7"
+        )
     )
 )]
 ///General-purpose register for double-precision floating-point numbers
 #[repr(u8)]
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Dreg {
     D0,
     D1,
@@ -619,14 +712,28 @@ pub enum Dreg {
     D31,
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 ///Second operand of a VCMP.F32 instruction, can be zero or a register
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VcmpF32Op2 {
     ///Compare with zero
     Zero,
@@ -634,14 +741,28 @@ pub enum VcmpF32Op2 {
     Reg(Sreg),
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 ///Second operand of a VCMP.F64 instruction, can be zero or a register
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VcmpF64Op2 {
     ///Compare with zero
     Zero,
@@ -649,45 +770,284 @@ pub enum VcmpF64Op2 {
     Reg(Dreg),
 }
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 ///A double-precision floating-point register and index (0 or 1) to move to/from
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DregIndex {
     pub dreg: Dreg,
     pub index: u32,
 }
+#[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+///Fixed-point data type of a VCVT instruction
+#[repr(u8)]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FixedType {
+    ///Signed 16-bit
+    S16,
+    ///Signed 32-bit
+    S32,
+    ///Unsigned 16-bit
+    U16,
+    ///Unsigned 32-bit
+    U32,
+}
+#[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+///Mnemonic suffix for VCVTB/VCVTT, specifies which half of the single-precision register holds the half-precision value
+#[repr(u8)]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Half {
+    ///Bottom half
+    B,
+    ///Top half
+    T,
+}
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 ///Floating-Point Status and Control Register
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fpscr {}
 #[cfg(
-    all(
-        feature = "arm",
-        feature = "vfp_v2",
-        any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(
+        all(
+            feature = "arm",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(
+            feature = "thumb",
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+            feature = "v7"
+        )
     )
 )]
 ///Mnemonic suffix for VLDM/VSTM, specifies how to step the base address
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VldmVstmMode {
     ///Increment After
     Ia,
     ///Decrement Before
     Db,
 }
+#[cfg(feature = "v7")]
+///Limits which memory accesses a DMB/DSB/ISB instruction applies to
+#[repr(u8)]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BarrierOption {
+    Reserved0,
+    Reserved1,
+    ///Outer shareable, stores only
+    Oshst,
+    ///Outer shareable
+    Osh,
+    Reserved4,
+    Reserved5,
+    ///Non-shareable, stores only
+    Nshst,
+    ///Non-shareable
+    Nsh,
+    Reserved8,
+    Reserved9,
+    ///Inner shareable, stores only
+    Ishst,
+    ///Inner shareable
+    Ish,
+    Reserved12,
+    Reserved13,
+    ///Full system, stores only
+    St,
+    ///Full system
+    Sy,
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+///Quadword register of the Advanced SIMD extension, overlaps two doubleword registers
+#[repr(u8)]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Qreg {
+    Q0,
+    Q1,
+    Q2,
+    Q3,
+    Q4,
+    Q5,
+    Q6,
+    Q7,
+    Q8,
+    Q9,
+    Q10,
+    Q11,
+    Q12,
+    Q13,
+    Q14,
+    Q15,
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+///Doubleword or quadword register of an Advanced SIMD instruction, selected by the highest bit
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NeonReg {
+    ///Doubleword register
+    D(Dreg),
+    ///Quadword register
+    Q(Qreg),
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+///Data type suffix of an Advanced SIMD instruction which works on integers of any signedness
+#[repr(u8)]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NeonIntType {
+    ///8-bit integer
+    I8,
+    ///16-bit integer
+    I16,
+    ///32-bit integer
+    I32,
+    ///64-bit integer
+    I64,
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+///Data type suffix of an Advanced SIMD instruction which works on signed or unsigned integers
+#[repr(u8)]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NeonSignType {
+    ///Signed 8-bit
+    S8,
+    ///Signed 16-bit
+    S16,
+    ///Signed 32-bit
+    S32,
+    ///Signed 64-bit
+    S64,
+    ///Unsigned 8-bit
+    U8,
+    ///Unsigned 16-bit
+    U16,
+    ///Unsigned 32-bit
+    U32,
+    ///Unsigned 64-bit
+    U64,
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+///Data type suffix of an Advanced SIMD instruction which only depends on the element size
+#[repr(u8)]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NeonSize {
+    ///8-bit elements
+    Size8,
+    ///16-bit elements
+    Size16,
+    ///32-bit elements
+    Size32,
+    ///64-bit elements
+    Size64,
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+///Source and destination data types of an Advanced SIMD VCVT instruction
+#[repr(u8)]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NeonCvtType {
+    ///Signed 32-bit integer to floating-point
+    F32S32,
+    ///Unsigned 32-bit integer to floating-point
+    F32U32,
+    ///Floating-point to signed 32-bit integer
+    S32F32,
+    ///Floating-point to unsigned 32-bit integer
+    U32F32,
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+///Alignment of the base address of VLD1-VLD4/VST1-VST4, in bits
+#[repr(u8)]
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NeonAlign {
+    ///Standard alignment
+    None,
+    Align16,
+    Align32,
+    Align64,
+    Align128,
+    Align256,
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+///Base register update of VLD1-VLD4/VST1-VST4
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NeonPost {
+    ///No writeback
+    None,
+    ///Add the transfer size to the base register
+    Writeback,
+    ///Add a register to the base register
+    Reg(Reg),
+}
+#[cfg(all(feature = "neon", feature = "v7"))]
+///The memory address of VLD1-VLD4/VST1-VST4
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NeonAddr {
+    ///Base register
+    pub rn: Reg,
+    ///Alignment of the base address
+    pub align: NeonAlign,
+    ///How to update the base register afterwards
+    pub post: NeonPost,
+}
 #[repr(u16)]
 #[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Ins {
     ///Add with Carry
     Adc { s: bool, thumb: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2 },
@@ -699,6 +1059,12 @@ pub enum Ins {
     Asr { s: bool, thumb: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2Shift },
     ///Branch
     B { cond: Cond, target: BranchTarget },
+    #[cfg(feature = "v7")]
+    ///Bit Field Clear
+    Bfc { cond: Cond, rd: Reg, lsb: u32, width: u32 },
+    #[cfg(feature = "v7")]
+    ///Bit Field Insert
+    Bfi { cond: Cond, rd: Reg, rn: Reg, lsb: u32, width: u32 },
     ///Bit Clear
     Bic { s: bool, thumb: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2 },
     #[cfg(
@@ -707,7 +1073,8 @@ pub enum Ins {
             feature = "v5te",
             feature = "v5tej",
             feature = "v6",
-            feature = "v6k"
+            feature = "v6k",
+            feature = "v7"
         )
     )]
     ///Breakpoint
@@ -720,7 +1087,8 @@ pub enum Ins {
             feature = "v5te",
             feature = "v5tej",
             feature = "v6",
-            feature = "v6k"
+            feature = "v6k",
+            feature = "v7"
         )
     )]
     ///Branch with Link and Exchange
@@ -732,15 +1100,30 @@ pub enum Ins {
             feature = "v5te",
             feature = "v5tej",
             feature = "v6",
-            feature = "v6k"
+            feature = "v6k",
+            feature = "v7"
         )
     )]
     ///Branch and Exchange
     Bx { cond: Cond, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v5tej", feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(
+                feature = "arm",
+                any(feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+            ),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Branch and Exchange Jazelle
     Bxj { cond: Cond, rm: Reg },
-    #[cfg(feature = "arm")]
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    ///Compare and Branch on Nonzero
+    Cbnz { rn: Reg, target: BranchTarget },
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    ///Compare and Branch on Zero
+    Cbz { rn: Reg, target: BranchTarget },
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Coprocessor Data Processing
     Cdp {
         cond: Cond,
@@ -752,32 +1135,45 @@ pub enum Ins {
         opc2: u32,
     },
     #[cfg(
-        all(
-            feature = "arm",
-            any(
-                feature = "v5t",
-                feature = "v5te",
-                feature = "v5tej",
-                feature = "v6",
-                feature = "v6k"
-            )
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Coprocessor Data Processing (extended)
     Cdp2 { coproc: Coproc, opc1: u32, crd: CoReg, crn: CoReg, crm: CoReg, opc2: u32 },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Clear Exclusive
     Clrex {},
     #[cfg(
-        all(
-            feature = "arm",
-            any(
-                feature = "v5t",
-                feature = "v5te",
-                feature = "v5tej",
-                feature = "v6",
-                feature = "v6k"
-            )
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Count Leading Zeros
@@ -786,30 +1182,51 @@ pub enum Ins {
     Cmn { cond: Cond, rn: Reg, op2: Op2 },
     ///Compare
     Cmp { cond: Cond, rn: Reg, op2: Op2 },
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Change Processor State
     Cps { effect: CpsEffect, aif: AifFlags, mode: u32 },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Consume of Speculative Data Barrier
     Csdb { cond: Cond },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Debug Hint
     Dbg { cond: Cond, option: u32 },
+    #[cfg(feature = "v7")]
+    ///Data Memory Barrier
+    Dmb { cond: Cond, option: BarrierOption },
+    #[cfg(feature = "v7")]
+    ///Data Synchronization Barrier
+    Dsb { cond: Cond, option: BarrierOption },
     ///Bitwise Exclusive OR
     Eor { s: bool, thumb: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2 },
-    #[cfg(feature = "arm")]
+    #[cfg(feature = "v7")]
+    ///Instruction Synchronization Barrier
+    Isb { cond: Cond, option: BarrierOption },
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    ///If-Then, makes up to four following instructions conditional
+    It { firstcond: Cond, mask: ItMask },
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Load Coprocessor
     Ldc { l: bool, cond: Cond, coproc: Coproc, crd: CoReg, dest: AddrLdcStc },
     #[cfg(
-        all(
-            feature = "arm",
-            any(
-                feature = "v5t",
-                feature = "v5te",
-                feature = "v5tej",
-                feature = "v6",
-                feature = "v6k"
-            )
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Load Coprocessor (extended)
@@ -827,43 +1244,81 @@ pub enum Ins {
     Ldr { cond: Cond, rd: Reg, addr: AddrLdrStr },
     ///Load Register Byte
     Ldrb { cond: Cond, rd: Reg, addr: AddrLdrStr },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Load Register Byte with Translation
-    Ldrbt { cond: Cond, rd: Reg, addr: AddrLdrStrPost },
+    Ldrbt { cond: Cond, rd: Reg, addr: AddrLdrStr },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Load Register Dual
     Ldrd { cond: Cond, rd: Reg, rd2: Reg, addr: AddrMiscLoad },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Load Register Exclusive
-    Ldrex { cond: Cond, rd: Reg, rn: Reg },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    Ldrex { cond: Cond, rd: Reg, rn: Reg, offset: u32 },
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Load Register Exclusive Byte
     Ldrexb { cond: Cond, rd: Reg, rn: Reg },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Load Register Exclusive Doubleword
     Ldrexd { cond: Cond, rd: Reg, rd2: Reg, rn: Reg },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Load Register Exclusive Halfword
     Ldrexh { cond: Cond, rd: Reg, rn: Reg },
     ///Load Register Halfword
     Ldrh { cond: Cond, rd: Reg, addr: AddrMiscLoad },
+    #[cfg(feature = "v7")]
+    ///Load Register Halfword with Translation
+    Ldrht { cond: Cond, rd: Reg, addr: AddrMiscLoad },
     ///Load Register Signed Byte
     Ldrsb { cond: Cond, rd: Reg, addr: AddrMiscLoad },
+    #[cfg(feature = "v7")]
+    ///Load Register Signed Byte with Translation
+    Ldrsbt { cond: Cond, rd: Reg, addr: AddrMiscLoad },
     ///Load Register Signed Halfword
     Ldrsh { cond: Cond, rd: Reg, addr: AddrMiscLoad },
-    #[cfg(feature = "arm")]
+    #[cfg(feature = "v7")]
+    ///Load Register Signed Halfword with Translation
+    Ldrsht { cond: Cond, rd: Reg, addr: AddrMiscLoad },
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Load Register with Translation
-    Ldrt { cond: Cond, rd: Reg, addr: AddrLdrStrPost },
+    Ldrt { cond: Cond, rd: Reg, addr: AddrLdrStr },
     ///Logical Shift Left
     Lsl { s: bool, thumb: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2Shift },
     ///Logical Shift Right
     Lsr { s: bool, thumb: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2Shift },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Move to Coprocessor from ARM Register
     Mcr {
         cond: Cond,
@@ -875,36 +1330,63 @@ pub enum Ins {
         opc2: u32,
     },
     #[cfg(
-        all(
-            feature = "arm",
-            any(
-                feature = "v5t",
-                feature = "v5te",
-                feature = "v5tej",
-                feature = "v6",
-                feature = "v6k"
-            )
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Move to Coprocessor from ARM Register (extended)
     Mcr2 { coproc: Coproc, opc1: u32, rd: Reg, crn: CoReg, crm: CoReg, opc2: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Move to Coprocessor from two ARM Registers
     Mcrr { cond: Cond, coproc: Coproc, opc: u32, rd: Reg, rd2: Reg, crm: CoReg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Move to Coprocessor from two ARM Registers (extended)
     Mcrr2 { coproc: Coproc, opc: u32, rd: Reg, rd2: Reg, crm: CoReg },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Multiply Accumulate
     Mla { s: bool, cond: Cond, rd: Reg, rn: Reg, rm: Reg, ra: Reg },
+    #[cfg(feature = "v7")]
+    ///Multiply and Subtract
+    Mls { cond: Cond, rd: Reg, rn: Reg, rm: Reg, ra: Reg },
     ///Move
     Mov { s: bool, thumb: bool, cond: Cond, rd: Reg, op2: Op2 },
-    #[cfg(feature = "arm")]
+    #[cfg(feature = "v7")]
+    ///Move Top, writes an immediate to the top halfword of a register
+    Movt { cond: Cond, rd: Reg, imm: u32 },
+    #[cfg(feature = "v7")]
+    ///Move Wide, writes a 16-bit immediate to a register
+    Movw { cond: Cond, rd: Reg, imm: u32 },
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Move to ARM Register from Coprocessor
     Mrc {
         cond: Cond,
@@ -916,34 +1398,52 @@ pub enum Ins {
         opc2: u32,
     },
     #[cfg(
-        all(
-            feature = "arm",
-            any(
-                feature = "v5t",
-                feature = "v5te",
-                feature = "v5tej",
-                feature = "v6",
-                feature = "v6k"
-            )
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Move to ARM Register from Coprocessor (extended)
     Mrc2 { coproc: Coproc, opc1: u32, rd: Reg, crn: CoReg, crm: CoReg, opc2: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Move to two ARM Registers from Coprocessor
     Mrrc { cond: Cond, coproc: Coproc, opc: u32, rd: Reg, rd2: Reg, crm: CoReg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Move to two ARM Registers from Coprocessor (extended)
     Mrrc2 { coproc: Coproc, opc: u32, rd: Reg, rd2: Reg, crm: CoReg },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Move to Register from Status register
     Mrs { cond: Cond, rd: Reg, status_reg: StatusReg },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Move to Status register
     Msr { cond: Cond, status_fields: StatusFields, op2: MsrOp2 },
     ///Multiply
@@ -953,94 +1453,198 @@ pub enum Ins {
     #[cfg(feature = "thumb")]
     ///Negate
     Neg { rd: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///No Operation
     Nop { cond: Cond },
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    ///Bitwise OR NOT
+    Orn { s: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2 },
     ///Bitwise OR
     Orr { s: bool, thumb: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Pack Halfword Bottom Top
     Pkhbt { cond: Cond, rd: Reg, rn: Reg, rm: Reg, shift_op: ShiftOp, shift: u32 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Pack Halfword Top Bottom
     Pkhtb { cond: Cond, rd: Reg, rn: Reg, rm: Reg, shift_op: ShiftOp, shift: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Preload Data
     Pld { addr: AddrLdrStr },
+    #[cfg(feature = "v7")]
+    ///Preload Instruction
+    Pli { addr: AddrLdrStr },
     ///Pop multiple registers
     Pop { cond: Cond, regs: RegList },
     ///Push multiple registers
     Push { cond: Cond, regs: RegList },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Saturating Add
     Qadd { cond: Cond, rd: Reg, rm: Reg, rn: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Saturating Add two 16-bit values
     Qadd16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Saturating Add four 8-bit values
     Qadd8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
-    ///Saturating Add and Subtract with Exchange
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
+    ///Saturating Add and Subtract with Exchange
     Qasx { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Saturating Double and Add
     Qdadd { cond: Cond, rd: Reg, rm: Reg, rn: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Saturating Double and Subtract
     Qdsub { cond: Cond, rd: Reg, rm: Reg, rn: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Saturating Subtract and Add with Exchange
     Qsax { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Saturating Subtract
     Qsub { cond: Cond, rd: Reg, rm: Reg, rn: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Saturating Subtract two 16-bit values
     Qsub16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Saturating Subtract four 8-bit values
     Qsub8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(feature = "v7")]
+    ///Reverse bits in word
+    Rbit { cond: Cond, rd: Reg, rm: Reg },
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Reverse bytes in word
     Rev { cond: Cond, rd: Reg, rm: Reg },
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Reverse bytes in packed halfwords
     Rev16 { cond: Cond, rd: Reg, rm: Reg },
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Reverse bytes in signed halfword
     Revsh { cond: Cond, rd: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Return From Exception
     Rfe { addr_mode: SrsRfeMode, rn: Reg, writeback: bool },
     ///Rotate Right
     Ror { s: bool, thumb: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2Shift },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Rotate Right with Extend
     Rrx { s: bool, cond: Cond, rd: Reg, rm: Reg },
     ///Reverse Subtract
@@ -1048,48 +1652,118 @@ pub enum Ins {
     #[cfg(feature = "arm")]
     ///Reverse Subtract with Carry
     Rsc { s: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Add two 16-bit values
     Sadd16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Add four 8-bit values
     Sadd8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Add and Subtract with Exchange
     Sasx { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
     ///Subtract with Carry
     Sbc { s: bool, thumb: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(feature = "v7")]
+    ///Signed Bit Field Extract
+    Sbfx { cond: Cond, rd: Reg, rn: Reg, lsb: u32, width: u32 },
+    #[cfg(feature = "v7")]
+    ///Signed Divide
+    Sdiv { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Select
     Sel { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Set Endianness
     Setend { endian: Endianness },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Send Event
     Sev { cond: Cond },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Halving Add two 16-bit values
     Shadd16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Halving Add four 8-bit values
     Shadd8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Halving Add and Subtract with Exchange
     Shasx { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Halving Subtract and Add with Exchange
     Shsax { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Halving Subtract two 16-bit values
     Shsub16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Halving Subtract four 8-bit values
     Shsub8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Signed Multiply Accumulate halfwords
@@ -1102,16 +1776,30 @@ pub enum Ins {
         rm_side: RegSide,
         ra: Reg,
     },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Multiply Accumulate Dual
     Smlad { cond: Cond, rd: Reg, rn: Reg, rm: Reg, swap_rm: bool, ra: Reg },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Signed Multiply Accumulate Long
     Smlal { s: bool, cond: Cond, rd_lo: Reg, rd_hi: Reg, rn: Reg, rm: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Signed Multiply Accumulate Long halfwords
@@ -1124,88 +1812,189 @@ pub enum Ins {
         rm: Reg,
         rm_side: RegSide,
     },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Multiply Accumulate Long Dual
     Smlald { cond: Cond, rd_lo: Reg, rd_hi: Reg, rn: Reg, rm: Reg, swap_rm: bool },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Signed Multiply Accumulate Word by halfword
     Smlaw { cond: Cond, rd: Reg, rn: Reg, rm: Reg, rm_side: RegSide, ra: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Multiply Subtract Dual
     Smlsd { cond: Cond, rd: Reg, rn: Reg, rm: Reg, swap_rm: bool, ra: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Multiply Subtract Long Dual
     Smlsld { cond: Cond, rd_lo: Reg, rd_hi: Reg, rn: Reg, rm: Reg, swap_rm: bool },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Most significant word Multiply Accumulate
     Smmla { round: bool, cond: Cond, rd: Reg, rn: Reg, rm: Reg, ra: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Most significant word Multiply Subtract
     Smmls { round: bool, cond: Cond, rd: Reg, rn: Reg, rm: Reg, ra: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Most significant word Multiply
     Smmul { round: bool, cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Multiply Add Dual
     Smuad { cond: Cond, rd: Reg, rn: Reg, rm: Reg, swap_rm: bool },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Signed Multiply
     Smul { cond: Cond, rd: Reg, rn: Reg, rn_side: RegSide, rm: Reg, rm_side: RegSide },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Signed Multiply Long
     Smull { s: bool, cond: Cond, rd_lo: Reg, rd_hi: Reg, rn: Reg, rm: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Signed Multiply Word by halfword
     Smulw { cond: Cond, rd: Reg, rn: Reg, rm: Reg, rm_side: RegSide },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Multiply Subtract Dual
     Smusd { cond: Cond, rd: Reg, rn: Reg, rm: Reg, swap_rm: bool },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Store Return State
     Srs { addr_mode: SrsRfeMode, rn: Reg, writeback: bool, mode: u32 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Saturate
     Ssat { cond: Cond, rd: Reg, imm: u32, op2: ShiftImm },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Saturate two 16-bit values
     Ssat16 { cond: Cond, rd: Reg, imm: u32, rn: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Subtract and Add with Exchange
     Ssax { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Subtract two 16-bit values
     Ssub16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Signed Subtract four 8-bit values
     Ssub8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Store Coprocessor
     Stc { l: bool, cond: Cond, coproc: Coproc, crd: CoReg, dest: AddrLdcStc },
     #[cfg(
-        all(
-            feature = "arm",
-            any(
-                feature = "v5t",
-                feature = "v5te",
-                feature = "v5tej",
-                feature = "v6",
-                feature = "v6k"
-            )
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5t",
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Store Coprocessor (extended)
@@ -1223,34 +2012,66 @@ pub enum Ins {
     Str { cond: Cond, rd: Reg, addr: AddrLdrStr },
     ///Store Register Byte
     Strb { cond: Cond, rd: Reg, addr: AddrLdrStr },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Store Register Byte with Translation
-    Strbt { cond: Cond, rd: Reg, addr: AddrLdrStrPost },
+    Strbt { cond: Cond, rd: Reg, addr: AddrLdrStr },
     #[cfg(
-        all(
-            feature = "arm",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(feature = "thumb", feature = "v7")
         )
     )]
     ///Store Register Dual
     Strd { cond: Cond, rd: Reg, rd2: Reg, addr: AddrMiscLoad },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Store Register Exclusive
-    Strex { cond: Cond, rd: Reg, rm: Reg, rn: Reg },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    Strex { cond: Cond, rd: Reg, rm: Reg, rn: Reg, offset: u32 },
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Store Register Exclusive Byte
     Strexb { cond: Cond, rd: Reg, rm: Reg, rn: Reg },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Store Register Exclusive Doubleword
     Strexd { cond: Cond, rd: Reg, rm: Reg, rm2: Reg, rn: Reg },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Store Register Exclusive Halfword
     Strexh { cond: Cond, rd: Reg, rm: Reg, rn: Reg },
     ///Store Register Halfword
     Strh { cond: Cond, rd: Reg, addr: AddrMiscLoad },
-    #[cfg(feature = "arm")]
+    #[cfg(feature = "v7")]
+    ///Store Register Halfword with Translation
+    Strht { cond: Cond, rd: Reg, addr: AddrMiscLoad },
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Store Register with Translation
-    Strt { cond: Cond, rd: Reg, addr: AddrLdrStrPost },
+    Strt { cond: Cond, rd: Reg, addr: AddrLdrStr },
     ///Subtract
     Sub { s: bool, thumb: bool, cond: Cond, rd: Reg, rn: Reg, op2: Op2 },
     ///Supervisor Call
@@ -1261,38 +2082,82 @@ pub enum Ins {
     #[cfg(feature = "arm")]
     ///Swap Byte
     Swpb { cond: Cond, rd: Reg, rd2: Reg, rn: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Sign Extend and Add Byte
     Sxtab { cond: Cond, rd: Reg, rn: Reg, rm: Reg, rotate: u32 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Sign Extend to 16 bits and Add Byte
     Sxtab16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg, rotate: u32 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Sign Extend and Add Halfword
     Sxtah { cond: Cond, rd: Reg, rn: Reg, rm: Reg, rotate: u32 },
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Sign Extend Byte
     Sxtb { cond: Cond, rd: Reg, rm: Reg, rotate: u32 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Sign Extend Byte to 16 bits
     Sxtb16 { cond: Cond, rd: Reg, rm: Reg, rotate: u32 },
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Sign Extend Halfword
     Sxth { cond: Cond, rd: Reg, rm: Reg, rotate: u32 },
-    #[cfg(feature = "arm")]
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    ///Table Branch Byte
+    Tbb { cond: Cond, rn: Reg, rm: Reg },
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    ///Table Branch Halfword
+    Tbh { cond: Cond, rn: Reg, rm: Reg },
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Test Equivalence
     Teq { cond: Cond, rn: Reg, op2: Op2 },
     ///Test
     Tst { cond: Cond, rn: Reg, op2: Op2 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Add two 16-bit values
     Uadd16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Add four 8-bit values
     Uadd8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Add and Subtract with Exchange
     Uasx { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
+    #[cfg(feature = "v7")]
+    ///Unsigned Bit Field Extract
+    Ubfx { cond: Cond, rd: Reg, rn: Reg, lsb: u32, width: u32 },
     #[cfg(
         any(
             feature = "v4t",
@@ -1300,642 +2165,2145 @@ pub enum Ins {
             feature = "v5te",
             feature = "v5tej",
             feature = "v6",
-            feature = "v6k"
+            feature = "v6k",
+            feature = "v7"
         )
     )]
     ///Undefined Permanently
     Udf { imm: u32 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(feature = "v7")]
+    ///Unsigned Divide
+    Udiv { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Halving Add two 16-bit values
     Uhadd16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Halving Add four 8-bit values
     Uhadd8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Halving Add and Subtract with Exchange
     Uhasx { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Halving Subtract and Add with Exchange
     Uhsax { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Halving Subtract two 16-bit values
     Uhsub16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Halving Subtract four 8-bit values
     Uhsub8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Multiply Accumulate Accumulate Long
     Umaal { cond: Cond, rd_lo: Reg, rd_hi: Reg, rn: Reg, rm: Reg },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Unsigned Multiply Accumulate Long
     Umlal { s: bool, cond: Cond, rd_lo: Reg, rd_hi: Reg, rn: Reg, rm: Reg },
-    #[cfg(feature = "arm")]
+    #[cfg(any(feature = "arm", all(feature = "thumb", feature = "v7")))]
     ///Unsigned Multiply Long
     Umull { s: bool, cond: Cond, rd_lo: Reg, rd_hi: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Saturating Add two 16-bit values
     Uqadd16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Saturating Add four 8-bit values
     Uqadd8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Saturating Add and Subtract with Exchange
     Uqasx { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Saturating Subtract and Add with Exchange
     Uqsax { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Saturating Subtract two 16-bit values
     Uqsub16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Saturating Subtract four 8-bit values
     Uqsub8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Sum of Absolute Differences for four 8-bit values
     Usad8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Sum of Absolute Differences and Accumulate four 8-bit values
     Usada8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg, ra: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Saturate
     Usat { cond: Cond, rd: Reg, imm: u32, op2: ShiftImm },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Saturate two 16-bit values
     Usat16 { cond: Cond, rd: Reg, imm: u32, rn: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Subtract and Add with Exchange
     Usax { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Subtract two 16-bit values
     Usub16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Subtract four 8-bit values
     Usub8 { cond: Cond, rd: Reg, rn: Reg, rm: Reg },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Extend and Add Byte
     Uxtab { cond: Cond, rd: Reg, rn: Reg, rm: Reg, rotate: u32 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Extend to 16 bits and Add Byte
     Uxtab16 { cond: Cond, rd: Reg, rn: Reg, rm: Reg, rotate: u32 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Extend and Add Halfword
     Uxtah { cond: Cond, rd: Reg, rn: Reg, rm: Reg, rotate: u32 },
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Unsigned Extend Byte
     Uxtb { cond: Cond, rd: Reg, rm: Reg, rotate: u32 },
-    #[cfg(all(feature = "arm", any(feature = "v6", feature = "v6k")))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6", feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Unsigned Extend Byte to 16 bits
     Uxtb16 { cond: Cond, rd: Reg, rm: Reg, rotate: u32 },
-    #[cfg(any(feature = "v6", feature = "v6k"))]
+    #[cfg(any(feature = "v6", feature = "v6k", feature = "v7"))]
     ///Unsigned Extend Halfword
     Uxth { cond: Cond, rd: Reg, rm: Reg, rotate: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Absolute Difference and Accumulate
+    Vaba { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Absolute Difference and Accumulate Long
+    Vabal { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Absolute Difference integer
+    Vabd { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Absolute Difference floating-point
+    VabdF32 { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Absolute Difference Long
+    Vabdl { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Absolute integer
+    Vabs { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Absolute 32-bit
     VabsF32 { cond: Cond, sd: Sreg, sm: Sreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Absolute floating-point
+    VabsF32Neon { cond: Cond, vd: NeonReg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Absolute 64-bit
     VabsF64 { cond: Cond, dd: Dreg, dm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Absolute Compare Greater Than or Equal
+    Vacge { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Absolute Compare Greater Than
+    Vacgt { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Add 32-bit
     VaddF32 { cond: Cond, sd: Sreg, sn: Sreg, sm: Sreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Add floating-point
+    VaddF32Neon { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Add 64-bit
     VaddF64 { cond: Cond, dd: Dreg, dn: Dreg, dm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Add integer
+    VaddI { cond: Cond, dt: NeonIntType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Add and Narrow returning High half
+    Vaddhn { cond: Cond, dt: NeonIntType, vd: Dreg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Add Long
+    Vaddl { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Add Wide
+    Vaddw { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise AND
+    Vand { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise Bit Clear
+    Vbic { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise Bit Clear immediate
+    VbicIImm { cond: Cond, dt: NeonIntType, vd: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise Insert if False
+    Vbif { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise Insert if True
+    Vbit { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise Select
+    Vbsl { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Equal integer
+    Vceq { cond: Cond, dt: NeonIntType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Equal floating-point
+    VceqF32 { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Equal to zero integer
+    VceqZero { cond: Cond, dt: NeonIntType, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Equal to zero floating-point
+    VceqZeroF32 { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Greater Than or Equal integer
+    Vcge { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Greater Than or Equal floating-point
+    VcgeF32 { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Greater Than or Equal to zero integer
+    VcgeZero { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Greater Than or Equal to zero floating-point
+    VcgeZeroF32 { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Greater Than integer
+    Vcgt { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Greater Than floating-point
+    VcgtF32 { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Greater Than zero integer
+    VcgtZero { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Greater Than zero floating-point
+    VcgtZeroF32 { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Less Than or Equal to zero integer
+    VcleZero { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Less Than or Equal to zero floating-point
+    VcleZeroF32 { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Count Leading Sign bits
+    Vcls { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Less Than zero integer
+    VcltZero { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Compare Less Than zero floating-point
+    VcltZeroF32 { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Count Leading Zeros
+    Vclz { cond: Cond, dt: NeonIntType, vd: NeonReg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Compare 32-bit
     VcmpF32 { nan_exc: bool, cond: Cond, sd: Sreg, op2: VcmpF32Op2 },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Compare 64-bit
     VcmpF64 { nan_exc: bool, cond: Cond, dd: Dreg, op2: VcmpF64Op2 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Count set bits
+    Vcnt { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    ///Vector Convert to Half-precision from Floating-point 32-bit
+    VcvtF16F32 { half: Half, cond: Cond, sd: Sreg, sm: Sreg },
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    ///Vector Convert to Floating-point 32-bit from Half-precision
+    VcvtF32F16 { half: Half, cond: Cond, sd: Sreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Convert to Floating-point 32-bit from Floating-point 64-bit
     VcvtF32F64 { cond: Cond, sd: Sreg, dm: Dreg },
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    ///Vector Convert to Floating-point 32-bit from Fixed-point
+    VcvtF32Fixed { cond: Cond, fixed: FixedType, sd: Sreg, fbits: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Convert to Floating-point 32-bit from Signed 32-bit integer
     VcvtF32S32 { cond: Cond, sd: Sreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Convert to Floating-point 32-bit from Unsigned 32-bit integer
     VcvtF32U32 { cond: Cond, sd: Sreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Convert to Floating-point 64-bit from Floating-point 32-bit
     VcvtF64F32 { cond: Cond, dd: Dreg, sm: Sreg },
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    ///Vector Convert to Floating-point 64-bit from Fixed-point
+    VcvtF64Fixed { cond: Cond, fixed: FixedType, dd: Dreg, fbits: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Convert to Floating-point 64-bit from Signed 32-bit integer
     VcvtF64S32 { cond: Cond, dd: Dreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Convert to Floating-point 64-bit from Unsigned 32-bit integer
     VcvtF64U32 { cond: Cond, dd: Dreg, sm: Sreg },
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    ///Vector Convert to Fixed-point from Floating-point 32-bit
+    VcvtFixedF32 { cond: Cond, fixed: FixedType, sd: Sreg, fbits: u32 },
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    ///Vector Convert to Fixed-point from Floating-point 64-bit
+    VcvtFixedF64 { cond: Cond, fixed: FixedType, dd: Dreg, fbits: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Convert between floating-point and fixed-point
+    VcvtFixedNeon { cond: Cond, dt: NeonCvtType, vd: NeonReg, vm: NeonReg, fbits: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Convert between floating-point and integer
+    VcvtNeon { cond: Cond, dt: NeonCvtType, vd: NeonReg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Convert to Signed 32-bit integer from Floating-point 32-bit
     VcvtS32F32 { round_zero: bool, cond: Cond, sd: Sreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Convert to Signed 32-bit integer from Floating-point 64-bit
     VcvtS32F64 { round_zero: bool, cond: Cond, sd: Sreg, dm: Dreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Convert to Unsigned 32-bit integer from Floating-point 32-bit
     VcvtU32F32 { round_zero: bool, cond: Cond, sd: Sreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Convert to Unsigned 32-bit integer from Floating-point 64-bit
     VcvtU32F64 { round_zero: bool, cond: Cond, sd: Sreg, dm: Dreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Divide Floating-point 32-bit
     VdivF32 { cond: Cond, sd: Sreg, sn: Sreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Divide Floating-point 64-bit
     VdivF64 { cond: Cond, dd: Dreg, dn: Dreg, dm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Duplicate from register
+    VdupReg { cond: Cond, size: NeonSize, vd: NeonReg, rt: Reg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Duplicate scalar
+    VdupScalar { cond: Cond, size: NeonSize, vd: NeonReg, dm: Dreg, index: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise Exclusive OR
+    Veor { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Extract
+    Vext { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Halving Add
+    Vhadd { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Halving Subtract
+    Vhsub { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Load single elements
+    Vld1 { cond: Cond, size: NeonSize, regs: NeonRegList, addr: NeonAddr },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Load 2-element structures
+    Vld2 { cond: Cond, size: NeonSize, regs: NeonRegList, addr: NeonAddr },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Load 3-element structures
+    Vld3 { cond: Cond, size: NeonSize, regs: NeonRegList, addr: NeonAddr },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Load 4-element structures
+    Vld4 { cond: Cond, size: NeonSize, regs: NeonRegList, addr: NeonAddr },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Load Multiple Floating-point 32-bit
     VldmF32 { mode: VldmVstmMode, cond: Cond, rn: Reg, writeback: bool, regs: SregList },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Load Multiple Floating-point 64-bit
     VldmF64 { mode: VldmVstmMode, cond: Cond, rn: Reg, writeback: bool, regs: DregList },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Load Register Floating-point 32-bit
     VldrF32 { cond: Cond, sd: Sreg, addr: AddrLdrStr },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Load Register Floating-point 64-bit
     VldrF64 { cond: Cond, dd: Dreg, addr: AddrLdrStr },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Maximum integer
+    Vmax { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Maximum floating-point
+    VmaxF32 { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Minimum integer
+    Vmin { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Minimum floating-point
+    VminF32 { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Multiply Accumulate Floating-point 32-bit
     VmlaF32 { cond: Cond, sd: Sreg, sn: Sreg, sm: Sreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Accumulate floating-point
+    VmlaF32Neon { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Accumulate floating-point by scalar
+    VmlaF32Scalar { cond: Cond, vd: NeonReg, vn: NeonReg, dm: Dreg, index: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Multiply Accumulate Floating-point 64-bit
     VmlaF64 { cond: Cond, dd: Dreg, dn: Dreg, dm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Accumulate integer
+    VmlaI { cond: Cond, dt: NeonIntType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Accumulate integer by scalar
+    VmlaIScalar {
+        cond: Cond,
+        dt: NeonIntType,
+        vd: NeonReg,
+        vn: NeonReg,
+        dm: Dreg,
+        index: u32,
+    },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Accumulate Long
+    Vmlal { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Accumulate Long by scalar
+    VmlalScalar {
+        cond: Cond,
+        dt: NeonSignType,
+        vd: NeonReg,
+        vn: Dreg,
+        dm: Dreg,
+        index: u32,
+    },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Multiply Subtract Floating-point 32-bit
     VmlsF32 { cond: Cond, sd: Sreg, sn: Sreg, sm: Sreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Subtract floating-point
+    VmlsF32Neon { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Subtract floating-point by scalar
+    VmlsF32Scalar { cond: Cond, vd: NeonReg, vn: NeonReg, dm: Dreg, index: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Multiply Subtract Floating-point 64-bit
     VmlsF64 { cond: Cond, dd: Dreg, dn: Dreg, dm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Subtract integer
+    VmlsI { cond: Cond, dt: NeonIntType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Subtract integer by scalar
+    VmlsIScalar {
+        cond: Cond,
+        dt: NeonIntType,
+        vd: NeonReg,
+        vn: NeonReg,
+        dm: Dreg,
+        index: u32,
+    },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Subtract Long
+    Vmlsl { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Subtract Long by scalar
+    VmlslScalar {
+        cond: Cond,
+        dt: NeonSignType,
+        vd: NeonReg,
+        vn: Dreg,
+        dm: Dreg,
+        index: u32,
+    },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move to 32-bit scalar from register
     Vmov32Reg { cond: Cond, dd: DregIndex, rt: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move Floating-point 32-bit
     VmovF32 { cond: Cond, sd: Sreg, sm: Sreg },
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    ///Vector Move Floating-point 32-bit immediate
+    VmovF32Imm { cond: Cond, sd: Sreg, imm: VfpImm },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Move floating-point immediate
+    VmovF32ImmNeon { cond: Cond, vd: NeonReg, imm: VfpImm },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move to Floating-point 32-bit from register
     VmovF32Reg { cond: Cond, sn: Sreg, rt: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move Floating-point 64-bit
     VmovF64 { cond: Cond, dd: Dreg, dm: Dreg },
+    #[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16"), feature = "v7"))]
+    ///Vector Move Floating-point 64-bit immediate
+    VmovF64Imm { cond: Cond, dd: Dreg, imm: VfpImm },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Move 64-bit immediate
+    VmovI64Imm { cond: Cond, vd: NeonReg, imm: NeonImm64 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Move integer immediate
+    VmovIImm { cond: Cond, dt: NeonIntType, vd: NeonReg, imm: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move to register from 32-bit scalar
     VmovReg32 { cond: Cond, rt: Reg, dn: DregIndex },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Move to register from 8-bit or 16-bit scalar
+    VmovRegScalar { cond: Cond, dt: NeonSignType, rt: Reg, dn: Dreg, index: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move to register from floating-point 32-bit
     VmovRegF32 { cond: Cond, rt: Reg, sn: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move to two registers from two floating-point 32-bit
     VmovRegF32Dual { cond: Cond, rt: Reg, rt2: Reg, sm: Sreg, sm2: Sreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Move to 8-bit or 16-bit scalar from register
+    VmovScalarReg { cond: Cond, size: NeonSize, dd: Dreg, index: u32, rt: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move to two floating-point 32-bit from two registers
     VmovF32RegDual { cond: Cond, sm: Sreg, sm2: Sreg, rt: Reg, rt2: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move to two registers from one floating-point 64-bit
     VmovRegF64 { cond: Cond, rt: Reg, rt2: Reg, dm: Dreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move to one floating-point 64-bit from two registers
     VmovF64Reg { cond: Cond, dm: Dreg, rt: Reg, rt2: Reg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Move and Narrow
+    Vmovn { cond: Cond, dt: NeonIntType, vd: Dreg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move to Register from Status register
     Vmrs { cond: Cond, rd: Reg, fpscr: Fpscr },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Move to Status register from Register
     Vmsr { cond: Cond, fpscr: Fpscr, rd: Reg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Multiply Floating-point 32-bit
     VmulF32 { cond: Cond, sd: Sreg, sn: Sreg, sm: Sreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply floating-point
+    VmulF32Neon { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply floating-point by scalar
+    VmulF32Scalar { cond: Cond, vd: NeonReg, vn: NeonReg, dm: Dreg, index: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Multiply Floating-point 64-bit
     VmulF64 { cond: Cond, dd: Dreg, dn: Dreg, dm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply integer
+    VmulI { cond: Cond, dt: NeonIntType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply integer by scalar
+    VmulIScalar {
+        cond: Cond,
+        dt: NeonIntType,
+        vd: NeonReg,
+        vn: NeonReg,
+        dm: Dreg,
+        index: u32,
+    },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply polynomial
+    VmulP8 { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Long integer
+    Vmull { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Long polynomial
+    VmullP8 { cond: Cond, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Multiply Long by scalar
+    VmullScalar {
+        cond: Cond,
+        dt: NeonSignType,
+        vd: NeonReg,
+        vn: Dreg,
+        dm: Dreg,
+        index: u32,
+    },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise NOT
+    Vmvn { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise NOT immediate
+    VmvnIImm { cond: Cond, dt: NeonIntType, vd: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Negate integer
+    Vneg { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Negate Floating-point 32-bit
     VnegF32 { cond: Cond, sd: Sreg, sm: Sreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Negate floating-point
+    VnegF32Neon { cond: Cond, vd: NeonReg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Negate Floating-point 64-bit
     VnegF64 { cond: Cond, dd: Dreg, dm: Dreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Negate Multiply Accumulate Floating-point 32-bit
     VnmlaF32 { cond: Cond, sd: Sreg, sn: Sreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Negate Multiply Accumulate Floating-point 64-bit
     VnmlaF64 { cond: Cond, dd: Dreg, dn: Dreg, dm: Dreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Negate Multiply Subtract Floating-point 32-bit
     VnmlsF32 { cond: Cond, sd: Sreg, sn: Sreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Negate Multiply Subtract Floating-point 64-bit
     VnmlsF64 { cond: Cond, dd: Dreg, dn: Dreg, dm: Dreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Negate Multiply Floating-point 32-bit
     VnmulF32 { cond: Cond, sd: Sreg, sn: Sreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Negate Multiply Floating-point 64-bit
     VnmulF64 { cond: Cond, dd: Dreg, dn: Dreg, dm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise OR NOT
+    Vorn { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise OR
+    Vorr { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Bitwise OR immediate
+    VorrIImm { cond: Cond, dt: NeonIntType, vd: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Pairwise Add and Accumulate Long
+    Vpadal { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Pairwise Add floating-point
+    VpaddF32 { cond: Cond, vd: Dreg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Pairwise Add integer
+    VpaddI { cond: Cond, dt: NeonIntType, vd: Dreg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Pairwise Add Long
+    Vpaddl { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Pairwise Maximum integer
+    Vpmax { cond: Cond, dt: NeonSignType, vd: Dreg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Pairwise Maximum floating-point
+    VpmaxF32 { cond: Cond, vd: Dreg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Pairwise Minimum integer
+    Vpmin { cond: Cond, dt: NeonSignType, vd: Dreg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Pairwise Minimum floating-point
+    VpminF32 { cond: Cond, vd: Dreg, vn: Dreg, vm: Dreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Pop Floating-point 32-bit
     VpopF32 { cond: Cond, regs: SregList },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Pop Floating-point 64-bit
     VpopF64 { cond: Cond, regs: DregList },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Push Floating-point 32-bit
     VpushF32 { cond: Cond, regs: SregList },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Push Floating-point 64-bit
     VpushF64 { cond: Cond, regs: DregList },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Absolute
+    Vqabs { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Add
+    Vqadd { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Doubling Multiply Accumulate Long
+    Vqdmlal { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Doubling Multiply Accumulate Long by scalar
+    VqdmlalScalar {
+        cond: Cond,
+        dt: NeonSignType,
+        vd: NeonReg,
+        vn: Dreg,
+        dm: Dreg,
+        index: u32,
+    },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Doubling Multiply Subtract Long
+    Vqdmlsl { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Doubling Multiply Subtract Long by scalar
+    VqdmlslScalar {
+        cond: Cond,
+        dt: NeonSignType,
+        vd: NeonReg,
+        vn: Dreg,
+        dm: Dreg,
+        index: u32,
+    },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Doubling Multiply returning High half
+    Vqdmulh { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Doubling Multiply returning High half by scalar
+    VqdmulhScalar {
+        cond: Cond,
+        dt: NeonSignType,
+        vd: NeonReg,
+        vn: NeonReg,
+        dm: Dreg,
+        index: u32,
+    },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Doubling Multiply Long
+    Vqdmull { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Doubling Multiply Long by scalar
+    VqdmullScalar {
+        cond: Cond,
+        dt: NeonSignType,
+        vd: NeonReg,
+        vn: Dreg,
+        dm: Dreg,
+        index: u32,
+    },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Move and Narrow
+    Vqmovn { cond: Cond, dt: NeonSignType, vd: Dreg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Move and Narrow, signed operand with Unsigned result
+    Vqmovun { cond: Cond, dt: NeonSignType, vd: Dreg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Negate
+    Vqneg { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Rounding Doubling Multiply returning High half
+    Vqrdmulh { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Rounding Doubling Multiply returning High half by scalar
+    VqrdmulhScalar {
+        cond: Cond,
+        dt: NeonSignType,
+        vd: NeonReg,
+        vn: NeonReg,
+        dm: Dreg,
+        index: u32,
+    },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Rounding Shift Left by register
+    Vqrshl { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Rounding Shift Right Narrow
+    Vqrshrn { cond: Cond, dt: NeonSignType, vd: Dreg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Rounding Shift Right, Narrow, signed operand with Unsigned result
+    Vqrshrun { cond: Cond, dt: NeonSignType, vd: Dreg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Shift Left by register
+    Vqshl { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Shift Left by immediate
+    VqshlImm { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Shift Left, signed operand with Unsigned result
+    Vqshlu { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Shift Right Narrow
+    Vqshrn { cond: Cond, dt: NeonSignType, vd: Dreg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Shift Right, Narrow, signed operand with Unsigned result
+    Vqshrun { cond: Cond, dt: NeonSignType, vd: Dreg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Saturating Subtract
+    Vqsub { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Rounding Add and Narrow returning High half
+    Vraddhn { cond: Cond, dt: NeonIntType, vd: Dreg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Reciprocal Estimate integer
+    Vrecpe { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Reciprocal Estimate floating-point
+    VrecpeF32 { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Reciprocal Step
+    Vrecps { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Reverse in halfwords
+    Vrev16 { cond: Cond, dt: NeonSize, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Reverse in words
+    Vrev32 { cond: Cond, dt: NeonSize, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Reverse in doublewords
+    Vrev64 { cond: Cond, dt: NeonSize, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Rounding Halving Add
+    Vrhadd { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Rounding Shift Left by register
+    Vrshl { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Rounding Shift Right
+    Vrshr { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Rounding Shift Right Narrow
+    Vrshrn { cond: Cond, dt: NeonIntType, vd: Dreg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Reciprocal Square Root Estimate integer
+    Vrsqrte { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Reciprocal Square Root Estimate floating-point
+    VrsqrteF32 { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Reciprocal Square Root Step
+    Vrsqrts { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Rounding Shift Right and Accumulate
+    Vrsra { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Rounding Subtract and Narrow returning High half
+    Vrsubhn { cond: Cond, dt: NeonIntType, vd: Dreg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Shift Left by register
+    Vshl { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Shift Left by immediate
+    VshlImm { cond: Cond, dt: NeonIntType, vd: NeonReg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Shift Left Long
+    Vshll { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: Dreg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Shift Left Long by the element size
+    VshllMax { cond: Cond, dt: NeonIntType, vd: NeonReg, vm: Dreg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Shift Right
+    Vshr { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Shift Right Narrow
+    Vshrn { cond: Cond, dt: NeonIntType, vd: Dreg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Shift Left and Insert
+    Vsli { cond: Cond, dt: NeonSize, vd: NeonReg, vm: NeonReg, imm: u32 },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Square Root Floating-point 32-bit
     VsqrtF32 { cond: Cond, sd: Sreg, sm: Sreg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Square Root Floating-point 64-bit
     VsqrtF64 { cond: Cond, dd: Dreg, dm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Shift Right and Accumulate
+    Vsra { cond: Cond, dt: NeonSignType, vd: NeonReg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Shift Right and Insert
+    Vsri { cond: Cond, dt: NeonSize, vd: NeonReg, vm: NeonReg, imm: u32 },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Store single elements
+    Vst1 { cond: Cond, size: NeonSize, regs: NeonRegList, addr: NeonAddr },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Store 2-element structures
+    Vst2 { cond: Cond, size: NeonSize, regs: NeonRegList, addr: NeonAddr },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Store 3-element structures
+    Vst3 { cond: Cond, size: NeonSize, regs: NeonRegList, addr: NeonAddr },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Store 4-element structures
+    Vst4 { cond: Cond, size: NeonSize, regs: NeonRegList, addr: NeonAddr },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Store Multiple Floating-point 32-bit
     VstmF32 { mode: VldmVstmMode, cond: Cond, rn: Reg, writeback: bool, regs: SregList },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Store Multiple Floating-point 64-bit
     VstmF64 { mode: VldmVstmMode, cond: Cond, rn: Reg, writeback: bool, regs: DregList },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Store Register Floating-point 32-bit
     VstrF32 { cond: Cond, sd: Sreg, addr: AddrLdrStr },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Store Register Floating-point 64-bit
     VstrF64 { cond: Cond, dd: Dreg, addr: AddrLdrStr },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Subtract 32-bit
     VsubF32 { cond: Cond, sd: Sreg, sn: Sreg, sm: Sreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Subtract floating-point
+    VsubF32Neon { cond: Cond, vd: NeonReg, vn: NeonReg, vm: NeonReg },
     #[cfg(
-        all(
-            feature = "arm",
-            feature = "vfp_v2",
-            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+        any(
+            all(
+                feature = "arm",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(
+                    feature = "v5te",
+                    feature = "v5tej",
+                    feature = "v6",
+                    feature = "v6k",
+                    feature = "v7"
+                )
+            ),
+            all(
+                feature = "thumb",
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                feature = "v7"
+            )
         )
     )]
     ///Vector Subtract 64-bit
     VsubF64 { cond: Cond, dd: Dreg, dn: Dreg, dm: Dreg },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Subtract integer
+    VsubI { cond: Cond, dt: NeonIntType, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Subtract and Narrow returning High half
+    Vsubhn { cond: Cond, dt: NeonIntType, vd: Dreg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Subtract Long
+    Vsubl { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: Dreg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Subtract Wide
+    Vsubw { cond: Cond, dt: NeonSignType, vd: NeonReg, vn: NeonReg, vm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Swap
+    Vswp { cond: Cond, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Table Lookup
+    Vtbl { cond: Cond, dd: Dreg, regs: NeonRegList, dm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Table Extension
+    Vtbx { cond: Cond, dd: Dreg, regs: NeonRegList, dm: Dreg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Transpose
+    Vtrn { cond: Cond, dt: NeonSize, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Test Bits
+    Vtst { cond: Cond, dt: NeonSize, vd: NeonReg, vn: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Unzip
+    Vuzp { cond: Cond, dt: NeonSize, vd: NeonReg, vm: NeonReg },
+    #[cfg(all(feature = "neon", feature = "v7"))]
+    ///Vector Zip
+    Vzip { cond: Cond, dt: NeonSize, vd: NeonReg, vm: NeonReg },
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Wait For Event
     Wfe { cond: Cond },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Wait For Interrupt
     Wfi { cond: Cond },
-    #[cfg(all(feature = "arm", feature = "v6k"))]
+    #[cfg(
+        any(
+            all(feature = "arm", any(feature = "v6k", feature = "v7")),
+            all(feature = "thumb", feature = "v7")
+        )
+    )]
     ///Yield
     Yield { cond: Cond },
     Word(u32),
@@ -0,0 +1,34 @@
+/// Expands the 12-bit modified immediate of a Thumb-2 data-processing instruction, which is either
+/// a byte repeated in a fixed pattern or a rotated 8-bit value with its top bit set.
+pub(crate) fn thumb_expand_imm(imm12: u32) -> u32 {
+    let imm8 = imm12 & 0xff;
+    if imm12 & 0xc00 != 0 {
+        return (0x80 | (imm12 & 0x7f)).rotate_right(imm12 >> 7);
+    }
+    match (imm12 >> 8) & 0x3 {
+        0 => imm8,
+        1 => imm8 * 0x00010001,
+        2 => imm8 * 0x01000100,
+        _ => imm8 * 0x01010101,
+    }
+}
+
+/// Inverse of [`thumb_expand_imm`], returns `None` if `value` has no modified immediate encoding.
+pub(crate) fn thumb_encode_imm(value: u32) -> Option<u32> {
+    let low = value & 0xff;
+    let high = (value >> 8) & 0xff;
+    if value == low {
+        Some(low)
+    } else if value == low * 0x00010001 {
+        Some(0x100 | low)
+    } else if value == high * 0x01000100 {
+        Some(0x200 | high)
+    } else if value == low * 0x01010101 {
+        Some(0x300 | low)
+    } else {
+        (8..32).find_map(|rotate| {
+            let imm = value.rotate_left(rotate);
+            (imm & !0x7f == 0x80).then_some((rotate << 7) | (imm & 0x7f))
+        })
+    }
+}
@@ -1,7 +1,216 @@
-use crate::Ins;
+use crate::*;
 
 impl Ins {
     pub fn discriminant(&self) -> u16 {
-        unsafe { *(self as *const Self as *const u16) }
+        self.opcode() as u16
+    }
+
+    /// Returns the registers and flags this instruction defines, like [`Ins::defs`] but with
+    /// register lists expanded.
+    pub fn def_set(&self) -> RegSet {
+        self.defs().into()
+    }
+
+    /// Returns the registers and flags this instruction uses, like [`Ins::uses`] but with
+    /// register lists expanded.
+    pub fn use_set(&self) -> RegSet {
+        self.uses().into()
+    }
+
+    /// Returns the bits of `code` which depend on where the instruction or its target is
+    /// located, such as branch offsets and PC-relative load offsets. The remaining bits are the
+    /// same for this instruction at any address, which is useful for signatures and binary diffs.
+    /// This instruction must be the result of parsing `code` in `mode` with `options`, and 32-bit
+    /// Thumb codes have the first halfword in the lower 16 bits.
+    pub fn wildcard_mask(&self, code: u32, mode: ParseMode, options: &Options) -> u32 {
+        let masks = match mode {
+            #[cfg(feature = "arm")]
+            ParseMode::Arm => arm_param_masks(code, self.opcode(), options),
+            #[cfg(feature = "thumb")]
+            ParseMode::Thumb => thumb_param_masks(code, self.opcode(), options),
+            ParseMode::Data => None,
+        };
+        let Some(masks) = masks else {
+            return 0;
+        };
+        if self.pc_relative_access(0, mode).is_some() {
+            masks.address | masks.immediate
+        } else {
+            masks.address
+        }
+    }
+
+    /// Returns the address which this instruction loads from or computes relative to the PC, such
+    /// as `ldr r0, [pc, #0x10]` or `add r0, pc, #0x10`. The `pc` is the address of this
+    /// instruction, which reads the PC as `pc + 8` in ARM and `Align(pc, 4) + 4` in Thumb.
+    pub fn pc_relative_address(&self, pc: u32, mode: ParseMode) -> Option<u32> {
+        self.pc_relative_access(pc, mode).map(|(addr, _)| addr)
+    }
+
+    pub(crate) fn pc_relative_access(
+        &self,
+        pc: u32,
+        mode: ParseMode,
+    ) -> Option<(u32, LiteralKind)> {
+        let base = match mode {
+            #[cfg(feature = "arm")]
+            ParseMode::Arm => pc.wrapping_add(8),
+            #[cfg(feature = "thumb")]
+            ParseMode::Thumb => (pc & !3).wrapping_add(4),
+            ParseMode::Data => return None,
+        };
+        let (offset, kind) = match *self {
+            Ins::Add { rn: Reg::Pc, op2: Op2::Imm(imm), .. } => (imm as i32, LiteralKind::Address),
+            Ins::Sub { rn: Reg::Pc, op2: Op2::Imm(imm), .. } => {
+                ((imm as i32).wrapping_neg(), LiteralKind::Address)
+            }
+            Ins::Ldr { addr, .. } => (addr.pc_offset()?, LiteralKind::Word),
+            Ins::Ldrb { addr, .. } => (addr.pc_offset()?, LiteralKind::Byte),
+            Ins::Ldrh { addr, .. } => (addr.pc_offset()?, LiteralKind::HalfWord),
+            Ins::Ldrsb { addr, .. } => (addr.pc_offset()?, LiteralKind::SignedByte),
+            Ins::Ldrsh { addr, .. } => (addr.pc_offset()?, LiteralKind::SignedHalfWord),
+            #[cfg(any(
+                all(
+                    feature = "arm",
+                    any(
+                        feature = "v5te",
+                        feature = "v5tej",
+                        feature = "v6",
+                        feature = "v6k",
+                        feature = "v7"
+                    )
+                ),
+                all(feature = "thumb", feature = "v7")
+            ))]
+            Ins::Ldrd { addr, .. } => (addr.pc_offset()?, LiteralKind::DoubleWord),
+            #[cfg(any(
+                all(
+                    feature = "arm",
+                    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                    any(
+                        feature = "v5te",
+                        feature = "v5tej",
+                        feature = "v6",
+                        feature = "v6k",
+                        feature = "v7"
+                    )
+                ),
+                all(
+                    feature = "thumb",
+                    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                    feature = "v7"
+                )
+            ))]
+            Ins::VldrF32 { addr, .. } => (addr.pc_offset()?, LiteralKind::Word),
+            #[cfg(any(
+                all(
+                    feature = "arm",
+                    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                    any(
+                        feature = "v5te",
+                        feature = "v5tej",
+                        feature = "v6",
+                        feature = "v6k",
+                        feature = "v7"
+                    )
+                ),
+                all(
+                    feature = "thumb",
+                    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                    feature = "v7"
+                )
+            ))]
+            Ins::VldrF64 { addr, .. } => (addr.pc_offset()?, LiteralKind::DoubleWord),
+            _ => return None,
+        };
+        Some((base.wrapping_add_signed(offset), kind))
+    }
+}
+
+/// Bits of an encoding which are parsed into its parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ParamMasks {
+    /// Bits of values computed from the PC, such as branch targets
+    pub address: u32,
+    /// Bits of immediate values
+    pub immediate: u32,
+}
+
+/// What a PC-relative instruction does with its address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LiteralKind {
+    /// The address itself is the result, like ADR
+    Address,
+    Byte,
+    SignedByte,
+    HalfWord,
+    SignedHalfWord,
+    Word,
+    #[cfg(any(
+        all(
+            feature = "arm",
+            any(
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            )
+        ),
+        all(feature = "thumb", feature = "v7")
+    ))]
+    DoubleWord,
+}
+
+impl LiteralKind {
+    /// Number of bytes loaded from the address.
+    #[cfg(all(feature = "arm", feature = "thumb"))]
+    pub(crate) fn size(self) -> u32 {
+        match self {
+            LiteralKind::Address => 0,
+            LiteralKind::Byte | LiteralKind::SignedByte => 1,
+            LiteralKind::HalfWord | LiteralKind::SignedHalfWord => 2,
+            LiteralKind::Word => 4,
+            #[cfg(any(
+                all(
+                    feature = "arm",
+                    any(
+                        feature = "v5te",
+                        feature = "v5tej",
+                        feature = "v6",
+                        feature = "v6k",
+                        feature = "v7"
+                    )
+                ),
+                all(feature = "thumb", feature = "v7")
+            ))]
+            LiteralKind::DoubleWord => 8,
+        }
+    }
+}
+
+impl AddrLdrStr {
+    fn pc_offset(&self) -> Option<i32> {
+        match *self {
+            AddrLdrStr::Pre {
+                rn: Reg::Pc,
+                offset: LdrStrOffset::Imm(offset),
+                writeback: false,
+            } => Some(offset),
+            _ => None,
+        }
+    }
+}
+
+impl AddrMiscLoad {
+    fn pc_offset(&self) -> Option<i32> {
+        match *self {
+            AddrMiscLoad::Pre {
+                rn: Reg::Pc,
+                offset: MiscLoadOffset::Imm(offset),
+                writeback: false,
+            } => Some(offset),
+            _ => None,
+        }
     }
 }
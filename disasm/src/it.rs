@@ -0,0 +1,117 @@
+use alloc::{vec, vec::Vec};
+
+use crate::*;
+
+/// Mnemonic suffix for IT, specifies which of the following instructions use the condition or its inverse
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItMask(u8);
+
+impl ItMask {
+    /// Parses the lower 5 bits of an IT instruction, which are the mask and the lowest bit of the
+    /// first condition.
+    pub fn parse(value: u32) -> Self {
+        Self((value & 0x1f) as u8)
+    }
+
+    pub(crate) fn encode(&self) -> u32 {
+        self.0 as u32
+    }
+
+    pub(crate) fn parse_asm<'a>(s: &'a str, _options: &Options) -> Vec<(Self, &'a str)> {
+        let len = s.find(|c: char| !matches!(c, 't' | 'T' | 'e' | 'E')).unwrap_or(s.len());
+        if len > 3 {
+            return Vec::new();
+        }
+        let (letters, rest) = s.split_at(len);
+        // The letters are relative to the first condition, so both values of its lowest bit are
+        // possible
+        let masks = [0, 1].map(|firstcond| {
+            let mut mask = 1 << (3 - len);
+            for (i, c) in letters.chars().enumerate() {
+                let then = c.eq_ignore_ascii_case(&'t');
+                mask |= ((then as u8) ^ firstcond ^ 1) << (3 - i);
+            }
+            (Self((firstcond << 4) | mask), rest)
+        });
+        vec![masks[0], masks[1]]
+    }
+
+    /// Number of instructions in the IT block, including the one right after the IT instruction.
+    pub fn count(&self) -> u32 {
+        4 - (self.0 & 0xf).trailing_zeros().min(4)
+    }
+
+    /// Returns true if instruction `index` of the IT block executes on the first condition, or false
+    /// if it executes on the inverse condition.
+    pub fn is_then(&self, index: u32) -> bool {
+        index == 0 || ((self.0 >> (4 - index)) & 1) == (self.0 >> 4)
+    }
+
+    pub fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        for index in 1..self.count() {
+            formatter.write_str(if self.is_then(index) { "t" } else { "e" })?;
+        }
+        Ok(())
+    }
+}
+
+/// Execution state of an IT block, which corresponds to the ITSTATE bits of the CPSR. The upper 3
+/// bits are the base condition and the lower 5 bits are the condition bit and mask of the next
+/// instruction.
+#[derive(Debug, Clone, Copy, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct ItState(u8);
+
+impl ItState {
+    /// State outside of an IT block.
+    pub const fn none() -> Self {
+        Self(0)
+    }
+
+    /// State of the first instruction after an IT instruction.
+    pub fn from_it(firstcond: Cond, mask: ItMask) -> Self {
+        Self(((firstcond as u8) & 0xe) << 4 | mask.0)
+    }
+
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns true if the next instruction is inside an IT block.
+    pub const fn in_block(&self) -> bool {
+        self.0 & 0xf != 0
+    }
+
+    /// Number of instructions left in the IT block, including the next one.
+    pub fn remaining(&self) -> u32 {
+        4 - (self.0 & 0xf).trailing_zeros().min(4)
+    }
+
+    /// Condition of the next instruction, or `None` if it's outside of an IT block.
+    pub fn cond(&self) -> Option<Cond> {
+        if !self.in_block() {
+            return None;
+        }
+        match self.0 >> 4 {
+            // Inverting AL is unpredictable, so treat it as AL
+            0xf => Some(Cond::Al),
+            cond => Some(Cond::parse(cond as u32, 0)),
+        }
+    }
+
+    /// Moves on to the next instruction of the IT block.
+    pub fn advance(&mut self) {
+        if self.0 & 0x7 == 0 {
+            self.0 = 0;
+        } else {
+            self.0 = (self.0 & 0xe0) | ((self.0 << 1) & 0x1f);
+        }
+    }
+}
@@ -1,16 +1,72 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+mod access;
+mod asm;
+#[cfg(all(feature = "arm", feature = "thumb"))]
+mod cfg;
 mod defs_uses;
+#[cfg(all(feature = "arm", feature = "thumb"))]
+mod discovery;
+#[cfg(feature = "elf")]
+mod elf;
+#[cfg(all(feature = "arm", feature = "thumb"))]
+mod emu;
+mod encode;
+mod flow;
 mod fmt;
 mod generated;
+#[cfg(all(feature = "thumb", feature = "v7"))]
+mod imm;
 mod ins;
+#[cfg(all(feature = "thumb", feature = "v7"))]
+mod it;
+#[cfg(all(feature = "neon", feature = "v7"))]
+mod neon;
+mod opcode;
+mod operands;
 mod parser;
 mod reg_list;
+mod reg_set;
+mod reloc;
+mod signature;
+mod symbols;
+mod values;
+#[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"), feature = "v7"))]
+mod vfp;
 
+pub use access::*;
+pub use asm::*;
+#[cfg(all(feature = "arm", feature = "thumb"))]
+pub use cfg::*;
 pub use defs_uses::*;
+#[cfg(all(feature = "arm", feature = "thumb"))]
+pub use discovery::*;
+#[cfg(feature = "elf")]
+pub use elf::*;
+#[cfg(all(feature = "arm", feature = "thumb"))]
+pub use emu::*;
+pub use encode::*;
+pub use flow::*;
 pub use fmt::*;
 pub use generated::*;
+#[cfg(all(feature = "thumb", feature = "v7"))]
+pub(crate) use imm::*;
+#[cfg(all(feature = "thumb", feature = "v7"))]
+pub use it::*;
+#[cfg(all(feature = "neon", feature = "v7"))]
+pub use neon::*;
+pub use opcode::*;
+pub use operands::*;
 pub use parser::*;
 pub use reg_list::*;
+pub use reg_set::*;
+pub use reloc::*;
+pub use signature::*;
+pub use symbols::*;
+pub use values::*;
+#[cfg(all(any(feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"), feature = "v7"))]
+pub use vfp::*;
@@ -0,0 +1,50 @@
+use alloc::{vec, vec::Vec};
+
+use crate::*;
+
+/// 64-bit immediate of VMOV, an 8-bit value where each bit is expanded to a byte of ones or zeros
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NeonImm64(u8);
+
+impl NeonImm64 {
+    pub fn parse(value: u32) -> Self {
+        Self((value & 0xff) as u8)
+    }
+
+    pub(crate) fn encode(&self) -> u32 {
+        self.0 as u32
+    }
+
+    pub(crate) fn parse_asm<'a>(s: &'a str, _options: &Options) -> Vec<(Self, &'a str)> {
+        let (digits, radix) = match s.get(..2) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("0x") => (&s[2..], 16),
+            _ => (s, 10),
+        };
+        let end = digits.find(|c: char| !c.is_digit(radix)).unwrap_or(digits.len());
+        let Ok(value) = u64::from_str_radix(&digits[..end], radix) else {
+            return Vec::new();
+        };
+        match (0..=0xff).map(Self).find(|imm| imm.value() == value) {
+            Some(imm) => vec![(imm, &digits[end..])],
+            None => Vec::new(),
+        }
+    }
+
+    /// The 8-bit encoded immediate.
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// The expanded 64-bit value, where bit `n` of [`Self::bits`] sets byte `n`.
+    pub fn value(&self) -> u64 {
+        (0..8).filter(|i| self.0 & (1 << i) != 0).map(|i| 0xff << (i * 8)).sum()
+    }
+
+    pub fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        write!(formatter, "{:#x}", self.value())
+    }
+}
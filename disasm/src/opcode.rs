@@ -0,0 +1,44 @@
+use crate::*;
+
+/// Fixed bits of one encoding of an [`Opcode`], see [`Opcode::encodings`]. An instruction matching
+/// the pattern may still belong to a more specific opcode or be illegal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Encoding {
+    pub mode: ParseMode,
+    /// Bits which are fixed by this encoding. For 32-bit Thumb encodings, the first halfword is in
+    /// the lower 16 bits.
+    pub mask: u32,
+    pub value: u32,
+    /// Size in bytes
+    pub size: u32,
+    pub versions: Versions,
+    /// Extensions of which any must be enabled, or [`Extensions::none`] if none are required
+    pub extensions: Extensions,
+}
+
+impl Encoding {
+    pub fn matches(&self, code: u32) -> bool {
+        code & self.mask == self.value
+    }
+
+    /// Returns whether this encoding is parsed with the given options.
+    pub fn is_supported(&self, options: &Options) -> bool {
+        self.versions.has(options.version)
+            && (self.extensions == Extensions::none()
+                || options.extensions.has_any(self.extensions))
+    }
+}
+
+impl Opcode {
+    /// Returns whether this opcode has any encoding in the given mode.
+    pub fn has_mode(self, mode: ParseMode) -> bool {
+        self.encodings().iter().any(|encoding| encoding.mode == mode)
+    }
+
+    /// Returns whether any encoding of this opcode is parsed in the given mode and options.
+    pub fn is_supported(self, mode: ParseMode, options: &Options) -> bool {
+        self.encodings()
+            .iter()
+            .any(|encoding| encoding.mode == mode && encoding.is_supported(options))
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{MAX_OPERANDS, Operand, Reg};
+
+/// Operands of an instruction in display order, see [`crate::Ins::operands`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Operands {
+    operands: [Operand; MAX_OPERANDS],
+    len: usize,
+    pos: usize,
+}
+
+impl Operands {
+    pub(crate) fn new() -> Self {
+        Self { operands: [Operand::Reg(Reg::R0); MAX_OPERANDS], len: 0, pos: 0 }
+    }
+
+    pub(crate) fn push(&mut self, operand: Operand) {
+        self.operands[self.len] = operand;
+        self.len += 1;
+    }
+
+    /// Returns the operands which have not been iterated yet.
+    pub fn as_slice(&self) -> &[Operand] {
+        &self.operands[self.pos..self.len]
+    }
+}
+
+impl Iterator for Operands {
+    type Item = Operand;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let operand = self.as_slice().first().copied()?;
+        self.pos += 1;
+        Some(operand)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.pos;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Operands {}
@@ -1,6 +1,6 @@
-use crate::*;
+use crate::{ins::LiteralKind, *};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ParseMode {
     #[cfg(feature = "arm")]
     Arm,
@@ -20,6 +20,16 @@ impl ParseMode {
             _ => None,
         }
     }
+
+    pub fn mapping_symbol(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "arm")]
+            ParseMode::Arm => "$a",
+            #[cfg(feature = "thumb")]
+            ParseMode::Thumb => "$t",
+            ParseMode::Data => "$d",
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -35,6 +45,10 @@ pub struct Parser<'a> {
     endian: ParseEndian,
     pc: u32,
     offset: usize,
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    it_state: ItState,
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    in_it_block: bool,
 }
 
 impl<'a> Parser<'a> {
@@ -44,7 +58,18 @@ impl<'a> Parser<'a> {
         endian: ParseEndian,
         options: Options,
     ) -> Self {
-        Self { bytes, options, mode, endian, pc: 0, offset: 0 }
+        Self {
+            bytes,
+            options,
+            mode,
+            endian,
+            pc: 0,
+            offset: 0,
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            it_state: ItState::none(),
+            #[cfg(all(feature = "thumb", feature = "v7"))]
+            in_it_block: false,
+        }
     }
 
     pub fn mode(&self) -> ParseMode {
@@ -79,9 +104,30 @@ impl<'a> Parser<'a> {
         self.endian = endianness;
     }
 
+    /// IT block state of the next Thumb instruction. To resume parsing in the middle of an IT block,
+    /// save this along with [`Self::offset`] and restore both with [`Self::set_offset`] and
+    /// [`Self::set_it_state`].
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    pub fn it_state(&self) -> ItState {
+        self.it_state
+    }
+
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    pub fn set_it_state(&mut self, it_state: ItState) {
+        self.it_state = it_state;
+    }
+
+    /// Returns true if the last parsed instruction was inside an IT block, in which case its
+    /// condition was taken from the IT instruction.
+    #[cfg(all(feature = "thumb", feature = "v7"))]
+    pub fn in_it_block(&self) -> bool {
+        self.in_it_block
+    }
+
+    /// Moves to `offset` in either direction, adjusting the PC by the same amount.
     pub fn goto_offset(&mut self, offset: usize) {
         let new_offset = offset.min(self.bytes.len());
-        let delta = new_offset.saturating_sub(self.offset);
+        let delta = new_offset.wrapping_sub(self.offset);
         self.offset = new_offset;
         self.pc = self.pc.wrapping_add(delta as u32);
     }
@@ -91,6 +137,31 @@ impl<'a> Parser<'a> {
         self.offset = self.offset.wrapping_add(delta);
         self.pc = self.pc.wrapping_add(delta as u32);
     }
+
+    /// Reads the literal loaded by a PC-relative load such as `ldr r0, [pc, #0x10]`, where `addr`
+    /// is the address of `ins`. Bytes and halfwords are zero- or sign-extended like the load would.
+    /// Returns `None` if `ins` is not a 32-bit or smaller PC-relative load, or if the literal is
+    /// outside of the parsed bytes.
+    pub fn read_literal(&self, ins: &Ins, addr: u32) -> Option<u32> {
+        let (literal, kind) = ins.pc_relative_access(addr, self.mode)?;
+        let base = self.pc.wrapping_sub(self.offset as u32);
+        let offset = literal.wrapping_sub(base) as usize;
+        if offset >= self.bytes.len() {
+            return None;
+        }
+        let value = match kind {
+            LiteralKind::Byte => *self.bytes.get(offset)? as u32,
+            LiteralKind::SignedByte => *self.bytes.get(offset)? as i8 as u32,
+            LiteralKind::HalfWord => bytes_to_u16(self.bytes, offset, self.endian)? as u32,
+            LiteralKind::SignedHalfWord => {
+                bytes_to_u16(self.bytes, offset, self.endian)? as i16 as u32
+            }
+            LiteralKind::Word => bytes_to_u32(self.bytes, offset, self.endian)?,
+            // Addresses aren't loaded and doublewords don't fit
+            _ => return None,
+        };
+        Some(value)
+    }
 }
 
 impl<'a> Iterator for Parser<'a> {
@@ -123,9 +194,21 @@ impl<'a> Iterator for Parser<'a> {
                 let second = bytes_to_u16(self.bytes, start + 2, self.endian()).unwrap_or(0) as u32;
                 let code = first | (second << 16);
 
-                let (ins, size) = parse_thumb(code, self.pc, &self.options);
+                #[allow(unused_mut)]
+                let (mut ins, size) = parse_thumb(code, self.pc, &self.options);
                 self.jump(size as isize);
 
+                #[cfg(feature = "v7")]
+                {
+                    self.in_it_block = self.it_state.in_block();
+                    if let Some(cond) = self.it_state.cond() {
+                        ins.set_it_cond(cond, size == 2);
+                        self.it_state.advance();
+                    } else if let Ins::It { firstcond, mask } = ins {
+                        self.it_state = ItState::from_it(firstcond, mask);
+                    }
+                }
+
                 Some(ins)
             }
             ParseMode::Data => {
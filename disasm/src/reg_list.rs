@@ -1,7 +1,11 @@
+use alloc::{vec, vec::Vec};
+
 use crate::*;
 
 /// List of general-purpose registers, used by LDM/STM
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "Vec<Reg>", from = "Vec<Reg>"))]
 pub struct RegList(u16);
 
 impl RegList {
@@ -22,6 +26,21 @@ impl RegList {
         Self(value as u16)
     }
 
+    pub(crate) fn encode(&self) -> u32 {
+        self.0 as u32
+    }
+
+    pub(crate) fn parse_asm<'a>(s: &'a str, options: &Options) -> Vec<(Self, &'a str)> {
+        let Some((regs, rest)) = parse_asm_list(s, |s| Reg::parse_asm(s, options)) else {
+            return Vec::new();
+        };
+        vec![(Self::of(&regs), rest)]
+    }
+
+    pub fn contains(&self, reg: Reg) -> bool {
+        (self.0 & (1 << reg as u16)) != 0
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = Reg> {
         (0..16).filter(|i| (self.0 & (1 << i)) != 0).map(|i| Reg::parse(i, 0))
     }
@@ -44,20 +63,33 @@ impl RegList {
     }
 }
 
+impl From<RegList> for Vec<Reg> {
+    fn from(value: RegList) -> Self {
+        value.iter().collect()
+    }
+}
+
+impl From<Vec<Reg>> for RegList {
+    fn from(value: Vec<Reg>) -> Self {
+        Self::of(&value)
+    }
+}
+
 #[cfg(all(
-    feature = "vfp_v2",
-    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
 ))]
 /// List of general-purpose single-precision floation-point registers, used by VLDM/VSTM
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SregList {
     start: u8,
     end: u8,
 }
 
 #[cfg(all(
-    feature = "vfp_v2",
-    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
 ))]
 impl SregList {
     pub fn range(first: Sreg, last: Sreg) -> Self {
@@ -66,10 +98,30 @@ impl SregList {
 
     pub fn parse(value: u32) -> Self {
         let start = (((value >> 22) & 0x1) | ((value >> 11) & 0x1e)) as u8;
-        let end = start.wrapping_add(value as u8).clamp(1, 31);
+        let end = start.saturating_add(value as u8).clamp(1, 32);
         Self { start, end }
     }
 
+    pub(crate) fn encode(&self) -> u32 {
+        let start = self.start as u32;
+        let count = self.end.wrapping_sub(self.start) as u32;
+        ((start & 0x1) << 22) | ((start & 0x1e) << 11) | count
+    }
+
+    pub(crate) fn parse_asm<'a>(s: &'a str, options: &Options) -> Vec<(Self, &'a str)> {
+        let Some((regs, rest)) = parse_asm_list(s, |s| Sreg::parse_asm(s, options)) else {
+            return Vec::new();
+        };
+        let (Some(&first), Some(&last)) = (regs.first(), regs.last()) else {
+            return vec![(Self { start: 0, end: 0 }, rest)];
+        };
+        // Only consecutive registers can be represented
+        if (first as usize..=last as usize).ne(regs.iter().map(|&reg| reg as usize)) {
+            return Vec::new();
+        }
+        vec![(Self::range(first, last), rest)]
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = Sreg> {
         (self.start..self.end).map(|i| Sreg::parse(i as u32, 0))
     }
@@ -93,19 +145,20 @@ impl SregList {
 }
 
 #[cfg(all(
-    feature = "vfp_v2",
-    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
 ))]
 /// List of general-purpose double-precision floation-point registers, used by VLDM/VSTM
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DregList {
     start: u8,
     end: u8,
 }
 
 #[cfg(all(
-    feature = "vfp_v2",
-    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k")
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
 ))]
 impl DregList {
     pub fn range(first: Dreg, last: Dreg) -> Self {
@@ -114,10 +167,30 @@ impl DregList {
 
     pub fn parse(value: u32) -> Self {
         let start = (((value >> 18) & 0x10) | ((value >> 12) & 0xf)) as u8;
-        let end = start.wrapping_add(((value & 0xfe) >> 1) as u8).clamp(1, 31);
+        let end = start.saturating_add(((value & 0xfe) >> 1) as u8).clamp(1, 32);
         Self { start, end }
     }
 
+    pub(crate) fn encode(&self) -> u32 {
+        let start = self.start as u32;
+        let count = self.end.wrapping_sub(self.start) as u32;
+        ((start & 0x10) << 18) | ((start & 0xf) << 12) | ((count << 1) & 0xfe)
+    }
+
+    pub(crate) fn parse_asm<'a>(s: &'a str, options: &Options) -> Vec<(Self, &'a str)> {
+        let Some((regs, rest)) = parse_asm_list(s, |s| Dreg::parse_asm(s, options)) else {
+            return Vec::new();
+        };
+        let (Some(&first), Some(&last)) = (regs.first(), regs.last()) else {
+            return vec![(Self { start: 0, end: 0 }, rest)];
+        };
+        // Only consecutive registers can be represented
+        if (first as usize..=last as usize).ne(regs.iter().map(|&reg| reg as usize)) {
+            return Vec::new();
+        }
+        vec![(Self::range(first, last), rest)]
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = Dreg> {
         (self.start..self.end).map(|i| Dreg::parse(i as u32, 0))
     }
@@ -139,3 +212,185 @@ impl DregList {
         Ok(())
     }
 }
+
+#[cfg(all(feature = "neon", feature = "v7"))]
+/// List of doubleword registers used by VLD1-VLD4/VST1-VST4 and VTBL/VTBX, either whole registers,
+/// one lane or all lanes
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NeonRegList {
+    start: u8,
+    count: u8,
+    spacing: u8,
+    elements: NeonElements,
+}
+
+#[cfg(all(feature = "neon", feature = "v7"))]
+/// Which elements of the registers in a [`NeonRegList`] are accessed
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NeonElements {
+    /// All elements of each register, e.g. `{d0, d1}`
+    Registers,
+    /// One lane of each register, e.g. `{d0[1], d1[1]}`
+    Lane(u8),
+    /// One element duplicated to all lanes of each register, e.g. `{d0[], d1[]}`
+    AllLanes,
+}
+
+#[cfg(all(feature = "neon", feature = "v7"))]
+impl NeonRegList {
+    /// Parses a list where bits 0..5 are the first register, bits 5..7 the number of registers
+    /// minus one, bit 8 the spacing between registers minus one, bits 9..11 the kind of
+    /// [`NeonElements`] and bits 12..15 the lane index. Returns `None` if the list extends past
+    /// d31.
+    pub fn parse(value: u32) -> Option<Self> {
+        let start = (value & 0x1f) as u8;
+        let count = ((value >> 5) & 0x3) as u8 + 1;
+        let spacing = ((value >> 8) & 0x1) as u8 + 1;
+        if start + (count - 1) * spacing > 31 {
+            return None;
+        }
+        let elements = match (value >> 9) & 0x3 {
+            0 => NeonElements::Registers,
+            1 => NeonElements::Lane(((value >> 12) & 0x7) as u8),
+            _ => NeonElements::AllLanes,
+        };
+        Some(Self { start, count, spacing, elements })
+    }
+
+    pub(crate) fn encode(&self) -> u32 {
+        let (kind, index) = match self.elements {
+            NeonElements::Registers => (0, 0),
+            NeonElements::Lane(index) => (1, index as u32),
+            NeonElements::AllLanes => (2, 0),
+        };
+        let count = (self.count as u32).wrapping_sub(1);
+        let spacing = (self.spacing as u32).wrapping_sub(1);
+        (self.start as u32 & 0x1f)
+            | ((count & 0x3) << 5)
+            | ((spacing & 0x1) << 8)
+            | (kind << 9)
+            | ((index & 0x7) << 12)
+    }
+
+    pub(crate) fn parse_asm<'a>(s: &'a str, options: &Options) -> Vec<(Self, &'a str)> {
+        let parse_element = |s: &'a str| {
+            Dreg::parse_asm(s, options)
+                .into_iter()
+                .filter_map(|(reg, rest)| {
+                    let (elements, rest) = parse_asm_elements(rest)?;
+                    Some(((reg, elements), rest))
+                })
+                .collect::<Vec<_>>()
+        };
+        let Some((regs, rest)) = parse_asm_list(s, parse_element) else {
+            return Vec::new();
+        };
+        let Some(&(first, elements)) = regs.first() else {
+            return Vec::new();
+        };
+        if regs.len() > 4 || regs.iter().any(|&(_, e)| e != elements) {
+            return Vec::new();
+        }
+        let spacing = match regs.get(1) {
+            Some(&(second, _)) => (second as u8).wrapping_sub(first as u8),
+            None => 1,
+        };
+        // Only evenly spaced registers can be represented
+        let first = first as u8;
+        if !matches!(spacing, 1 | 2)
+            || regs.iter().zip(0..).any(|(&(reg, _), i)| reg as u8 != first + i * spacing)
+        {
+            return Vec::new();
+        }
+        let count = regs.len() as u8;
+        vec![(Self { start: first, count, spacing, elements }, rest)]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Dreg> {
+        let Self { start, spacing, .. } = *self;
+        (0..self.count).map(move |i| Dreg::parse((start + i * spacing) as u32, 0))
+    }
+
+    pub fn elements(&self) -> NeonElements {
+        self.elements
+    }
+
+    /// Returns the number of `size` elements transferred by a VLDn/VSTn instruction with this
+    /// list, where `structures` is n.
+    pub(crate) fn element_count(&self, size: NeonSize, structures: u8) -> u8 {
+        match self.elements {
+            NeonElements::Registers => (self.count * 8) >> size as u8,
+            NeonElements::Lane(_) => self.count,
+            NeonElements::AllLanes => structures,
+        }
+    }
+
+    pub fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        let write_element = |formatter: &mut F, reg: Dreg| {
+            formatter.write_dreg(reg)?;
+            match self.elements {
+                NeonElements::Registers => Ok(()),
+                NeonElements::Lane(index) => {
+                    formatter.write_str("[")?;
+                    formatter.write_uimm(index as u32)?;
+                    formatter.write_str("]")
+                }
+                NeonElements::AllLanes => formatter.write_str("[]"),
+            }
+        };
+        formatter.write_str("{")?;
+        let mut iter = self.iter();
+        if let Some(reg) = iter.next() {
+            write_element(formatter, reg)?;
+        }
+        for reg in iter {
+            formatter.write_separator()?;
+            write_element(formatter, reg)?;
+        }
+        formatter.write_str("}")?;
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "neon", feature = "v7"))]
+/// Parses the optional lane suffix of a register in a [`NeonRegList`], such as `[1]` or `[]`.
+fn parse_asm_elements(s: &str) -> Option<(NeonElements, &str)> {
+    let Some(s) = asm_text(s, "[") else {
+        return Some((NeonElements::Registers, s));
+    };
+    if let Some(rest) = asm_text(s, "]") {
+        return Some((NeonElements::AllLanes, rest));
+    }
+    let (index, rest) = asm_uimm(s)?;
+    let rest = asm_text(rest, "]")?;
+    (index < 8).then_some((NeonElements::Lane(index as u8), rest))
+}
+
+/// Parses a list of registers in braces, such as `{r0, r1}`.
+fn parse_asm_list<'a, T>(
+    s: &'a str,
+    parse: impl Fn(&'a str) -> Vec<(T, &'a str)>,
+) -> Option<(Vec<T>, &'a str)> {
+    let mut s = asm_text(s, "{")?;
+    let mut regs = Vec::new();
+    if let Some(rest) = asm_text(s, "}") {
+        return Some((regs, rest));
+    }
+    loop {
+        // Registers can be prefixes of each other (e.g. r1 and r10), so pick the one followed by a
+        // separator or the end of the list
+        let (reg, rest) = parse(s)
+            .into_iter()
+            .find(|(_, rest)| asm_separator(rest).is_some() || asm_text(rest, "}").is_some())?;
+        regs.push(reg);
+        if let Some(rest) = asm_text(rest, "}") {
+            return Some((regs, rest));
+        }
+        s = asm_separator(rest)?;
+    }
+}
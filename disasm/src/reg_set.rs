@@ -0,0 +1,241 @@
+use core::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign},
+};
+
+use crate::*;
+
+const REG_SHIFT: u32 = 0;
+const SREG_SHIFT: u32 = 16;
+/// D16-D31, since D0-D15 are stored as their two S registers
+#[cfg(all(
+    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"),
+    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+))]
+const DREG_SHIFT: u32 = 48;
+const FLAG_SHIFT: u32 = 64;
+const FLAGS: [Flag; 6] = [Flag::N, Flag::Z, Flag::C, Flag::V, Flag::Q, Flag::Ge];
+
+/// Set of general-purpose registers, single and double-precision registers and status flags, see
+/// [`Ins::def_set`] and [`Ins::use_set`]. D0-D15 are added as the two S registers they overlap,
+/// and quadword registers as their two doubleword registers, so overlapping registers intersect.
+/// Status register fields of the CPSR are added as the flags they contain.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RegSet(u128);
+
+impl RegSet {
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Adds `arg` to the set. Register lists add all of their registers, and arguments which are
+    /// neither registers nor flags, such as the SPSR, are ignored.
+    pub fn insert<T>(&mut self, arg: T)
+    where
+        T: Into<DefUseArgument>,
+    {
+        self.0 |= Self::arg_bits(arg.into());
+    }
+
+    /// Removes all registers and flags of `arg` from the set.
+    pub fn remove<T>(&mut self, arg: T)
+    where
+        T: Into<DefUseArgument>,
+    {
+        self.0 &= !Self::arg_bits(arg.into());
+    }
+
+    /// Returns whether the set contains all registers and flags of `arg`.
+    pub fn contains<T>(&self, arg: T) -> bool
+    where
+        T: Into<DefUseArgument>,
+    {
+        let bits = Self::arg_bits(arg.into());
+        self.0 & bits == bits
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the registers and flags in this set which are not in `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub const fn is_disjoint(self, other: Self) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns the registers and flags in this set, in the order of general-purpose registers,
+    /// single-precision registers, double-precision registers D16-D31 and flags. D0-D15 are
+    /// returned as their S registers.
+    pub fn iter(self) -> impl Iterator<Item = DefUseArgument> {
+        (0..FLAG_SHIFT + FLAGS.len() as u32).filter(move |&i| self.0 & (1 << i) != 0).map(|i| {
+            match i {
+                REG_SHIFT..SREG_SHIFT => DefUseArgument::Reg(Reg::parse(i - REG_SHIFT, 0)),
+                #[cfg(all(
+                    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+                ))]
+                SREG_SHIFT..DREG_SHIFT => DefUseArgument::Sreg(Sreg::parse(i - SREG_SHIFT, 0)),
+                #[cfg(all(
+                    any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"),
+                    any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+                ))]
+                DREG_SHIFT..FLAG_SHIFT => DefUseArgument::Dreg(Dreg::parse(i - DREG_SHIFT + 16, 0)),
+                _ => DefUseArgument::Flag(FLAGS[(i - FLAG_SHIFT) as usize]),
+            }
+        })
+    }
+
+    fn flag_bits(flags: &[Flag]) -> u128 {
+        flags.iter().fold(0, |bits, &flag| bits | 1 << (FLAG_SHIFT + flag as u32))
+    }
+
+    fn arg_bits(arg: DefUseArgument) -> u128 {
+        #[cfg(all(
+            any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"),
+            any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+        ))]
+        let dreg = |dreg: Dreg| match dreg as u32 {
+            d @ 0..16 => 0b11 << (SREG_SHIFT + d * 2),
+            d => 1 << (DREG_SHIFT + d - 16),
+        };
+        match arg {
+            DefUseArgument::Reg(reg) => 1 << (REG_SHIFT + reg as u32),
+            #[cfg(all(
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+            ))]
+            DefUseArgument::Sreg(sreg) => 1 << (SREG_SHIFT + sreg as u32),
+            #[cfg(all(
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16", feature = "neon"),
+                any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+            ))]
+            DefUseArgument::Dreg(d) => dreg(d),
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            DefUseArgument::Qreg(qreg) => {
+                dreg(Dreg::parse(qreg as u32 * 2, 0)) | dreg(Dreg::parse(qreg as u32 * 2 + 1, 0))
+            }
+            DefUseArgument::RegList(regs) => (regs.encode() as u128) << REG_SHIFT,
+            #[cfg(all(
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+            ))]
+            DefUseArgument::SregList(sregs) => {
+                sregs.iter().fold(0, |bits, sreg| bits | 1 << (SREG_SHIFT + sreg as u32))
+            }
+            #[cfg(all(
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+            ))]
+            DefUseArgument::DregList(dregs) => dregs.iter().fold(0, |bits, d| bits | dreg(d)),
+            #[cfg(all(
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+            ))]
+            DefUseArgument::DregIndex(DregIndex { dreg: d, .. }) => dreg(d),
+            #[cfg(all(feature = "neon", feature = "v7"))]
+            DefUseArgument::NeonRegList(dregs) => dregs.iter().fold(0, |bits, d| bits | dreg(d)),
+            DefUseArgument::Flag(flag) => 1 << (FLAG_SHIFT + flag as u32),
+            DefUseArgument::StatusReg(StatusReg::Cpsr) => Self::flag_bits(&FLAGS),
+            DefUseArgument::StatusFields(StatusFields { reg: StatusReg::Cpsr, s, f, .. }) => {
+                let mut bits = 0;
+                if f {
+                    bits |= Self::flag_bits(&[Flag::N, Flag::Z, Flag::C, Flag::V, Flag::Q]);
+                }
+                if s {
+                    bits |= Self::flag_bits(&[Flag::Ge]);
+                }
+                bits
+            }
+            DefUseArgument::StatusReg(StatusReg::Spsr)
+            | DefUseArgument::StatusFields(_)
+            | DefUseArgument::CoReg(_) => 0,
+            #[cfg(all(
+                any(feature = "vfp_v2", feature = "vfp_v3", feature = "vfp_v3_d16"),
+                any(feature = "v5te", feature = "v5tej", feature = "v6", feature = "v6k", feature = "v7")
+            ))]
+            DefUseArgument::Fpscr(_) => 0,
+        }
+    }
+}
+
+impl fmt::Debug for RegSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<DefUseArgument> for RegSet {
+    fn from_iter<I: IntoIterator<Item = DefUseArgument>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|arg| set.insert(arg));
+        set
+    }
+}
+
+impl From<DefsUses> for RegSet {
+    fn from(value: DefsUses) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl BitOr for RegSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for RegSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for RegSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for RegSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl Sub for RegSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for RegSet {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
@@ -0,0 +1,87 @@
+/// Relocation of an instruction or data word in a relocatable object, see
+/// [`crate::SymbolFormatter::write_relocated_ins_at`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Relocation<'a> {
+    pub kind: RelocationKind,
+    /// Name of the symbol which the relocation refers to
+    pub symbol: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelocationKind {
+    /// `R_ARM_PC24`, ARM branch (deprecated)
+    Pc24,
+    /// `R_ARM_ABS32`, absolute 32-bit address
+    Abs32,
+    /// `R_ARM_REL32`, 32-bit offset from the relocated address
+    Rel32,
+    /// `R_ARM_THM_CALL`, Thumb BL or BLX
+    ThmCall,
+    /// `R_ARM_CALL`, ARM BL or BLX
+    Call,
+    /// `R_ARM_JUMP24`, ARM B or conditional BL
+    Jump24,
+    /// `R_ARM_THM_JUMP24`, Thumb-2 B
+    ThmJump24,
+    /// `R_ARM_THM_JUMP19`, Thumb-2 conditional B
+    ThmJump19,
+    /// `R_ARM_THM_JUMP11`, Thumb B
+    ThmJump11,
+    /// `R_ARM_THM_JUMP8`, Thumb conditional B
+    ThmJump8,
+    /// Any other relocation type
+    Other(u32),
+}
+
+impl RelocationKind {
+    /// Returns the kind of an ELF relocation type.
+    pub fn from_elf(r_type: u32) -> Self {
+        match r_type {
+            1 => Self::Pc24,
+            2 => Self::Abs32,
+            3 => Self::Rel32,
+            10 => Self::ThmCall,
+            28 => Self::Call,
+            29 => Self::Jump24,
+            30 => Self::ThmJump24,
+            51 => Self::ThmJump19,
+            102 => Self::ThmJump11,
+            103 => Self::ThmJump8,
+            _ => Self::Other(r_type),
+        }
+    }
+
+    /// Returns true if this relocates the target of a branch or call.
+    pub fn is_branch(&self) -> bool {
+        matches!(
+            self,
+            Self::Pc24
+                | Self::ThmCall
+                | Self::Call
+                | Self::Jump24
+                | Self::ThmJump24
+                | Self::ThmJump19
+                | Self::ThmJump11
+                | Self::ThmJump8
+        )
+    }
+}
+
+impl core::fmt::Display for RelocationKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            Self::Pc24 => "R_ARM_PC24",
+            Self::Abs32 => "R_ARM_ABS32",
+            Self::Rel32 => "R_ARM_REL32",
+            Self::ThmCall => "R_ARM_THM_CALL",
+            Self::Call => "R_ARM_CALL",
+            Self::Jump24 => "R_ARM_JUMP24",
+            Self::ThmJump24 => "R_ARM_THM_JUMP24",
+            Self::ThmJump19 => "R_ARM_THM_JUMP19",
+            Self::ThmJump11 => "R_ARM_THM_JUMP11",
+            Self::ThmJump8 => "R_ARM_THM_JUMP8",
+            Self::Other(r_type) => return write!(f, "R_ARM_{r_type}"),
+        };
+        f.write_str(name)
+    }
+}
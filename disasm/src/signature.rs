@@ -0,0 +1,199 @@
+use alloc::vec::Vec;
+
+use crate::*;
+
+/// A byte pattern where some bits are wildcards, used to find known code such as library
+/// functions in a code image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    mode: ParseMode,
+    /// Expected bytes, zero in the wildcard bits
+    bytes: Vec<u8>,
+    /// Bits of `bytes` which must match
+    mask: Vec<u8>,
+}
+
+impl Signature {
+    /// Creates a signature from machine code, where the bits of each instruction which depend on
+    /// its address are wildcards. See [`Ins::wildcard_mask`].
+    pub fn from_code(code: &[u8], mode: ParseMode, endian: ParseEndian, options: &Options) -> Self {
+        let mut signature = Self { mode, bytes: Vec::new(), mask: Vec::new() };
+        let mut parser = Parser::new(code, mode, endian, options.clone());
+        loop {
+            let start = parser.offset();
+            let Some(ins) = parser.next() else {
+                break;
+            };
+            let size = parser.offset() - start;
+            let value = read_code(&code[start..start + size], mode, endian);
+            let mask = ins.wildcard_mask(value, mode, options);
+            signature.push(value, !mask, size, mode, endian);
+        }
+        signature
+    }
+
+    /// Creates a signature by encoding instructions, starting at the address `pc`. Like
+    /// [`Signature::from_code`], the address-dependent bits are wildcards.
+    pub fn from_ins(
+        ins: &[Ins],
+        mut pc: u32,
+        mode: ParseMode,
+        endian: ParseEndian,
+        options: &Options,
+    ) -> Result<Self, EncodeError> {
+        let mut signature = Self { mode, bytes: Vec::new(), mask: Vec::new() };
+        for ins in ins {
+            let (value, size) = match mode {
+                #[cfg(feature = "arm")]
+                ParseMode::Arm => (ins.encode_arm(pc, options)?, 4),
+                #[cfg(feature = "thumb")]
+                ParseMode::Thumb => ins.encode_thumb(pc, options)?,
+                ParseMode::Data => match *ins {
+                    Ins::Word(value) => (value, 4),
+                    Ins::HalfWord(value) => (value as u32, 2),
+                    Ins::Byte(value) => (value as u32, 1),
+                    _ => return Err(EncodeError::NoEncoding),
+                },
+            };
+            let mask = ins.wildcard_mask(value, mode, options);
+            signature.push(value, !mask, size as usize, mode, endian);
+            pc = pc.wrapping_add(size);
+        }
+        Ok(signature)
+    }
+
+    /// Parses a signature from hex bytes in file order, such as `0048 ???? 7047`. A `?` is a
+    /// wildcard hex digit, and whitespace is ignored.
+    pub fn from_hex(hex: &str, mode: ParseMode) -> Result<Self, SignatureError> {
+        let mut signature = Self { mode, bytes: Vec::new(), mask: Vec::new() };
+        let mut digits = hex.chars().filter(|c| !c.is_whitespace());
+        while let Some(high) = digits.next() {
+            let low = digits.next().ok_or(SignatureError::OddLength)?;
+            let (high, high_mask) = parse_hex_digit(high)?;
+            let (low, low_mask) = parse_hex_digit(low)?;
+            signature.bytes.push((high << 4) | low);
+            signature.mask.push((high_mask << 4) | low_mask);
+        }
+        Ok(signature)
+    }
+
+    fn push(&mut self, value: u32, mask: u32, size: usize, mode: ParseMode, endian: ParseEndian) {
+        let value = value & mask;
+        let (bytes, mask) = match mode {
+            #[cfg(feature = "thumb")]
+            ParseMode::Thumb if size == 4 => {
+                let bytes =
+                    [write_u16(value as u16, endian), write_u16((value >> 16) as u16, endian)];
+                let mask = [write_u16(mask as u16, endian), write_u16((mask >> 16) as u16, endian)];
+                (bytes.concat(), mask.concat())
+            }
+            _ => (write_code(value, size, endian), write_code(mask, size, endian)),
+        };
+        self.bytes.extend(bytes);
+        self.mask.extend(mask);
+    }
+
+    pub fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Returns the length of this signature in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns whether `bytes` starts with code matching this signature.
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        bytes.len() >= self.bytes.len()
+            && bytes
+                .iter()
+                .zip(self.bytes.iter().zip(&self.mask))
+                .all(|(byte, (expected, mask))| byte & mask == *expected)
+    }
+
+    /// Returns the offsets in `bytes` where this signature matches. Only offsets which are aligned
+    /// to the instruction size of the signature's mode are checked, relative to the start of
+    /// `bytes`.
+    pub fn scan<'a>(&'a self, bytes: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        let step = match self.mode {
+            #[cfg(feature = "arm")]
+            ParseMode::Arm => 4,
+            #[cfg(feature = "thumb")]
+            ParseMode::Thumb => 2,
+            ParseMode::Data => 1,
+        };
+        let end = bytes.len().checked_sub(self.bytes.len()).map_or(0, |end| end + 1);
+        (0..end).step_by(step).filter(move |&offset| self.matches(&bytes[offset..]))
+    }
+}
+
+/// Error returned by [`Signature::from_hex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignatureError {
+    /// A character is neither a hex digit, `?` nor whitespace
+    InvalidCharacter(char),
+    /// The last byte is missing a digit
+    OddLength,
+}
+
+impl core::fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SignatureError::InvalidCharacter(c) => {
+                write!(f, "invalid character '{c}' in signature")
+            }
+            SignatureError::OddLength => f.write_str("signature has an odd number of hex digits"),
+        }
+    }
+}
+
+impl core::error::Error for SignatureError {}
+
+/// Returns the value and mask of a hex digit or wildcard.
+fn parse_hex_digit(c: char) -> Result<(u8, u8), SignatureError> {
+    if c == '?' {
+        return Ok((0, 0));
+    }
+    let digit = c.to_digit(16).ok_or(SignatureError::InvalidCharacter(c))?;
+    Ok((digit as u8, 0xf))
+}
+
+/// Reads the code of one instruction in the same layout as [`Parser`].
+fn read_code(bytes: &[u8], mode: ParseMode, endian: ParseEndian) -> u32 {
+    let read_u16 = |bytes: &[u8]| match endian {
+        ParseEndian::Little => u16::from_le_bytes([bytes[0], bytes[1]]) as u32,
+        ParseEndian::Big => u16::from_be_bytes([bytes[0], bytes[1]]) as u32,
+    };
+    match (mode, bytes.len()) {
+        #[cfg(feature = "thumb")]
+        (ParseMode::Thumb, 4) => read_u16(&bytes[0..2]) | (read_u16(&bytes[2..4]) << 16),
+        (_, 4) => match endian {
+            ParseEndian::Little => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            ParseEndian::Big => u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        },
+        (_, 2) => read_u16(bytes),
+        _ => bytes[0] as u32,
+    }
+}
+
+fn write_code(value: u32, size: usize, endian: ParseEndian) -> Vec<u8> {
+    match size {
+        4 => match endian {
+            ParseEndian::Little => value.to_le_bytes().to_vec(),
+            ParseEndian::Big => value.to_be_bytes().to_vec(),
+        },
+        2 => write_u16(value as u16, endian).to_vec(),
+        _ => alloc::vec![value as u8],
+    }
+}
+
+fn write_u16(value: u16, endian: ParseEndian) -> [u8; 2] {
+    match endian {
+        ParseEndian::Little => value.to_le_bytes(),
+        ParseEndian::Big => value.to_be_bytes(),
+    }
+}
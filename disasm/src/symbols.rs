@@ -0,0 +1,193 @@
+use alloc::collections::BTreeMap;
+use core::fmt::Write;
+
+use crate::{ins::LiteralKind, *};
+
+/// Looks up the names of addresses for [`SymbolFormatter`].
+pub trait SymbolResolver {
+    /// Returns the symbol containing `addr`, or `None` to write the address as usual.
+    fn resolve(&self, addr: u32) -> Option<Symbol<'_>>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol<'a> {
+    pub name: &'a str,
+    /// Distance from the start of the symbol to the resolved address
+    pub offset: u32,
+}
+
+/// Resolves addresses to the closest symbol at or before them.
+impl<S: AsRef<str>> SymbolResolver for BTreeMap<u32, S> {
+    fn resolve(&self, addr: u32) -> Option<Symbol<'_>> {
+        let (start, name) = self.range(..=addr).next_back()?;
+        Some(Symbol { name: name.as_ref(), offset: addr - start })
+    }
+}
+
+/// Wraps any [`FormatIns`] to write branch targets as symbols, like `bl func_02001234` or
+/// `b .L_loop+0x4`. Everything else is written by the wrapped formatter.
+pub struct SymbolFormatter<'a, F, R: ?Sized> {
+    pub(crate) formatter: F,
+    resolver: &'a R,
+    literals: Option<(&'a [u8], u32, ParseEndian)>,
+    /// Address and relocation of each relocated literal, in order of address
+    literal_relocations: &'a [(u32, Relocation<'a>)],
+    /// Address and relocation of the instruction being written
+    relocation: Option<(u32, Relocation<'a>)>,
+}
+
+impl<'a, F, R> SymbolFormatter<'a, F, R>
+where
+    F: FormatIns,
+    R: SymbolResolver + ?Sized,
+{
+    pub fn new(formatter: F, resolver: &'a R) -> Self {
+        Self {
+            formatter,
+            resolver,
+            literals: None,
+            literal_relocations: &[],
+            relocation: None,
+        }
+    }
+
+    /// Lets [`Self::write_ins_at`] read literals from `bytes` loaded at the address `base`.
+    pub fn with_literals(mut self, bytes: &'a [u8], base: u32, endian: ParseEndian) -> Self {
+        self.literals = Some((bytes, base, endian));
+        self
+    }
+
+    /// Lets [`Self::write_ins_at`] write literals patched by `relocations` as their symbol instead
+    /// of the value in the bytes. The relocations must be in order of address, like
+    /// `ElfSection::relocations`.
+    pub fn with_literal_relocations(mut self, relocations: &'a [(u32, Relocation<'a>)]) -> Self {
+        self.literal_relocations = relocations;
+        self
+    }
+
+    pub fn formatter(&self) -> &F {
+        &self.formatter
+    }
+
+    pub fn into_inner(self) -> F {
+        self.formatter
+    }
+
+    /// Writes `ins` located at `addr`. If it computes a PC-relative address or loads a literal
+    /// from one, the value is written as a comment like `; =func_02001234` or `; =0x02001234`.
+    /// Literal values are only written as symbols if they point exactly to one. Relocated
+    /// literals are written like `; =symbol+0x4`, or not at all if the relocation isn't absolute.
+    pub fn write_ins_at(&mut self, ins: &Ins, addr: u32, mode: ParseMode) -> core::fmt::Result {
+        self.write_ins(ins)?;
+
+        let Some((literal, kind)) = ins.pc_relative_access(addr, mode) else {
+            return Ok(());
+        };
+        let relocation = match (kind, self.literal_relocation(literal)) {
+            (LiteralKind::Address, _) => None,
+            (LiteralKind::Word, Some(r)) if r.kind == RelocationKind::Abs32 => Some(r),
+            // The loaded value is only known for absolute relocations of whole words
+            (_, Some(_)) => return Ok(()),
+            (_, None) => None,
+        };
+        let value = match (kind, self.literals) {
+            (LiteralKind::Address, _) => literal,
+            (_, Some((bytes, base, endian))) => {
+                let mut parser = Parser::new(bytes, mode, endian, self.options().clone());
+                parser.set_pc(base);
+                let Some(value) = parser.read_literal(ins, addr) else {
+                    return Ok(());
+                };
+                value
+            }
+            (_, None) => return Ok(()),
+        };
+
+        self.write_str(" ; =")?;
+        if let Some(relocation) = relocation {
+            // The addend of an absolute relocation is stored in the literal
+            return self.write_symbol_name(Symbol { name: relocation.symbol, offset: value });
+        }
+        match self.resolver.resolve(value) {
+            Some(symbol) if kind == LiteralKind::Address || symbol.offset == 0 => {
+                self.write_symbol_name(symbol)
+            }
+            _ => write!(self, "{value:#010x}"),
+        }
+    }
+
+    /// Writes `ins` located at `addr` like [`Self::write_ins_at`], but with the operand patched by
+    /// `relocation` written as its symbol. Branches are written like `bl printf`, data words like
+    /// `.word symbol+0x4`, and other relocations are written as a comment.
+    pub fn write_relocated_ins_at(
+        &mut self,
+        ins: &Ins,
+        addr: u32,
+        mode: ParseMode,
+        relocation: Relocation<'a>,
+    ) -> core::fmt::Result {
+        match (relocation.kind, ins) {
+            (RelocationKind::Abs32, Ins::Word(addend)) => {
+                ins.write_opcode(self)?;
+                self.write_space()?;
+                self.write_str(relocation.symbol)?;
+                if *addend != 0 {
+                    write!(self, "+{addend:#x}")?;
+                }
+                Ok(())
+            }
+            (kind, _) if kind.is_branch() => {
+                self.relocation = Some((addr, relocation));
+                let result = self.write_ins_at(ins, addr, mode);
+                self.relocation = None;
+                result
+            }
+            (kind, _) => {
+                self.write_ins_at(ins, addr, mode)?;
+                write!(self, " ; {kind} {}", relocation.symbol)
+            }
+        }
+    }
+
+    pub(crate) fn write_symbol(&mut self, addr: u32) -> core::fmt::Result {
+        if let Some((ins_addr, relocation)) = self.relocation {
+            // The addend of a branch relocation is stored in the instruction, so the target is
+            // relative to the instruction instead of the symbol
+            self.write_str(relocation.symbol)?;
+            let offset = addr.wrapping_sub(ins_addr) as i32;
+            return match offset {
+                0 => Ok(()),
+                1.. => write!(self, "+{offset:#x}"),
+                _ => write!(self, "-{:#x}", offset.unsigned_abs()),
+            };
+        }
+        match self.resolver.resolve(addr) {
+            Some(symbol) => self.write_symbol_name(symbol),
+            None => self.formatter.write_branch_target(BranchTarget { addr }),
+        }
+    }
+
+    fn literal_relocation(&self, addr: u32) -> Option<Relocation<'a>> {
+        let relocations = self.literal_relocations;
+        let index = relocations.binary_search_by_key(&addr, |(addr, _)| *addr).ok()?;
+        Some(relocations[index].1)
+    }
+
+    fn write_symbol_name(&mut self, symbol: Symbol) -> core::fmt::Result {
+        self.write_str(symbol.name)?;
+        if symbol.offset != 0 {
+            write!(self, "+{:#x}", symbol.offset)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F, R> Write for SymbolFormatter<'_, F, R>
+where
+    F: FormatIns,
+    R: ?Sized,
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.formatter.write_str(s)
+    }
+}
@@ -0,0 +1,271 @@
+use alloc::vec::Vec;
+
+use crate::*;
+
+/// Known constant values of the general-purpose registers at some point in the code. A register
+/// is unknown (`None`) unless an earlier instruction set it to a value that can be computed
+/// statically, such as an immediate, a literal pool load or arithmetic on other known registers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RegValues([Option<u32>; 16]);
+
+/// Registers which a subroutine call may overwrite, according to the AAPCS
+const CALLER_SAVED: [Reg; 5] = [Reg::R0, Reg::R1, Reg::R2, Reg::R3, Reg::R12];
+
+impl RegValues {
+    /// Creates a state where all registers are unknown.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the known value of `reg`. The PC is never tracked, see [`Self::read`].
+    pub fn get(&self, reg: Reg) -> Option<u32> {
+        self.0[reg as usize]
+    }
+
+    pub fn set(&mut self, reg: Reg, value: Option<u32>) {
+        self.0[reg as usize] = value;
+    }
+
+    /// Returns the value an instruction at `pc` reads from `reg`, where reading the PC gives the
+    /// address of the instruction plus 8 in ARM mode or plus 4 in Thumb mode.
+    pub fn read(&self, reg: Reg, pc: u32, mode: ParseMode) -> Option<u32> {
+        if reg != Reg::Pc {
+            return self.get(reg);
+        }
+        match mode {
+            #[cfg(feature = "arm")]
+            ParseMode::Arm => Some(pc.wrapping_add(8)),
+            #[cfg(feature = "thumb")]
+            ParseMode::Thumb => Some(pc.wrapping_add(4)),
+            ParseMode::Data => None,
+        }
+    }
+
+    /// Updates the register values after executing `ins` at the address `pc`. Literal pool loads
+    /// are read from `parser`, which must be in the same mode as `ins`. Registers written by
+    /// conditional instructions, instructions that are not tracked and subroutine calls become
+    /// unknown.
+    pub fn update(&mut self, ins: &Ins, pc: u32, parser: &Parser) {
+        let value = self.evaluate(ins, pc, parser);
+
+        for def in ins.defs().iter() {
+            match def {
+                DefUseArgument::Reg(reg) => self.set(*reg, None),
+                DefUseArgument::RegList(regs) => regs.iter().for_each(|reg| self.set(reg, None)),
+                _ => {}
+            }
+        }
+        if matches!(ins.flow(), FlowKind::Call { .. }) {
+            CALLER_SAVED.iter().for_each(|reg| self.set(*reg, None));
+        }
+        if let Some((rd, value)) = value
+            && rd != Reg::Pc
+        {
+            self.set(rd, Some(value));
+        }
+    }
+
+    /// Returns the register values before each of `instructions`, starting with this state. The
+    /// instructions are paired with their addresses, like in `BasicBlock::instructions`.
+    pub fn track(&self, instructions: &[(u32, Ins)], parser: &Parser) -> Vec<RegValues> {
+        let mut values = *self;
+        instructions
+            .iter()
+            .map(|(pc, ins)| {
+                let before = values;
+                values.update(ins, *pc, parser);
+                before
+            })
+            .collect()
+    }
+
+    /// Returns the destination register and its new value, if `ins` always writes a known value.
+    fn evaluate(&self, ins: &Ins, pc: u32, parser: &Parser) -> Option<(Reg, u32)> {
+        let mode = parser.mode();
+        if let Some(value) = ins.pc_relative_address(pc, mode) {
+            return match *ins {
+                Ins::Add { cond: Cond::Al, rd, .. } | Ins::Sub { cond: Cond::Al, rd, .. } => {
+                    Some((rd, value))
+                }
+                Ins::Ldr { cond: Cond::Al, rd, .. }
+                | Ins::Ldrb { cond: Cond::Al, rd, .. }
+                | Ins::Ldrh { cond: Cond::Al, rd, .. }
+                | Ins::Ldrsb { cond: Cond::Al, rd, .. }
+                | Ins::Ldrsh { cond: Cond::Al, rd, .. } => {
+                    parser.read_literal(ins, pc).map(|value| (rd, value))
+                }
+                _ => None,
+            };
+        }
+
+        let read = |reg| self.read(reg, pc, mode);
+        let op2 = |op2| self.op2(op2, pc, mode);
+        let op2_shift = |op2| match op2 {
+            Op2Shift::Imm(imm) => Some(imm),
+            Op2Shift::Reg(reg) => read(reg).map(|value| value & 0xff),
+        };
+        let (rd, value) = match *ins {
+            Ins::Mov { cond: Cond::Al, rd, op2: value, .. } => (rd, op2(value)?),
+            Ins::Mvn { cond: Cond::Al, rd, op2: value, .. } => (rd, !op2(value)?),
+            Ins::Add { cond: Cond::Al, rd, rn, op2: value, .. } => {
+                (rd, read(rn)?.wrapping_add(op2(value)?))
+            }
+            Ins::Sub { cond: Cond::Al, rd, rn, op2: value, .. } => {
+                (rd, read(rn)?.wrapping_sub(op2(value)?))
+            }
+            Ins::Rsb { cond: Cond::Al, rd, rn, op2: value, .. } => {
+                (rd, op2(value)?.wrapping_sub(read(rn)?))
+            }
+            Ins::And { cond: Cond::Al, rd, rn, op2: value, .. } => (rd, read(rn)? & op2(value)?),
+            Ins::Orr { cond: Cond::Al, rd, rn, op2: value, .. } => (rd, read(rn)? | op2(value)?),
+            Ins::Eor { cond: Cond::Al, rd, rn, op2: value, .. } => (rd, read(rn)? ^ op2(value)?),
+            Ins::Bic { cond: Cond::Al, rd, rn, op2: value, .. } => (rd, read(rn)? & !op2(value)?),
+            Ins::Lsl { cond: Cond::Al, rd, rn, op2, .. } => {
+                (rd, shift(read(rn)?, ShiftOp::Lsl, op2_shift(op2)?))
+            }
+            Ins::Lsr { cond: Cond::Al, rd, rn, op2, .. } => {
+                (rd, shift(read(rn)?, ShiftOp::Lsr, op2_shift(op2)?))
+            }
+            Ins::Asr { cond: Cond::Al, rd, rn, op2, .. } => {
+                (rd, shift(read(rn)?, ShiftOp::Asr, op2_shift(op2)?))
+            }
+            Ins::Ror { cond: Cond::Al, rd, rn, op2, .. } => {
+                (rd, shift(read(rn)?, ShiftOp::Ror, op2_shift(op2)?))
+            }
+            #[cfg(feature = "thumb")]
+            Ins::Neg { rd, rm } => (rd, read(rm)?.wrapping_neg()),
+            #[cfg(feature = "v7")]
+            Ins::Movw { cond: Cond::Al, rd, imm } => (rd, imm),
+            #[cfg(feature = "v7")]
+            Ins::Movt { cond: Cond::Al, rd, imm } => (rd, (read(rd)? & 0xffff) | (imm << 16)),
+            _ => return None,
+        };
+        Some((rd, value))
+    }
+
+    fn op2(&self, op2: Op2, pc: u32, mode: ParseMode) -> Option<u32> {
+        match op2 {
+            Op2::Imm(imm) => Some(imm),
+            Op2::ShiftReg(ShiftReg { rm, shift_op, rs }) => {
+                let amount = self.read(rs, pc, mode)? & 0xff;
+                Some(shift(self.read(rm, pc, mode)?, shift_op, amount))
+            }
+            Op2::ShiftImm(ShiftImm { rm, shift_op, imm }) => {
+                let value = self.read(rm, pc, mode)?;
+                match (shift_op, imm) {
+                    (ShiftOp::Lsl, _) => Some(shift(value, shift_op, imm)),
+                    // RRX, which depends on the carry flag
+                    (ShiftOp::Ror, 0) => None,
+                    (_, 0) => Some(shift(value, shift_op, 32)),
+                    _ => Some(shift(value, shift_op, imm)),
+                }
+            }
+        }
+    }
+
+    /// Returns the target of `ins` if it is a BX or BLX to a register with a known value. Bit 0
+    /// of the target is set if it is Thumb code.
+    pub fn branch_target(&self, ins: &Ins) -> Option<u32> {
+        let rm = match *ins {
+            #[cfg(any(
+                feature = "v4t",
+                feature = "v5t",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            ))]
+            Ins::Bx { rm, .. } => rm,
+            #[cfg(any(
+                feature = "v5t",
+                feature = "v5te",
+                feature = "v5tej",
+                feature = "v6",
+                feature = "v6k",
+                feature = "v7"
+            ))]
+            Ins::Blx { target: BlxTarget::Indirect(rm), .. } => rm,
+            _ => return None,
+        };
+        self.get(rm)
+    }
+
+    /// Returns the memory address accessed by `ins` at the address `pc`, if it is a single
+    /// register load or store whose base and offset registers have known values.
+    pub fn access_address(&self, ins: &Ins, pc: u32, mode: ParseMode) -> Option<u32> {
+        if let Some(addr) = ins.pc_relative_address(pc, mode) {
+            return match ins {
+                Ins::Add { .. } | Ins::Sub { .. } => None,
+                _ => Some(addr),
+            };
+        }
+        match *ins {
+            Ins::Ldr { addr, .. }
+            | Ins::Ldrb { addr, .. }
+            | Ins::Str { addr, .. }
+            | Ins::Strb { addr, .. } => self.addr_ldr_str(addr, pc, mode),
+            Ins::Ldrh { addr, .. }
+            | Ins::Ldrsb { addr, .. }
+            | Ins::Ldrsh { addr, .. }
+            | Ins::Strh { addr, .. } => self.addr_misc_load(addr, pc, mode),
+            _ => None,
+        }
+    }
+
+    fn addr_ldr_str(&self, addr: AddrLdrStr, pc: u32, mode: ParseMode) -> Option<u32> {
+        let (rn, offset) = match addr {
+            AddrLdrStr::Pre { rn, offset, .. } => {
+                (rn, self.ldr_str_offset(offset, pc, mode)? as u32)
+            }
+            AddrLdrStr::Post(AddrLdrStrPost { rn, .. }) => (rn, 0),
+        };
+        Some(self.read(rn, pc, mode)?.wrapping_add(offset))
+    }
+
+    fn ldr_str_offset(&self, offset: LdrStrOffset, pc: u32, mode: ParseMode) -> Option<i32> {
+        match offset {
+            LdrStrOffset::Imm(imm) => Some(imm),
+            LdrStrOffset::Reg { subtract, rm, shift_op, imm } => {
+                let value = self.op2(Op2::ShiftImm(ShiftImm { rm, shift_op, imm }), pc, mode)?;
+                Some(if subtract {
+                    (value as i32).wrapping_neg()
+                } else {
+                    value as i32
+                })
+            }
+        }
+    }
+
+    fn addr_misc_load(&self, addr: AddrMiscLoad, pc: u32, mode: ParseMode) -> Option<u32> {
+        let (rn, offset) = match addr {
+            AddrMiscLoad::Pre { rn, offset, .. } => {
+                let offset = match offset {
+                    MiscLoadOffset::Imm(imm) => imm,
+                    MiscLoadOffset::Reg { subtract, rm, imm } => {
+                        let value = self.read(rm, pc, mode)? << imm;
+                        if subtract {
+                            (value as i32).wrapping_neg()
+                        } else {
+                            value as i32
+                        }
+                    }
+                };
+                (rn, offset as u32)
+            }
+            AddrMiscLoad::Post { rn, .. } => (rn, 0),
+        };
+        Some(self.read(rn, pc, mode)?.wrapping_add(offset))
+    }
+}
+
+/// Shifts `value` by `amount` bits, like a register-specified shift where amounts of 32 or more
+/// are allowed.
+pub(crate) fn shift(value: u32, shift_op: ShiftOp, amount: u32) -> u32 {
+    match shift_op {
+        ShiftOp::Lsl => value.checked_shl(amount).unwrap_or(0),
+        ShiftOp::Lsr => value.checked_shr(amount).unwrap_or(0),
+        ShiftOp::Asr => ((value as i32) >> amount.min(31)) as u32,
+        ShiftOp::Ror => value.rotate_right(amount),
+    }
+}
@@ -0,0 +1,58 @@
+use alloc::{vec, vec::Vec};
+
+use crate::*;
+
+/// Floating-point immediate of VMOV, an 8-bit value expanded to a sign, 3-bit exponent and 4-bit
+/// fraction
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VfpImm(u8);
+
+impl VfpImm {
+    pub fn parse(value: u32) -> Self {
+        Self((value & 0xff) as u8)
+    }
+
+    pub(crate) fn encode(&self) -> u32 {
+        self.0 as u32
+    }
+
+    pub(crate) fn parse_asm<'a>(s: &'a str, _options: &Options) -> Vec<(Self, &'a str)> {
+        let len = s
+            .find(|c: char| !matches!(c, '0'..='9' | '.' | '+' | '-' | 'e' | 'E'))
+            .unwrap_or(s.len());
+        let (number, rest) = s.split_at(len);
+        let Ok(value) = number.parse::<f64>() else {
+            return Vec::new();
+        };
+        match (0..=0xff).map(Self).find(|imm| imm.value() == value) {
+            Some(imm) => vec![(imm, rest)],
+            None => Vec::new(),
+        }
+    }
+
+    /// The 8-bit encoded immediate.
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// The expanded floating-point value, which is exact in both single and double precision.
+    pub fn value(&self) -> f64 {
+        let fraction = (16 + (self.0 & 0xf)) as f64 / 16.0;
+        let exponent = (self.0 >> 4) & 0x3;
+        let scale = if self.0 & 0x40 != 0 {
+            1.0 / (1 << (3 - exponent)) as f64
+        } else {
+            (2 << exponent) as f64
+        };
+        let value = fraction * scale;
+        if self.0 & 0x80 != 0 { -value } else { value }
+    }
+
+    pub fn write<F>(&self, formatter: &mut F) -> core::fmt::Result
+    where
+        F: FormatIns + ?Sized,
+    {
+        write!(formatter, "{:?}", self.value())
+    }
+}
@@ -0,0 +1,23 @@
+    .arch armv5te
+    .syntax unified
+    .text
+    .arm
+    .global caller
+caller:
+    push {r4, lr}
+    bl printf
+    bl local
+    b exit
+    ldr r0, =message
+    pop {r4, pc}
+local:
+    bx lr
+    .thumb
+    .thumb_func
+thumb_caller:
+    bl printf
+    .align 2
+    .word message+4
+    .data
+message:
+    .asciz "hello"
@@ -0,0 +1,16 @@
+    .arch armv5te
+    .syntax unified
+    .text
+    .arm
+    .global arm_func
+arm_func:
+    ldr r0, =0x2001234
+    add r0, r0, #1
+    bx lr
+    .ltorg
+    .thumb
+    .global thumb_func
+    .thumb_func
+thumb_func:
+    movs r0, #1
+    bx lr
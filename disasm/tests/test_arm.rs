@@ -1,11 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use unarm::{Options, parse_arm, parse_arm_with_discriminant};
+    use unarm::{Options, Version, parse_arm, parse_arm_with_discriminant};
 
     macro_rules! assert_asm {
-        ($code:expr, $disasm:literal) => {{
+        ($code:expr, $disasm:literal) => {{ assert_asm!($code, Version::V6K, $disasm) }};
+        ($code:expr, $version:expr, $disasm:literal) => {{
             let options = Options {
-                version: unarm::Version::V6K,
+                version: $version,
                 extensions: unarm::Extensions::all(),
                 av: false,
                 r9_use: unarm::R9Use::R9,
@@ -171,6 +172,8 @@ mod tests {
     fn test_cps() {
         assert_asm!(0xf102001a, "cps #0x1a");
         assert_asm!(0xf10a01df, "cpsie aif, #0x1f");
+        assert_asm!(0xf10c0080, "cpsid i");
+        assert_asm!(0xf100001a, "<illegal>"); // M == 0 without imod
     }
 
     #[test]
@@ -309,6 +312,14 @@ mod tests {
         assert_asm!(0xe1f030b1, "ldrh r3, [r0, #0x1]!");
     }
 
+    #[test]
+    fn test_ldrht() {
+        assert_asm!(0xe0f12fbf, Version::V7, "ldrht r2, [r1], #0xff");
+        assert_asm!(0xe03120b3, Version::V7, "ldrht r2, [r1], -r3");
+        assert_asm!(0x5076c0be, Version::V7, "ldrhtpl r12, [r6], #-0xe");
+        assert_asm!(0xe0f12fbf, "<illegal>");
+    }
+
     #[test]
     fn test_ldrsb() {
         assert_asm!(0xe1d12fdf, "ldrsb r2, [r1, #0xff]");
@@ -321,6 +332,13 @@ mod tests {
         assert_asm!(0xe1f030d1, "ldrsb r3, [r0, #0x1]!");
     }
 
+    #[test]
+    fn test_ldrsbt() {
+        assert_asm!(0xe0f12fdf, Version::V7, "ldrsbt r2, [r1], #0xff");
+        assert_asm!(0xe03120d3, Version::V7, "ldrsbt r2, [r1], -r3");
+        assert_asm!(0xe0f657dd, Version::V7, "ldrsbt r5, [r6], #0x7d");
+    }
+
     #[test]
     fn test_ldrsh() {
         assert_asm!(0xe1d12fff, "ldrsh r2, [r1, #0xff]");
@@ -333,6 +351,12 @@ mod tests {
         assert_asm!(0xe1f030f1, "ldrsh r3, [r0, #0x1]!");
     }
 
+    #[test]
+    fn test_ldrsht() {
+        assert_asm!(0xe0f12fff, Version::V7, "ldrsht r2, [r1], #0xff");
+        assert_asm!(0xe03120f3, Version::V7, "ldrsht r2, [r1], -r3");
+    }
+
     #[test]
     fn test_ldrt() {
         assert_asm!(0xe4b12fff, "ldrt r2, [r1], #0xfff");
@@ -907,6 +931,13 @@ mod tests {
         assert_asm!(0xe00120b3, "strh r2, [r1], -r3");
     }
 
+    #[test]
+    fn test_strht() {
+        assert_asm!(0xe0e12fbf, Version::V7, "strht r2, [r1], #0xff");
+        assert_asm!(0xe02120b3, Version::V7, "strht r2, [r1], -r3");
+        assert_asm!(0xe02121b3, Version::V7, "<illegal>"); // SBZ bits set
+    }
+
     #[test]
     fn test_strt() {
         assert_asm!(0xe4a12fff, "strt r2, [r1], #0xfff");
@@ -0,0 +1,243 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{AsmError, Ins, Options, ParseMode, parse_arm, parse_asm, parse_thumb};
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V6K,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    macro_rules! assert_asm {
+        ($options:expr, $text:literal, $code:literal) => {{ assert_asm!($options, $text, 0, $code) }};
+        ($options:expr, $text:literal, $pc:literal, $code:literal) => {{
+            let options: &Options = &$options;
+            let ins = parse_asm($text, $pc, ParseMode::Arm, options);
+            assert_eq!(ins, Ok(parse_arm($code, $pc, options)));
+        }};
+    }
+
+    macro_rules! assert_asm_thumb {
+        ($options:expr, $text:literal, $code:literal) => {{
+            let options: &Options = &$options;
+            let ins = parse_asm($text, 0, ParseMode::Thumb, options);
+            assert_eq!(ins, Ok(parse_thumb($code, 0, options).0));
+        }};
+    }
+
+    #[test]
+    fn test_asm_arm() {
+        assert_asm!(options!(), "add r1, r2, r3", 0xe0821003);
+        assert_asm!(options!(), "add r1, r2, #0x100", 0xe2821c01);
+        assert_asm!(options!(), "mov r0, #0xff000000", 0xe3a004ff);
+        assert_asm!(options!(), "lslne r1, r2, #0x2", 0x11a01102);
+        assert_asm!(options!(), "ldr r2, [r1, #0x4]", 0xe5912004);
+        assert_asm!(options!(), "ldr r2, [r1, #-0x4]", 0xe5112004);
+        assert_asm!(options!(), "ldr r2, [r1, r3, lsl #0x2]!", 0xe7b12103);
+        assert_asm!(options!(), "ldrh r2, [r1, #0x4]", 0xe1d120b4);
+        assert_asm!(options!(), "pop {r4, pc}", 0xe8bd8010);
+        assert_asm!(options!(), "push {r4, lr}", 0xe92d4010);
+        assert_asm!(options!(), "bl #0x1048", 0x1000, 0xeb000010);
+        assert_asm!(options!(), "blx #0xc", 0xfa000001);
+        assert_asm!(options!(), "bx lr", 0xe12fff1e);
+        assert_asm!(options!(), "mrs r0, cpsr", 0xe10f0000);
+        assert_asm!(options!(), "msr cpsr_fc, r0", 0xe129f000);
+        assert_asm!(options!(), "mcr p15, #0x0, r0, c7, c10, #0x4", 0xee070f9a);
+        assert_asm!(options!(), "vpop {d8, d9}", 0xecbd8b04);
+        assert_asm!(options!(), "vadd.f32 s0, s1, s2", 0xee300a81);
+
+        let options = Options { version: unarm::Version::V7, ..options!() };
+        assert_asm!(options, "ldrhtpl r12, [r6], #-0xe", 0x5076c0be);
+        assert_asm!(options, "ldrsbt r2, [r1], -r3", 0xe03120d3);
+    }
+
+    #[test]
+    fn test_asm_thumb() {
+        // Syntax shared with ARM instructions
+        assert_asm_thumb!(options!(), "mov r0, r1", 0x4608);
+        assert_asm_thumb!(options!(), "adds r4, r1, #0x0", 0x1c0c);
+        assert_asm_thumb!(options!(), "add r1, pc, #0x4", 0xa101);
+        assert_asm_thumb!(options!(), "ldr r0, [r1, #0x0]", 0x6808);
+        assert_asm_thumb!(options!(), "push {r4, lr}", 0xb510);
+        assert_asm_thumb!(options!(), "bx lr", 0x4770);
+
+        let options = Options { ual: false, ..options!() };
+        assert_asm_thumb!(options, "add r0, #0x10", 0x3010);
+        assert_asm_thumb!(options, "lsl r0, r1, #0x2", 0x0088);
+        assert_asm_thumb!(options, "bic r1, r2", 0x4391);
+        assert_asm_thumb!(options, "mul r1, r2", 0x4351);
+        assert_asm_thumb!(options, "ror r1, r2", 0x41d1);
+    }
+
+    #[test]
+    fn test_asm_thumb2() {
+        let options = Options { version: unarm::Version::V7, ..options!() };
+        assert_asm_thumb!(options, "cbz r1, #0x10", 0xb131);
+        assert_asm_thumb!(options, "ite eq", 0xbf0c);
+        assert_asm_thumb!(options, "tbb [r1, r2]", 0xf002e8d1);
+        assert_asm_thumb!(options, "tbh [r1, r2, lsl #0x1]", 0xf012e8d1);
+        assert_asm_thumb!(options, "orn r1, r2, r3, lsl #0x2", 0x0183ea62);
+        assert_asm_thumb!(options, "ldr r1, [r2, r3, lsl #0x2]", 0x1023f852);
+        assert_asm_thumb!(options, "ldrt r0, [sp, #0x4d]", 0x0e4df85d);
+        assert_asm_thumb!(options, "strht r2, [r2, #0x4]", 0x2e04f822);
+    }
+
+    #[test]
+    fn test_asm_divided() {
+        let options = Options { ual: false, ..options!() };
+        assert_asm!(options, "addnes r1, r2, r3", 0x10921003);
+        assert_asm!(options, "ldmeqia r0!, {r1, r2}", 0x08b00006);
+        assert_asm!(options, "ldrned r2, [r1, #0x8]", 0x11c120d8);
+        assert_asm!(options, "fadds s0, s1, s2", 0xee300a81);
+        assert_asm!(options, "fmdhr d1, r0", 0xee210b10);
+        assert_asm!(options, "fmdlr d1, r0", 0xee010b10);
+    }
+
+    #[test]
+    fn test_asm_aliases() {
+        let options = Options { av: true, ..options!() };
+        assert_asm!(options, "add a2, a3, v1", 0xe0821004);
+        assert_asm!(options, "mov v6, v7", 0xe1a0900a);
+        let options =
+            Options { r9_use: unarm::R9Use::Sb, sl: true, fp: true, ip: true, ..options!() };
+        assert_asm!(options, "add sb, sl, fp", 0xe08a900b);
+        assert_asm!(options, "mov ip, sp", 0xe1a0c00d);
+        let options = Options { r9_use: unarm::R9Use::Tr, ..options!() };
+        assert_asm!(options, "mov tr, r0", 0xe1a09000);
+    }
+
+    #[test]
+    fn test_asm_lenient() {
+        assert_asm!(options!(), "  ADD R1, R2, R3  ", 0xe0821003);
+        assert_asm!(options!(), "add r1,r2,#256", 0xe2821c01);
+        assert_asm!(options!(), "ldr r2, [ r1 , # -4 ]", 0xe5112004);
+        assert_asm!(options!(), "pop {r4,pc}", 0xe8bd8010);
+    }
+
+    #[test]
+    fn test_asm_data() {
+        let options = options!();
+        assert_eq!(
+            parse_asm(".word 0x12345678", 0, ParseMode::Arm, &options),
+            Ok(Ins::Word(0x12345678))
+        );
+        assert_eq!(
+            parse_asm(".hword 0x1234", 0, ParseMode::Arm, &options),
+            Ok(Ins::HalfWord(0x1234))
+        );
+        assert_eq!(parse_asm(".byte 18", 0, ParseMode::Arm, &options), Ok(Ins::Byte(0x12)));
+        assert_eq!(
+            parse_asm(".byte 0x100", 0, ParseMode::Arm, &options),
+            Err(AsmError::InvalidOperands)
+        );
+        assert_eq!(parse_asm(".word 0x1", 0, ParseMode::Data, &options), Ok(Ins::Word(0x1)));
+        assert_eq!(
+            parse_asm("bx lr", 0, ParseMode::Data, &options),
+            Err(AsmError::UnknownMnemonic)
+        );
+    }
+
+    #[test]
+    fn test_asm_errors() {
+        let options = options!();
+        assert_eq!(
+            parse_asm("foo r0", 0, ParseMode::Arm, &options),
+            Err(AsmError::UnknownMnemonic)
+        );
+        assert_eq!(parse_asm("", 0, ParseMode::Arm, &options), Err(AsmError::UnknownMnemonic));
+        assert_eq!(
+            parse_asm("add r0, [r1]", 0, ParseMode::Arm, &options),
+            Err(AsmError::InvalidOperands)
+        );
+        assert_eq!(
+            parse_asm("add r0, r1, r2, r3", 0, ParseMode::Arm, &options),
+            Err(AsmError::InvalidOperands)
+        );
+        // Registers must use the aliases given by the options
+        let options = Options { fp: true, ..options!() };
+        assert_eq!(
+            parse_asm("mov r11, r0", 0, ParseMode::Arm, &options),
+            Err(AsmError::InvalidOperands)
+        );
+        // Divided syntax is not accepted in UAL mode
+        assert_eq!(
+            parse_asm("addnes r1, r2, r3", 0, ParseMode::Arm, &options!()),
+            Err(AsmError::UnknownMnemonic)
+        );
+    }
+
+    #[test]
+    fn test_asm_from_str() {
+        let ins: Ins = "bx lr".parse().unwrap();
+        assert_eq!(ins, parse_arm(0xe12fff1e, 0, &Options::default()));
+    }
+
+    /// Linear congruential generator for deterministic test input
+    fn codes() -> impl Iterator<Item = u32> {
+        let mut state = 0x12345678u32;
+        core::iter::repeat_with(move || {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            state
+        })
+    }
+
+    #[test]
+    fn test_asm_arm_round_trip() {
+        for ual in [false, true] {
+            let options = Options { ual, ..options!() };
+            for code in codes().take(5000) {
+                let ins = parse_arm(code, 0x2000000, &options);
+                if ins == Ins::Illegal {
+                    continue;
+                }
+                let text = ins.display(&options).to_string();
+                // Register lists which wrap around are displayed as empty
+                if text.ends_with("{}") {
+                    continue;
+                }
+                let parsed = parse_asm(&text, 0x2000000, ParseMode::Arm, &options);
+                assert_eq!(parsed, Ok(ins), "failed to parse '{text}' ({code:#010x})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_asm_thumb_round_trip() {
+        for (version, ual) in
+            [(unarm::Version::V6K, false), (unarm::Version::V6K, true), (unarm::Version::V7, true)]
+        {
+            let options = Options { version, ual, ..options!() };
+            for code in codes().take(5000) {
+                let (ins, _size) = parse_thumb(code, 0x2000000, &options);
+                if ins == Ins::Illegal {
+                    continue;
+                }
+                let text = ins.display(&options).to_string();
+                // Register lists which wrap around are displayed as empty
+                if text.ends_with("{}") {
+                    continue;
+                }
+                let parsed = parse_asm(&text, 0x2000000, ParseMode::Thumb, &options);
+                // Divided syntax displays some flag-setting instructions like other ones, such as
+                // `adds r1, r4, #0x0` and `mov r1, r4`
+                if !ual
+                    && let Ok(parsed) = &parsed
+                    && *parsed != ins
+                    && parsed.display(&options).to_string() == text
+                {
+                    continue;
+                }
+                assert_eq!(parsed, Ok(ins), "failed to parse '{text}' ({code:#010x})");
+            }
+        }
+    }
+}
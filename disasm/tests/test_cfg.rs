@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{Cfg, Cond, Edge, EdgeKind, Ins, Options, ParseEndian, ParseMode};
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V5Te,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    macro_rules! edge {
+        ($from:literal, $to:literal, $mode:ident, $kind:ident) => {
+            Edge { from: $from, to: $to, mode: ParseMode::$mode, kind: EdgeKind::$kind }
+        };
+    }
+
+    macro_rules! assert_block {
+        ($cfg:expr, $start:literal..$end:literal, $mode:ident, [$($edge:expr),* $(,)?]) => {{
+            let block = $cfg.block($start).expect("missing block");
+            assert_eq!((block.start(), block.end()), ($start, $end), "mismatched block bounds");
+            assert_eq!(block.mode(), ParseMode::$mode, "mismatched block mode");
+            assert_eq!($cfg.successors(block), &[$($edge),*], "mismatched edges");
+        }};
+    }
+
+    #[rustfmt::skip]
+    const ARM_CODE: &[u8] = &[
+        0x00, 0x00, 0x50, 0xe3, // 0x00: cmp r0, #0x0
+        0x01, 0x00, 0x00, 0x0a, // 0x04: beq #0x10
+        0x01, 0x00, 0x80, 0xe2, // 0x08: add r0, r0, #0x1
+        0x02, 0x00, 0x00, 0xeb, // 0x0c: bl #0x1c
+        0x01, 0xc0, 0x8f, 0xe2, // 0x10: add ip, pc, #0x1
+        0x1c, 0xff, 0x2f, 0xe1, // 0x14: bx ip
+        0x70, 0x47, 0xc0, 0x46, // 0x18: bx lr; nop
+        0x01, 0x00, 0x50, 0xe3, // 0x1c: cmp r0, #0x1
+        0x1e, 0xff, 0x2f, 0x01, // 0x20: bxeq lr
+        0x10, 0xff, 0x2f, 0xe1, // 0x24: bx r0
+    ];
+
+    #[test]
+    fn test_arm() {
+        let cfg = Cfg::build(ARM_CODE, 0x2000000, ParseMode::Arm, ParseEndian::Little, options!());
+        assert_eq!(cfg.blocks().count(), 6);
+        assert_block!(
+            cfg,
+            0x2000000..0x2000008,
+            Arm,
+            [
+                edge!(0x2000004, 0x2000010, Arm, ConditionalBranch),
+                edge!(0x2000004, 0x2000008, Arm, Fallthrough),
+            ]
+        );
+        assert_block!(
+            cfg,
+            0x2000008..0x2000010,
+            Arm,
+            [edge!(0x200000c, 0x200001c, Arm, Call), edge!(0x200000c, 0x2000010, Arm, Fallthrough)]
+        );
+        assert_block!(cfg, 0x2000010..0x2000018, Arm, [edge!(0x2000014, 0x2000018, Thumb, Branch)]);
+        assert_block!(cfg, 0x2000018..0x200001a, Thumb, []);
+        assert_block!(
+            cfg,
+            0x200001c..0x2000024,
+            Arm,
+            [edge!(0x2000020, 0x2000024, Arm, Fallthrough)]
+        );
+        assert_block!(cfg, 0x2000024..0x2000028, Arm, []);
+        assert_eq!(cfg.unresolved(), &[0x2000024]);
+
+        assert_eq!(cfg.predecessors(0x2000010).count(), 2);
+        assert_eq!(cfg.block_containing(0x2000020).map(|b| b.start()), Some(0x200001c));
+        assert_eq!(cfg.block_containing(0x200001a), None);
+    }
+
+    #[test]
+    fn test_split() {
+        let mut cfg =
+            Cfg::build(ARM_CODE, 0x2000000, ParseMode::Arm, ParseEndian::Little, options!());
+        cfg.add_entry(0x2000004, ParseMode::Arm);
+        assert_eq!(cfg.blocks().count(), 7);
+        assert_block!(
+            cfg,
+            0x2000000..0x2000004,
+            Arm,
+            [edge!(0x2000000, 0x2000004, Arm, Fallthrough)]
+        );
+        assert_block!(
+            cfg,
+            0x2000004..0x2000008,
+            Arm,
+            [
+                edge!(0x2000004, 0x2000010, Arm, ConditionalBranch),
+                edge!(0x2000004, 0x2000008, Arm, Fallthrough),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conditional_indirect() {
+        #[rustfmt::skip]
+        let code = &[
+            0x04, 0x30, 0x8f, 0xe2, // 0x00: add r3, pc, #0x4
+            0x00, 0x00, 0x50, 0xe3, // 0x04: cmp r0, #0x0
+            0x13, 0xff, 0x2f, 0x11, // 0x08: bxne r3
+            0x1e, 0xff, 0x2f, 0xe1, // 0x0c: bx lr
+        ];
+        let cfg = Cfg::build(code, 0x2000000, ParseMode::Arm, ParseEndian::Little, options!());
+        assert_block!(
+            cfg,
+            0x2000000..0x200000c,
+            Arm,
+            [
+                edge!(0x2000008, 0x200000c, Arm, ConditionalBranch),
+                edge!(0x2000008, 0x200000c, Arm, Fallthrough),
+            ]
+        );
+        assert!(cfg.unresolved().is_empty());
+    }
+
+    #[test]
+    fn test_thumb() {
+        #[rustfmt::skip]
+        let code = &[
+            0x00, 0x48,             // 0x00: ldr r0, [pc, #0x0]
+            0x00, 0x47,             // 0x02: bx r0
+            0x09, 0x00, 0x00, 0x02, // 0x04: .word 0x2000009
+            0x00, 0xf0, 0x00, 0xf8, // 0x08: bl #0xc
+            0xfe, 0xe7,             // 0x0c: b #0xc
+        ];
+        let cfg = Cfg::build(code, 0x2000000, ParseMode::Thumb, ParseEndian::Little, options!());
+        assert_eq!(cfg.blocks().count(), 3);
+        assert_block!(
+            cfg,
+            0x2000000..0x2000004,
+            Thumb,
+            [edge!(0x2000002, 0x2000008, Thumb, Branch)]
+        );
+        assert_block!(
+            cfg,
+            0x2000008..0x200000c,
+            Thumb,
+            [
+                edge!(0x2000008, 0x200000c, Thumb, Call),
+                edge!(0x2000008, 0x200000c, Thumb, Fallthrough),
+            ]
+        );
+        assert_block!(
+            cfg,
+            0x200000c..0x200000e,
+            Thumb,
+            [edge!(0x200000c, 0x200000c, Thumb, Branch)]
+        );
+        assert!(cfg.unresolved().is_empty());
+        assert_eq!(cfg.block_containing(0x2000004), None);
+    }
+
+    #[test]
+    fn test_it_block() {
+        #[rustfmt::skip]
+        let code = &[
+            0x01, 0xe0, // 0x00: b #0x6
+            0x04, 0xbf, // 0x02: itt eq
+            0x01, 0x20, // 0x04: moveq r0, #0x1
+            0x02, 0x21, // 0x06: moveq r1, #0x2
+            0x70, 0x47, // 0x08: bx lr
+        ];
+        let options = Options { version: unarm::Version::V7, ..options!() };
+        let cond = |cfg: &Cfg, addr: u32| match &cfg.block(addr).unwrap().instructions()[0].1 {
+            Ins::Mov { cond, .. } => *cond,
+            ins => panic!("unexpected {ins:?}"),
+        };
+
+        // The block at 0x6 is decoded before the IT instruction, and again once it's found
+        let mut cfg =
+            Cfg::build(code, 0x2000000, ParseMode::Thumb, ParseEndian::Little, options.clone());
+        assert_eq!(cond(&cfg, 0x2000006), Cond::Al);
+        cfg.add_entry(0x2000002, ParseMode::Thumb);
+        assert_eq!(cond(&cfg, 0x2000006), Cond::Eq);
+        assert_block!(
+            cfg,
+            0x2000002..0x2000006,
+            Thumb,
+            [edge!(0x2000004, 0x2000006, Thumb, Fallthrough)]
+        );
+        assert_block!(cfg, 0x2000006..0x200000a, Thumb, []);
+
+        // Splitting a block inside an IT block keeps the conditions
+        let mut cfg = Cfg::new(code, 0x2000000, ParseEndian::Little, options);
+        cfg.add_entry(0x2000002, ParseMode::Thumb);
+        cfg.add_entry(0x2000006, ParseMode::Thumb);
+        assert_eq!(cond(&cfg, 0x2000006), Cond::Eq);
+    }
+
+    #[test]
+    fn test_truncated_target() {
+        #[rustfmt::skip]
+        let code = &[
+            0xff, 0xff, 0xff, 0xea, // 0x00: b #0x4
+            0x00, 0x00,             // 0x04: truncated
+        ];
+        let cfg = Cfg::build(code, 0x2000000, ParseMode::Arm, ParseEndian::Little, options!());
+        assert_eq!(cfg.blocks().count(), 1);
+        assert_block!(cfg, 0x2000000..0x2000004, Arm, [edge!(0x2000000, 0x2000004, Arm, Branch)]);
+        assert_eq!(cfg.block(0x2000004), None);
+    }
+}
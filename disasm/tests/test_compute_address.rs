@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{
+        AccessRange, AddrLdrStr, Ins, LdmStmMode, Options, Reg, RegisterFile, parse_arm,
+        parse_thumb,
+    };
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V7,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    #[derive(Default)]
+    struct Regs {
+        regs: [u32; 16],
+        carry: bool,
+    }
+
+    impl Regs {
+        fn with(mut self, reg: Reg, value: u32) -> Self {
+            self.regs[reg as usize] = value;
+            self
+        }
+    }
+
+    impl RegisterFile for Regs {
+        fn reg(&self, reg: Reg) -> u32 {
+            self.regs[reg as usize]
+        }
+
+        fn carry(&self) -> bool {
+            self.carry
+        }
+    }
+
+    fn range(ins: &Ins, regs: &Regs) -> AccessRange {
+        ins.memory_access().unwrap().compute_address(regs)
+    }
+
+    #[test]
+    fn test_block() {
+        let options = options!();
+        let regs = Regs::default().with(Reg::R0, 0x1000).with(Reg::Sp, 0x2000);
+
+        let ins = parse_arm(0xe830000e, 0, &options); // ldmda r0!, {r1, r2, r3}
+        let expected = AccessRange { start: 0xff8, size: 12, writeback: Some(0xff4) };
+        assert_eq!(range(&ins, &regs), expected);
+
+        let ins = parse_arm(0xe9900006, 0, &options); // ldmib r0, {r1, r2}
+        assert_eq!(range(&ins, &regs), AccessRange { start: 0x1004, size: 8, writeback: None });
+
+        let ins = parse_arm(0xe92d4010, 0, &options); // push {r4, lr}
+        let expected = AccessRange { start: 0x1ff8, size: 8, writeback: Some(0x1ff8) };
+        assert_eq!(range(&ins, &regs), expected);
+
+        let range = LdmStmMode::Ia.compute_address(Reg::R0, 3, true, &regs);
+        assert_eq!(range, AccessRange { start: 0x1000, size: 12, writeback: Some(0x100c) });
+        assert_eq!(range.end(), 0x100c);
+        assert!(range.contains(0x1008));
+        assert!(!range.contains(0x100c));
+    }
+
+    #[test]
+    fn test_shifted_offset() {
+        let options = options!();
+        let regs = Regs::default().with(Reg::R1, 0x1000).with(Reg::R2, 0x80000004);
+
+        let ins = parse_arm(0xe7910042, 0, &options); // ldr r0, [r1, r2, asr #0x20]
+        assert_eq!(range(&ins, &regs), AccessRange { start: 0xfff, size: 4, writeback: None });
+
+        let ins = parse_arm(0xe7110062, 0, &options); // ldr r0, [r1, -r2, rrx]
+        let Ins::Ldr { addr, .. } = ins else { unreachable!() };
+        assert_eq!(addr.compute_address(4, &regs).start, 0x1000u32.wrapping_sub(0x40000002));
+        let regs = Regs { carry: true, ..regs };
+        assert_eq!(addr.compute_address(4, &regs).start, 0x1000u32.wrapping_sub(0xc0000002));
+
+        let ins = parse_arm(0xe6f10102, 0, &options); // ldrbt r0, [r1], r2, lsl #0x2
+        let expected = AccessRange { start: 0x1000, size: 1, writeback: Some(0x1010) };
+        assert_eq!(range(&ins, &regs), expected);
+    }
+
+    #[test]
+    fn test_pc_base() {
+        let options = options!();
+        let (ins, _) = parse_thumb(0x4801, 0x1002, &options); // ldr r0, [pc, #0x4]
+        let regs = Regs::default().with(Reg::Pc, 0x1006);
+        assert_eq!(range(&ins, &regs), AccessRange { start: 0x1008, size: 4, writeback: None });
+
+        let addr: AddrLdrStr = match parse_arm(0xe59f0004, 0x1000, &options) {
+            Ins::Ldr { addr, .. } => addr, // ldr r0, [pc, #0x4]
+            _ => unreachable!(),
+        };
+        let regs = Regs::default().with(Reg::Pc, 0x1008);
+        assert_eq!(addr.compute_address(4, &regs).start, 0x100c);
+    }
+
+    #[test]
+    fn test_neon_writeback() {
+        let options = options!();
+        let regs = Regs::default().with(Reg::R0, 0x1000);
+        let ins = parse_arm(0xf420028d, 0, &options); // vld1.32 {d0, d1, d2, d3}, [r0]!
+        let expected = AccessRange { start: 0x1000, size: 0x20, writeback: Some(0x1020) };
+        assert_eq!(range(&ins, &regs), expected);
+    }
+}
@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod tests {
     use unarm::{
-        CoReg, DefUseArgument, Dreg, DregIndex, DregList, Fpscr, Options, Reg, RegList, Sreg,
-        SregList, StatusFields, StatusReg, parse_arm, parse_thumb,
+        CoReg, DefUseArgument, Dreg, DregIndex, DregList, Flag, Fpscr, Options, Qreg, Reg, RegList,
+        Sreg, SregList, StatusFields, StatusReg, parse_arm, parse_thumb,
     };
 
     macro_rules! options {
@@ -63,23 +63,35 @@ mod tests {
 
     #[test]
     fn test_arm() {
-        assert_arm!(0xe0a12003, "adc r2, r1, r3", defs!(Reg::R2), uses!(Reg::R1, Reg::R3));
-        assert_arm!(0xe2a45e23, "adc r5, r4, #0x230", defs!(Reg::R5), uses!(Reg::R4));
+        assert_arm!(0xe0a12003, "adc r2, r1, r3", defs!(Reg::R2), uses!(Reg::R1, Flag::C, Reg::R3));
+        assert_arm!(0xe2a45e23, "adc r5, r4, #0x230", defs!(Reg::R5), uses!(Reg::R4, Flag::C));
         assert_arm!(
             0x40a5e238,
             "adcmi lr, r5, r8, lsr r2",
             defs!(Reg::Lr),
-            uses!(Reg::R5, Reg::R8, Reg::R2)
+            uses!(Reg::R5, Flag::C, Reg::R8, Reg::R2, Flag::N)
         );
 
-        assert_arm!(0xe1b02153, "asrs r2, r3, r1", defs!(Reg::R2), uses!(Reg::R3, Reg::R1));
+        assert_arm!(
+            0xe1b02153,
+            "asrs r2, r3, r1",
+            defs!(Reg::R2, Flag::N, Flag::Z, Flag::C),
+            uses!(Reg::R3, Flag::C, Reg::R1)
+        );
+
+        assert_arm!(
+            0xe0110312,
+            "ands r0, r1, r2, lsl r3",
+            defs!(Reg::R0, Flag::N, Flag::Z, Flag::C),
+            uses!(Reg::R1, Flag::C, Reg::R2, Reg::R3)
+        );
 
         assert_arm!(0xea000000, "b #0x8", defs!(Reg::Pc), uses!());
 
         assert_arm!(0xe1200070, "bkpt #0x0", defs!(), uses!());
 
-        assert_arm!(0xfa000000, "blx #0x8", defs!(Reg::Pc), uses!());
-        assert_arm!(0xe12fff30, "blx r0", defs!(Reg::Pc), uses!(Reg::R0));
+        assert_arm!(0xfa000000, "blx #0x8", defs!(Reg::Pc, Reg::Lr), uses!());
+        assert_arm!(0xe12fff30, "blx r0", defs!(Reg::Pc, Reg::Lr), uses!(Reg::R0));
 
         assert_arm!(0xe12fff10, "bx r0", defs!(Reg::Pc), uses!(Reg::R0));
 
@@ -92,9 +104,36 @@ mod tests {
 
         assert_arm!(0xe16f5f1f, "clz r5, pc", defs!(Reg::R5), uses!(Reg::Pc));
 
-        assert_arm!(0xe1510003, "cmp r1, r3", defs!(), uses!(Reg::R1, Reg::R3));
-        assert_arm!(0xe3540e23, "cmp r4, #0x230", defs!(), uses!(Reg::R4));
-        assert_arm!(0x41550238, "cmpmi r5, r8, lsr r2", defs!(), uses!(Reg::R5, Reg::R8, Reg::R2));
+        assert_arm!(
+            0xe1510003,
+            "cmp r1, r3",
+            defs!(Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Reg::R1, Reg::R3)
+        );
+        assert_arm!(
+            0xe3540e23,
+            "cmp r4, #0x230",
+            defs!(Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Reg::R4)
+        );
+        assert_arm!(
+            0x41550238,
+            "cmpmi r5, r8, lsr r2",
+            defs!(Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Reg::R5, Reg::R8, Reg::R2, Flag::N)
+        );
+        assert_arm!(
+            0xc0912003,
+            "addsgt r2, r1, r3",
+            defs!(Reg::R2, Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Reg::R1, Reg::R3, Flag::Z, Flag::N, Flag::V)
+        );
+        assert_arm!(
+            0xc1b00061,
+            "rrxsgt r0, r1",
+            defs!(Reg::R0, Flag::N, Flag::Z, Flag::C),
+            uses!(Reg::R1, Flag::C, Flag::Z, Flag::N, Flag::V)
+        );
 
         assert_arm!(0xf102001a, "cps #0x1a", defs!(), uses!());
 
@@ -103,7 +142,7 @@ mod tests {
             0x4d332169,
             "ldcmi p1, c2, [r3, #-0x1a4]!",
             defs!(CoReg::C2, Reg::R3),
-            uses!(Reg::R3)
+            uses!(Reg::R3, Flag::N)
         );
 
         assert_arm!(
@@ -143,11 +182,16 @@ mod tests {
             0xe7312063,
             "ldr r2, [r1, -r3, rrx]!",
             defs!(Reg::R2, Reg::R1),
-            uses!(Reg::R1, Reg::R3)
+            uses!(Reg::R1, Reg::R3, Flag::C)
         );
 
-        assert_arm!(0xe4f12fff, "ldrbt r2, [r1], #0xfff", defs!(Reg::R2), uses!(Reg::R1));
-        assert_arm!(0xe6712003, "ldrbt r2, [r1], -r3", defs!(Reg::R2), uses!(Reg::R1, Reg::R3));
+        assert_arm!(0xe4f12fff, "ldrbt r2, [r1], #0xfff", defs!(Reg::R2, Reg::R1), uses!(Reg::R1));
+        assert_arm!(
+            0xe6712003,
+            "ldrbt r2, [r1], -r3",
+            defs!(Reg::R2, Reg::R1),
+            uses!(Reg::R1, Reg::R3)
+        );
 
         assert_arm!(
             0xe1c12fdf,
@@ -173,6 +217,14 @@ mod tests {
             defs!(Reg::R2, Reg::R3, Reg::R1),
             uses!(Reg::R1, Reg::R3)
         );
+        assert_arm!(
+            0xe0c12fdf,
+            "ldrd r2, r3, [r1], #0xff",
+            defs!(Reg::R2, Reg::R3, Reg::R1),
+            uses!(Reg::R1)
+        );
+
+        assert_arm!(0xe0d12fbf, "ldrh r2, [r1], #0xff", defs!(Reg::R2, Reg::R1), uses!(Reg::R1));
 
         assert_arm!(0xe1912f9f, "ldrex r2, [r1]", defs!(Reg::R2), uses!(Reg::R1));
 
@@ -201,6 +253,20 @@ mod tests {
 
         assert_arm!(0xe1a02003, "mov r2, r3", defs!(Reg::R2), uses!(Reg::R3));
         assert_arm!(0xe3a05e23, "mov r5, #0x230", defs!(Reg::R5), uses!());
+        assert_arm!(0xe3b00001, "movs r0, #0x1", defs!(Reg::R0, Flag::N, Flag::Z), uses!());
+        assert_arm!(0xe1b00001, "movs r0, r1", defs!(Reg::R0, Flag::N, Flag::Z), uses!(Reg::R1));
+        assert_arm!(
+            0xe3b05e23,
+            "movs r5, #0x230",
+            defs!(Reg::R5, Flag::N, Flag::Z, Flag::C),
+            uses!()
+        );
+        assert_arm!(
+            0xe1b00101,
+            "movs r0, r1, lsl #0x2",
+            defs!(Reg::R0, Flag::N, Flag::Z, Flag::C),
+            uses!(Reg::R1)
+        );
 
         assert_arm!(
             0xee3234b6,
@@ -259,38 +325,72 @@ mod tests {
             uses!(Reg::Sp, RegList::of(&[Reg::R0, Reg::R2, Reg::R8, Reg::R10]))
         );
 
-        assert_arm!(0xe1012053, "qadd r2, r3, r1", defs!(Reg::R2), uses!(Reg::R3, Reg::R1));
+        assert_arm!(
+            0xe1012053,
+            "qadd r2, r3, r1",
+            defs!(Reg::R2, Flag::Q),
+            uses!(Reg::R3, Reg::R1)
+        );
 
         assert_arm!(0xe6212f13, "qadd16 r2, r1, r3", defs!(Reg::R2), uses!(Reg::R1, Reg::R3));
 
-        assert_arm!(0xe1412053, "qdadd r2, r3, r1", defs!(Reg::R2), uses!(Reg::R3, Reg::R1));
+        assert_arm!(
+            0xe1412053,
+            "qdadd r2, r3, r1",
+            defs!(Reg::R2, Flag::Q),
+            uses!(Reg::R3, Reg::R1)
+        );
 
         assert_arm!(0xe6bf1f32, "rev r1, r2", defs!(Reg::R1), uses!(Reg::R2));
 
         assert_arm!(0xf8170a00, "rfeda r7", defs!(), uses!(Reg::R7));
         assert_arm!(0xf9370a00, "rfedb r7!", defs!(Reg::R7), uses!(Reg::R7));
 
-        assert_arm!(0xe6812fb3, "sel r2, r1, r3", defs!(Reg::R2), uses!(Reg::R1, Reg::R3));
+        assert_arm!(
+            0xe6812fb3,
+            "sel r2, r1, r3",
+            defs!(Reg::R2),
+            uses!(Reg::R1, Reg::R3, Flag::Ge)
+        );
 
         assert_arm!(
             0xe1012384,
             "smlabb r1, r4, r3, r2",
-            defs!(Reg::R1),
+            defs!(Reg::R1, Flag::Q),
             uses!(Reg::R4, Reg::R3, Reg::R2)
         );
 
         assert_arm!(
             0xe1212384,
             "smlawb r1, r4, r3, r2",
-            defs!(Reg::R1),
+            defs!(Reg::R1, Flag::Q),
             uses!(Reg::R4, Reg::R3, Reg::R2)
         );
 
+        assert_arm!(
+            0xe0e21493,
+            "smlal r1, r2, r3, r4",
+            defs!(Reg::R1, Reg::R2),
+            uses!(Reg::R1, Reg::R2, Reg::R3, Reg::R4)
+        );
+        assert_arm!(
+            0xe14214c3,
+            "smlalbt r1, r2, r3, r4",
+            defs!(Reg::R1, Reg::R2),
+            uses!(Reg::R1, Reg::R2, Reg::R3, Reg::R4)
+        );
+        assert_arm!(
+            0xe7421413,
+            "smlald r1, r2, r3, r4",
+            defs!(Reg::R1, Reg::R2),
+            uses!(Reg::R1, Reg::R2, Reg::R3, Reg::R4)
+        );
+
         assert_arm!(
             0xe7412354,
             "smlsld r2, r1, r4, r3",
             defs!(Reg::R2, Reg::R1),
-            uses!(Reg::R4, Reg::R3)
+            uses!(Reg::R2, Reg::R1, Reg::R4, Reg::R3)
         );
 
         assert_arm!(0xe751f314, "smmul r1, r4, r3", defs!(Reg::R1), uses!(Reg::R4, Reg::R3));
@@ -298,14 +398,19 @@ mod tests {
         assert_arm!(0xf84d051f, "srsda sp, #0x1f", defs!(), uses!(Reg::Sp));
         assert_arm!(0xf96d051f, "srsdb sp!, #0x1f", defs!(Reg::Sp), uses!(Reg::Sp));
 
-        assert_arm!(0xe6af1512, "ssat r1, #0x10, r2, lsl #0xa", defs!(Reg::R1), uses!(Reg::R2));
+        assert_arm!(
+            0xe6af1512,
+            "ssat r1, #0x10, r2, lsl #0xa",
+            defs!(Reg::R1, Flag::Q),
+            uses!(Reg::R2)
+        );
 
         assert_arm!(0xed032169, "stc p1, c2, [r3, #-0x1a4]", defs!(), uses!(CoReg::C2, Reg::R3));
         assert_arm!(
             0x4d232169,
             "stcmi p1, c2, [r3, #-0x1a4]!",
             defs!(Reg::R3),
-            uses!(CoReg::C2, Reg::R3)
+            uses!(CoReg::C2, Reg::R3, Flag::N)
         );
 
         assert_arm!(
@@ -345,7 +450,7 @@ mod tests {
             0xe7212063,
             "str r2, [r1, -r3, rrx]!",
             defs!(Reg::R1),
-            uses!(Reg::R2, Reg::R1, Reg::R3)
+            uses!(Reg::R2, Reg::R1, Reg::R3, Flag::C)
         );
 
         assert_arm!(
@@ -355,14 +460,31 @@ mod tests {
             uses!(Reg::R2, Reg::R3, Reg::R1, Reg::R3)
         );
 
+        assert_arm!(0xe10100b2, "strh r0, [r1, -r2]", defs!(), uses!(Reg::R0, Reg::R1, Reg::R2));
+
+        assert_arm!(0xe4a12004, "strt r2, [r1], #0x4", defs!(Reg::R1), uses!(Reg::R2, Reg::R1));
+
         assert_arm!(0xe1012093, "swp r2, r3, [r1]", defs!(Reg::R2), uses!(Reg::R3, Reg::R1));
 
         assert_arm!(0xe6a12073, "sxtab r2, r1, r3", defs!(Reg::R2), uses!(Reg::R1, Reg::R3));
 
         assert_arm!(0xe7f000f0, "udf #0x0", defs!(), uses!());
 
+        assert_arm!(
+            0xe0421493,
+            "umaal r1, r2, r3, r4",
+            defs!(Reg::R1, Reg::R2),
+            uses!(Reg::R1, Reg::R2, Reg::R3, Reg::R4)
+        );
+        assert_arm!(
+            0xe0b21493,
+            "umlals r1, r2, r3, r4",
+            defs!(Reg::R1, Reg::R2, Flag::N, Flag::Z),
+            uses!(Reg::R1, Reg::R2, Reg::R3, Reg::R4)
+        );
+
         assert_arm!(0xeef0fae9, "vabs.f32 s31, s19", defs!(Sreg::S31), uses!(Sreg::S19));
-        assert_arm!(0x0ef0fbe9, "vabseq.f64 d31, d25", defs!(Dreg::D31), uses!(Dreg::D25));
+        assert_arm!(0x0ef0fbe9, "vabseq.f64 d31, d25", defs!(Dreg::D31), uses!(Dreg::D25, Flag::Z));
 
         assert_arm!(
             0xee73fa29,
@@ -374,17 +496,37 @@ mod tests {
             0x0e73fb29,
             "vaddeq.f64 d31, d3, d25",
             defs!(Dreg::D31),
-            uses!(Dreg::D3, Dreg::D25)
+            uses!(Dreg::D3, Dreg::D25, Flag::Z)
         );
 
         assert_arm!(0xeef4fa69, "vcmp.f32 s31, s19", defs!(), uses!(Sreg::S31, Sreg::S19));
         assert_arm!(0xeef5fa40, "vcmp.f32 s31, #0.0", defs!(), uses!(Sreg::S31));
-        assert_arm!(0x0ef4fb69, "vcmpeq.f64 d31, d25", defs!(), uses!(Dreg::D31, Dreg::D25));
-        assert_arm!(0x0ef5fb40, "vcmpeq.f64 d31, #0.0", defs!(), uses!(Dreg::D31));
+        assert_arm!(
+            0x0ef4fb69,
+            "vcmpeq.f64 d31, d25",
+            defs!(),
+            uses!(Dreg::D31, Dreg::D25, Flag::Z)
+        );
+        assert_arm!(0x0ef5fb40, "vcmpeq.f64 d31, #0.0", defs!(), uses!(Dreg::D31, Flag::Z));
 
-        assert_arm!(0x0ef7fbe9, "vcvteq.f32.f64 s31, d25", defs!(Sreg::S31), uses!(Dreg::D25));
-        assert_arm!(0x1ef8fae9, "vcvtne.f32.s32 s31, s19", defs!(Sreg::S31), uses!(Sreg::S19));
-        assert_arm!(0x3ef7fae9, "vcvtlo.f64.f32 d31, s19", defs!(Dreg::D31), uses!(Sreg::S19));
+        assert_arm!(
+            0x0ef7fbe9,
+            "vcvteq.f32.f64 s31, d25",
+            defs!(Sreg::S31),
+            uses!(Dreg::D25, Flag::Z)
+        );
+        assert_arm!(
+            0x1ef8fae9,
+            "vcvtne.f32.s32 s31, s19",
+            defs!(Sreg::S31),
+            uses!(Sreg::S19, Flag::Z)
+        );
+        assert_arm!(
+            0x3ef7fae9,
+            "vcvtlo.f64.f32 d31, s19",
+            defs!(Dreg::D31),
+            uses!(Sreg::S19, Flag::C)
+        );
 
         assert_arm!(
             0xecb79a08,
@@ -396,27 +538,32 @@ mod tests {
             0x0c979b08,
             "vldmiaeq r7, {d9, d10, d11, d12}",
             defs!(DregList::range(Dreg::D9, Dreg::D12)),
-            uses!(Reg::R7)
+            uses!(Reg::R7, Flag::Z)
         );
 
         assert_arm!(0xedd79a29, "vldr s19, [r7, #0xa4]", defs!(Sreg::S19), uses!(Reg::R7));
-        assert_arm!(0x0d579b29, "vldreq d25, [r7, #-0xa4]", defs!(Dreg::D25), uses!(Reg::R7));
+        assert_arm!(
+            0x0d579b29,
+            "vldreq d25, [r7, #-0xa4]",
+            defs!(Dreg::D25),
+            uses!(Reg::R7, Flag::Z)
+        );
 
         assert_arm!(0xee097a90, "vmov s19, r7", defs!(Sreg::S19), uses!(Reg::R7));
         assert_arm!(
             0x0e097b90,
             "vmoveq.32 d25[0x0], r7",
             defs!(DregIndex { dreg: Dreg::D25, index: 0 }),
-            uses!(Reg::R7)
+            uses!(Reg::R7, Flag::Z)
         );
         assert_arm!(0xeef0fa69, "vmov.f32 s31, s19", defs!(Sreg::S31), uses!(Sreg::S19));
-        assert_arm!(0x0ef0fb69, "vmoveq.f64 d31, d25", defs!(Dreg::D31), uses!(Dreg::D25));
+        assert_arm!(0x0ef0fb69, "vmoveq.f64 d31, d25", defs!(Dreg::D31), uses!(Dreg::D25, Flag::Z));
         assert_arm!(0xee1f7a90, "vmov r7, s31", defs!(Reg::R7), uses!(Sreg::S31));
         assert_arm!(
             0x0e1f7b90,
             "vmoveq.32 r7, d31[0x0]",
             defs!(Reg::R7),
-            uses!(DregIndex { dreg: Dreg::D31, index: 0 })
+            uses!(DregIndex { dreg: Dreg::D31, index: 0 }, Flag::Z)
         );
         assert_arm!(
             0xec5b7a39,
@@ -432,6 +579,12 @@ mod tests {
         );
 
         assert_arm!(0xeef17a10, "vmrs r7, fpscr", defs!(Reg::R7), uses!(Fpscr {}));
+        assert_arm!(
+            0xeef1fa10,
+            "vmrs pc, fpscr",
+            defs!(Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Fpscr {})
+        );
 
         assert_arm!(0xeee17a10, "vmsr fpscr, r7", defs!(Fpscr {}), uses!(Reg::R7));
 
@@ -445,7 +598,7 @@ mod tests {
             0x0cbd9b08,
             "vpopeq {d9, d10, d11, d12}",
             defs!(Reg::Sp, DregList::range(Dreg::D9, Dreg::D12)),
-            uses!(Reg::Sp)
+            uses!(Reg::Sp, Flag::Z)
         );
 
         assert_arm!(
@@ -458,7 +611,7 @@ mod tests {
             0x0d2d9b08,
             "vpusheq {d9, d10, d11, d12}",
             defs!(Reg::Sp),
-            uses!(Reg::Sp, DregList::range(Dreg::D9, Dreg::D12))
+            uses!(Reg::Sp, DregList::range(Dreg::D9, Dreg::D12), Flag::Z)
         );
 
         assert_arm!(
@@ -471,37 +624,82 @@ mod tests {
             0x0cc79b08,
             "vstmiaeq r7, {d25, d26, d27, d28}",
             defs!(),
-            uses!(DregList::range(Dreg::D25, Dreg::D28), Reg::R7)
+            uses!(DregList::range(Dreg::D25, Dreg::D28), Reg::R7, Flag::Z)
         );
 
         assert_arm!(0xedc79a29, "vstr s19, [r7, #0xa4]", defs!(), uses!(Sreg::S19, Reg::R7));
-        assert_arm!(0x0d479b29, "vstreq d25, [r7, #-0xa4]", defs!(), uses!(Dreg::D25, Reg::R7));
+        assert_arm!(
+            0x0d479b29,
+            "vstreq d25, [r7, #-0xa4]",
+            defs!(),
+            uses!(Dreg::D25, Reg::R7, Flag::Z)
+        );
     }
 
     #[test]
     fn test_thumb() {
-        assert_thumb!(0x4157, "adcs r7, r7, r2", defs!(Reg::R7), uses!(Reg::R7, Reg::R2));
+        assert_thumb!(
+            0x4157,
+            "adcs r7, r7, r2",
+            defs!(Reg::R7, Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Reg::R7, Flag::C, Reg::R2)
+        );
 
-        assert_thumb!(0x1cca, "adds r2, r1, #0x3", defs!(Reg::R2), uses!(Reg::R1));
-        assert_thumb!(0x1853, "adds r3, r2, r1", defs!(Reg::R3), uses!(Reg::R2, Reg::R1));
+        assert_thumb!(
+            0x1cca,
+            "adds r2, r1, #0x3",
+            defs!(Reg::R2, Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Reg::R1)
+        );
+        assert_thumb!(
+            0x1853,
+            "adds r3, r2, r1",
+            defs!(Reg::R3, Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Reg::R2, Reg::R1)
+        );
         assert_thumb!(0x44de, "add lr, lr, r11", defs!(Reg::Lr), uses!(Reg::Lr, Reg::R11));
         assert_thumb!(0xacff, "add r4, sp, #0x3fc", defs!(Reg::R4), uses!(Reg::Sp));
         assert_thumb!(0xb03a, "add sp, sp, #0xe8", defs!(Reg::Sp), uses!(Reg::Sp));
         assert_thumb!(0xa413, "add r4, pc, #0x4c", defs!(Reg::R4), uses!(Reg::Pc));
 
-        assert_thumb!(0x1023, "asrs r3, r4, #0x20", defs!(Reg::R3), uses!(Reg::R4));
-        assert_thumb!(0x4117, "asrs r7, r7, r2", defs!(Reg::R7), uses!(Reg::R7, Reg::R2));
+        assert_thumb!(
+            0x1023,
+            "asrs r3, r4, #0x20",
+            defs!(Reg::R3, Flag::N, Flag::Z, Flag::C),
+            uses!(Reg::R4)
+        );
+        assert_thumb!(
+            0x4117,
+            "asrs r7, r7, r2",
+            defs!(Reg::R7, Flag::N, Flag::Z, Flag::C),
+            uses!(Reg::R7, Flag::C, Reg::R2)
+        );
 
-        assert_thumb!(0xd042, "beq #0x88", defs!(Reg::Pc), uses!());
+        assert_thumb!(0xd042, "beq #0x88", defs!(Reg::Pc), uses!(Flag::Z));
 
-        assert_thumb!(0xf000, 0xe801, "blx #0x4", defs!(Reg::Pc), uses!());
-        assert_thumb!(0x47d0, "blx r10", defs!(Reg::Pc), uses!(Reg::R10));
+        assert_thumb!(0xf000, 0xe801, "blx #0x4", defs!(Reg::Pc, Reg::Lr), uses!());
+        assert_thumb!(0x47d0, "blx r10", defs!(Reg::Pc, Reg::Lr), uses!(Reg::R10));
 
         assert_thumb!(0x4750, "bx r10", defs!(Reg::Pc), uses!(Reg::R10));
 
-        assert_thumb!(0x2942, "cmp r1, #0x42", defs!(), uses!(Reg::R1));
-        assert_thumb!(0x4297, "cmp r7, r2", defs!(), uses!(Reg::R7, Reg::R2));
-        assert_thumb!(0x45de, "cmp lr, r11", defs!(), uses!(Reg::Lr, Reg::R11));
+        assert_thumb!(
+            0x2942,
+            "cmp r1, #0x42",
+            defs!(Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Reg::R1)
+        );
+        assert_thumb!(
+            0x4297,
+            "cmp r7, r2",
+            defs!(Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Reg::R7, Reg::R2)
+        );
+        assert_thumb!(
+            0x45de,
+            "cmp lr, r11",
+            defs!(Flag::N, Flag::Z, Flag::C, Flag::V),
+            uses!(Reg::Lr, Reg::R11)
+        );
 
         assert_thumb!(
             0xc955,
@@ -519,8 +717,8 @@ mod tests {
         assert_thumb!(0x6c22, "ldr r2, [r4, #0x40]", defs!(Reg::R2), uses!(Reg::R4));
         assert_thumb!(0x5822, "ldr r2, [r4, r0]", defs!(Reg::R2), uses!(Reg::R4, Reg::R0));
 
-        assert_thumb!(0x2163, "movs r1, #0x63", defs!(Reg::R1), uses!());
-        assert_thumb!(0x0017, "movs r7, r2", defs!(Reg::R7), uses!(Reg::R2));
+        assert_thumb!(0x2163, "movs r1, #0x63", defs!(Reg::R1, Flag::N, Flag::Z), uses!());
+        assert_thumb!(0x0017, "movs r7, r2", defs!(Reg::R7, Flag::N, Flag::Z), uses!(Reg::R2));
 
         assert_thumb!(
             0xbdff,
@@ -572,4 +770,41 @@ mod tests {
         assert_thumb!(0x6422, "str r2, [r4, #0x40]", defs!(), uses!(Reg::R2, Reg::R4));
         assert_thumb!(0x5022, "str r2, [r4, r0]", defs!(), uses!(Reg::R2, Reg::R4, Reg::R0));
     }
+
+    #[test]
+    fn test_neon() {
+        let options = Options { version: unarm::Version::V7, ..options!() };
+        let defs_uses = |code: u32| {
+            let ins = parse_arm(code, 0, &options);
+            (ins.defs().into_iter().collect::<Vec<_>>(), ins.uses().into_iter().collect::<Vec<_>>())
+        };
+
+        // vadd.i32 q0, q1, q2
+        assert_eq!(defs_uses(0xf2220844), (defs!(Qreg::Q0), uses!(Qreg::Q1, Qreg::Q2)));
+        // vbsl d0, d1, d2
+        assert_eq!(
+            defs_uses(0xf3110112),
+            (defs!(Dreg::D0), uses!(Dreg::D0, Dreg::D1, Dreg::D2))
+        );
+        // vdup.32 q0, r1
+        assert_eq!(defs_uses(0xeea01b10), (defs!(Qreg::Q0), uses!(Reg::R1)));
+
+        // vld1.8 {d0, d1}, [r0]!
+        let (defs, uses) = defs_uses(0xf4200a0d);
+        let DefUseArgument::NeonRegList(regs) = defs[0] else {
+            panic!("expected register list, got {:?}", defs[0]);
+        };
+        assert!(regs.iter().eq([Dreg::D0, Dreg::D1]));
+        assert_eq!(defs[1..], defs!(Reg::R0));
+        assert_eq!(uses, uses!(Reg::R0));
+
+        // vst4.32 {d0, d2, d4, d6}, [r2:128], r3
+        let (defs, uses) = defs_uses(0xf40201a3);
+        assert_eq!(defs, defs!(Reg::R2));
+        let DefUseArgument::NeonRegList(regs) = uses[0] else {
+            panic!("expected register list, got {:?}", uses[0]);
+        };
+        assert!(regs.iter().eq([Dreg::D0, Dreg::D2, Dreg::D4, Dreg::D6]));
+        assert_eq!(uses[1..], uses!(Reg::R2, Reg::R3));
+    }
 }
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{Discovery, Options, ParseEndian, ParseMode, Region};
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V5Te,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    #[rustfmt::skip]
+    const CODE: &[u8] = &[
+        0x04, 0x00, 0x9f, 0xe5, // 0x00: ldr r0, [pc, #0x4]
+        0x00, 0x00, 0xa0, 0xe1, // 0x04: mov r0, r0
+        0x00, 0x00, 0x00, 0xeb, // 0x08: bl #0x10
+        0x19, 0x00, 0x00, 0x02, // 0x0c: .word 0x2000019
+        0x01, 0xc0, 0x8f, 0xe2, // 0x10: add ip, pc, #0x1
+        0x1c, 0xff, 0x2f, 0xe1, // 0x14: bx ip
+        0x01, 0x48,             // 0x18: ldr r0, [pc, #0x4]
+        0x70, 0x47,             // 0x1a: bx lr
+        0x00, 0x00, 0x00, 0x00, // 0x1c: unreachable
+        0x78, 0x56, 0x34, 0x12, // 0x20: .word 0x12345678
+    ];
+
+    #[test]
+    fn test_discovery() {
+        let mut discovery = Discovery::new(CODE, 0x2000000, ParseEndian::Little, options!());
+        discovery.add_entry(0x2000000, ParseMode::Arm);
+
+        assert_eq!(
+            discovery.functions().collect::<Vec<_>>(),
+            vec![(0x2000000, ParseMode::Arm), (0x2000010, ParseMode::Arm)]
+        );
+        assert_eq!(discovery.literals().collect::<Vec<_>>(), vec![(0x200000c, 4), (0x2000020, 4)]);
+
+        // The literal at 0xc is reached by falling through from the BL, but is still data
+        assert_eq!(
+            discovery.regions(),
+            vec![
+                Region { start: 0x2000000, end: 0x200000c, mode: ParseMode::Arm },
+                Region { start: 0x200000c, end: 0x2000010, mode: ParseMode::Data },
+                Region { start: 0x2000010, end: 0x2000018, mode: ParseMode::Arm },
+                Region { start: 0x2000018, end: 0x200001c, mode: ParseMode::Thumb },
+                Region { start: 0x200001c, end: 0x2000024, mode: ParseMode::Data },
+            ]
+        );
+        assert_eq!(
+            discovery.mapping_symbols().collect::<Vec<_>>(),
+            vec![
+                (0x2000000, "$a"),
+                (0x200000c, "$d"),
+                (0x2000010, "$a"),
+                (0x2000018, "$t"),
+                (0x200001c, "$d"),
+            ]
+        );
+        for (_, symbol) in discovery.mapping_symbols() {
+            assert!(ParseMode::from_mapping_symbol(symbol).is_some());
+        }
+    }
+
+    #[test]
+    fn test_noreturn() {
+        #[rustfmt::skip]
+        let code = &[
+            0x04, 0x00, 0x9f, 0xe5, // 0x00: ldr r0, [pc, #0x4]
+            0x01, 0x00, 0x00, 0xeb, // 0x04: bl #0x10
+            0x01, 0x00, 0x00, 0xeb, // 0x08: unreachable, looks like bl #0x14
+            0x78, 0x56, 0x34, 0x12, // 0x0c: .word 0x12345678
+            0xfe, 0xff, 0xff, 0xea, // 0x10: b #0x10
+            0x1e, 0xff, 0x2f, 0xe1, // 0x14: bx lr
+        ];
+
+        let mut discovery = Discovery::new(code, 0x2000000, ParseEndian::Little, options!());
+        discovery.add_entry(0x2000000, ParseMode::Arm);
+        assert!(discovery.functions().any(|(addr, _)| addr == 0x2000014));
+
+        let mut discovery = Discovery::new(code, 0x2000000, ParseEndian::Little, options!());
+        discovery.add_noreturn(0x2000010);
+        discovery.add_entry(0x2000000, ParseMode::Arm);
+        assert_eq!(
+            discovery.functions().collect::<Vec<_>>(),
+            vec![(0x2000000, ParseMode::Arm), (0x2000010, ParseMode::Arm)]
+        );
+        assert_eq!(
+            discovery.regions(),
+            vec![
+                Region { start: 0x2000000, end: 0x2000008, mode: ParseMode::Arm },
+                Region { start: 0x2000008, end: 0x2000010, mode: ParseMode::Data },
+                Region { start: 0x2000010, end: 0x2000014, mode: ParseMode::Arm },
+                Region { start: 0x2000014, end: 0x2000018, mode: ParseMode::Data },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multiple_entries() {
+        let mut discovery = Discovery::new(CODE, 0x2000000, ParseEndian::Little, options!());
+        discovery.add_entry(0x2000018, ParseMode::Thumb);
+        assert_eq!(
+            discovery.mapping_symbols().collect::<Vec<_>>(),
+            vec![(0x2000000, "$d"), (0x2000018, "$t"), (0x200001c, "$d")]
+        );
+
+        discovery.add_entry(0x2000010, ParseMode::Arm);
+        assert_eq!(
+            discovery.mapping_symbols().collect::<Vec<_>>(),
+            vec![(0x2000000, "$d"), (0x2000010, "$a"), (0x2000018, "$t"), (0x200001c, "$d")]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(all(test, feature = "elf"))]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use unarm::{
+        ElfError, ElfFile, ParseEndian, ParseMode, Relocation, RelocationKind, StringFormatter,
+        SymbolFormatter, Version,
+    };
+
+    // Assembled from elf/test.s with llvm-mc for armv5te-none-eabi and armebv5te-none-eabi
+    const ELF_LE: &[u8] = include_bytes!("elf/test.o");
+    const ELF_BE: &[u8] = include_bytes!("elf/test_be.o");
+    const ELF_RELOC: &[u8] = include_bytes!("elf/reloc.o");
+
+    fn disassemble(data: &[u8]) -> Vec<(u32, ParseMode, String)> {
+        let elf = ElfFile::parse(data).unwrap();
+        let options = elf.options();
+        let sections = elf.code_sections().collect::<Vec<_>>();
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].name, ".text");
+
+        let mut parser = sections[0].parser(options.clone());
+        let mut result = Vec::new();
+        while let Some((addr, ins)) = parser.next() {
+            result.push((addr, parser.mode(), ins.display(&options).to_string()));
+        }
+        result
+    }
+
+    #[test]
+    fn test_elf() {
+        let elf = ElfFile::parse(ELF_LE).unwrap();
+        assert_eq!(elf.endian(), ParseEndian::Little);
+        assert_eq!(elf.version(), Some(Version::V5Te));
+        assert_eq!(elf.options().version, Version::V5Te);
+
+        let section = elf.code_sections().next().unwrap();
+        assert_eq!(
+            section.mapping_symbols(),
+            &[(0x0, ParseMode::Arm), (0xc, ParseMode::Data), (0x10, ParseMode::Thumb)]
+        );
+        assert_eq!(section.mode_at(0xe), ParseMode::Data);
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let expected = [
+            (0x0, ParseMode::Arm, "ldr r0, [pc, #0x4]"),
+            (0x4, ParseMode::Arm, "add r0, r0, #0x1"),
+            (0x8, ParseMode::Arm, "bx lr"),
+            (0xc, ParseMode::Data, ".word 0x2001234"),
+            (0x10, ParseMode::Thumb, "movs r0, #0x1"),
+            (0x12, ParseMode::Thumb, "bx lr"),
+        ];
+        let expected = expected.map(|(addr, mode, ins)| (addr, mode, ins.to_string()));
+        assert_eq!(disassemble(ELF_LE), expected);
+        assert_eq!(disassemble(ELF_BE), expected);
+        assert_eq!(ElfFile::parse(ELF_BE).unwrap().endian(), ParseEndian::Big);
+    }
+
+    #[test]
+    fn test_relocations() {
+        let elf = ElfFile::parse(ELF_RELOC).unwrap();
+        let options = elf.options();
+        let section = elf.code_sections().next().unwrap();
+        assert_eq!(
+            section.relocation(0x4),
+            Some(Relocation { kind: RelocationKind::Call, symbol: "printf" })
+        );
+        assert_eq!(section.relocation(0x0), None);
+
+        let symbols = BTreeMap::<u32, &str>::new();
+        let mut parser = section.parser(options.clone());
+        let mut lines = Vec::new();
+        while let Some((addr, ins)) = parser.next() {
+            let mut formatter = SymbolFormatter::new(StringFormatter::new(&options), &symbols)
+                .with_literals(section.data, section.address, elf.endian())
+                .with_literal_relocations(section.relocations());
+            match parser.relocation() {
+                Some(relocation) => {
+                    formatter.write_relocated_ins_at(&ins, addr, parser.mode(), relocation)
+                }
+                None => formatter.write_ins_at(&ins, addr, parser.mode()),
+            }
+            .unwrap();
+            lines.push(formatter.into_inner().into_string());
+        }
+        assert_eq!(
+            lines,
+            [
+                "push {r4, lr}",
+                "bl printf",
+                "bl local",
+                "b exit",
+                "ldr r0, [pc, #0xc] ; =.data",
+                "pop {r4, pc}",
+                "bx lr",
+                "bl printf",
+                ".word .data+0x4",
+                ".word .data",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(ElfFile::parse(&[0; 16]), Err(ElfError::Invalid(_))));
+
+        let mut data = ELF_LE.to_vec();
+        // e_machine = EM_386
+        data[18] = 3;
+        assert!(matches!(ElfFile::parse(&data), Err(ElfError::NotArm)));
+    }
+}
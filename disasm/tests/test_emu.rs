@@ -0,0 +1,382 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{
+        Bus, Cpu, CpuMode, Emu, EmuError, Flag, Ins, Options, ParseMode, Reg, Sreg, parse_asm,
+    };
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V5Te,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    const STACK_TOP: u32 = 0x1000;
+
+    /// Assembles ARM `code` at `addr`.
+    fn assemble(emu: &mut Emu<Vec<u8>>, addr: u32, code: &[&str]) {
+        let options = options!();
+        for (i, line) in code.iter().enumerate() {
+            let pc = addr + i as u32 * 4;
+            let ins = parse_asm(line, pc, ParseMode::Arm, &options).unwrap();
+            emu.bus.write_u32(pc, ins.encode_arm(pc, &options).unwrap()).unwrap();
+        }
+    }
+
+    /// Writes Thumb `code` at `addr`.
+    fn write_thumb(emu: &mut Emu<Vec<u8>>, addr: u32, code: &[u16]) {
+        for (i, &code) in code.iter().enumerate() {
+            emu.bus.write_u16(addr + i as u32 * 2, code).unwrap();
+        }
+    }
+
+    fn emu() -> Emu<Vec<u8>> {
+        let mut emu = Emu::new(vec![0; 0x2000], options!());
+        emu.cpu.set_mode(CpuMode::System);
+        emu.cpu.set(Reg::Sp, STACK_TOP);
+        emu
+    }
+
+    #[test]
+    fn test_cpu_reset() {
+        let cpu = Cpu::new();
+        assert_eq!(cpu.mode(), CpuMode::Supervisor);
+        assert!(!cpu.thumb());
+        assert_eq!(cpu.cpsr(), 0xd3);
+        assert_eq!(cpu.spsr(), Some(0));
+    }
+
+    #[test]
+    fn test_banked_registers() {
+        let mut cpu = Cpu::new();
+        cpu.set_mode(CpuMode::System);
+        cpu.set(Reg::Sp, 0x100);
+        cpu.set(Reg::R8, 8);
+        cpu.set_banked(Reg::Sp, CpuMode::Irq, 0x200);
+        cpu.set_banked(Reg::R8, CpuMode::Fiq, 0x88);
+
+        cpu.set_mode(CpuMode::Irq);
+        assert_eq!(cpu.get(Reg::Sp), 0x200);
+        assert_eq!(cpu.get(Reg::R8), 8);
+        assert_eq!(cpu.banked(Reg::Sp, CpuMode::User), 0x100);
+
+        cpu.set_mode(CpuMode::Fiq);
+        assert_eq!(cpu.get(Reg::R8), 0x88);
+        assert_eq!(cpu.banked(Reg::R8, CpuMode::System), 8);
+
+        cpu.set_cpsr(CpuMode::User.bits());
+        assert_eq!(cpu.get(Reg::Sp), 0x100);
+        assert_eq!(cpu.get(Reg::R8), 8);
+        assert_eq!(cpu.spsr(), None);
+    }
+
+    #[test]
+    fn test_call_arm() {
+        let mut emu = emu();
+        // Sums the first n words of an array
+        assemble(
+            &mut emu,
+            0x100,
+            &[
+                "mov r2, #0x0",
+                "cmp r1, #0x0",
+                "beq #0x11c",
+                "ldr r3, [r0], #0x4",
+                "add r2, r2, r3",
+                "subs r1, r1, #0x1",
+                "bne #0x10c",
+                "mov r0, r2",
+                "bx lr",
+            ],
+        );
+        for (i, value) in [3, 5, 7, 11].into_iter().enumerate() {
+            emu.bus.write_u32(0x800 + i as u32 * 4, value).unwrap();
+        }
+        assert_eq!(emu.call(0x100, &[0x800, 4], 100), Ok(26));
+        assert_eq!(emu.call(0x100, &[0x800, 0], 100), Ok(0));
+        assert_eq!(emu.cpu.get(Reg::Sp), STACK_TOP);
+    }
+
+    #[test]
+    fn test_call_thumb() {
+        let mut emu = emu();
+        write_thumb(
+            &mut emu,
+            0x100,
+            &[
+                0xb510, // push {r4, lr}
+                0x1c0c, // adds r4, r1, #0x0
+                0xf000, // bl #0x110
+                0xf804, 0x1900, // adds r0, r0, r4
+                0xbd10, // pop {r4, pc}
+            ],
+        );
+        write_thumb(
+            &mut emu,
+            0x110,
+            &[
+                0x0080, // lsls r0, r0, #0x2
+                0x4770, // bx lr
+            ],
+        );
+        assert_eq!(emu.call(0x101, &[5, 3], 100), Ok(23));
+        // Returned to ARM code since bit 0 of the return address is clear
+        assert!(!emu.cpu.thumb());
+    }
+
+    #[test]
+    fn test_interworking() {
+        let mut emu = emu();
+        // ARM calls Thumb, which returns to ARM with pop {pc}
+        assemble(&mut emu, 0x100, &["push {lr}", "blx #0x200", "add r0, r0, #0x1", "pop {pc}"]);
+        write_thumb(
+            &mut emu,
+            0x200,
+            &[
+                0xb500, // push {lr}
+                0x3010, // adds r0, r0, #0x10
+                0xbd00, // pop {pc}
+            ],
+        );
+        assert_eq!(emu.call(0x100, &[1], 100), Ok(0x12));
+        assert!(!emu.cpu.thumb());
+
+        // Thumb calls ARM through a register
+        write_thumb(
+            &mut emu,
+            0x300,
+            &[
+                0xb500, // push {lr}
+                0x4788, // blx r1
+                0xbd00, // pop {pc}
+            ],
+        );
+        assemble(&mut emu, 0x400, &["rsb r0, r0, #0x0", "bx lr"]);
+        assert_eq!(emu.call(0x301, &[7, 0x400], 100), Ok(-7i32 as u32));
+    }
+
+    #[test]
+    fn test_stack_args() {
+        let mut emu = emu();
+        assemble(
+            &mut emu,
+            0x100,
+            &["ldr r0, [sp, #0x0]", "ldr r1, [sp, #0x4]", "sub r0, r0, r1", "bx lr"],
+        );
+        assert_eq!(emu.call(0x100, &[0, 0, 0, 0, 10, 4], 10), Ok(6));
+        assert_eq!(emu.cpu.get(Reg::Sp), STACK_TOP);
+    }
+
+    #[test]
+    fn test_flags() {
+        let mut emu = emu();
+        // 64-bit addition
+        assemble(&mut emu, 0x100, &["adds r0, r0, r2", "adc r1, r1, r3", "bx lr"]);
+        emu.call(0x100, &[0xffff_ffff, 1, 1, 2], 10).unwrap();
+        assert_eq!(emu.cpu.get(Reg::R0), 0);
+        assert_eq!(emu.cpu.get(Reg::R1), 4);
+        assert!(emu.cpu.flag(Flag::C));
+        assert!(emu.cpu.flag(Flag::Z));
+
+        // Signed overflow
+        assemble(&mut emu, 0x100, &["adds r0, r0, r1", "movvs r0, #0x0", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[0x7fff_ffff, 1], 10), Ok(0));
+        assert!(emu.cpu.flag(Flag::V));
+        assert!(emu.cpu.flag(Flag::N));
+
+        // Shifter carry out
+        assemble(&mut emu, 0x100, &["lsrs r0, r0, #0x1", "movhs r0, #0xff", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[3], 10), Ok(0xff));
+        assert_eq!(emu.call(0x100, &[4], 10), Ok(2));
+
+        // Shift by register amounts of 32 and more
+        assemble(&mut emu, 0x100, &["lsls r0, r0, r1", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[1, 32], 10), Ok(0));
+        assert!(emu.cpu.flag(Flag::C));
+        assert_eq!(emu.call(0x100, &[1, 33], 10), Ok(0));
+        assert!(!emu.cpu.flag(Flag::C));
+    }
+
+    #[test]
+    fn test_multiply() {
+        let mut emu = emu();
+        assemble(&mut emu, 0x100, &["mla r0, r0, r1, r2", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[6, 7, 8], 10), Ok(50));
+
+        assemble(&mut emu, 0x100, &["smull r0, r1, r0, r1", "bx lr"]);
+        emu.call(0x100, &[-3i32 as u32, 0x4000_0000], 10).unwrap();
+        assert_eq!(emu.cpu.get(Reg::R0), 0x4000_0000);
+        assert_eq!(emu.cpu.get(Reg::R1), 0xffff_ffff);
+    }
+
+    #[test]
+    fn test_memory() {
+        let mut emu = emu();
+        emu.bus.write_u32(0x800, 0x4433_2211).unwrap();
+        emu.bus.write_u32(0x804, 0x8877_6655).unwrap();
+
+        // Unaligned word loads are rotated
+        assemble(&mut emu, 0x100, &["ldr r0, [r0, #0x1]", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[0x800], 10), Ok(0x1144_3322));
+
+        assemble(&mut emu, 0x100, &["ldrsh r0, [r0, #0x6]", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[0x800], 10), Ok(0xffff_8877));
+
+        assemble(&mut emu, 0x100, &["ldrsb r0, [r0, r1]", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[0x800, 3], 10), Ok(0x44));
+
+        assemble(&mut emu, 0x100, &["strb r1, [r0, #0x1]!", "strh r1, [r0, #0x1]", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[0x800, 0xabcd], 10), Ok(0x801));
+        assert_eq!(emu.bus.read_u32(0x800), Some(0xabcd_cd11));
+
+        assemble(&mut emu, 0x100, &["ldm r0!, {r1, r2}", "stmdb r0, {r1, r2, r3}", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[0x800, 0, 0, 0x99], 10), Ok(0x808));
+        assert_eq!(emu.bus.read_u32(0x7fc), Some(0xabcd_cd11));
+        assert_eq!(emu.bus.read_u32(0x800), Some(0x8877_6655));
+        assert_eq!(emu.bus.read_u32(0x804), Some(0x99));
+
+        assemble(&mut emu, 0x100, &["swp r0, r1, [r0]", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[0x804, 0x1234], 10), Ok(0x99));
+        assert_eq!(emu.bus.read_u32(0x804), Some(0x1234));
+    }
+
+    #[test]
+    fn test_thumb_pc_relative() {
+        let mut emu = emu();
+        write_thumb(
+            &mut emu,
+            0x102,
+            &[
+                0x4801, // ldr r0, [pc, #0x4]
+                0xa101, // add r1, pc, #0x4
+                0x4770, // bx lr
+            ],
+        );
+        emu.bus.write_u32(0x108, 0xdead_beef).unwrap();
+        assert_eq!(emu.call(0x103, &[], 10), Ok(0xdead_beef));
+        assert_eq!(emu.cpu.get(Reg::R1), 0x10c);
+    }
+
+    #[test]
+    fn test_exceptions() {
+        let mut emu = emu();
+        // SVC handler which returns the SVC number plus one
+        assemble(&mut emu, 0x08, &["b #0x40"]);
+        assemble(
+            &mut emu,
+            0x40,
+            &["ldr r0, [lr, #-0x4]", "bic r0, r0, #0xff000000", "add r0, r0, #0x1", "movs pc, lr"],
+        );
+        emu.cpu.set_banked(Reg::Sp, CpuMode::Supervisor, 0xf00);
+        assemble(&mut emu, 0x100, &["svc #0x41", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[], 10), Ok(0x42));
+        assert_eq!(emu.cpu.mode(), CpuMode::System);
+
+        // Undefined instruction handler which skips the instruction
+        assemble(&mut emu, 0x04, &["movs pc, lr"]);
+        assemble(&mut emu, 0x100, &["udf #0x0", "mov r0, #0x5", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[], 10), Ok(5));
+
+        // IRQ returns to the interrupted instruction
+        assemble(&mut emu, 0x18, &["mov r1, #0x1", "subs pc, lr, #0x4"]);
+        emu.cpu.set_cpsr(CpuMode::User.bits());
+        emu.cpu.set_pc(0x100);
+        assert!(emu.interrupt(false));
+        assert_eq!(emu.cpu.mode(), CpuMode::Irq);
+        assert_eq!(emu.run_until(0x100, 10), Ok(2));
+        assert_eq!(emu.cpu.mode(), CpuMode::User);
+        assert_eq!(emu.cpu.get(Reg::R1), 1);
+
+        emu.cpu.set_cpsr(CpuMode::User.bits() | 0x80);
+        assert!(!emu.interrupt(false));
+    }
+
+    #[test]
+    fn test_msr() {
+        let mut emu = emu();
+        assemble(
+            &mut emu,
+            0x100,
+            &["msr cpsr_c, #0xd2", "mov sp, #0x400", "msr cpsr_c, #0x1f", "mrs r0, cpsr", "bx lr"],
+        );
+        assert_eq!(emu.call(0x100, &[], 10), Ok(0x1f));
+        assert_eq!(emu.cpu.banked(Reg::Sp, CpuMode::Irq), 0x400);
+        assert_eq!(emu.cpu.get(Reg::Sp), STACK_TOP);
+
+        // User mode can only change the flags
+        emu.cpu.set_mode(CpuMode::User);
+        assemble(&mut emu, 0x100, &["msr cpsr_fc, r0", "bx lr"]);
+        emu.call(0x100, &[0xf000_001f], 10).unwrap();
+        assert_eq!(emu.cpu.mode(), CpuMode::User);
+        assert!(emu.cpu.flag(Flag::N) && emu.cpu.flag(Flag::V));
+    }
+
+    #[test]
+    fn test_dsp() {
+        let mut emu = emu();
+        assemble(&mut emu, 0x100, &["qadd r0, r0, r1", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[0x7fff_fff0, 0x100], 10), Ok(0x7fff_ffff));
+        assert!(emu.cpu.flag(Flag::Q));
+
+        assemble(&mut emu, 0x100, &["smlabt r0, r0, r1, r2", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[0xffff_fffe, 0x0003_0000, 10], 10), Ok(4));
+
+        assemble(&mut emu, 0x100, &["ldrd r2, r3, [r0, #0x0]", "strd r2, r3, [r0, #0x8]", "bx lr"]);
+        emu.bus.write_u32(0x800, 1).unwrap();
+        emu.bus.write_u32(0x804, 2).unwrap();
+        emu.call(0x100, &[0x800], 10).unwrap();
+        assert_eq!(emu.bus.read_u32(0x808), Some(1));
+        assert_eq!(emu.bus.read_u32(0x80c), Some(2));
+    }
+
+    #[test]
+    fn test_vfp() {
+        let mut emu = emu();
+        assemble(
+            &mut emu,
+            0x100,
+            &[
+                "vmov s0, r0",
+                "vmov s1, r1",
+                "vcvt.f32.s32 s0, s0",
+                "vcvt.f32.s32 s1, s1",
+                "vdiv.f32 s2, s0, s1",
+                "vcmp.f32 s2, s1",
+                "vmrs pc, fpscr",
+                "vcvt.s32.f32 s2, s2",
+                "vmov r0, s2",
+                "movlt r0, #0x0",
+                "bx lr",
+            ],
+        );
+        // Converting to an integer rounds toward zero
+        assert_eq!(emu.call(0x100, &[22, 4], 20), Ok(5));
+        assert_eq!(f32::from_bits(emu.cpu.sreg(Sreg::S0)), 22.0);
+        assert_eq!(emu.call(0x100, &[10, 4], 20), Ok(0));
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut emu = emu();
+        assemble(&mut emu, 0x100, &["ldr r0, [r0, #0x0]", "bx lr"]);
+        assert_eq!(emu.call(0x100, &[0x10000], 10), Err(EmuError::BusFault { addr: 0x10000 }));
+        assert_eq!(emu.cpu.pc(), 0x100);
+
+        assemble(&mut emu, 0x100, &["b #0x100"]);
+        assert_eq!(emu.call(0x100, &[], 10), Err(EmuError::StepLimit));
+
+        assemble(&mut emu, 0x100, &["mcr p15, #0x0, r0, c7, c10, #0x4"]);
+        let ins = parse_asm("mcr p15, #0x0, r0, c7, c10, #0x4", 0x100, ParseMode::Arm, &options!())
+            .unwrap();
+        assert!(matches!(ins, Ins::Mcr { .. }));
+        assert_eq!(emu.step(), Err(EmuError::Unimplemented(ins)));
+    }
+}
@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{EncodeError, Ins, Options, parse_arm, parse_thumb};
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V6K,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    macro_rules! assert_arm {
+        ($code:literal, $disasm:literal) => {{ assert_arm!($code, 0, $disasm) }};
+        ($code:literal, $pc:literal, $disasm:literal) => {{
+            let options = options!();
+            let ins = parse_arm($code, $pc, &options);
+            assert_eq!(ins.display(&options).to_string(), $disasm);
+            assert_eq!(ins.encode_arm($pc, &options), Ok($code));
+        }};
+    }
+
+    macro_rules! assert_thumb {
+        ($code:literal, $disasm:literal) => {{
+            let options = options!();
+            let (ins, _size) = parse_thumb($code, 0, &options);
+            assert_eq!(ins.display(&options).to_string(), $disasm);
+            assert_eq!(ins.encode_thumb(0, &options), Ok(($code, 2)));
+        }};
+        ($code:literal, $next:literal, $disasm:literal) => {{
+            let options = options!();
+            let (ins, _size) = parse_thumb($code | ($next << 16), 0, &options);
+            assert_eq!(ins.display(&options).to_string(), $disasm);
+            assert_eq!(ins.encode_thumb(0, &options), Ok(($code | ($next << 16), 4)));
+        }};
+    }
+
+    #[test]
+    fn test_encode_arm() {
+        assert_arm!(0xe0821003, "add r1, r2, r3");
+        assert_arm!(0xe2821c01, "add r1, r2, #0x100");
+        assert_arm!(0xe3a004ff, "mov r0, #0xff000000");
+        assert_arm!(0x11a01102, "lslne r1, r2, #0x2");
+        assert_arm!(0xe5912004, "ldr r2, [r1, #0x4]");
+        assert_arm!(0xe5112004, "ldr r2, [r1, #-0x4]");
+        assert_arm!(0xe7b12103, "ldr r2, [r1, r3, lsl #0x2]!");
+        assert_arm!(0xe1d120b4, "ldrh r2, [r1, #0x4]");
+        assert_arm!(0xe8bd8010, "pop {r4, pc}");
+        assert_arm!(0xe92d4010, "push {r4, lr}");
+        assert_arm!(0xeb000010, 0x1000, "bl #0x1048");
+        assert_arm!(0xeafffffe, 0x1000, "b #0x1000");
+        assert_arm!(0xfa000001, "blx #0xc");
+        assert_arm!(0xe12fff1e, "bx lr");
+        assert_arm!(0xe10f0000, "mrs r0, cpsr");
+        assert_arm!(0xe129f000, "msr cpsr_fc, r0");
+        assert_arm!(0xf102001a, "cps #0x1a");
+        assert_arm!(0xf10c0080, "cpsid i");
+        assert_arm!(0xee070f9a, "mcr p15, #0x0, r0, c7, c10, #0x4");
+        assert_arm!(0xecbd8b04, "vpop {d8, d9}");
+        assert_arm!(0xee300a81, "vadd.f32 s0, s1, s2");
+
+        let options = Options { version: unarm::Version::V7, ..options!() };
+        for (code, disasm) in
+            [(0x5076c0be, "ldrhtpl r12, [r6], #-0xe"), (0xe03120d3, "ldrsbt r2, [r1], -r3")]
+        {
+            let ins = parse_arm(code, 0, &options);
+            assert_eq!(ins.display(&options).to_string(), disasm);
+            assert_eq!(ins.encode_arm(0, &options), Ok(code));
+        }
+    }
+
+    #[test]
+    fn test_encode_thumb() {
+        assert_thumb!(0x1888, "adds r0, r1, r2");
+        assert_thumb!(0x3010, "adds r0, r0, #0x10");
+        assert_thumb!(0x4468, "add r0, sp, r0");
+        assert_thumb!(0x6848, "ldr r0, [r1, #0x4]");
+        assert_thumb!(0xb510, "push {r4, lr}");
+        assert_thumb!(0xbd10, "pop {r4, pc}");
+        assert_thumb!(0xd0fe, "beq #0x0");
+        assert_thumb!(0x4770, "bx lr");
+        assert_thumb!(0xf000, 0xf802, "bl #0x8");
+        assert_thumb!(0xf7ff, 0xeffe, "blx #0x0");
+        let (ins, _size) = parse_thumb(0xd000f000, 0, &options!());
+        assert_eq!(ins.encode_thumb(0, &options!()), Ok((0xf800f000, 4)));
+    }
+
+    #[test]
+    fn test_encode_thumb2() {
+        let options = Options { version: unarm::Version::V7, ..options!() };
+        for (code, disasm) in [
+            (0x2134f241, "movw r1, #0x1234"),
+            (0x6178f2c5, "movt r1, #0x5678"),
+            (0x417ff502, "add r1, r2, #0xff00"),
+            (0x0183ea62, "orn r1, r2, r3, lsl #0x2"),
+            (0x1023f852, "ldr r1, [r2, r3, lsl #0x2]"),
+            (0x1f01e852, "ldrex r1, [r2, #0x4]"),
+            (0x0e4df85d, "ldrt r0, [sp, #0x4d]"),
+            (0x8eedf833, "ldrht r8, [r3, #0xed]"),
+            (0x110bf362, "bfi r1, r2, #0x4, #0x8"),
+            (0xf1f3fb92, "sdiv r1, r2, r3"),
+            (0xf012e8d1, "tbh [r1, r2, lsl #0x1]"),
+            (0x8f5bf3bf, "dmb ish"),
+            (0x8113f3af, "cps #0x13"),
+            (0x8793f3af, "cpsid a, #0x13"),
+            (0xb800f001, "b #0x1004"),
+        ] {
+            let (ins, size) = parse_thumb(code, 0, &options);
+            assert_eq!(ins.display(&options).to_string(), disasm);
+            assert_eq!(ins.encode_thumb(0, &options), Ok((code, size)));
+        }
+    }
+
+    #[test]
+    fn test_encode_data() {
+        let options = options!();
+        assert_eq!(Ins::Word(0x12345678).encode_arm(0, &options), Ok(0x12345678));
+        assert_eq!(Ins::Word(0x12345678).encode_thumb(0, &options), Ok((0x12345678, 4)));
+        assert_eq!(Ins::HalfWord(0x1234).encode_thumb(0, &options), Ok((0x1234, 2)));
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        let options = options!();
+        assert_eq!(Ins::Illegal.encode_arm(0, &options), Err(EncodeError::NoEncoding));
+        assert_eq!(Ins::Illegal.encode_thumb(0, &options), Err(EncodeError::NoEncoding));
+
+        // MCR has no Thumb encoding before ARMv7
+        let ins = parse_arm(0xee070f9a, 0, &options);
+        assert_eq!(ins.encode_thumb(0, &options), Err(EncodeError::NoEncoding));
+        let v7 = Options { version: unarm::Version::V7, ..options!() };
+        assert_eq!(ins.encode_thumb(0, &v7), Ok((0x0f9aee07, 4)));
+
+        // Conditional instructions can't be encoded in Thumb
+        let ins = parse_arm(0x10821003, 0, &options);
+        assert_eq!(ins.encode_thumb(0, &options), Err(EncodeError::InvalidOperands));
+
+        // Immediate can't be represented as a rotated 8-bit value
+        let ins = parse_arm(0xe2821c01, 0, &options);
+        let Ins::Add { s, thumb, cond, rd, rn, .. } = ins else {
+            panic!("expected add instruction");
+        };
+        let ins = Ins::Add { s, thumb, cond, rd, rn, op2: unarm::Op2::Imm(0x101) };
+        assert_eq!(ins.encode_arm(0, &options), Err(EncodeError::InvalidOperands));
+
+        // Branch target is out of range
+        let ins = parse_arm(0xeb000010, 0, &options);
+        assert_eq!(ins.encode_arm(0x8000000, &options), Err(EncodeError::InvalidOperands));
+    }
+
+    /// Linear congruential generator for deterministic test input
+    fn codes() -> impl Iterator<Item = u32> {
+        let mut state = 0x12345678u32;
+        core::iter::repeat_with(move || {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            state
+        })
+    }
+
+    #[test]
+    fn test_encode_arm_round_trip() {
+        for version in [unarm::Version::V6K, unarm::Version::V7] {
+            let options = Options { version, ..options!() };
+            for code in codes().take(200000) {
+                let ins = parse_arm(code, 0x2000000, &options);
+                if ins == Ins::Illegal {
+                    continue;
+                }
+                let encoded = ins.encode_arm(0x2000000, &options);
+                assert!(encoded.is_ok(), "failed to encode {code:#010x} ({ins:?})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_thumb_round_trip() {
+        for version in [unarm::Version::V6K, unarm::Version::V7] {
+            let options = Options { version, ..options!() };
+            for code in codes().take(200000) {
+                let (ins, size) = parse_thumb(code, 0x2000000, &options);
+                if ins == Ins::Illegal {
+                    continue;
+                }
+                let encoded = ins.encode_thumb(0x2000000, &options);
+                // Wide Thumb-2 instructions may be re-encoded in a narrow encoding
+                let narrowed = version == unarm::Version::V7 && size == 4;
+                assert!(
+                    encoded.is_ok_and(|(_, s)| s == size || (narrowed && s == 2)),
+                    "failed to encode {code:#010x} ({ins:?})"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,341 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{
+        EncodeError, Extension, Extensions, Options, ParseMode, Version, parse_arm,
+        parse_arm_with_discriminant, parse_asm, parse_thumb, parse_thumb_with_discriminant,
+    };
+
+    macro_rules! options {
+        ($extensions:expr) => {{
+            Options {
+                version: Version::V7,
+                extensions: $extensions,
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    macro_rules! assert_arm {
+        ($code:literal, $extensions:expr, $disasm:literal) => {{
+            let options = options!($extensions);
+            let ins = parse_arm($code, 0, &options);
+            let s = ins.display(&options).to_string();
+            assert_eq!(s, $disasm);
+            let discriminant = ins.discriminant();
+            let ins = parse_arm_with_discriminant($code, discriminant, 0, &options);
+            let s = ins.display(&options).to_string();
+            assert_eq!(s, $disasm, "mismatched parse with discriminant");
+        }};
+    }
+
+    macro_rules! assert_thumb {
+        ($code:literal, $next:literal, $extensions:expr, $disasm:literal) => {{
+            let options = options!($extensions);
+            let (ins, _size) = parse_thumb($code | ($next << 16), 0, &options);
+            let s = ins.display(&options).to_string();
+            assert_eq!(s, $disasm);
+            let discriminant = ins.discriminant();
+            let ins =
+                parse_thumb_with_discriminant($code | ($next << 16), discriminant, 0, &options);
+            let s = ins.display(&options).to_string();
+            assert_eq!(s, $disasm, "mismatched parse with discriminant");
+        }};
+    }
+
+    const VFP_V2: Extensions = Extensions::of(&[Extension::VfpV2]);
+    const VFP_V3: Extensions = Extensions::of(&[Extension::VfpV3]);
+    const VFP_V3_D16: Extensions = Extensions::of(&[Extension::VfpV3D16]);
+    const NEON: Extensions = Extensions::of(&[Extension::VfpV3, Extension::Neon]);
+
+    #[test]
+    fn test_vfp_v2() {
+        assert_arm!(0xee310a42, VFP_V2, "vsub.f32 s0, s2, s4");
+        assert_arm!(0xee310a42, VFP_V3, "vsub.f32 s0, s2, s4");
+        assert_arm!(0xee310a42, VFP_V3_D16, "vsub.f32 s0, s2, s4");
+        assert_arm!(0xee310a42, Extensions::none(), "cdp p10, #0x3, c0, c1, c2, #0x2");
+        assert_thumb!(0xee31, 0x0a42, VFP_V3_D16, "vsub.f32 s0, s2, s4");
+        assert_thumb!(0xee31, 0x0a42, Extensions::none(), "cdp p10, #0x3, c0, c1, c2, #0x2");
+    }
+
+    #[test]
+    fn test_vfp_v3_d32() {
+        assert_arm!(0xeef0fb6e, VFP_V3, "vmov.f64 d31, d30");
+        assert_arm!(0xeef0fb6e, VFP_V3_D16, "<illegal>");
+        assert_arm!(0xeef0fb6e, VFP_V2, "<illegal>");
+        assert_arm!(0xeeb00b41, VFP_V2, "vmov.f64 d0, d1");
+        assert_thumb!(0xeef0, 0xfb6e, VFP_V3, "vmov.f64 d31, d30");
+        assert_thumb!(0xeef0, 0xfb6e, VFP_V3_D16, "<illegal>");
+
+        assert_arm!(0xeeb41b61, VFP_V3, "vcmp.f64 d1, d17");
+        assert_arm!(0xeeb41b61, VFP_V2, "<illegal>");
+
+        assert_arm!(0xec510b34, VFP_V3, "vmov r0, r1, d20");
+        assert_arm!(0xec510b34, VFP_V2, "<illegal>");
+
+        assert_arm!(0xee232b90, VFP_V3, "vmov.32 d19[0x1], r2");
+        assert_arm!(0xee232b90, VFP_V2, "<illegal>");
+
+        assert_arm!(
+            0xecd00b20,
+            VFP_V3,
+            "vldmia r0, {d16, d17, d18, d19, d20, d21, d22, d23, d24, d25, d26, d27, d28, d29, d30, d31}"
+        );
+        assert_arm!(0xecd00b20, VFP_V2, "<illegal>");
+        assert_arm!(0xec900b04, VFP_V2, "vldmia r0, {d0, d1}");
+        assert_arm!(0xec90fb04, VFP_V2, "<illegal>");
+        assert_arm!(
+            0xec900a20,
+            VFP_V2,
+            "vldmia r0, {s0, s1, s2, s3, s4, s5, s6, s7, s8, s9, s10, s11, s12, s13, s14, s15, s16, s17, s18, s19, s20, s21, s22, s23, s24, s25, s26, s27, s28, s29, s30, s31}"
+        );
+    }
+
+    #[test]
+    fn test_vfp_v3_vmov_imm() {
+        assert_arm!(0xeef70a00, VFP_V3_D16, "vmov.f32 s1, #1.0");
+        assert_arm!(0xeef70a00, VFP_V2, "cdp p10, #0xf, c0, c7, c0, #0x0");
+        assert_arm!(0xeeb30a0f, VFP_V3, "vmov.f32 s0, #31.0");
+        assert_arm!(0x0efc1b00, VFP_V3, "vmoveq.f64 d17, #-0.125");
+        assert_arm!(0xeefc1b00, VFP_V3_D16, "<illegal>");
+        assert_thumb!(0xeef7, 0x0a00, VFP_V3, "vmov.f32 s1, #1.0");
+        assert_thumb!(0xeefc, 0x1b00, VFP_V3, "vmov.f64 d17, #-0.125");
+    }
+
+    #[test]
+    fn test_vfp_v3_vcvt_fixed() {
+        assert_arm!(0xeeba1a66, VFP_V3, "vcvt.f32.s16 s2, s2, #0x3");
+        assert_arm!(0xeeba1a66, VFP_V2, "cdp p10, #0xb, c1, c10, c6, #0x3");
+        assert_arm!(0xeeba1ac0, VFP_V3, "vcvt.f32.s32 s2, s2, #0x20");
+        assert_arm!(0xeefb1a40, VFP_V3, "vcvt.f32.u16 s3, s3, #0x10");
+        assert_arm!(0xeefb1aef, VFP_V3, "vcvt.f32.u32 s3, s3, #0x1");
+        assert_arm!(0xeefb1a68, VFP_V3, "<illegal>");
+        assert_arm!(0x1eba2b66, VFP_V3, "vcvtne.f64.s16 d2, d2, #0x3");
+        assert_arm!(0xeefb2bec, VFP_V3, "vcvt.f64.u32 d18, d18, #0x7");
+        assert_arm!(0xeefb2bec, VFP_V3_D16, "<illegal>");
+        assert_arm!(0xeebe1a66, VFP_V3, "vcvt.s16.f32 s2, s2, #0x3");
+        assert_arm!(0xeebf1aee, VFP_V3, "vcvt.u32.f32 s2, s2, #0x3");
+        assert_arm!(0xeebe2bee, VFP_V3, "vcvt.s32.f64 d2, d2, #0x3");
+        assert_arm!(0xeebf2b66, VFP_V3, "vcvt.u16.f64 d2, d2, #0x3");
+        assert_thumb!(0xeeba, 0x1a66, VFP_V3, "vcvt.f32.s16 s2, s2, #0x3");
+        assert_thumb!(0xeefb, 0x2bec, VFP_V3, "vcvt.f64.u32 d18, d18, #0x7");
+        assert_thumb!(0xeebf, 0x2b66, VFP_V3, "vcvt.u16.f64 d2, d2, #0x3");
+    }
+
+    #[test]
+    fn test_vfp_v3_vcvt_half() {
+        assert_arm!(0xeef20a41, VFP_V3, "vcvtb.f32.f16 s1, s2");
+        assert_arm!(0xeef20a41, VFP_V2, "cdp p10, #0xf, c0, c2, c1, #0x2");
+        assert_arm!(0x0ef20ac1, VFP_V3_D16, "vcvtteq.f32.f16 s1, s2");
+        assert_arm!(0xeef30a41, VFP_V3, "vcvtb.f16.f32 s1, s2");
+        assert_arm!(0xeef30ac1, VFP_V3, "vcvtt.f16.f32 s1, s2");
+        assert_thumb!(0xeef2, 0x0a41, VFP_V3, "vcvtb.f32.f16 s1, s2");
+        assert_thumb!(0xeef3, 0x0ac1, VFP_V3, "vcvtt.f16.f32 s1, s2");
+    }
+
+    #[test]
+    fn test_vfp_v3_asm() {
+        let options = options!(VFP_V3);
+        let ins = parse_asm("vmov.f32 s1, #1.0", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xeef70a00));
+        let ins = parse_asm("vmov.f64 d17, #-0.125", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xeefc1b00));
+        let ins = parse_asm("vcvt.f32.s16 s2, s2, #3", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xeeba1a66));
+        let ins = parse_asm("vcvt.f64.u32 d18, d18, #7", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xeefb2bec));
+        assert_eq!(ins.encode_thumb(0, &options), Ok((0x2beceefb, 4)));
+
+        // Not representable as an 8-bit floating-point immediate
+        assert!(parse_asm("vmov.f32 s1, #0.1", 0, ParseMode::Arm, &options).is_err());
+
+        // d16-d31 are not available without VFPv3
+        let ins = parse_asm("vmov.f64 d31, d30", 0, ParseMode::Arm, &options).unwrap();
+        let vfp_v2 = options!(VFP_V2);
+        assert_eq!(ins.encode_arm(0, &vfp_v2), Err(EncodeError::InvalidOperands));
+    }
+
+    #[test]
+    fn test_neon() {
+        assert_arm!(0xf2220844, NEON, "vadd.i32 q0, q1, q2");
+        assert_arm!(0xf2220844, VFP_V3, "<illegal>");
+        assert_arm!(0xf2230844, NEON, "<illegal>"); // odd quadword register
+        assert_arm!(0xf3420df4, NEON, "vmul.f32 q8, q9, q10");
+        assert_arm!(0xf3110602, NEON, "vmax.u16 d0, d1, d2");
+        assert_arm!(0xf2010612, NEON, "vmin.s8 d0, d1, d2");
+        assert_arm!(0xf2220154, NEON, "vorr q0, q1, q2");
+        assert_arm!(0xf2220152, NEON, "vmov q0, q1");
+        assert_arm!(0xeea01b10, NEON, "vdup.32 q0, r1");
+        assert_arm!(0xee801b30, NEON, "vdup.16 d0, r1");
+        assert_arm!(0xf3b50c01, NEON, "vdup.8 d0, d1[0x2]");
+        assert_thumb!(0xef22, 0x0844, NEON, "vadd.i32 q0, q1, q2");
+        assert_thumb!(0xff42, 0x0df4, NEON, "vmul.f32 q8, q9, q10");
+        assert_thumb!(0xeea0, 0x1b10, NEON, "vdup.32 q0, r1");
+        assert_thumb!(0xef22, 0x0844, VFP_V3, "<illegal>");
+    }
+
+    #[test]
+    fn test_neon_three_regs() {
+        assert_arm!(0xf2010002, NEON, "vhadd.s8 d0, d1, d2");
+        assert_arm!(0xf3320054, NEON, "vqadd.u64 q0, q1, q2");
+        assert_arm!(0xf2220442, NEON, "vshl.s32 q0, q1, q1");
+        assert_arm!(0xf2010402, NEON, "vshl.s8 d0, d2, d1");
+        assert_arm!(0xf3200852, NEON, "vceq.i32 q0, q0, q1");
+        assert_arm!(0xf3010912, NEON, "vmul.p8 d0, d1, d2");
+        assert_arm!(0xf2110b02, NEON, "vqdmulh.s16 d0, d1, d2");
+        assert_arm!(0xf2010b02, NEON, "<illegal>"); // size == 0b00
+        assert_arm!(0xf2110b12, NEON, "vpadd.i16 d0, d1, d2");
+        assert_arm!(0xf2110b52, NEON, "<illegal>"); // quadword registers
+        assert_arm!(0xf3220e44, NEON, "vcgt.f32 q0, q1, q2");
+        assert_arm!(0xf2020f11, NEON, "vrecps.f32 d0, d2, d1");
+        assert_arm!(0xf2810002, NEON, "vaddl.s8 q0, d1, d2");
+        assert_arm!(0xf3920102, NEON, "vaddw.u16 q0, q1, d2");
+        assert_arm!(0xf2a20404, NEON, "vaddhn.i64 d0, q1, q2");
+        assert_arm!(0xf2810e02, NEON, "vmull.p8 q0, d1, d2");
+        assert_arm!(0xf2910902, NEON, "vqdmlal.s16 q0, d1, d2");
+        assert_arm!(0xf2910842, NEON, "vmul.i16 d0, d1, d2[0x0]");
+        assert_arm!(0xf3a2096b, NEON, "vmul.f32 q0, q1, d11[0x1]");
+        assert_arm!(0xf3910a6a, NEON, "vmull.u16 q0, d1, d2[0x3]");
+        assert_arm!(0xf2b10302, NEON, "vext.8 d0, d1, d2, #0x3");
+        assert_arm!(0xf2b10b02, NEON, "<illegal>"); // imm4 > 7
+        assert_thumb!(0xef01, 0x0002, NEON, "vhadd.s8 d0, d1, d2");
+        assert_thumb!(0xff20, 0x0852, NEON, "vceq.i32 q0, q0, q1");
+        assert_thumb!(0xff91, 0x0a6a, NEON, "vmull.u16 q0, d1, d2[0x3]");
+    }
+
+    #[test]
+    fn test_neon_two_regs() {
+        assert_arm!(0xf3b00560, NEON, "vcnt.8 q0, q8");
+        assert_arm!(0xf3b90000, NEON, "vcgt.s32 d0, d0, #0");
+        assert_arm!(0xf3b91500, NEON, "vceq.f32 d1, d0, #0");
+        assert_arm!(0xf3b10202, NEON, "vclt.s8 d0, d2, #0");
+        assert_arm!(0xf3b10342, NEON, "vabs.s8 q0, q1");
+        assert_arm!(0xf3b907c2, NEON, "vneg.f32 q0, q1");
+        assert_arm!(0xf3b00002, NEON, "vrev64.8 d0, d2");
+        assert_arm!(0xf3b80082, NEON, "<illegal>"); // vrev32 with size == 0b10
+        assert_arm!(0xf3b002c2, NEON, "vpaddl.u8 q0, q1");
+        assert_arm!(0xf3b005c2, NEON, "vmvn q0, q1");
+        assert_arm!(0xf3b20042, NEON, "vswp q0, q1");
+        assert_arm!(0xf3b60082, NEON, "vtrn.16 d0, d2");
+        assert_arm!(0xf3b60142, NEON, "vuzp.16 q0, q1");
+        assert_arm!(0xf3b60204, NEON, "vmovn.i32 d0, q2");
+        assert_arm!(0xf3b602c4, NEON, "vqmovn.u32 d0, q2");
+        assert_arm!(0xf3b60302, NEON, "vshll.i16 q0, d2, #0x10");
+        assert_arm!(0xf3bb0642, NEON, "vcvt.f32.s32 q0, q1");
+        assert_arm!(0xf3bb07c2, NEON, "vcvt.u32.f32 q0, q1");
+        assert_arm!(0xf3bb0402, NEON, "vrecpe.u32 d0, d2");
+        assert_arm!(0xf3b10904, NEON, "vtbl.8 d0, {d1, d2}, d4");
+        assert_arm!(0xf3b20b44, NEON, "vtbx.8 d0, {d2, d3, d4, d5}, d4");
+        assert_thumb!(0xffb0, 0x0560, NEON, "vcnt.8 q0, q8");
+        assert_thumb!(0xffb9, 0x0000, NEON, "vcgt.s32 d0, d0, #0");
+        assert_thumb!(0xffbb, 0x0642, NEON, "vcvt.f32.s32 q0, q1");
+        assert_thumb!(0xffb1, 0x0904, NEON, "vtbl.8 d0, {d1, d2}, d4");
+    }
+
+    #[test]
+    fn test_neon_imm() {
+        assert_arm!(0xf2800010, NEON, "vmov.i32 d0, #0x0");
+        assert_arm!(0xf3870e5f, NEON, "vmov.i8 q0, #0xff");
+        assert_arm!(0xf2810a12, NEON, "vmov.i16 d0, #0x1200");
+        assert_arm!(0xf2810c12, NEON, "vmov.i32 d0, #0x12ff");
+        assert_arm!(0xf2810e35, NEON, "vmov.i64 d0, #0xff00ff00ff");
+        assert_arm!(0xf2870f10, NEON, "vmov.f32 d0, #1.0");
+        assert_arm!(0xf3800f30, NEON, "<illegal>"); // op == 1 and cmode == 0b1111
+        assert_arm!(0xf2800030, NEON, "vmvn.i32 d0, #0x0");
+        assert_arm!(0xf2810117, NEON, "vorr.i32 d0, #0x17");
+        assert_arm!(0xf3c70b3f, NEON, "vbic.i16 d16, #0xff00");
+        assert_arm!(0xf2bd0011, NEON, "vshr.s32 d0, d1, #0x3");
+        assert_arm!(0xf38000d2, NEON, "vshr.u64 q0, q1, #0x40");
+        assert_arm!(0xf2880552, NEON, "vshl.i8 q0, q1, #0x0");
+        assert_arm!(0xf3bf0592, NEON, "vsli.64 d0, d2, #0x3f");
+        assert_arm!(0xf3c80452, NEON, "vsri.8 q8, q1, #0x8");
+        assert_arm!(0xf3900654, NEON, "vqshlu.s16 q0, q2, #0x0");
+        assert_arm!(0xf2880814, NEON, "vshrn.i16 d0, q2, #0x8");
+        assert_arm!(0xf3a80954, NEON, "vqrshrn.u64 d0, q2, #0x18");
+        assert_arm!(0xf2880a12, NEON, "vmovl.s8 q0, d2");
+        assert_arm!(0xf3930a12, NEON, "vshll.u16 q0, d2, #0x3");
+        assert_arm!(0xf2b00e52, NEON, "vcvt.f32.s32 q0, q1, #0x10");
+        assert_arm!(0xf2900e52, NEON, "<illegal>"); // fbits > 32
+        assert_thumb!(0xef80, 0x0010, NEON, "vmov.i32 d0, #0x0");
+        assert_thumb!(0xef81, 0x0e35, NEON, "vmov.i64 d0, #0xff00ff00ff");
+        assert_thumb!(0xefbd, 0x0011, NEON, "vshr.s32 d0, d1, #0x3");
+        assert_thumb!(0xef80, 0x0010, VFP_V3, "<illegal>");
+    }
+
+    #[test]
+    fn test_neon_scalar_transfer() {
+        assert_arm!(0xee608b50, NEON, "vmov.8 d0[0x6], r8");
+        assert_arm!(0xee002b70, NEON, "vmov.16 d0[0x1], r2");
+        assert_arm!(0xeed7ebf0, NEON, "vmov.u8 lr, d23[0x3]");
+        assert_arm!(0xee362b70, NEON, "vmov.s16 r2, d6[0x3]");
+        assert_thumb!(0xee60, 0x8b50, NEON, "vmov.8 d0[0x6], r8");
+        assert_thumb!(0xeed7, 0xebf0, NEON, "vmov.u8 lr, d23[0x3]");
+    }
+
+    #[test]
+    fn test_neon_load_store() {
+        assert_arm!(0xf4200a0d, NEON, "vld1.8 {d0, d1}, [r0]!");
+        assert_arm!(0xf4200a0d, VFP_V3, "<illegal>");
+        assert_arm!(0xf420072f, NEON, "<illegal>"); // 256-bit alignment of one register
+        assert_arm!(0xf40201a3, NEON, "vst4.32 {d0, d2, d4, d6}, [r2:128], r3");
+        assert_arm!(0xf4a1056f, NEON, "vld2.16 {d0[0x1], d2[0x1]}, [r1]");
+        assert_arm!(0xf4a00cbf, NEON, "vld1.32 {d0[], d1[]}, [r0:32]");
+        assert_arm!(0xf4a00e4f, NEON, "vld3.16 {d0[], d1[], d2[]}, [r0]");
+        assert_arm!(0xf4a10b52, NEON, "vld4.32 {d0[0x0], d2[0x0], d4[0x0], d6[0x0]}, [r1:64], r2");
+        assert_thumb!(0xf920, 0x0a0d, NEON, "vld1.8 {d0, d1}, [r0]!");
+        assert_thumb!(0xf9a1, 0x056f, NEON, "vld2.16 {d0[0x1], d2[0x1]}, [r1]");
+        assert_thumb!(0xf920, 0x0a0d, VFP_V3, "<illegal>");
+        assert_arm!(0xf4e4cb83, NEON, "vld4.32 {d28[0x1], d29[0x1], d30[0x1], d31[0x1]}, [r4], r3");
+        // Lists can't extend past d31
+        assert_arm!(0xf4e4eb83, NEON, "<illegal>");
+        assert_arm!(0xf3bf0984, NEON, "<illegal>");
+        assert_thumb!(0xf9e4, 0xeb83, NEON, "<illegal>");
+    }
+
+    #[test]
+    fn test_neon_asm() {
+        let options = options!(NEON);
+        let ins = parse_asm("vadd.i32 q0, q1, q2", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf2220844));
+        assert_eq!(ins.encode_thumb(0, &options), Ok((0x0844ef22, 4)));
+        let ins = parse_asm("vmov q0, q1", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf2220152));
+        let ins = parse_asm("vld1.8 {d0, d1}, [r0]!", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf4200a0d));
+        let ins = parse_asm("vst4.32 {d0, d2, d4, d6}, [r2:128], r3", 0, ParseMode::Arm, &options)
+            .unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf40201a3));
+        let ins = parse_asm("vld1.32 {d0[], d1[]}, [r0:32]", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf4a00cbf));
+        let ins = parse_asm("vmov.i32 d0, #0x12ff", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf2810c12));
+        let ins = parse_asm("vmov.i64 d0, #0xff00ff00ff", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf2810e35));
+        let ins = parse_asm("vmov.f32 q0, #1.0", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf2870f50));
+        let ins = parse_asm("vshr.u64 q0, q1, #64", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf38000d2));
+        let ins = parse_asm("vmull.u16 q0, d1, d2[3]", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_thumb(0, &options), Ok((0x0a6aff91, 4)));
+        let ins = parse_asm("vtbl.8 d0, {d1, d2}, d4", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf3b10904));
+        let ins = parse_asm("vmovl.s8 q0, d2", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Ok(0xf2880a12));
+
+        // Not representable as a modified immediate
+        let ins = parse_asm("vmov.i32 d0, #0x1234", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Err(EncodeError::InvalidOperands));
+        // Shift amounts of right shifts start at 1
+        let ins = parse_asm("vshr.s8 d0, d1, #0", 0, ParseMode::Arm, &options).unwrap();
+        assert_eq!(ins.encode_arm(0, &options), Err(EncodeError::InvalidOperands));
+        // Registers must be evenly spaced
+        assert!(parse_asm("vld4.8 {d0, d1, d3, d4}, [r0]", 0, ParseMode::Arm, &options).is_err());
+        assert!(parse_asm("vld2.8 {d31, d0}, [r0]", 0, ParseMode::Arm, &options).is_err());
+        // Quadword registers are aliases of two doubleword registers
+        assert!(parse_asm("vadd.i8 q0, q1, q16", 0, ParseMode::Arm, &options).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{FlowKind, Options, ParseEndian, ParseMode, Parser, parse_arm, parse_thumb};
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V7,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    macro_rules! assert_arm {
+        ($code:literal, $disasm:literal, $flow:expr) => {{
+            let options = options!();
+            let ins = parse_arm($code, 0x2000000, &options);
+            assert_eq!(ins.display(&options).to_string(), $disasm);
+            assert_eq!(ins.flow(), $flow, "mismatched flow of {}", $disasm);
+        }};
+    }
+
+    macro_rules! assert_thumb {
+        ($code:literal, $disasm:literal, $flow:expr) => {{
+            let options = options!();
+            let (ins, _size) = parse_thumb($code, 0x2000000, &options);
+            assert_eq!(ins.display(&options).to_string(), $disasm);
+            assert_eq!(ins.flow(), $flow, "mismatched flow of {}", $disasm);
+        }};
+        ($code:literal, $next:literal, $disasm:literal, $flow:expr) => {{
+            let options = options!();
+            let (ins, _size) = parse_thumb($code | ($next << 16), 0x2000000, &options);
+            assert_eq!(ins.display(&options).to_string(), $disasm);
+            assert_eq!(ins.flow(), $flow, "mismatched flow of {}", $disasm);
+        }};
+    }
+
+    #[test]
+    fn test_arm() {
+        assert_arm!(0xe2800001, "add r0, r0, #0x1", FlowKind::Fallthrough);
+        assert_arm!(0xe5910000, "ldr r0, [r1, #0x0]", FlowKind::Fallthrough);
+        assert_arm!(0xe8bd4010, "pop {r4, lr}", FlowKind::Fallthrough);
+
+        assert_arm!(0xea000000, "b #0x2000008", FlowKind::Branch { target: 0x2000008 });
+        assert_arm!(
+            0x0afffffe,
+            "beq #0x2000000",
+            FlowKind::ConditionalBranch { target: 0x2000000 }
+        );
+        assert_arm!(
+            0xeb000000,
+            "bl #0x2000008",
+            FlowKind::Call { target: Some(0x2000008), exchange: false }
+        );
+        assert_arm!(
+            0xfa000000,
+            "blx #0x2000008",
+            FlowKind::Call { target: Some(0x2000008), exchange: true }
+        );
+        assert_arm!(0xe12fff33, "blx r3", FlowKind::Call { target: None, exchange: true });
+
+        assert_arm!(0xe12fff1e, "bx lr", FlowKind::Return { conditional: false });
+        assert_arm!(0x012fff1e, "bxeq lr", FlowKind::Return { conditional: true });
+        assert_arm!(0xe12fff10, "bx r0", FlowKind::IndirectJump { conditional: false });
+        assert_arm!(0xe1a0f00e, "mov pc, lr", FlowKind::Return { conditional: false });
+        assert_arm!(0xe1a0f000, "mov pc, r0", FlowKind::IndirectJump { conditional: false });
+        assert_arm!(0xe25ef004, "subs pc, lr, #0x4", FlowKind::Return { conditional: false });
+        assert_arm!(
+            0x908ff100,
+            "addls pc, pc, r0, lsl #0x2",
+            FlowKind::IndirectJump { conditional: true }
+        );
+
+        assert_arm!(0xe8bd8010, "pop {r4, pc}", FlowKind::Return { conditional: false });
+        assert_arm!(0xe8fd8000, "ldm sp!, {pc}^", FlowKind::Return { conditional: false });
+        assert_arm!(0xe8908010, "ldm r0, {r4, pc}", FlowKind::IndirectJump { conditional: false });
+        assert_arm!(0xe49df004, "pop {pc}", FlowKind::Return { conditional: false });
+        assert_arm!(
+            0xe51ff004,
+            "ldr pc, [pc, #-0x4]",
+            FlowKind::IndirectJump { conditional: false }
+        );
+
+        assert_arm!(0xef000000, "svc #0x0", FlowKind::Exception { conditional: false });
+        assert_arm!(0xe1200070, "bkpt #0x0", FlowKind::Exception { conditional: false });
+    }
+
+    #[test]
+    fn test_thumb() {
+        assert_thumb!(0x1840, "adds r0, r0, r1", FlowKind::Fallthrough);
+        assert_thumb!(0xbc10, "pop {r4}", FlowKind::Fallthrough);
+
+        assert_thumb!(0xe7fe, "b #0x2000000", FlowKind::Branch { target: 0x2000000 });
+        assert_thumb!(0xd0fe, "beq #0x2000000", FlowKind::ConditionalBranch { target: 0x2000000 });
+        assert_thumb!(
+            0xb100,
+            "cbz r0, #0x2000004",
+            FlowKind::ConditionalBranch { target: 0x2000004 }
+        );
+        assert_thumb!(
+            0xf000,
+            0xf800,
+            "bl #0x2000004",
+            FlowKind::Call { target: Some(0x2000004), exchange: false }
+        );
+        assert_thumb!(
+            0xf000,
+            0xe800,
+            "blx #0x2000004",
+            FlowKind::Call { target: Some(0x2000004), exchange: true }
+        );
+        assert_thumb!(0x4798, "blx r3", FlowKind::Call { target: None, exchange: true });
+
+        assert_thumb!(0x4770, "bx lr", FlowKind::Return { conditional: false });
+        assert_thumb!(0x4700, "bx r0", FlowKind::IndirectJump { conditional: false });
+        assert_thumb!(0x46f7, "mov pc, lr", FlowKind::Return { conditional: false });
+        assert_thumb!(0xbd10, "pop {r4, pc}", FlowKind::Return { conditional: false });
+        assert_thumb!(0xf85d, 0xfb04, "pop {pc}", FlowKind::Return { conditional: false });
+        assert_thumb!(
+            0xe8d0,
+            0xf001,
+            "tbb [r0, r1]",
+            FlowKind::IndirectJump { conditional: false }
+        );
+
+        assert_thumb!(0xdf00, "svc #0x0", FlowKind::Exception { conditional: false });
+        assert_thumb!(0xde00, "udf #0x0", FlowKind::Exception { conditional: false });
+    }
+
+    #[test]
+    fn test_it_block() {
+        #[rustfmt::skip]
+        let code = &[
+            0x08, 0xbf, // it eq
+            0x70, 0x47, // bxeq lr
+            0x70, 0x47, // bx lr
+        ];
+        let mut parser = Parser::new(code, ParseMode::Thumb, ParseEndian::Little, options!());
+        assert_eq!(parser.next().unwrap().flow(), FlowKind::Fallthrough);
+        assert_eq!(parser.next().unwrap().flow(), FlowKind::Return { conditional: true });
+        assert_eq!(parser.next().unwrap().flow(), FlowKind::Return { conditional: false });
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{Options, ParseEndian, ParseMode, Parser, parse_arm, parse_thumb};
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V7,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    macro_rules! assert_arm {
+        ($code:literal, $disasm:literal, $addr:expr) => {{
+            let options = options!();
+            let ins = parse_arm($code, 0x2000000, &options);
+            assert_eq!(ins.display(&options).to_string(), $disasm);
+            assert_eq!(
+                ins.pc_relative_address(0x2000000, ParseMode::Arm),
+                $addr,
+                "mismatched address of {}",
+                $disasm
+            );
+        }};
+    }
+
+    macro_rules! assert_thumb {
+        ($code:literal, $disasm:literal, $addr:expr) => {{
+            let options = options!();
+            let (ins, _size) = parse_thumb($code, 0x2000002, &options);
+            assert_eq!(ins.display(&options).to_string(), $disasm);
+            assert_eq!(
+                ins.pc_relative_address(0x2000002, ParseMode::Thumb),
+                $addr,
+                "mismatched address of {}",
+                $disasm
+            );
+        }};
+        ($code:literal, $next:literal, $disasm:literal, $addr:expr) => {{
+            let options = options!();
+            let (ins, _size) = parse_thumb($code | ($next << 16), 0x2000002, &options);
+            assert_eq!(ins.display(&options).to_string(), $disasm);
+            assert_eq!(
+                ins.pc_relative_address(0x2000002, ParseMode::Thumb),
+                $addr,
+                "mismatched address of {}",
+                $disasm
+            );
+        }};
+    }
+
+    #[test]
+    fn test_arm() {
+        assert_arm!(0xe59f0004, "ldr r0, [pc, #0x4]", Some(0x200000c));
+        assert_arm!(0xe51f0004, "ldr r0, [pc, #-0x4]", Some(0x2000004));
+        assert_arm!(0xe5df0004, "ldrb r0, [pc, #0x4]", Some(0x200000c));
+        assert_arm!(0xe1df00b2, "ldrh r0, [pc, #0x2]", Some(0x200000a));
+        assert_arm!(0xe1cf00d8, "ldrd r0, r1, [pc, #0x8]", Some(0x2000010));
+        assert_arm!(0xed9f0b02, "vldr d0, [pc, #0x8]", Some(0x2000010));
+        assert_arm!(0xe28f0010, "add r0, pc, #0x10", Some(0x2000018));
+        assert_arm!(0xe24f0004, "sub r0, pc, #0x4", Some(0x2000004));
+
+        assert_arm!(0xe5910004, "ldr r0, [r1, #0x4]", None);
+        assert_arm!(0xe5bf0004, "ldr r0, [pc, #0x4]!", None);
+        assert_arm!(0xe28f0001, "add r0, pc, #0x1", Some(0x2000009));
+        assert_arm!(0xe08f0001, "add r0, pc, r1", None);
+    }
+
+    #[test]
+    fn test_thumb() {
+        assert_thumb!(0x4801, "ldr r0, [pc, #0x4]", Some(0x2000008));
+        assert_thumb!(0xa001, "add r0, pc, #0x4", Some(0x2000008));
+        assert_thumb!(0xf85f, 0x0004, "ldr r0, [pc, #-0x4]", Some(0x2000000));
+        assert_thumb!(0xf8df, 0x0004, "ldr r0, [pc, #0x4]", Some(0x2000008));
+        assert_thumb!(0xf81f, 0x0004, "ldrb r0, [pc, #-0x4]", Some(0x2000000));
+        assert_thumb!(0xf9bf, 0x0002, "ldrsh r0, [pc, #0x2]", Some(0x2000006));
+        assert_thumb!(0xed9f, 0x0a01, "vldr s0, [pc, #0x4]", Some(0x2000008));
+
+        assert_thumb!(0x6848, "ldr r0, [r1, #0x4]", None);
+    }
+
+    #[test]
+    fn test_read_literal() {
+        #[rustfmt::skip]
+        let code = &[
+            0x00, 0x48,             // 0x00: ldr r0, [pc, #0x0]
+            0x01, 0x4a,             // 0x02: ldr r2, [pc, #0x4]
+            0x34, 0x12, 0x00, 0x02, // 0x04: .word 0x2001234
+            0xfe, 0xff, 0x00, 0x00, // 0x08: .word 0xfffe
+        ];
+        let mut parser = Parser::new(code, ParseMode::Thumb, ParseEndian::Little, options!());
+        parser.set_pc(0x2000000);
+        let ins = parser.next().unwrap();
+        assert_eq!(parser.read_literal(&ins, 0x2000000), Some(0x2001234));
+        let ins = parser.next().unwrap();
+        assert_eq!(parser.read_literal(&ins, 0x2000002), Some(0xfffe));
+
+        let mut parser = Parser::new(code, ParseMode::Arm, ParseEndian::Little, options!());
+        parser.set_pc(0x2000000);
+        // ldrsh r0, [pc, #0x0] reads the halfword at 0x2000008
+        let ins = parse_arm(0xe1df00f0, 0x2000000, &options!());
+        assert_eq!(parser.read_literal(&ins, 0x2000000), Some(0xfffffffe));
+        // add r0, pc, #0x0 has no literal
+        let ins = parse_arm(0xe28f0000, 0x2000000, &options!());
+        assert_eq!(parser.read_literal(&ins, 0x2000000), None);
+        // ldr r0, [pc, #0x8] is out of bounds
+        let ins = parse_arm(0xe59f0008, 0x2000000, &options!());
+        assert_eq!(parser.read_literal(&ins, 0x2000000), None);
+    }
+}
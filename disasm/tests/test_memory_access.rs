@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{
+        AccessAddress, AccessKind, AccessOffset, Indexing, LdmStmMode, MemAccess, Options, Reg,
+        ShiftOp, parse_arm, parse_thumb,
+    };
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V7,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    fn load(size: u8, count: u8, address: AccessAddress) -> MemAccess {
+        MemAccess {
+            kind: AccessKind::Load,
+            size,
+            count,
+            signed: false,
+            exclusive: false,
+            user_mode: false,
+            address,
+        }
+    }
+
+    fn address(
+        base: Reg,
+        offset: AccessOffset,
+        indexing: Indexing,
+        writeback: bool,
+    ) -> AccessAddress {
+        AccessAddress { base, offset, indexing, writeback }
+    }
+
+    #[test]
+    fn test_single() {
+        let options = options!();
+        let ins = parse_arm(0xe5b10004, 0, &options); // ldr r0, [r1, #0x4]!
+        let addr = address(Reg::R1, AccessOffset::Imm(4), Indexing::Pre, true);
+        assert_eq!(ins.memory_access(), Some(load(4, 1, addr)));
+
+        let ins = parse_arm(0xe01100f2, 0, &options); // ldrsh r0, [r1], -r2
+        let offset =
+            AccessOffset::Reg { subtract: true, rm: Reg::R2, shift_op: ShiftOp::Lsl, imm: 0 };
+        let addr = address(Reg::R1, offset, Indexing::Post, true);
+        assert_eq!(ins.memory_access(), Some(MemAccess { signed: true, ..load(2, 1, addr) }));
+
+        let ins = parse_arm(0xe4f10001, 0, &options); // ldrbt r0, [r1], #0x1
+        let addr = address(Reg::R1, AccessOffset::Imm(1), Indexing::Post, true);
+        assert_eq!(ins.memory_access(), Some(MemAccess { user_mode: true, ..load(1, 1, addr) }));
+
+        let ins = parse_arm(0xe1c020d8, 0, &options); // ldrd r2, r3, [r0, #0x8]
+        let addr = address(Reg::R0, AccessOffset::Imm(8), Indexing::Pre, false);
+        assert_eq!(ins.memory_access(), Some(load(4, 2, addr)));
+
+        let ins = parse_arm(0xecb32104, 0, &options); // ldc p1, c2, [r3], #0x10
+        let addr = address(Reg::R3, AccessOffset::Imm(0x10), Indexing::Post, true);
+        assert_eq!(ins.memory_access(), Some(load(4, 1, addr)));
+    }
+
+    #[test]
+    fn test_block() {
+        let options = options!();
+        let ins = parse_arm(0xe92d4010, 0, &options); // push {r4, lr}
+        let access = ins.memory_access().unwrap();
+        assert_eq!(access.kind, AccessKind::Store);
+        assert_eq!(access.count, 2);
+        let addr = address(Reg::Sp, AccessOffset::Imm(0), Indexing::Block(LdmStmMode::Db), true);
+        assert_eq!(access.address, addr);
+
+        let ins = parse_arm(0xe9900006, 0, &options); // ldmib r0, {r1, r2}
+        let addr = address(Reg::R0, AccessOffset::Imm(0), Indexing::Block(LdmStmMode::Ib), false);
+        assert_eq!(ins.memory_access(), Some(load(4, 2, addr)));
+
+        let ins = parse_arm(0xed2d8b04, 0, &options); // vpush {d8, d9}
+        let access = ins.memory_access().unwrap();
+        assert_eq!((access.kind, access.size, access.count), (AccessKind::Store, 8, 2));
+
+        let (ins, _) = parse_thumb(0xbc01, 0, &options); // pop {r0}
+        let addr = address(Reg::Sp, AccessOffset::Imm(0), Indexing::Block(LdmStmMode::Ia), true);
+        assert_eq!(ins.memory_access(), Some(load(4, 1, addr)));
+    }
+
+    #[test]
+    fn test_exclusive_and_swap() {
+        let options = options!();
+        let ins = parse_arm(0xe1820f91, 0, &options); // strex r0, r1, [r2]
+        let access = ins.memory_access().unwrap();
+        assert_eq!(access.kind, AccessKind::Store);
+        assert!(access.exclusive);
+        assert_eq!(access.address, address(Reg::R2, AccessOffset::Imm(0), Indexing::Pre, false));
+
+        let ins = parse_arm(0xe1420091, 0, &options); // swpb r0, r1, [r2]
+        let access = ins.memory_access().unwrap();
+        assert_eq!((access.kind, access.size, access.exclusive), (AccessKind::Swap, 1, false));
+    }
+
+    #[test]
+    fn test_thumb() {
+        let options = options!();
+        let (ins, _) = parse_thumb(0x5e88, 0, &options); // ldrsh r0, [r1, r2]
+        let offset =
+            AccessOffset::Reg { subtract: false, rm: Reg::R2, shift_op: ShiftOp::Lsl, imm: 0 };
+        let addr = address(Reg::R1, offset, Indexing::Pre, false);
+        assert_eq!(ins.memory_access(), Some(MemAccess { signed: true, ..load(2, 1, addr) }));
+
+        let (ins, _) = parse_thumb(0xf011e8d0, 0, &options); // tbh [r0, r1, lsl #0x1]
+        let offset =
+            AccessOffset::Reg { subtract: false, rm: Reg::R1, shift_op: ShiftOp::Lsl, imm: 1 };
+        let addr = address(Reg::R0, offset, Indexing::Pre, false);
+        assert_eq!(ins.memory_access(), Some(load(2, 1, addr)));
+    }
+
+    #[test]
+    fn test_neon() {
+        let options = options!();
+        let ins = parse_arm(0xf420028d, 0, &options); // vld1.32 {d0, d1, d2, d3}, [r0]!
+        let addr = address(Reg::R0, AccessOffset::Imm(0x20), Indexing::Post, true);
+        assert_eq!(ins.memory_access(), Some(load(4, 8, addr)));
+
+        let ins = parse_arm(0xf4a00c2d, 0, &options); // vld1.8 {d0[], d1[]}, [r0]!
+        let addr = address(Reg::R0, AccessOffset::Imm(1), Indexing::Post, true);
+        assert_eq!(ins.memory_access(), Some(load(1, 1, addr)));
+    }
+
+    #[test]
+    fn test_no_access() {
+        let options = options!();
+        assert_eq!(parse_arm(0xe1a00001, 0, &options).memory_access(), None); // mov r0, r1
+        assert_eq!(parse_arm(0xf5d0f000, 0, &options).memory_access(), None); // pld [r0]
+    }
+}
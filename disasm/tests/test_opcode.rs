@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{
+        Extension, Extensions, Ins, Opcode, Options, ParseMode, Version, Versions, parse_arm,
+        parse_arm_with_discriminant, parse_thumb,
+    };
+
+    macro_rules! options {
+        ($version:expr, $extensions:expr) => {{
+            Options {
+                version: $version,
+                extensions: $extensions,
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    #[test]
+    fn test_discriminant() {
+        for (i, opcode) in Opcode::ALL.iter().enumerate() {
+            assert_eq!(*opcode as u16, i as u16);
+            assert_eq!(Opcode::from_discriminant(i as u16), Some(*opcode));
+        }
+        assert_eq!(Opcode::from_discriminant(Opcode::ALL.len() as u16), None);
+
+        let options = options!(Version::V7, Extensions::all());
+        let ins = [
+            parse_arm(0xe0a10002, 0, &options), // adc r0, r1, r2
+            parse_arm(0xee300a01, 0, &options), // vadd.f32 s0, s0, s2
+            parse_thumb(0xb108, 0, &options).0, // cbz r0, #0x6
+            Ins::Word(0x12345678),
+            Ins::HalfWord(0x1234),
+            Ins::Byte(0x12),
+            Ins::Illegal,
+        ];
+        let opcodes = [
+            Opcode::Adc,
+            Opcode::VaddF32,
+            Opcode::Cbz,
+            Opcode::Word,
+            Opcode::HalfWord,
+            Opcode::Byte,
+            Opcode::Illegal,
+        ];
+        for (ins, opcode) in ins.iter().zip(opcodes) {
+            assert_eq!(ins.opcode(), opcode);
+            assert_eq!(ins.discriminant(), opcode as u16);
+        }
+    }
+
+    #[test]
+    fn test_parse_data_with_discriminant() {
+        let options = options!(Version::V7, Extensions::all());
+        let parse =
+            |opcode: Opcode| parse_arm_with_discriminant(0x12345678, opcode as u16, 0, &options);
+        assert_eq!(parse(Opcode::Word), Ins::Word(0x12345678));
+        assert_eq!(parse(Opcode::HalfWord), Ins::HalfWord(0x5678));
+        assert_eq!(parse(Opcode::Byte), Ins::Byte(0x78));
+        assert_eq!(parse(Opcode::Illegal), Ins::Illegal);
+    }
+
+    #[test]
+    fn test_name_description() {
+        assert_eq!(Opcode::Adc.name(), "adc");
+        assert_eq!(Opcode::VaddF32.name(), "vadd_f32");
+        assert_eq!(Opcode::HalfWord.name(), "half_word");
+        assert_eq!(Opcode::Adc.description(), "Add with Carry");
+        assert_eq!(Opcode::Illegal.description(), "Illegal instruction");
+    }
+
+    #[test]
+    fn test_encodings() {
+        for opcode in Opcode::ALL {
+            for encoding in opcode.encodings() {
+                assert_eq!(encoding.value & !encoding.mask, 0, "{opcode:?}");
+            }
+        }
+        assert!(Opcode::Word.encodings().is_empty());
+
+        let adc = Opcode::Adc.encodings();
+        let arm = adc.iter().find(|e| e.mode == ParseMode::Arm).unwrap();
+        assert_eq!(arm.size, 4);
+        assert!(arm.matches(0xe0a10002));
+        assert!(!arm.matches(0xe0810002)); // add r0, r1, r2
+        let thumb = adc.iter().filter(|e| e.mode == ParseMode::Thumb).collect::<Vec<_>>();
+        assert_eq!(thumb.len(), 3);
+        assert!(thumb.iter().any(|e| e.size == 2 && e.matches(0x4148)));
+    }
+
+    #[test]
+    fn test_modes() {
+        assert!(Opcode::Adc.has_mode(ParseMode::Arm));
+        assert!(Opcode::Adc.has_mode(ParseMode::Thumb));
+        assert!(!Opcode::Cbz.has_mode(ParseMode::Arm));
+        assert!(Opcode::Cbz.has_mode(ParseMode::Thumb));
+        assert!(!Opcode::Swp.has_mode(ParseMode::Thumb));
+        assert!(!Opcode::Word.has_mode(ParseMode::Data));
+    }
+
+    #[test]
+    fn test_versions_extensions() {
+        assert_eq!(Opcode::Adc.versions(ParseMode::Arm), Versions::all());
+        let qadd = Opcode::Qadd.versions(ParseMode::Arm);
+        assert!(!qadd.has(Version::V5T));
+        assert!(qadd.has(Version::V5Te));
+        assert_eq!(Opcode::Swp.versions(ParseMode::Thumb), Versions::none());
+
+        assert_eq!(Opcode::Adc.extensions(ParseMode::Arm), Extensions::none());
+        let vadd = Opcode::VaddF32.extensions(ParseMode::Arm);
+        assert!(vadd.has_any(Extensions::of(&[Extension::VfpV2])));
+        assert!(!vadd.has_any(Extensions::none()));
+    }
+
+    #[test]
+    fn test_is_supported() {
+        let v5t = options!(Version::V5T, Extensions::all());
+        let v5te = options!(Version::V5Te, Extensions::all());
+        assert!(!Opcode::Qadd.is_supported(ParseMode::Arm, &v5t));
+        assert!(Opcode::Qadd.is_supported(ParseMode::Arm, &v5te));
+        assert!(!Opcode::Qadd.is_supported(ParseMode::Thumb, &v5te));
+
+        let no_vfp = options!(Version::V7, Extensions::none());
+        let vfp_v2 = options!(Version::V7, Extensions::of(&[Extension::VfpV2]));
+        assert!(!Opcode::VaddF32.is_supported(ParseMode::Arm, &no_vfp));
+        assert!(Opcode::VaddF32.is_supported(ParseMode::Arm, &vfp_v2));
+        assert!(Opcode::Adc.is_supported(ParseMode::Arm, &no_vfp));
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{
+        AddrLdrStr, Ins, LdrStrOffset, Op2, Operand, Options, Reg, RegList, ShiftImm, ShiftOp,
+        Sreg, parse_arm, parse_thumb,
+    };
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V7,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    #[test]
+    fn test_mnemonic() {
+        let mut options = options!();
+        let mnemonic = |code: u32, options: &Options| parse_arm(code, 0, options).mnemonic(options);
+        assert_eq!(mnemonic(0x10810002, &options), "add"); // addne r0, r1, r2
+        assert_eq!(mnemonic(0xe0910002, &options), "adds"); // adds r0, r1, r2
+        assert_eq!(mnemonic(0x10910002, &options), "adds"); // addsne r0, r1, r2
+        assert_eq!(mnemonic(0xe751f332, &options), "smmulr"); // smmulr r1, r2, r3
+        assert_eq!(mnemonic(0x15d10000, &options), "ldrb"); // ldrbne r0, [r1]
+        assert_eq!(mnemonic(0xe8bd8010, &options), "pop");
+        assert_eq!(mnemonic(0xe8900003, &options), "ldm"); // ldm r0, {r0, r1}
+        assert_eq!(mnemonic(0xee300a01, &options), "vadd.f32");
+        assert_eq!(mnemonic(0xef000000, &options), "svc");
+        assert_eq!(mnemonic(0xe9900002, &options), "ldmib"); // ldmib r0, {r1}
+        assert_eq!(mnemonic(0xe8100002, &options), "ldmda"); // ldmda r0, {r1}
+        assert_eq!(mnemonic(0xe9800002, &options), "stmib"); // stmib r0, {r1}
+        assert_eq!(mnemonic(0xe14214c3, &options), "smlalbt"); // smlalbt r1, r2, r3, r4
+        assert_eq!(mnemonic(0xe10032c1, &options), "smlabt"); // smlabt r0, r1, r2, r3
+        assert_eq!(mnemonic(0xe12002a1, &options), "smulwb"); // smulwb r0, r1, r2
+        assert_eq!(mnemonic(0xeeb30ae0, &options), "vcvtt.f16.f32"); // vcvtt.f16.f32 s0, s1
+        assert_eq!(mnemonic(0xeeb30a60, &options), "vcvtb.f16.f32"); // vcvtb.f16.f32 s0, s1
+        assert_eq!(mnemonic(0xec900b02, &options), "vldmia"); // vldmia r0, {d0}
+        assert_eq!(mnemonic(0xf1080080, &options), "cpsie"); // cpsie i
+        assert_eq!(mnemonic(0xf10c0080, &options), "cpsid"); // cpsid i
+        assert_eq!(mnemonic(0xeebb0bc6, &options), "vcvt.f64.u32"); // vcvt.f64.u32 d0, d0, #0x14
+        assert_eq!(mnemonic(0xeebe0b44, &options), "vcvt.s16.f64"); // vcvt.s16.f64 d0, d0, #0x8
+        assert_eq!(mnemonic(0xf2010802, &options), "vadd.i8"); // vadd.i8 d0, d1, d2
+        assert_eq!(mnemonic(0xf2110802, &options), "vadd.i16"); // vadd.i16 d0, d1, d2
+        options.ual = false;
+        assert_eq!(mnemonic(0xe8900003, &options), "ldmia"); // ldmia r0, {r0, r1}
+        assert_eq!(mnemonic(0xec900b02, &options), "fldmiad"); // fldmiad r0, {d0}
+        assert_eq!(mnemonic(0xeebe0b44, &options), "ftoshd"); // ftoshd d0, #0x8
+        assert_eq!(mnemonic(0x15d10000, &options), "ldrb");
+        assert_eq!(mnemonic(0x10910002, &options), "adds"); // addnes r0, r1, r2
+        assert_eq!(mnemonic(0xee300a01, &options), "fadds");
+        assert_eq!(mnemonic(0xef000000, &options), "swi");
+        assert_eq!(Ins::Word(0).mnemonic(&options), ".word");
+        assert_eq!(Ins::Illegal.mnemonic(&options), "<illegal>");
+    }
+
+    #[test]
+    fn test_operands() {
+        let options = options!();
+        let operands = |code: u32| parse_arm(code, 0, &options).operands().collect::<Vec<_>>();
+        assert_eq!(
+            operands(0xe0810102), // add r0, r1, r2, lsl #0x2
+            [
+                Operand::Reg(Reg::R0),
+                Operand::Reg(Reg::R1),
+                Operand::Op2(Op2::ShiftImm(ShiftImm {
+                    rm: Reg::R2,
+                    shift_op: ShiftOp::Lsl,
+                    imm: 2
+                })),
+            ]
+        );
+        assert_eq!(
+            operands(0xe5910004), // ldr r0, [r1, #0x4]
+            [
+                Operand::Reg(Reg::R0),
+                Operand::AddrLdrStr(AddrLdrStr::Pre {
+                    rn: Reg::R1,
+                    offset: LdrStrOffset::Imm(4),
+                    writeback: false
+                }),
+            ]
+        );
+        assert_eq!(
+            operands(0xe8b00003), // ldm r0!, {r0, r1}
+            [
+                Operand::Reg(Reg::R0),
+                Operand::Wb(true),
+                Operand::RegList(RegList::of(&[Reg::R0, Reg::R1])),
+                Operand::UserMode(false),
+            ]
+        );
+        assert_eq!(
+            operands(0xee300a01), // vadd.f32 s0, s0, s2
+            [Operand::Sreg(Sreg::S0), Operand::Sreg(Sreg::S0), Operand::Sreg(Sreg::S2)]
+        );
+        assert_eq!(operands(0xe320f000), []); // nop
+        assert_eq!(Ins::Word(0x1234).operands().collect::<Vec<_>>(), [Operand::Uimm(0x1234)]);
+    }
+
+    #[test]
+    fn test_thumb_operands() {
+        let mut options = options!();
+        options.ual = false;
+        // adc r0, r1 in divided syntax leaves out the first source register
+        let (ins, _size) = parse_thumb(0x4148, 0, &options);
+        assert_eq!(ins.display(&options).to_string(), "adc r0, r1");
+        assert_eq!(ins.mnemonic(&options), "adc");
+        let operands = ins.operands();
+        assert_eq!(operands.len(), 3);
+        assert_eq!(operands.as_slice()[1], Operand::Reg(Reg::R0));
+        options.ual = true;
+        assert_eq!(ins.mnemonic(&options), "adcs");
+    }
+}
@@ -1,9 +1,9 @@
 #[cfg(test)]
 mod tests {
     use unarm::{
-        AddrLdrStr, AddrMiscLoad, Cond, Extensions, Ins, LdrStrOffset, MiscLoadOffset, Op2,
-        Op2Shift, Options, ParseEndian, ParseMode, Parser, R9Use, Reg, RegList, ShiftImm, ShiftOp,
-        Version,
+        AddrLdrStr, AddrMiscLoad, Cond, Extensions, Ins, ItState, LdrStrOffset, MiscLoadOffset,
+        Op2, Op2Shift, Options, ParseEndian, ParseMode, Parser, R9Use, Reg, RegList, ShiftImm,
+        ShiftOp, Version,
     };
 
     #[test]
@@ -415,6 +415,70 @@ mod tests {
         assert_eq!(parser.next(), None);
     }
 
+    #[test]
+    fn test_it_block() {
+        #[rustfmt::skip]
+        let code = &[
+            0x0c, 0xbf,             // ite eq
+            0x40, 0x18,             // addeq r0, r0, r1
+            0x00, 0x20,             // movne r0, #0x0
+            0x40, 0x18,             // adds r0, r0, r1
+            0xc4, 0xbf,             // itt gt
+            0x01, 0xf1, 0x01, 0x00, // addgt r0, r1, #0x1
+            0x11, 0xf1, 0x01, 0x00, // addsgt r0, r1, #0x1
+            0x70, 0x47,             // bx lr
+        ];
+        let options = Options {
+            version: Version::V7,
+            extensions: Extensions::all(),
+            av: false,
+            r9_use: R9Use::R9,
+            sl: false,
+            fp: false,
+            ip: false,
+            ual: true,
+        };
+        let mut parser = Parser::new(code, ParseMode::Thumb, ParseEndian::Little, options.clone());
+        let mut next = |in_it_block: bool| {
+            let ins = parser.next().unwrap();
+            assert_eq!(parser.in_it_block(), in_it_block);
+            ins.display(&options).to_string()
+        };
+        // 0x00
+        assert_eq!(next(false), "ite eq");
+        // 0x02
+        assert_eq!(next(true), "addeq r0, r0, r1");
+        // 0x04
+        assert_eq!(next(true), "movne r0, #0x0");
+        // 0x06
+        assert_eq!(next(false), "adds r0, r0, r1");
+        // 0x08
+        assert_eq!(next(false), "itt gt");
+        // 0x0a
+        assert_eq!(next(true), "addgt r0, r1, #0x1");
+        // 0x0e
+        assert_eq!(next(true), "addsgt r0, r1, #0x1");
+        // 0x12
+        assert_eq!(next(false), "bx lr");
+        assert_eq!(parser.next(), None);
+
+        // Resume in the middle of the first IT block
+        parser.set_offset(0);
+        parser.set_it_state(ItState::none());
+        parser.next();
+        parser.next();
+        let (offset, it_state) = (parser.offset(), parser.it_state());
+        assert_eq!(it_state.cond(), Some(Cond::Ne));
+        parser.set_offset(0x12);
+        parser.set_it_state(ItState::none());
+        assert_eq!(parser.next(), Some(Ins::Bx { cond: Cond::Al, rm: Reg::Lr }));
+        parser.set_offset(offset);
+        parser.set_it_state(it_state);
+        assert_eq!(parser.next().unwrap().display(&options).to_string(), "movne r0, #0x0");
+        assert!(parser.in_it_block());
+        assert!(!parser.it_state().in_block());
+    }
+
     #[test]
     fn test_data() {
         #[rustfmt::skip]
@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{
+        DefUseArgument, Dreg, Flag, Options, Qreg, Reg, RegList, RegSet, Sreg, StatusReg, parse_arm,
+    };
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V7,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    fn set(args: &[DefUseArgument]) -> RegSet {
+        args.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_def_use_sets() {
+        let options = options!();
+        let ins = parse_arm(0xe92d4010, 0, &options); // push {r4, lr}
+        assert_eq!(ins.def_set(), set(&[Reg::Sp.into()]));
+        assert_eq!(ins.use_set(), set(&[Reg::R4.into(), Reg::Sp.into(), Reg::Lr.into()]));
+        assert!(ins.use_set().contains(RegList::of(&[Reg::R4, Reg::Lr])));
+        assert!(!ins.use_set().contains(RegList::of(&[Reg::R4, Reg::R5])));
+
+        let ins = parse_arm(0xc0912003, 0, &options); // addsgt r2, r1, r3
+        let defs = ins.def_set();
+        assert_eq!(defs.len(), 5);
+        assert!(defs.contains(Flag::V));
+        assert!(ins.use_set().contains(Flag::Z));
+        assert!(!ins.use_set().contains(Flag::C));
+
+        let ins = parse_arm(0xed2d8b04, 0, &options); // vpush {d8, d9}
+        assert_eq!(ins.use_set(), set(&[Reg::Sp.into(), Dreg::D8.into(), Dreg::D9.into()]));
+
+        let ins = parse_arm(0xe10f0000, 0, &options); // mrs r0, cpsr
+        assert_eq!(ins.def_set(), set(&[Reg::R0.into()]));
+        assert!(ins.use_set().contains(Flag::C));
+        assert!(ins.use_set().contains(Flag::Ge));
+
+        let ins = parse_arm(0xe128f000, 0, &options); // msr cpsr_f, r0
+        let flags = [Flag::N, Flag::Z, Flag::C, Flag::V, Flag::Q].map(DefUseArgument::Flag);
+        assert_eq!(ins.def_set(), set(&flags));
+
+        let ins = parse_arm(0xe14f0000, 0, &options); // mrs r0, spsr
+        assert!(ins.use_set().is_empty());
+    }
+
+    #[test]
+    fn test_overlap() {
+        let options = options!();
+        let def = parse_arm(0xee000a90, 0, &options); // vmov s1, r0
+        let uses = parse_arm(0xee300b00, 0, &options); // vadd.f64 d0, d0, d0
+        assert_eq!(def.def_set(), set(&[Sreg::S1.into()]));
+        assert!(!def.def_set().is_disjoint(uses.use_set()));
+        assert!(uses.use_set().contains(Sreg::S0));
+        assert!(!def.def_set().contains(Dreg::D0));
+        assert!(set(&[Qreg::Q0.into()]).contains(Sreg::S3));
+    }
+
+    #[test]
+    fn test_algebra() {
+        let a = set(&[Reg::R0.into(), Reg::R1.into(), Flag::C.into()]);
+        let b = set(&[Reg::R1.into(), Sreg::S31.into()]);
+        assert_eq!(a | b, set(&[Reg::R0.into(), Reg::R1.into(), Sreg::S31.into(), Flag::C.into()]));
+        assert_eq!(a & b, set(&[Reg::R1.into()]));
+        assert_eq!(a - b, set(&[Reg::R0.into(), Flag::C.into()]));
+        assert!((a & b).is_subset(a));
+        assert!((a - b).is_disjoint(b));
+
+        let mut c = a;
+        c.remove(Reg::R0);
+        c.insert(StatusReg::Spsr);
+        assert_eq!(c, set(&[Reg::R1.into(), Flag::C.into()]));
+        c -= a;
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut set = RegSet::new();
+        set.insert(Flag::Ge);
+        set.insert(Qreg::Q15);
+        set.insert(Sreg::S0);
+        set.insert(Reg::Pc);
+        assert!(set.contains(Dreg::D31));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![
+                DefUseArgument::Reg(Reg::Pc),
+                DefUseArgument::Sreg(Sreg::S0),
+                DefUseArgument::Dreg(Dreg::D30),
+                DefUseArgument::Dreg(Dreg::D31),
+                DefUseArgument::Flag(Flag::Ge),
+            ]
+        );
+        assert_eq!(format!("{set:?}"), "{Reg(Pc), Sreg(S0), Dreg(D30), Dreg(D31), Flag(Ge)}");
+    }
+
+    #[test]
+    fn test_liveness() {
+        let options = options!();
+        let code = [
+            0xe3a00001, // mov r0, #0x1
+            0xe0801002, // add r1, r0, r2
+            0xe3510000, // cmp r1, #0x0
+            0x03a03000, // moveq r3, #0x0
+        ];
+        let mut live = RegSet::new();
+        for code in code.iter().rev() {
+            let ins = parse_arm(*code, 0, &options);
+            live = (live - ins.def_set()) | ins.use_set();
+        }
+        assert_eq!(live, set(&[Reg::R2.into()]));
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use unarm::{Ins, Options, parse_arm, parse_thumb};
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V7,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    fn round_trip(ins: &Ins) -> Ins {
+        let json = serde_json::to_string(ins).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_serialize() {
+        let options = options!();
+        let ins = parse_arm(0xe59f0004, 0, &options);
+        assert_eq!(
+            serde_json::to_string(&ins).unwrap(),
+            r#"{"Ldr":{"cond":"Al","rd":"R0","addr":{"Pre":{"rn":"Pc","offset":{"Imm":4},"writeback":false}}}}"#
+        );
+        let ins = parse_arm(0xe92d4010, 0, &options);
+        assert_eq!(
+            serde_json::to_string(&ins).unwrap(),
+            r#"{"Push":{"cond":"Al","regs":["R4","Lr"]}}"#
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let options = options!();
+        for code in [
+            0xe59f0004, 0xe92d4010, 0x10810002, 0xe1a00251, 0xeb000036, 0xe8bd8010, 0xeef1fa10,
+            0xf3bf8f5f, 0xf4200a0f, 0xe7f000f0,
+        ] {
+            let ins = parse_arm(code, 0x2000000, &options);
+            assert_eq!(round_trip(&ins), ins, "{code:#010x}");
+        }
+        for code in [0xf86ef000, 0x4770, 0xb500, 0xbf18] {
+            let (ins, _size) = parse_thumb(code, 0x2000000, &options);
+            assert_eq!(round_trip(&ins), ins, "{code:#010x}");
+        }
+        assert_eq!(round_trip(&Ins::Word(0x1234)), Ins::Word(0x1234));
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{Ins, ParseEndian, ParseMode, Parser, Signature, SignatureError};
+
+    macro_rules! options {
+        () => {{
+            unarm::Options {
+                version: unarm::Version::V5Te,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    fn words(words: &[u32], endian: ParseEndian) -> Vec<u8> {
+        words
+            .iter()
+            .flat_map(|word| match endian {
+                ParseEndian::Little => word.to_le_bytes(),
+                ParseEndian::Big => word.to_be_bytes(),
+            })
+            .collect()
+    }
+
+    fn halfwords(halfwords: &[u16]) -> Vec<u8> {
+        halfwords.iter().flat_map(|halfword| halfword.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn test_arm() {
+        let options = options!();
+        let function = words(
+            &[
+                0xe92d4010, // push {r4, lr}
+                0xeb000010, // bl #0x4c
+                0xe59f0008, // ldr r0, [pc, #0x8]
+                0xe8bd8010, // pop {r4, pc}
+            ],
+            ParseEndian::Little,
+        );
+        let signature =
+            Signature::from_code(&function, ParseMode::Arm, ParseEndian::Little, &options);
+        assert_eq!(signature.len(), 16);
+        assert!(signature.matches(&function));
+
+        let image = words(
+            &[
+                0xe3a00000, // mov r0, #0x0
+                0xe92d4010, // push {r4, lr}
+                0xe92d4010, // push {r4, lr}
+                0xebfffff0, // bl #-0x38
+                0xe59f0010, // ldr r0, [pc, #0x10]
+                0xe8bd8010, // pop {r4, pc}
+                0xe92d4010, // push {r4, lr}
+                0xeb000010, // bl #0x4c
+                0xe59f0008, // ldr r0, [pc, #0x8]
+                0xe8bd4010, // pop {r4, lr}
+            ],
+            ParseEndian::Little,
+        );
+        assert_eq!(signature.scan(&image).collect::<Vec<_>>(), vec![8]);
+        assert!(signature.scan(&image[..20]).next().is_none());
+    }
+
+    #[test]
+    fn test_arm_big_endian() {
+        let options = options!();
+        let function = words(&[0xe92d4010, 0xeb000010], ParseEndian::Big);
+        let signature = Signature::from_code(&function, ParseMode::Arm, ParseEndian::Big, &options);
+        let image = words(&[0xe1a00000, 0xe92d4010, 0xeb123456], ParseEndian::Big);
+        assert_eq!(signature.scan(&image).collect::<Vec<_>>(), vec![4]);
+    }
+
+    #[test]
+    fn test_thumb() {
+        let options = options!();
+        let function = halfwords(&[
+            0xb510, // push {r4, lr}
+            0xf000, 0xf808, // bl #0x14
+            0x4801, // ldr r0, [pc, #0x4]
+            0xbd10, // pop {r4, pc}
+        ]);
+        let signature =
+            Signature::from_code(&function, ParseMode::Thumb, ParseEndian::Little, &options);
+        let image = halfwords(&[
+            0x2000, // movs r0, #0x0
+            0xb510, // push {r4, lr}
+            0xf7ff, 0xfffd, // bl #-0x2
+            0x4803, // ldr r0, [pc, #0xc]
+            0xbd10, // pop {r4, pc}
+        ]);
+        assert_eq!(signature.scan(&image).collect::<Vec<_>>(), vec![2]);
+        // Not aligned to a halfword
+        assert!(signature.scan(&image[1..]).next().is_none());
+    }
+
+    #[test]
+    fn test_from_ins() {
+        let options = options!();
+        let function = halfwords(&[0xb510, 0xf000, 0xf808, 0x4801, 0xbd10]);
+        let mut parser = Parser::new(&function, ParseMode::Thumb, ParseEndian::Little, options);
+        parser.set_pc(0x2000000);
+        let ins = parser.collect::<Vec<Ins>>();
+
+        let options = options!();
+        let from_ins =
+            Signature::from_ins(&ins, 0x2000000, ParseMode::Thumb, ParseEndian::Little, &options)
+                .unwrap();
+        let from_code =
+            Signature::from_code(&function, ParseMode::Thumb, ParseEndian::Little, &options);
+        assert_eq!(from_ins, from_code);
+    }
+
+    #[test]
+    fn test_from_hex() {
+        let signature = Signature::from_hex("10b5 ??f? ??f? 01?8 10bd", ParseMode::Thumb).unwrap();
+        assert_eq!(signature.len(), 10);
+        let image = halfwords(&[0x2000, 0xb510, 0xf7ff, 0xfffd, 0x4803, 0xbd10]);
+        assert_eq!(signature.scan(&image).collect::<Vec<_>>(), Vec::<usize>::new());
+        let image = halfwords(&[0x2000, 0xb510, 0xf7ff, 0xfffd, 0x4801, 0xbd10]);
+        assert_eq!(signature.scan(&image).collect::<Vec<_>>(), vec![2]);
+
+        assert_eq!(Signature::from_hex("10b", ParseMode::Thumb), Err(SignatureError::OddLength));
+        assert_eq!(
+            Signature::from_hex("10xb", ParseMode::Thumb),
+            Err(SignatureError::InvalidCharacter('x'))
+        );
+        assert!(Signature::from_hex("", ParseMode::Data).unwrap().is_empty());
+    }
+}
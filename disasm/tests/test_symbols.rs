@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use unarm::{
+        FormatIns, Ins, Options, ParseEndian, ParseMode, Relocation, RelocationKind,
+        StringFormatter, SymbolFormatter, parse_arm, parse_thumb,
+    };
+
+    macro_rules! options {
+        () => {{
+            Options {
+                version: unarm::Version::V5Te,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    fn symbols() -> BTreeMap<u32, &'static str> {
+        BTreeMap::from([
+            (0x2000000, "func_02000000"),
+            (0x2000010, ".L_loop"),
+            (0x2000100, "func_02000100"),
+            (0x2000200, "data_02000200"),
+        ])
+    }
+
+    macro_rules! assert_arm {
+        ($code:literal, $disasm:literal) => {{
+            let options = options!();
+            let symbols = symbols();
+            let ins = parse_arm($code, 0x2000020, &options);
+            let mut formatter = SymbolFormatter::new(StringFormatter::new(&options), &symbols);
+            formatter.write_ins(&ins).unwrap();
+            assert_eq!(formatter.into_inner().into_string(), $disasm);
+        }};
+    }
+
+    #[test]
+    fn test_branch_target() {
+        assert_arm!(0xeb000036, "bl func_02000100");
+        assert_arm!(0xeafffffb, "b .L_loop+0x4");
+        assert_arm!(0x1afffff6, "bne func_02000000");
+        assert_arm!(0xfa000036, "blx func_02000100");
+        assert_arm!(0xe12fff31, "blx r1");
+        assert_arm!(0xebfffff0, "bl #0x1ffffe8");
+    }
+
+    #[test]
+    fn test_thumb() {
+        let options = options!();
+        let symbols = symbols();
+        let (ins, _size) = parse_thumb(0xf86ef000, 0x2000020, &options);
+        let mut formatter = SymbolFormatter::new(StringFormatter::new(&options), &symbols);
+        formatter.write_ins(&ins).unwrap();
+        assert_eq!(formatter.into_inner().into_string(), "bl func_02000100");
+    }
+
+    #[test]
+    fn test_literals() {
+        let options = options!();
+        let symbols = symbols();
+        #[rustfmt::skip]
+        let code = &[
+            0x00, 0x00, 0x9f, 0xe5, // 0x00: ldr r0, [pc, #0x0]
+            0x00, 0x10, 0x9f, 0xe5, // 0x04: ldr r1, [pc, #0x0]
+            0x00, 0x01, 0x00, 0x02, // 0x08: .word func_02000100
+            0x34, 0x12, 0x00, 0x00, // 0x0c: .word 0x1234
+        ];
+        let write = |ins: u32, addr: u32, literals: bool| {
+            let ins = parse_arm(ins, addr, &options);
+            let mut formatter = SymbolFormatter::new(StringFormatter::new(&options), &symbols);
+            if literals {
+                formatter = formatter.with_literals(code, 0x2000000, ParseEndian::Little);
+            }
+            formatter.write_ins_at(&ins, addr, ParseMode::Arm).unwrap();
+            formatter.into_inner().into_string()
+        };
+        assert_eq!(write(0xe59f0000, 0x2000000, true), "ldr r0, [pc, #0x0] ; =func_02000100");
+        assert_eq!(write(0xe59f1000, 0x2000004, true), "ldr r1, [pc, #0x0] ; =0x00001234");
+        assert_eq!(write(0xe59f0000, 0x2000000, false), "ldr r0, [pc, #0x0]");
+        assert_eq!(write(0xe28f0f7e, 0x2000000, false), "add r0, pc, #0x1f8 ; =data_02000200");
+        assert_eq!(write(0xe28f0f7f, 0x2000000, false), "add r0, pc, #0x1fc ; =data_02000200+0x4");
+        assert_eq!(write(0xe0800001, 0x2000000, true), "add r0, r0, r1");
+    }
+
+    #[test]
+    fn test_literal_relocations() {
+        let options = options!();
+        let symbols = symbols();
+        #[rustfmt::skip]
+        let code = &[
+            0x00, 0x00, 0x9f, 0xe5, // 0x00: ldr r0, [pc, #0x0]
+            0x00, 0x10, 0x9f, 0xe5, // 0x04: ldr r1, [pc, #0x0]
+            0x04, 0x00, 0x00, 0x00, // 0x08: .word message+0x4
+            0x00, 0x00, 0x00, 0x00, // 0x0c: .word 0x0 ; R_ARM_REL32 other
+        ];
+        let relocations = [
+            (0x2000008, Relocation { kind: RelocationKind::Abs32, symbol: "message" }),
+            (0x200000c, Relocation { kind: RelocationKind::Rel32, symbol: "other" }),
+        ];
+        let write = |ins: u32, addr: u32| {
+            let ins = parse_arm(ins, addr, &options);
+            let mut formatter = SymbolFormatter::new(StringFormatter::new(&options), &symbols)
+                .with_literals(code, 0x2000000, ParseEndian::Little)
+                .with_literal_relocations(&relocations);
+            formatter.write_ins_at(&ins, addr, ParseMode::Arm).unwrap();
+            formatter.into_inner().into_string()
+        };
+        assert_eq!(write(0xe59f0000, 0x2000000), "ldr r0, [pc, #0x0] ; =message+0x4");
+        assert_eq!(write(0xe59f1000, 0x2000004), "ldr r1, [pc, #0x0]");
+    }
+
+    #[test]
+    fn test_relocations() {
+        let options = options!();
+        let symbols = symbols();
+        let write = |ins: Ins, addr: u32, kind: RelocationKind, symbol: &str| {
+            let mut formatter = SymbolFormatter::new(StringFormatter::new(&options), &symbols);
+            let relocation = Relocation { kind, symbol };
+            formatter.write_relocated_ins_at(&ins, addr, ParseMode::Arm, relocation).unwrap();
+            formatter.into_inner().into_string()
+        };
+        let bl = parse_arm(0xebfffffe, 0x4, &options);
+        assert_eq!(write(bl, 0x4, RelocationKind::Call, "printf"), "bl printf");
+        let b = parse_arm(0xea000000, 0x4, &options);
+        assert_eq!(write(b, 0x4, RelocationKind::Jump24, "exit"), "b exit+0x8");
+        assert_eq!(write(Ins::Word(4), 0x8, RelocationKind::Abs32, ".data"), ".word .data+0x4");
+        assert_eq!(
+            write(Ins::Word(0), 0x8, RelocationKind::Rel32, "message"),
+            ".word 0x0 ; R_ARM_REL32 message"
+        );
+    }
+}
@@ -1,11 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use unarm::{Options, parse_thumb, parse_thumb_with_discriminant};
+    use unarm::{Options, Version, parse_thumb, parse_thumb_with_discriminant};
 
     macro_rules! options {
-        () => {{
+        () => {{ options!(Version::V6K) }};
+        ($version:expr) => {{
             Options {
-                version: unarm::Version::V6K,
+                version: $version,
                 extensions: unarm::Extensions::all(),
                 av: false,
                 r9_use: unarm::R9Use::R9,
@@ -28,8 +29,9 @@ mod tests {
             let s = ins.display(&options).to_string();
             assert_eq!(s, $disasm, "mismatched parse with discriminant");
         }};
-        ($code:literal, $next:literal, $disasm:literal) => {{
-            let options = options!();
+        ($code:literal, $next:literal, $disasm:literal) => {{ assert_asm!($code, $next, Version::V6K, $disasm) }};
+        ($code:literal, $next:literal, $version:expr, $disasm:literal) => {{
+            let options = options!($version);
             let (ins, _size) = parse_thumb($code | ($next << 16), 0, &options);
             let s = ins.display(&options).to_string();
             assert_eq!(s, $disasm);
@@ -151,10 +153,23 @@ mod tests {
         assert_asm!(0x5c22, "ldrb r2, [r4, r0]");
     }
 
+    #[test]
+    fn test_ldrbt() {
+        assert_asm!(0xf811, 0x2e04, Version::V7, "ldrbt r2, [r1, #0x4]");
+    }
+
     #[test]
     fn test_ldrh() {
         assert_asm!(0x8c22, "ldrh r2, [r4, #0x20]");
         assert_asm!(0x5a22, "ldrh r2, [r4, r0]");
+        assert_asm!(0xf8b3, 0x1e12, Version::V7, "ldrh r1, [r3, #0xe12]");
+        assert_asm!(0xf8b3, 0xfe12, Version::V7, "<illegal>"); // PLDW
+        assert_asm!(0xf833, 0xf001, Version::V7, "<illegal>"); // unallocated memory hint
+    }
+
+    #[test]
+    fn test_ldrht() {
+        assert_asm!(0xf833, 0x8eed, Version::V7, "ldrht r8, [r3, #0xed]");
     }
 
     #[test]
@@ -162,9 +177,27 @@ mod tests {
         assert_asm!(0x5622, "ldrsb r2, [r4, r0]");
     }
 
+    #[test]
+    fn test_ldrsbt() {
+        assert_asm!(0xf911, 0x2e04, Version::V7, "ldrsbt r2, [r1, #0x4]");
+    }
+
     #[test]
     fn test_ldrsh() {
         assert_asm!(0x5e22, "ldrsh r2, [r4, r0]");
+        assert_asm!(0xf9bf, 0x1004, Version::V7, "ldrsh r1, [pc, #0x4]");
+        assert_asm!(0xf9bf, 0xf004, Version::V7, "<illegal>"); // unallocated memory hint
+    }
+
+    #[test]
+    fn test_ldrsht() {
+        assert_asm!(0xf933, 0x2e04, Version::V7, "ldrsht r2, [r3, #0x4]");
+    }
+
+    #[test]
+    fn test_ldrt() {
+        assert_asm!(0xf85d, 0x0e4d, Version::V7, "ldrt r0, [sp, #0x4d]");
+        assert_asm!(0xf85f, 0x2e04, Version::V7, "ldr r2, [pc, #-0xe04]");
     }
 
     #[test]
@@ -250,6 +283,9 @@ mod tests {
     #[test]
     fn test_stm() {
         assert_asm!(0xc155, "stm r1!, {r0, r2, r4, r6}");
+        assert_asm!(0xe90a, 0x083a, Version::V7, "stmdb r10, {r1, r3, r4, r5, r11}");
+        assert_asm!(0xe90a, 0x283a, Version::V7, "<illegal>"); // SP in register list
+        assert_asm!(0xe8a7, 0x97cd, Version::V7, "<illegal>"); // PC in register list
     }
 
     #[test]
@@ -257,18 +293,41 @@ mod tests {
         assert_asm!(0x6422, "str r2, [r4, #0x40]");
         assert_asm!(0x5022, "str r2, [r4, r0]");
         assert_asm!(0x9742, "str r7, [sp, #0x108]");
+        assert_asm!(0xf84b, 0x5bfa, Version::V7, "str r5, [r11], #0xfa");
+        assert_asm!(0xf84f, 0x5bfa, Version::V7, "<illegal>"); // Rn == PC
     }
 
     #[test]
     fn test_strb() {
         assert_asm!(0x7422, "strb r2, [r4, #0x10]");
         assert_asm!(0x5422, "strb r2, [r4, r0]");
+        assert_asm!(0xf80f, 0x5004, Version::V7, "<illegal>"); // Rn == PC
+    }
+
+    #[test]
+    fn test_strbt() {
+        assert_asm!(0xf801, 0x2e04, Version::V7, "strbt r2, [r1, #0x4]");
+        assert_asm!(0xf80f, 0x2e04, Version::V7, "<illegal>"); // Rn == PC
     }
 
     #[test]
     fn test_strh() {
         assert_asm!(0x8422, "strh r2, [r4, #0x20]");
         assert_asm!(0x5222, "strh r2, [r4, r0]");
+        assert_asm!(0xf8ab, 0xbc43, Version::V7, "strh r11, [r11, #0xc43]");
+        assert_asm!(0xf8af, 0xbc43, Version::V7, "<illegal>"); // Rn == PC
+    }
+
+    #[test]
+    fn test_strht() {
+        assert_asm!(0xf822, 0x2e04, Version::V7, "strht r2, [r2, #0x4]");
+        assert_asm!(0xf82f, 0x2e04, Version::V7, "<illegal>"); // Rn == PC
+    }
+
+    #[test]
+    fn test_strt() {
+        assert_asm!(0xf841, 0x2e04, Version::V7, "strt r2, [r1, #0x4]");
+        assert_asm!(0xf84f, 0x2e04, Version::V7, "<illegal>"); // Rn == PC
     }
 
     #[test]
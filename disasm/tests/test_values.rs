@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{Cfg, Ins, ParseEndian, ParseMode, Parser, Reg, RegValues};
+
+    macro_rules! options {
+        () => {{
+            unarm::Options {
+                version: unarm::Version::V5Te,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    fn parse(parser: &mut Parser, count: usize) -> Vec<(u32, Ins)> {
+        (0..count).map(|_| (parser.pc(), parser.next().unwrap())).collect()
+    }
+
+    #[rustfmt::skip]
+    const ARM_CODE: &[u8] = &[
+        0x10, 0x00, 0xa0, 0xe3, // 0x00: mov r0, #0x10
+        0x00, 0x11, 0xa0, 0xe1, // 0x04: lsl r1, r0, #0x2
+        0x01, 0x10, 0x81, 0xe3, // 0x08: orr r1, r1, #0x1
+        0x01, 0x20, 0x41, 0xe2, // 0x0c: sub r2, r1, #0x1
+        0x08, 0x30, 0x9f, 0xe5, // 0x10: ldr r3, [pc, #0x8]
+        0x04, 0x00, 0x92, 0xe5, // 0x14: ldr r0, [r2, #0x4]
+        0x33, 0xff, 0x2f, 0xe1, // 0x18: blx r3
+        0x00, 0x00, 0xa0, 0xe1, // 0x1c: mov r0, r0
+        0x01, 0x01, 0x00, 0x02, // 0x20: .word 0x2000101
+    ];
+
+    #[test]
+    fn test_arm() {
+        let mut parser = Parser::new(ARM_CODE, ParseMode::Arm, ParseEndian::Little, options!());
+        parser.set_pc(0x2000000);
+        let ins = parse(&mut parser, 7);
+        let values = RegValues::new().track(&ins, &parser);
+        assert_eq!(values.len(), 7);
+        assert_eq!(values[0], RegValues::new());
+        assert_eq!(values[4].get(Reg::R0), Some(0x10));
+        assert_eq!(values[4].get(Reg::R1), Some(0x41));
+        assert_eq!(values[4].get(Reg::R2), Some(0x40));
+        assert_eq!(values[5].get(Reg::R3), Some(0x2000101));
+
+        let (pc, ldr) = &ins[5];
+        assert_eq!(values[5].access_address(ldr, *pc, ParseMode::Arm), Some(0x44));
+        assert_eq!(values[6].get(Reg::R0), None);
+        assert_eq!(values[6].branch_target(&ins[6].1), Some(0x2000101));
+        assert_eq!(values[5].branch_target(&ins[5].1), None);
+    }
+
+    #[test]
+    fn test_arm_shifts_and_calls() {
+        #[rustfmt::skip]
+        let code = &[
+            0x02, 0x01, 0xa0, 0xe3, // mov r0, #0x80000000
+            0x40, 0x10, 0xa0, 0xe1, // asr r1, r0, #0x20
+            0x20, 0x20, 0xa0, 0xe1, // lsr r2, r0, #0x20
+            0x60, 0x30, 0xa0, 0xe1, // rrx r3, r0
+            0x07, 0x40, 0xa0, 0xe3, // mov r4, #0x7
+            0x01, 0xc0, 0xa0, 0xe3, // mov ip, #0x1
+            0x05, 0x00, 0xa0, 0x03, // moveq r0, #0x5
+            0x00, 0x00, 0x00, 0xeb, // bl #0x8
+        ];
+        let mut parser = Parser::new(code, ParseMode::Arm, ParseEndian::Little, options!());
+        let ins = parse(&mut parser, 8);
+        let values = RegValues::new().track(&ins, &parser);
+        let before_bl = values[7];
+        assert_eq!(before_bl.get(Reg::R0), None);
+        assert_eq!(before_bl.get(Reg::R1), Some(0xffffffff));
+        assert_eq!(before_bl.get(Reg::R2), Some(0));
+        assert_eq!(before_bl.get(Reg::R3), None);
+        assert_eq!(before_bl.get(Reg::R12), Some(1));
+
+        let mut after_bl = before_bl;
+        after_bl.update(&ins[7].1, ins[7].0, &parser);
+        assert_eq!(after_bl.get(Reg::R1), None);
+        assert_eq!(after_bl.get(Reg::R12), None);
+        assert_eq!(after_bl.get(Reg::R4), Some(7));
+        assert_eq!(after_bl.read(Reg::Pc, 0x100, ParseMode::Arm), Some(0x108));
+    }
+
+    #[test]
+    fn test_thumb() {
+        #[rustfmt::skip]
+        let code = &[
+            0x01, 0x20,             // 0x00: movs r0, #0x1
+            0x00, 0x01,             // 0x02: lsls r0, r0, #0x4
+            0x01, 0x30,             // 0x04: adds r0, #0x1
+            0x01, 0x4a,             // 0x06: ldr r2, [pc, #0x4]
+            0x51, 0x68,             // 0x08: ldr r1, [r2, #0x4]
+            0x10, 0x47,             // 0x0a: bx r2
+            0x21, 0x00, 0x00, 0x02, // 0x0c: .word 0x2000021
+        ];
+        let mut parser = Parser::new(code, ParseMode::Thumb, ParseEndian::Little, options!());
+        parser.set_pc(0x2000000);
+        let ins = parse(&mut parser, 6);
+        let values = RegValues::new().track(&ins, &parser);
+        assert_eq!(values[3].get(Reg::R0), Some(0x11));
+        assert_eq!(values[4].get(Reg::R2), Some(0x2000021));
+        let (pc, ldr) = &ins[4];
+        assert_eq!(values[4].access_address(ldr, *pc, ParseMode::Thumb), Some(0x2000025));
+        assert_eq!(values[5].branch_target(&ins[5].1), Some(0x2000021));
+    }
+
+    #[test]
+    fn test_cfg() {
+        let mut cfg =
+            Cfg::build(ARM_CODE, 0x2000000, ParseMode::Arm, ParseEndian::Little, options!());
+        let values = cfg.reg_values(0x2000000).unwrap();
+        assert_eq!(values.len(), 7);
+        assert_eq!(values[6].get(Reg::R3), Some(0x2000101));
+        assert!(cfg.reg_values(0x2000004).is_none());
+    }
+}
@@ -87,6 +87,11 @@ mod tests {
         assert_thumb!(0x47d0, Version::V5T, "blx r10");
         assert_thumb!(0x47d0, Version::V4T, "<illegal>");
 
+        // Bits 11 and 13 of the second halfword are ignored before ARMv7
+        assert_thumb!(0xf000, 0xd000, Version::V4T, "bl #0x4");
+        assert_thumb!(0xf000, 0xd000, Version::V6K, "bl #0x4");
+        assert_thumb!(0xf000, 0xc000, Version::V6K, "blx #0x4");
+
         assert_arm!(0xfe1234a6, Version::V5T, "cdp2 p4, #0x1, c3, c2, c6, #0x5");
         assert_arm!(0xfe1234a6, Version::V4T, "<illegal>");
 
@@ -453,4 +458,84 @@ mod tests {
         assert_arm!(0xe320f001, Version::V6K, "yield");
         assert_arm!(0xe320f001, Version::V6, "<illegal>");
     }
+
+    #[test]
+    fn test_v7() {
+        assert_arm!(0xe7cb121f, Version::V7, "bfc r1, #0x4, #0x8");
+        assert_arm!(0xe7cb121f, Version::V6K, "<illegal>");
+
+        assert_arm!(0xe7cb1212, Version::V7, "bfi r1, r2, #0x4, #0x8");
+        assert_arm!(0xe7cb1212, Version::V6K, "<illegal>");
+        assert_thumb!(0xf362, 0x110b, Version::V7, "bfi r1, r2, #0x4, #0x8");
+
+        assert_thumb!(0xb942, Version::V7, "cbnz r2, #0x14");
+        assert_thumb!(0xb942, Version::V6K, "<illegal>");
+
+        assert_thumb!(0xb141, Version::V7, "cbz r1, #0x14");
+        assert_thumb!(0xb141, Version::V6K, "<illegal>");
+        assert_thumb!(0xb510, Version::V7, "push {r4, lr}");
+        assert_thumb!(0xbd10, Version::V7, "pop {r4, pc}");
+
+        assert_thumb!(0xf3af, 0x8113, Version::V7, "cps #0x13");
+        assert_thumb!(0xf3af, 0x8113, Version::V6K, "<illegal>");
+        assert_thumb!(0xf3af, 0x8193, Version::V7, "<illegal>"); // imod == 0b00 with A/I/F
+        assert_thumb!(0xf3af, 0x8693, Version::V7, "<illegal>"); // M == 0 with mode
+
+        assert_arm!(0xf57ff05b, Version::V7, "dmb ish");
+        assert_arm!(0xf57ff05b, Version::V6K, "<illegal>");
+        assert_thumb!(0xf3bf, 0x8f5b, Version::V7, "dmb ish");
+
+        assert_arm!(0xf57ff04f, Version::V7, "dsb sy");
+        assert_arm!(0xf57ff04f, Version::V6K, "<illegal>");
+
+        assert_arm!(0xf57ff06f, Version::V7, "isb sy");
+        assert_arm!(0xf57ff06f, Version::V6K, "<illegal>");
+
+        assert_thumb!(0xbf0c, Version::V7, "ite eq");
+        assert_thumb!(0xbf0c, Version::V6K, "<illegal>");
+
+        assert_thumb!(0xe852, 0x1f01, Version::V7, "ldrex r1, [r2, #0x4]");
+
+        assert_arm!(0xe0614392, Version::V7, "mls r1, r2, r3, r4");
+        assert_arm!(0xe0614392, Version::V6K, "<illegal>");
+
+        assert_arm!(0xe3451678, Version::V7, "movt r1, #0x5678");
+        assert_arm!(0xe3451678, Version::V6K, "<illegal>");
+        assert_thumb!(0xf2c5, 0x6178, Version::V7, "movt r1, #0x5678");
+
+        assert_arm!(0xe3011234, Version::V7, "movw r1, #0x1234");
+        assert_arm!(0xe3011234, Version::V6K, "<illegal>");
+        assert_thumb!(0xf241, 0x2134, Version::V7, "movw r1, #0x1234");
+
+        assert_thumb!(0xea62, 0x0183, Version::V7, "orn r1, r2, r3, lsl #0x2");
+
+        assert_arm!(0xf4d1f010, Version::V7, "pli [r1, #0x10]");
+        assert_arm!(0xf4d1f010, Version::V6K, "<illegal>");
+
+        assert_arm!(0xe6ff1f32, Version::V7, "rbit r1, r2");
+        assert_arm!(0xe6ff1f32, Version::V6K, "<illegal>");
+        assert_thumb!(0xfa92, 0xf1a2, Version::V7, "rbit r1, r2");
+
+        assert_arm!(0xe7a71252, Version::V7, "sbfx r1, r2, #0x4, #0x8");
+        assert_arm!(0xe7a71252, Version::V6K, "<illegal>");
+
+        assert_arm!(0xe711f312, Version::V7, "sdiv r1, r2, r3");
+        assert_arm!(0xe711f312, Version::V6K, "<illegal>");
+        assert_thumb!(0xfb92, 0xf1f3, Version::V7, "sdiv r1, r2, r3");
+
+        assert_thumb!(0xe8d1, 0xf002, Version::V7, "tbb [r1, r2]");
+        assert_thumb!(0xe8d1, 0xf012, Version::V7, "tbh [r1, r2, lsl #0x1]");
+
+        assert_arm!(0xe7e71252, Version::V7, "ubfx r1, r2, #0x4, #0x8");
+        assert_arm!(0xe7e71252, Version::V6K, "<illegal>");
+        assert_thumb!(0xf3c2, 0x1107, Version::V7, "ubfx r1, r2, #0x4, #0x8");
+
+        assert_arm!(0xe731f312, Version::V7, "udiv r1, r2, r3");
+        assert_arm!(0xe731f312, Version::V6K, "<illegal>");
+        assert_thumb!(0xfbb2, 0xf1f3, Version::V7, "udiv r1, r2, r3");
+
+        assert_thumb!(0xf502, 0x417f, Version::V7, "add r1, r2, #0xff00");
+        assert_thumb!(0xf852, 0x1023, Version::V7, "ldr r1, [r2, r3, lsl #0x2]");
+        assert_thumb!(0xee30, 0x0a81, Version::V7, "vadd.f32 s0, s1, s2");
+    }
 }
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use unarm::{Ins, Options, ParseMode, Version, parse_arm, parse_thumb};
+
+    macro_rules! options {
+        ($version:expr) => {{
+            Options {
+                version: $version,
+                extensions: unarm::Extensions::all(),
+                av: false,
+                r9_use: unarm::R9Use::R9,
+                sl: false,
+                fp: false,
+                ip: false,
+                ual: true,
+            }
+        }};
+    }
+
+    fn arm_mask(code: u32, options: &Options) -> u32 {
+        parse_arm(code, 0, options).wildcard_mask(code, ParseMode::Arm, options)
+    }
+
+    fn thumb_mask(code: u32, options: &Options) -> u32 {
+        parse_thumb(code, 0, options).0.wildcard_mask(code, ParseMode::Thumb, options)
+    }
+
+    #[test]
+    fn test_arm_branches() {
+        let options = options!(Version::V5Te);
+        assert_eq!(arm_mask(0xea000010, &options), 0x00ffffff); // b #0x48
+        assert_eq!(arm_mask(0x0b000010, &options), 0x00ffffff); // bleq #0x48
+        assert_eq!(arm_mask(0xfb000010, &options), 0x01ffffff); // blx #0x4a
+        assert_eq!(arm_mask(0xe12fff31, &options), 0); // blx r1
+        assert_eq!(arm_mask(0xe12fff1e, &options), 0); // bx lr
+    }
+
+    #[test]
+    fn test_arm_pc_relative() {
+        let options = options!(Version::V5Te);
+        assert_eq!(arm_mask(0xe59f0010, &options), 0x00800fff); // ldr r0, [pc, #0x10]
+        assert_eq!(arm_mask(0xe5910004, &options), 0); // ldr r0, [r1, #0x4]
+        assert_eq!(arm_mask(0xe1df00b4, &options), 0x00800f0f); // ldrh r0, [pc, #0x4]
+        assert_eq!(arm_mask(0xe28f0010, &options), 0xfff); // add r0, pc, #0x10
+        assert_eq!(arm_mask(0xe2810010, &options), 0); // add r0, r1, #0x10
+        assert_eq!(arm_mask(0xe0810002, &options), 0); // add r0, r1, r2
+    }
+
+    #[test]
+    fn test_thumb_branches() {
+        let v5te = options!(Version::V5Te);
+        let v7 = options!(Version::V7);
+        assert_eq!(thumb_mask(0xe000, &v5te), 0x7ff); // b #0x4
+        assert_eq!(thumb_mask(0xd000, &v5te), 0xff); // beq #0x4
+        assert_eq!(thumb_mask(0xf800f000, &v5te), 0x07ff07ff); // bl #0x4
+        assert_eq!(thumb_mask(0xf800f000, &v7), 0x2fff07ff); // bl #0x4
+        assert_eq!(thumb_mask(0xe800f000, &v5te), 0x07fe07ff); // blx #0x4
+        assert_eq!(thumb_mask(0xb108, &v7), 0x2f8); // cbz r0, #0x6
+        assert_eq!(thumb_mask(0x4770, &v5te), 0); // bx lr
+    }
+
+    #[test]
+    fn test_thumb_pc_relative() {
+        let options = options!(Version::V7);
+        assert_eq!(thumb_mask(0x4802, &options), 0xff); // ldr r0, [pc, #0x8]
+        assert_eq!(thumb_mask(0x6848, &options), 0); // ldr r0, [r1, #0x4]
+        assert_eq!(thumb_mask(0xa002, &options), 0xff); // adr r0, #0x8
+        assert_eq!(thumb_mask(0x0008f8df, &options), 0x0fff0080); // ldr.w r0, [pc, #0x8]
+    }
+
+    #[test]
+    fn test_data() {
+        let options = options!(Version::V7);
+        let word = Ins::Word(0xea000010);
+        assert_eq!(word.wildcard_mask(0xea000010, ParseMode::Arm, &options), 0);
+        assert_eq!(word.wildcard_mask(0xea000010, ParseMode::Data, &options), 0);
+    }
+}
@@ -1,7 +1,7 @@
 use std::{hint::black_box, ops::RangeInclusive};
 
 use rand::RngCore;
-use unarm::{parse_arm, parse_arm_with_discriminant, Options};
+use unarm::{parse_arm, parse_arm_with_discriminant, parse_asm, Ins, Options, ParseMode};
 
 use crate::Test;
 
@@ -21,6 +21,8 @@ pub fn fuzz(num_threads: usize, iterations: usize, options: Options, test: Test)
         Test::Reparse => fuzzers.iter().map(|f| f.reparse()).collect(),
         Test::Defs => fuzzers.iter().map(|f| f.defs()).collect(),
         Test::Uses => fuzzers.iter().map(|f| f.uses()).collect(),
+        Test::Encode => fuzzers.iter().map(|f| f.encode()).collect(),
+        Test::Asm => fuzzers.iter().map(|f| f.asm()).collect(),
     };
     for handle in handles {
         handle.join().unwrap();
@@ -124,4 +126,53 @@ impl Fuzzer {
             }
         })
     }
+
+    fn encode(&self) -> std::thread::JoinHandle<()> {
+        let range = self.range.clone();
+        let iterations = self.iterations;
+        let options = self.options.clone();
+        std::thread::spawn(move || {
+            for _ in 0..iterations {
+                for code in range.clone() {
+                    let ins = parse_arm(code, 0, &options);
+                    if ins == Ins::Illegal {
+                        continue;
+                    }
+                    let encoded = ins
+                        .encode_arm(0, &options)
+                        .unwrap_or_else(|e| panic!("Failed to encode {code:#010x} ({ins:?}): {e}"));
+                    let reparsed = parse_arm(encoded, 0, &options);
+                    assert_eq!(
+                        ins, reparsed,
+                        "{code:#010x} was encoded as {encoded:#010x} which parses differently"
+                    );
+                }
+            }
+        })
+    }
+
+    fn asm(&self) -> std::thread::JoinHandle<()> {
+        let range = self.range.clone();
+        let iterations = self.iterations;
+        let options = self.options.clone();
+        std::thread::spawn(move || {
+            for _ in 0..iterations {
+                for code in range.clone() {
+                    let ins = parse_arm(code, 0, &options);
+                    if ins == Ins::Illegal {
+                        continue;
+                    }
+                    // Some encodings have bits which aren't displayed, so compare the text
+                    let text = ins.display(&options).to_string();
+                    let parsed = parse_asm(&text, 0, ParseMode::Arm, &options)
+                        .unwrap_or_else(|e| panic!("Failed to parse '{text}' ({code:#010x}): {e}"));
+                    assert_eq!(
+                        text,
+                        parsed.display(&options).to_string(),
+                        "'{text}' ({code:#010x}) parses differently"
+                    );
+                }
+            }
+        })
+    }
 }
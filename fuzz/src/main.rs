@@ -13,6 +13,8 @@ pub enum Test {
     Reparse,
     Defs,
     Uses,
+    Encode,
+    Asm,
 }
 
 fn main() {
@@ -45,6 +47,7 @@ fn main() {
                 "v5tej" => version = Some(Version::V5Tej),
                 "v6" => version = Some(Version::V6),
                 "v6k" => version = Some(Version::V6K),
+                "v7" => version = Some(Version::V7),
                 "ual" => ual = true,
                 "parse" => test = Some(Test::Parse),
                 "parse-random" => test = Some(Test::ParseRandom),
@@ -52,6 +55,8 @@ fn main() {
                 "reparse" => test = Some(Test::Reparse),
                 "defs" => test = Some(Test::Defs),
                 "uses" => test = Some(Test::Uses),
+                "encode" => test = Some(Test::Encode),
+                "asm" => test = Some(Test::Asm),
                 _ => panic!("Unknown argument '{}'", arg),
             }
         }
@@ -67,7 +72,7 @@ fn main() {
         panic!("Expected one of: arm, thumb");
     }
     let Some(version) = version else {
-        panic!("Expected one of: v4, v4t, v5t, v5te, v5tej, v6, v6k");
+        panic!("Expected one of: v4, v4t, v5t, v5te, v5tej, v6, v6k, v7");
     };
     let Some(test) = test else {
         panic!("Expected one of: parse, parse-random, parse-and-write, reparse");
@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 
 use crate::isa::{Arch, Isa};
@@ -50,6 +50,9 @@ impl Isa {
         let parse_thumb_with_discriminant_fn =
             self.opcodes().parse_with_discriminant_tokens(self, Arch::Thumb);
         let opcode_parse_fns = self.opcodes().parse_fns_tokens(self);
+        let arm_param_masks_fn = self.opcodes().param_masks_fn_tokens(self, Arch::Arm);
+        let thumb_param_masks_fn = self.opcodes().param_masks_fn_tokens(self, Arch::Thumb);
+        let set_it_cond_fn = self.opcodes().set_it_cond_tokens(self);
 
         quote! {
             #![cfg_attr(rustfmt, rustfmt_skip)]
@@ -60,6 +63,8 @@ impl Isa {
             #![allow(clippy::derivable_impls)]
             #![allow(clippy::needless_else)]
             #![allow(clippy::manual_range_patterns)]
+            #![allow(clippy::identity_op)]
+            #![allow(clippy::collapsible_match)]
             #![allow(unused_parens)]
             #![allow(unused_variables)]
 
@@ -72,6 +77,9 @@ impl Isa {
             #parse_thumb_fn
             #parse_arm_with_discriminant_fn
             #parse_thumb_with_discriminant_fn
+            #arm_param_masks_fn
+            #thumb_param_masks_fn
+            #set_it_cond_fn
             #opcode_parse_fns
         }
     }
@@ -83,6 +91,8 @@ impl Isa {
 
         let ins_fmt_impl = self.opcodes().write_impl_tokens(self);
 
+        let symbol_formatter_impl = self.types().symbol_formatter_impl_tokens(self);
+
         quote! {
             #![cfg_attr(rustfmt, rustfmt_skip)]
 
@@ -98,12 +108,15 @@ impl Isa {
             #data_fmt_impls
 
             #ins_fmt_impl
+
+            #symbol_formatter_impl
         }
     }
 
     pub fn generate_defs_uses(&self) -> TokenStream {
         let ins_defs_uses_impl = self.opcodes().defs_uses_impl_tokens(self);
         let type_defs_uses_impls = self.types().defs_uses_impl_tokens(self);
+        let max_defs_uses = Literal::usize_unsuffixed(self.opcodes().max_defs_uses_len(self));
 
         quote! {
             #![cfg_attr(rustfmt, rustfmt_skip)]
@@ -111,11 +124,135 @@ impl Isa {
             #![allow(unused_variables)]
             #![allow(unreachable_patterns)]
             #![allow(clippy::single_match)]
+            #![allow(clippy::collapsible_match)]
 
             use crate::*;
 
+            /// Most arguments that any instruction defines or uses
+            pub(crate) const MAX_DEFS_USES: usize = #max_defs_uses;
+
             #ins_defs_uses_impl
             #type_defs_uses_impls
         }
     }
+
+    pub fn generate_operands(&self) -> TokenStream {
+        let operand_enum = self.opcodes().operand_enum_tokens(self);
+        let ins_operands_impl = self.opcodes().operands_impl_tokens(self);
+
+        quote! {
+            #![cfg_attr(rustfmt, rustfmt_skip)]
+
+            #![allow(unused_variables)]
+            #![allow(clippy::explicit_auto_deref)]
+
+            use crate::*;
+
+            #operand_enum
+            #ins_operands_impl
+        }
+    }
+
+    pub fn generate_opcode(&self) -> TokenStream {
+        let opcode_enum = self.opcodes().opcode_enum_tokens(self);
+        let opcode_impl = self.opcodes().opcode_impl_tokens(self);
+
+        quote! {
+            #![cfg_attr(rustfmt, rustfmt_skip)]
+
+            use crate::*;
+
+            #opcode_enum
+            #opcode_impl
+        }
+    }
+
+    pub fn generate_flow(&self) -> TokenStream {
+        let ins_flow_impl = self.opcodes().flow_impl_tokens(self);
+
+        quote! {
+            #![cfg_attr(rustfmt, rustfmt_skip)]
+
+            #![allow(unused_variables)]
+            #![allow(clippy::explicit_auto_deref)]
+            #![allow(clippy::useless_conversion)]
+
+            use crate::*;
+
+            #ins_flow_impl
+        }
+    }
+
+    pub fn generate_access(&self) -> TokenStream {
+        let ins_access_impl = self.opcodes().access_impl_tokens(self);
+
+        quote! {
+            #![cfg_attr(rustfmt, rustfmt_skip)]
+
+            #![allow(unused_variables)]
+            #![allow(clippy::explicit_auto_deref)]
+            #![allow(clippy::useless_conversion)]
+            #![allow(clippy::unnecessary_cast)]
+
+            use crate::*;
+
+            #ins_access_impl
+        }
+    }
+
+    pub fn generate_encoder(&self) -> TokenStream {
+        let type_encode_impls = self.types().encode_impls_tokens(self);
+        let encode_arm_fn = self.opcodes().encode_fn_tokens(self, Arch::Arm);
+        let encode_thumb_fn = self.opcodes().encode_fn_tokens(self, Arch::Thumb);
+        let encode_fns = self.opcodes().encode_fns_tokens(self);
+
+        quote! {
+            #![cfg_attr(rustfmt, rustfmt_skip)]
+
+            #![allow(unused_mut)]
+            #![allow(unused_parens)]
+            #![allow(unused_variables)]
+            #![allow(clippy::double_parens)]
+            #![allow(clippy::identity_op)]
+            #![allow(clippy::type_complexity)]
+            #![allow(clippy::unnecessary_cast)]
+            #![allow(clippy::redundant_locals)]
+
+            use crate::*;
+
+            #type_encode_impls
+
+            impl Ins {
+                #encode_arm_fn
+                #encode_thumb_fn
+            }
+
+            #encode_fns
+        }
+    }
+
+    pub fn generate_assembler(&self) -> TokenStream {
+        let type_asm_impls = self.types().asm_impls_tokens(self);
+        let parse_asm_fn = self.opcodes().asm_fn_tokens(self);
+        let asm_fns = self.opcodes().asm_fns_tokens(self);
+
+        quote! {
+            #![cfg_attr(rustfmt, rustfmt_skip)]
+
+            #![allow(unused_mut)]
+            #![allow(unused_variables)]
+            #![allow(clippy::let_and_return)]
+            #![allow(clippy::type_complexity)]
+
+            use alloc::{vec, vec::Vec};
+
+            use crate::*;
+
+            #type_asm_impls
+
+            #parse_asm_fn
+
+            #asm_fns
+        }
+    }
 }
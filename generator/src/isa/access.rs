@@ -0,0 +1,212 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use serde::Deserialize;
+use syn::Ident;
+
+use crate::{
+    isa::{FormatCond, Isa, Opcode, Opcodes},
+    util::str::snake_to_pascal_case,
+};
+
+/// The memory an instruction accesses, see `MemAccess` in the disassembler.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub enum Access {
+    #[serde(rename = "load")]
+    Load(AccessInfo),
+    #[serde(rename = "store")]
+    Store(AccessInfo),
+    #[serde(rename = "swap")]
+    Swap(AccessInfo),
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct AccessInfo {
+    /// Size of each element in bytes, evaluates to `u8`
+    size: FormatCond,
+    /// Number of elements, evaluates to any integer. Defaults to 1.
+    #[serde(default)]
+    count: Option<FormatCond>,
+    #[serde(default)]
+    signed: bool,
+    #[serde(default)]
+    exclusive: bool,
+    /// Evaluates to `bool`, defaults to false
+    #[serde(default)]
+    user_mode: Option<FormatCond>,
+    addr: AccessAddr,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub enum AccessAddr {
+    /// Evaluates to an addressing mode which converts into `AccessAddress`, such as `AddrLdrStr`
+    #[serde(rename = "mode")]
+    Mode(FormatCond),
+    /// Evaluates to a `NeonAddr`, whose writeback adds the number of bytes transferred
+    #[serde(rename = "neon")]
+    Neon(FormatCond),
+    /// Consecutive words stepping from a base register, like LDM/STM
+    #[serde(rename = "block")]
+    Block(BlockAddr),
+    /// A base register with an optional offset and no writeback
+    #[serde(rename = "base")]
+    Base(BaseAddr),
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BlockAddr {
+    rn: FormatCond,
+    /// Evaluates to a type which converts into `LdmStmMode`
+    mode: FormatCond,
+    writeback: FormatCond,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BaseAddr {
+    rn: FormatCond,
+    /// Immediate offset, evaluates to any integer
+    #[serde(default)]
+    offset: Option<FormatCond>,
+    /// Index register to add to the base register, shifted left by `shift`
+    #[serde(default)]
+    index: Option<FormatCond>,
+    #[serde(default)]
+    shift: u32,
+}
+
+impl Access {
+    fn expr_tokens(&self) -> TokenStream {
+        let (kind, info) = match self {
+            Access::Load(info) => (quote!(AccessKind::Load), info),
+            Access::Store(info) => (quote!(AccessKind::Store), info),
+            Access::Swap(info) => (quote!(AccessKind::Swap), info),
+        };
+        let AccessInfo { size, count, signed, exclusive, user_mode, addr } = info;
+
+        let size = size.as_tokens(None);
+        let count = match count {
+            Some(count) => {
+                let count = count.as_tokens(None);
+                quote!((#count) as u8)
+            }
+            None => quote!(1),
+        };
+        let user_mode = match user_mode {
+            Some(user_mode) => user_mode.as_tokens(None),
+            None => quote!(false),
+        };
+        let address = addr.expr_tokens();
+
+        quote! {
+            let (size, count): (u8, u8) = (#size, #count);
+            Some(MemAccess {
+                kind: #kind,
+                size,
+                count,
+                signed: #signed,
+                exclusive: #exclusive,
+                user_mode: #user_mode,
+                address: #address,
+            })
+        }
+    }
+}
+
+impl AccessAddr {
+    fn expr_tokens(&self) -> TokenStream {
+        match self {
+            AccessAddr::Mode(addr) => {
+                let addr = addr.as_tokens(None);
+                quote!(AccessAddress::from(#addr))
+            }
+            AccessAddr::Neon(addr) => {
+                let addr = addr.as_tokens(None);
+                quote!(AccessAddress::neon(#addr, size as u32 * count as u32))
+            }
+            AccessAddr::Block(BlockAddr { rn, mode, writeback }) => {
+                let rn = rn.as_tokens(None);
+                let mode = mode.as_tokens(None);
+                let writeback = writeback.as_tokens(None);
+                quote! {
+                    AccessAddress {
+                        base: #rn,
+                        offset: AccessOffset::Imm(0),
+                        indexing: Indexing::Block(LdmStmMode::from(#mode)),
+                        writeback: #writeback,
+                    }
+                }
+            }
+            AccessAddr::Base(BaseAddr { rn, offset, index, shift }) => {
+                let rn = rn.as_tokens(None);
+                let offset = match (offset, index) {
+                    (Some(_), Some(_)) => panic!("Base address can't have both offset and index"),
+                    (Some(offset), None) => {
+                        let offset = offset.as_tokens(None);
+                        quote!(AccessOffset::Imm((#offset) as i32))
+                    }
+                    (None, Some(index)) => {
+                        let index = index.as_tokens(None);
+                        quote! {
+                            AccessOffset::Reg {
+                                subtract: false,
+                                rm: #index,
+                                shift_op: ShiftOp::Lsl,
+                                imm: #shift,
+                            }
+                        }
+                    }
+                    (None, None) => quote!(AccessOffset::Imm(0)),
+                };
+                quote! {
+                    AccessAddress {
+                        base: #rn,
+                        offset: #offset,
+                        indexing: Indexing::Pre,
+                        writeback: false,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Opcodes {
+    pub fn access_impl_tokens(&self, isa: &Isa) -> TokenStream {
+        let access_cases = self.iter().filter_map(|op| op.access_case_tokens(isa));
+
+        quote! {
+            impl Ins {
+                /// Returns the memory accessed by this instruction, see [`MemAccess`]. Preload
+                /// hints such as PLD are not considered memory accesses.
+                pub fn memory_access(&self) -> Option<MemAccess> {
+                    match self {
+                        #(#access_cases)*
+                        _ => None,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Opcode {
+    fn access_case_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+        let access = self.access()?;
+
+        let variant_ident = Ident::new(&snake_to_pascal_case(self.mnemonic()), Span::call_site());
+        let param_names = self.params().keys().map(|k| Ident::new(&k.0, Span::call_site()));
+        let access_expr = access.expr_tokens();
+
+        let cfg = self.cfg_attribute_tokens(isa);
+        Some(quote! {
+            #cfg
+            Ins::#variant_ident { #(#param_names),* } => {
+                #access_expr
+            }
+        })
+    }
+}
@@ -0,0 +1,593 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::Ident;
+
+use crate::{
+    isa::{
+        DataType, DataTypeEnumVariant, DataTypeKind, DataTypeStruct, DataTypes, Format,
+        FormatFragment, FormatParams, Isa, Opcode, Opcodes,
+    },
+    util::str::snake_to_pascal_case,
+};
+
+// The assembler walks each format tree the same way as the display functions, but over a list of
+// parse states. A state holds the fields parsed so far (each an `Option`) and the remaining text.
+// Every fragment maps each state to zero or more new states, and the complete values are verified
+// by displaying them and comparing the result against the input text.
+
+impl Opcodes {
+    pub fn asm_fn_tokens(&self, isa: &Isa) -> TokenStream {
+        let parsers = self.iter().map(|opcode| {
+            let cfg = opcode.cfg_attribute_tokens(isa);
+            let asm_fn_ident = opcode.asm_fn_ident();
+            quote!(#cfg #asm_fn_ident)
+        });
+        quote! {
+            pub(crate) fn parse_asm_ins(
+                text: &str,
+                pc: u32,
+                mode: ParseMode,
+                options: &Options,
+            ) -> Result<Ins, AsmError> {
+                let parsers: &[fn(&str, u32, ParseMode, &Options) -> Result<Ins, AsmError>] =
+                    &[#(#parsers),*];
+                let mut error = AsmError::UnknownMnemonic;
+                let mut candidates = AsmCandidates::new(pc, mode, options);
+                for parse in parsers {
+                    match parse(text, pc, mode, options) {
+                        Ok(ins) => {
+                            if candidates.offer(ins) {
+                                break;
+                            }
+                        }
+                        Err(AsmError::UnknownMnemonic) => {}
+                        Err(e) => error = e,
+                    }
+                }
+                candidates.into_best().ok_or(error)
+            }
+        }
+    }
+
+    pub fn asm_fns_tokens(&self, isa: &Isa) -> TokenStream {
+        let asm_fns = self.iter().map(|o| o.asm_fn_tokens(isa));
+        quote!(#(#asm_fns)*)
+    }
+}
+
+impl Opcode {
+    fn asm_fn_ident(&self) -> Ident {
+        let name = format!("asm_{}", self.mnemonic());
+        Ident::new(&name, Span::call_site())
+    }
+
+    fn asm_fn_tokens(&self, isa: &Isa) -> TokenStream {
+        let params = self.format_params(isa);
+        let fields: Vec<(Ident, &DataType)> = self
+            .params()
+            .keys()
+            .map(|name| (name.as_ident(), params.get(&name.0).unwrap()))
+            .collect();
+        let fields_struct = fields_struct_tokens(isa, &fields);
+        let fields_init = fields_init_tokens(&fields);
+
+        let opcode_stmts = self.format().opcode().asm_stmts_tokens(isa, &params);
+        let params_stmts = if self.format().params().is_empty() {
+            quote!()
+        } else {
+            let params_stmts = self.format().params().asm_stmts_tokens(isa, &params);
+            quote! {
+                let states: Vec<_> = states.into_iter().map(|(fields, s)| (fields, asm_space(s))).collect();
+                #params_stmts
+            }
+        };
+
+        let variant_ident = Ident::new(&snake_to_pascal_case(self.mnemonic()), Span::call_site());
+        let field_idents = fields.iter().map(|(name_ident, _)| name_ident);
+        let build = build_loops_tokens(
+            isa,
+            &fields,
+            quote! {
+                let ins = Ins::#variant_ident { #(#field_idents),* };
+                if asm_matches(text, &ins, options) && candidates.offer(ins) {
+                    return Ok(candidates.into_best().unwrap());
+                }
+            },
+        );
+
+        let asm_fn_ident = self.asm_fn_ident();
+        let cfg = self.cfg_attribute_tokens(isa);
+        quote! {
+            #cfg
+            fn #asm_fn_ident(
+                text: &str,
+                pc: u32,
+                parse_mode: ParseMode,
+                options: &Options,
+            ) -> Result<Ins, AsmError> {
+                #fields_struct
+                let states = vec![(#fields_init, text)];
+                #opcode_stmts
+                let states: Vec<_> = states.into_iter().filter(|(_, s)| asm_mnemonic_end(s)).collect();
+                if states.is_empty() {
+                    return Err(AsmError::UnknownMnemonic);
+                }
+                #params_stmts
+                // Prefer values which can be encoded in `parse_mode`, as the text may omit fields
+                // which the encoding constrains, and ARM and Thumb instructions can display
+                // identically
+                let mut candidates = AsmCandidates::new(pc, parse_mode, options);
+                for (fields, rest) in states {
+                    if !rest.trim_start().is_empty() {
+                        continue;
+                    }
+                    #build
+                }
+                candidates.into_best().ok_or(AsmError::InvalidOperands)
+            }
+        }
+    }
+}
+
+impl DataTypes {
+    pub fn asm_impls_tokens(&self, isa: &Isa) -> TokenStream {
+        let impls = self.iter().filter_map(|t| t.asm_impl_tokens(isa));
+        quote!(#(#impls)*)
+    }
+}
+
+impl DataType {
+    fn asm_impl_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+        let body = match self.kind() {
+            DataTypeKind::Enum(data_type_enum) => {
+                let variants = data_type_enum.variants().iter().map(|v| v.asm_stmts_tokens(isa));
+                quote!(#(#variants)*)
+            }
+            DataTypeKind::Union(data_type_union) => {
+                let variants = data_type_union.variants().values().map(|v| v.asm_stmts_tokens(isa));
+                quote!(#(#variants)*)
+            }
+            DataTypeKind::Struct(data_type_struct) => {
+                data_type_struct.asm_stmts_tokens(isa, quote!(Self))
+            }
+            _ => return None,
+        };
+        let name_ident = self.name().as_pascal_ident();
+        let cfg = self.cfg_attribute_tokens(isa);
+        Some(quote! {
+            #cfg
+            impl #name_ident {
+                /// Parses all possible values at the start of `s`, along with the text following
+                /// each value.
+                pub(crate) fn parse_asm<'a>(s: &'a str, options: &Options) -> Vec<(Self, &'a str)> {
+                    let mut values = Vec::new();
+                    #body
+                    values
+                }
+            }
+        })
+    }
+
+    /// Generates an expression which evaluates to a `Vec` of all possible values of this type at
+    /// the start of `s`, along with the remaining text.
+    fn asm_candidates_tokens(&self, isa: &Isa) -> TokenStream {
+        let data_type = self.canonical(isa);
+        match data_type.kind() {
+            DataTypeKind::Bool { format, .. } => {
+                let format = format.clone().unwrap_or_else(|| text_format(&data_type.name().0));
+                let stmts = format.asm_stmts_tokens(isa, &HashMap::new());
+                quote! {{
+                    let states = vec![((), s)];
+                    #stmts
+                    let mut values = vec![(false, s)];
+                    values.extend(states.into_iter().map(|((), s)| (true, s)));
+                    values
+                }}
+            }
+            DataTypeKind::UInt(_) => quote!(Vec::from_iter(asm_uimm(s))),
+            DataTypeKind::Int(_) => quote!(Vec::from_iter(asm_simm(s))),
+            DataTypeKind::Enum(_)
+            | DataTypeKind::Union(_)
+            | DataTypeKind::Struct(_)
+            | DataTypeKind::Custom(_) => {
+                let name_ident = data_type.name().as_pascal_ident();
+                quote!(#name_ident::parse_asm(s, options))
+            }
+            DataTypeKind::Type(_, _) => unreachable!(),
+        }
+    }
+
+    /// Generates a slice of values to try for a field which was not parsed, because the format
+    /// omits it.
+    fn asm_missing_tokens(&self, isa: &Isa) -> TokenStream {
+        let data_type = self.canonical(isa);
+        match (self.kind(), data_type.kind()) {
+            (_, DataTypeKind::Bool { .. }) => quote!(&[false, true]),
+            (DataTypeKind::Type(_, expr), DataTypeKind::UInt(_) | DataTypeKind::Int(_))
+                if expr.value_mask() == Some(1) =>
+            {
+                quote!(&[0, 1])
+            }
+            (_, DataTypeKind::Custom(_)) => quote!(&[]),
+            (_, DataTypeKind::Enum(data_type_enum))
+                if data_type.default_expr_tokens(isa).is_none() =>
+            {
+                let name_ident = data_type.name().as_pascal_ident();
+                let variants = data_type_enum.variants().iter().map(|v| v.name().as_pascal_ident());
+                quote!(&[#(#name_ident::#variants),*])
+            }
+            _ => match data_type.default_expr_tokens(isa) {
+                Some(default) => quote!(&[#default]),
+                None => quote!(&[]),
+            },
+        }
+    }
+}
+
+impl DataTypeEnumVariant {
+    fn asm_stmts_tokens(&self, isa: &Isa) -> TokenStream {
+        let variant_ident = self.name().as_pascal_ident();
+        match (self.format(), self.data()) {
+            (Some(format), data) => {
+                let mut params: FormatParams = HashMap::new();
+                let fields: Vec<(Ident, &DataType)> =
+                    data.into_iter().map(|data| (data.name().as_ident(), data)).collect();
+                let value = if let Some(data) = data {
+                    if let DataTypeKind::Struct(_) = data.kind() {
+                        panic!("Variant '{}' with struct data can't have a format", self.name());
+                    }
+                    params.insert(data.name().0.clone(), data.clone());
+                    let data_ident = data.name().as_ident();
+                    quote!(Self::#variant_ident(#data_ident))
+                } else {
+                    quote!(Self::#variant_ident)
+                };
+                format_values_tokens(isa, format, &params, &fields, value)
+            }
+            (None, Some(data)) => {
+                if let DataTypeKind::Struct(data_type_struct) = data.kind() {
+                    data_type_struct.asm_stmts_tokens(isa, quote!(Self::#variant_ident))
+                } else {
+                    let candidates = data.asm_candidates_tokens(isa);
+                    quote! {
+                        values.extend(#candidates.into_iter().map(|(value, s)| (Self::#variant_ident(value), s)));
+                    }
+                }
+            }
+            (None, None) => {
+                let text = &self.name().0;
+                quote! {
+                    if let Some(s) = asm_text(s, #text) {
+                        values.push((Self::#variant_ident, s));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl DataTypeStruct {
+    fn asm_stmts_tokens(&self, isa: &Isa, path: TokenStream) -> TokenStream {
+        let mut params: FormatParams = HashMap::new();
+        for field in self.fields() {
+            params.insert(field.name().0.clone(), field.clone());
+        }
+        let fields: Vec<(Ident, &DataType)> =
+            self.fields().iter().map(|field| (field.name().as_ident(), field)).collect();
+        let field_idents = fields.iter().map(|(name_ident, _)| name_ident);
+        let value = quote!(#path { #(#field_idents),* });
+        format_values_tokens(isa, self.format(), &params, &fields, value)
+    }
+}
+
+impl Format {
+    /// Generates statements which replace `states` with the states following this format.
+    fn asm_stmts_tokens(&self, isa: &Isa, params: &FormatParams) -> TokenStream {
+        match self {
+            Format::If(if_format) => {
+                let cond = if_format.cond().expr();
+                let if_true = if_format.if_true().asm_stmts_tokens(isa, params);
+                let if_false = if_format.if_false().asm_stmts_tokens(isa, params);
+                if !references_fields(cond) {
+                    let condition = if_format.cond().as_tokens(Some(quote!(options)));
+                    return quote! {
+                        let states = if #condition {
+                            #if_true
+                            states
+                        } else {
+                            #if_false
+                            states
+                        };
+                    };
+                }
+
+                // The condition depends on fields which may not be parsed yet, so try both
+                // branches and assume the condition to be true or false in each
+                let branch = |stmts: TokenStream, truth: bool| {
+                    let assume = assume_tokens(cond, truth);
+                    if assume.is_empty() {
+                        quote! {{
+                            #stmts
+                            states
+                        }}
+                    } else if stmts.is_empty() {
+                        quote! {{
+                            let mut states = states;
+                            states.retain_mut(|(fields, _)| { #assume true });
+                            states
+                        }}
+                    } else {
+                        quote! {{
+                            let mut states = states;
+                            states.retain_mut(|(fields, _)| { #assume true });
+                            #stmts
+                            let mut states = states;
+                            states.retain_mut(|(fields, _)| { #assume true });
+                            states
+                        }}
+                    }
+                };
+                let if_true = branch(if_true, true);
+                let if_false = branch(if_false, false);
+                quote! {
+                    let states = {
+                        let if_true = {
+                            let states = states.clone();
+                            #if_true
+                        };
+                        let if_false = #if_false;
+                        if_true.into_iter().chain(if_false).collect::<Vec<_>>()
+                    };
+                }
+            }
+            Format::Match(match_format) => {
+                let value = match_format.value().as_tokens(Some(quote!(options)));
+                let enum_ident =
+                    Ident::new(&snake_to_pascal_case(match_format.option()), Span::call_site());
+                let cases = match_format.cases().iter().map(|(variant_name, format)| {
+                    let variant_ident =
+                        Ident::new(&snake_to_pascal_case(variant_name), Span::call_site());
+                    let stmts = format.asm_stmts_tokens(isa, params);
+                    quote! {
+                        #enum_ident::#variant_ident => {
+                            #stmts
+                            states
+                        }
+                    }
+                });
+                quote! {
+                    let states = match #value {
+                        #(#cases),*
+                    };
+                }
+            }
+            Format::Fragments(fragments_format) => {
+                let fragments =
+                    fragments_format.fragments().iter().map(|f| f.asm_stmts_tokens(isa, params));
+                quote!(#(#fragments)*)
+            }
+            Format::Sequence(formats) => {
+                let formats = formats.iter().map(|f| f.asm_stmts_tokens(isa, params));
+                quote!(#(#formats)*)
+            }
+        }
+    }
+}
+
+impl FormatFragment {
+    fn asm_stmts_tokens(&self, isa: &Isa, params: &FormatParams) -> TokenStream {
+        match self {
+            FormatFragment::Text(text) => quote! {
+                let states: Vec<_> = states
+                    .into_iter()
+                    .filter_map(|(fields, s)| Some((fields, asm_text(s, #text)?)))
+                    .collect();
+            },
+            FormatFragment::Space => quote! {
+                let states: Vec<_> = states.into_iter().map(|(fields, s)| (fields, asm_space(s))).collect();
+            },
+            FormatFragment::Separator => quote! {
+                let states: Vec<_> = states
+                    .into_iter()
+                    .filter_map(|(fields, s)| Some((fields, asm_separator(s)?)))
+                    .collect();
+            },
+            FormatFragment::Param(param_name) => {
+                let Some(param) = params.get(param_name) else {
+                    panic!("Parameter {param_name} in format does not exist");
+                };
+                let param_ident = Ident::new(param_name, Span::call_site());
+                let candidates = param.asm_candidates_tokens(isa);
+                quote! {
+                    let states: Vec<_> = states
+                        .into_iter()
+                        .flat_map(|(fields, s)| {
+                            #candidates.into_iter().filter_map(move |(value, s)| {
+                                let mut fields = fields;
+                                asm_assign(&mut fields.#param_ident, value).then_some((fields, s))
+                            })
+                        })
+                        .collect();
+                }
+            }
+        }
+    }
+}
+
+fn text_format(text: &str) -> Format {
+    Format::Fragments(text.parse().unwrap())
+}
+
+fn fields_struct_tokens(isa: &Isa, fields: &[(Ident, &DataType)]) -> TokenStream {
+    let fields = fields.iter().map(|(name_ident, data_type)| {
+        let type_tokens = data_type.type_tokens(isa);
+        quote!(#name_ident: Option<#type_tokens>)
+    });
+    quote! {
+        #[derive(Clone, Copy)]
+        struct Fields { #(#fields),* }
+    }
+}
+
+fn fields_init_tokens(fields: &[(Ident, &DataType)]) -> TokenStream {
+    let fields = fields.iter().map(|(name_ident, _)| name_ident);
+    quote!(Fields { #(#fields: None),* })
+}
+
+/// Generates nested loops over every field, trying each missing field with all of its candidate
+/// values.
+fn build_loops_tokens(isa: &Isa, fields: &[(Ident, &DataType)], body: TokenStream) -> TokenStream {
+    fields.iter().rev().fold(body, |body, (name_ident, data_type)| {
+        let missing = data_type.asm_missing_tokens(isa);
+        quote! {
+            for #name_ident in asm_field(fields.#name_ident, #missing) {
+                #body
+            }
+        }
+    })
+}
+
+/// Generates statements which parse `format` at the start of `s` and push `value` to `values`
+/// for each complete parse.
+fn format_values_tokens(
+    isa: &Isa,
+    format: &Format,
+    params: &FormatParams,
+    fields: &[(Ident, &DataType)],
+    value: TokenStream,
+) -> TokenStream {
+    let fields_struct = fields_struct_tokens(isa, fields);
+    let fields_init = fields_init_tokens(fields);
+    let stmts = format.asm_stmts_tokens(isa, params);
+    let build = build_loops_tokens(isa, fields, quote!(values.push((#value, rest));));
+    quote! {
+        {
+            #fields_struct
+            let states = vec![(#fields_init, s)];
+            #stmts
+            for (fields, rest) in states {
+                #build
+            }
+        }
+    }
+}
+
+fn references_fields(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Paren(paren) => references_fields(&paren.expr),
+        syn::Expr::Unary(unary) => references_fields(&unary.expr),
+        syn::Expr::Binary(binary) => {
+            references_fields(&binary.left) || references_fields(&binary.right)
+        }
+        syn::Expr::Field(field) => references_fields(&field.base),
+        syn::Expr::MethodCall(call) => references_fields(&call.receiver),
+        syn::Expr::Call(call) => call_name(call) == "field",
+        _ => false,
+    }
+}
+
+fn call_name(call: &syn::ExprCall) -> String {
+    call.func.to_token_stream().to_string()
+}
+
+/// Returns the field name if `expr` is `field(name)`.
+fn field_ident(expr: &syn::Expr) -> Option<&syn::Expr> {
+    match expr {
+        syn::Expr::Paren(paren) => field_ident(&paren.expr),
+        syn::Expr::Call(call) if call_name(call) == "field" => call.args.first(),
+        _ => None,
+    }
+}
+
+/// Returns the tokens of a constant in a format condition.
+fn const_tokens(expr: &syn::Expr) -> Option<TokenStream> {
+    match expr {
+        syn::Expr::Paren(paren) => const_tokens(&paren.expr),
+        syn::Expr::Lit(lit) => Some(lit.to_token_stream()),
+        syn::Expr::Call(call) if call_name(call) == "enum_variant" => {
+            let type_name = call.args[0].to_token_stream().to_string();
+            let variant_name = call.args[1].to_token_stream().to_string();
+            let type_ident = Ident::new(&snake_to_pascal_case(&type_name), Span::call_site());
+            let variant_ident = Ident::new(&snake_to_pascal_case(&variant_name), Span::call_site());
+            Some(quote!(#type_ident::#variant_ident))
+        }
+        _ => None,
+    }
+}
+
+/// Generates statements which assign fields such that the condition `expr` evaluates to `truth`,
+/// or return `false` if the fields or options contradict it. Conditions which can't be satisfied
+/// by assignment are left to the final verification.
+fn assume_tokens(expr: &syn::Expr, truth: bool) -> TokenStream {
+    match expr {
+        syn::Expr::Paren(paren) => assume_tokens(&paren.expr, truth),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Not(_), expr, .. }) => {
+            assume_tokens(expr, !truth)
+        }
+        syn::Expr::Call(call) => match call_name(call).as_str() {
+            "option" => {
+                let option = &call.args[0];
+                let not = truth.then(|| quote!(!));
+                quote! {
+                    if #not options.#option {
+                        return false;
+                    }
+                }
+            }
+            "field" => {
+                let field = &call.args[0];
+                quote! {
+                    if !asm_assign(&mut fields.#field, #truth) {
+                        return false;
+                    }
+                }
+            }
+            _ => quote!(),
+        },
+        syn::Expr::Binary(binary) => match binary.op {
+            syn::BinOp::And(_) if truth => {
+                let left = assume_tokens(&binary.left, truth);
+                let right = assume_tokens(&binary.right, truth);
+                quote!(#left #right)
+            }
+            syn::BinOp::Or(_) if !truth => {
+                let left = assume_tokens(&binary.left, truth);
+                let right = assume_tokens(&binary.right, truth);
+                quote!(#left #right)
+            }
+            syn::BinOp::Eq(_) if truth => assume_eq_tokens(&binary.left, &binary.right),
+            syn::BinOp::Ne(_) if !truth => assume_eq_tokens(&binary.left, &binary.right),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    }
+}
+
+fn assume_eq_tokens(left: &syn::Expr, right: &syn::Expr) -> TokenStream {
+    match (field_ident(left), field_ident(right)) {
+        (Some(left), Some(right)) => quote! {
+            match (fields.#left, fields.#right) {
+                (Some(left), Some(right)) => {
+                    if left != right {
+                        return false;
+                    }
+                }
+                (Some(left), None) => fields.#right = Some(left),
+                (None, Some(right)) => fields.#left = Some(right),
+                (None, None) => {}
+            }
+        },
+        (Some(field), None) => match const_tokens(right) {
+            Some(value) => quote! {
+                if !asm_assign(&mut fields.#field, #value) {
+                    return false;
+                }
+            },
+            None => quote!(),
+        },
+        (None, Some(_)) => assume_eq_tokens(right, left),
+        (None, None) => quote!(),
+    }
+}
@@ -20,7 +20,7 @@ pub fn cfg_condition_tokens(
             let feature = extension_features.next().unwrap();
             Some(feature)
         }
-        _ => Some(quote!(all(#(#extension_features),*))),
+        _ => Some(quote!(any(#(#extension_features),*))),
     };
 
     let version_features = if isa.versions().has_all(versions, arch) {
@@ -10,7 +10,8 @@ use syn::{Ident, visit_mut::VisitMut};
 use crate::{
     isa::{
         Arch, BitRange, DefsUses, Format, FormatParams, IllegalChecks, Isa, IsaExtension,
-        IsaVersionSet, OpcodeParamValue, Pattern, SynExpr, cfg_attribute_tokens,
+        IsaVersionSet, OpcodeParamValue, Pattern, SynExpr, cfg_attribute_tokens, legal_expr_tokens,
+        merge_extensions,
     },
     util::{hex_literal::HexLiteral, str::snake_to_pascal_case},
 };
@@ -85,6 +86,42 @@ impl DataTypes {
             }
         }
     }
+
+    /// Implements `FormatIns` for `SymbolFormatter` by forwarding to the wrapped formatter, except
+    /// for branch targets which are resolved to symbols.
+    pub fn symbol_formatter_impl_tokens(&self, isa: &Isa) -> TokenStream {
+        let forward_fns = self
+            .0
+            .iter()
+            .filter(|dt| !matches!(dt.name.0.as_str(), "branch_target" | "blx_target"))
+            .filter_map(|dt| dt.forward_write_fn_tokens(isa));
+
+        quote! {
+            impl<F, R> FormatIns for SymbolFormatter<'_, F, R>
+            where
+                F: FormatIns,
+                R: SymbolResolver + ?Sized,
+            {
+                fn options(&self) -> &Options {
+                    self.formatter.options()
+                }
+
+                fn write_space(&mut self) -> core::fmt::Result {
+                    self.formatter.write_space()
+                }
+
+                fn write_separator(&mut self) -> core::fmt::Result {
+                    self.formatter.write_separator()
+                }
+
+                #(#forward_fns)*
+
+                fn write_branch_target(&mut self, branch_target: BranchTarget) -> core::fmt::Result {
+                    self.write_symbol(branch_target.addr)
+                }
+            }
+        }
+    }
 }
 
 #[derive(Deserialize, Debug, PartialEq, Eq, Hash, Clone)]
@@ -106,6 +143,13 @@ impl Display for DataTypeName {
     }
 }
 
+/// Function which is generated for unions and structs
+#[derive(Clone, Copy)]
+pub enum DataTypeFn {
+    Parse,
+    Encode,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct DataType {
@@ -159,6 +203,14 @@ impl DataType {
         &self.kind
     }
 
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn no_parse(&self) -> bool {
+        self.no_parse
+    }
+
     pub fn validate(&self, isa: &Isa) -> Result<()> {
         match &self.kind {
             DataTypeKind::Bool { .. } => Ok(()),
@@ -230,7 +282,12 @@ impl DataType {
             quote!(Self)
         };
 
-        let cfg = self.cfg_attribute_tokens(isa);
+        let cfg = match &self.kind {
+            DataTypeKind::Union(_) | DataTypeKind::Struct(_) => {
+                self.callers_cfg_attribute_tokens(isa, DataTypeFn::Parse)?
+            }
+            _ => self.cfg_attribute_tokens(isa),
+        };
 
         Some(quote! {
             #cfg
@@ -338,7 +395,7 @@ impl DataType {
             DataTypeKind::Type(data_type_name, _) => {
                 isa.types().get(data_type_name).unwrap().can_be_illegal(isa)
             }
-            DataTypeKind::Custom(_) => false,
+            DataTypeKind::Custom(data_type_custom) => data_type_custom.can_be_illegal,
         }
     }
 
@@ -518,7 +575,7 @@ impl DataType {
         }
     }
 
-    fn cfg_attribute_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+    pub fn cfg_attribute_tokens(&self, isa: &Isa) -> Option<TokenStream> {
         let arm_versions = self.versions(isa, Arch::Arm);
         let thumb_versions = self.versions(isa, Arch::Thumb);
         let arm_extensions = self.extensions(isa, Arch::Arm);
@@ -532,6 +589,103 @@ impl DataType {
         )
     }
 
+    /// Returns the cfg attribute of the encodings which call `function` of this type, directly or
+    /// through other types, or `None` if it is never called. Unions and structs are only parsed
+    /// field by field in some encodings, so their functions may be unused where the type is used.
+    pub fn callers_cfg_attribute_tokens(
+        &self,
+        isa: &Isa,
+        function: DataTypeFn,
+    ) -> Option<Option<TokenStream>> {
+        let mut arm_versions = IsaVersionSet::new();
+        let mut thumb_versions = IsaVersionSet::new();
+        let mut arm_extensions = None;
+        let mut thumb_extensions = None;
+        self.caller_versions(isa, Arch::Arm, function, &mut arm_versions, &mut arm_extensions);
+        self.caller_versions(
+            isa,
+            Arch::Thumb,
+            function,
+            &mut thumb_versions,
+            &mut thumb_extensions,
+        );
+        if arm_versions.0.is_empty() && thumb_versions.0.is_empty() {
+            return None;
+        }
+        Some(cfg_attribute_tokens(
+            &arm_versions,
+            &arm_extensions.unwrap_or_default(),
+            &thumb_versions,
+            &thumb_extensions.unwrap_or_default(),
+            isa,
+        ))
+    }
+
+    fn caller_versions(
+        &self,
+        isa: &Isa,
+        arch: Arch,
+        function: DataTypeFn,
+        versions: &mut IsaVersionSet,
+        extensions: &mut Option<IndexSet<IsaExtension>>,
+    ) {
+        for opcode in isa.opcodes().iter() {
+            let encodings = match arch {
+                Arch::Arm => opcode.arm_encodings(),
+                Arch::Thumb => opcode.thumb_encodings(),
+            };
+            for encoding in encodings {
+                let calls = match function {
+                    DataTypeFn::Parse => encoding.calls_parse(opcode, isa, self),
+                    DataTypeFn::Encode => encoding.calls_encode(opcode, isa, self),
+                };
+                if calls {
+                    versions.0.extend(encoding.versions(isa).0);
+                    merge_extensions(extensions, encoding.extensions(isa));
+                }
+            }
+        }
+        for data_type in isa.types().iter() {
+            let calls = match function {
+                DataTypeFn::Parse => data_type.parse_impl_calls_parse(isa, self),
+                DataTypeFn::Encode => data_type.encode_impl_calls_encode(isa, self),
+            };
+            if calls {
+                data_type.caller_versions(isa, arch, function, versions, extensions);
+            }
+        }
+    }
+
+    /// Returns whether [`DataType::parse_expr_tokens`] calls the `parse` function of `callee`.
+    pub fn parse_expr_calls_parse(&self, isa: &Isa, callee: &DataType) -> bool {
+        match &self.kind {
+            DataTypeKind::Enum(_) | DataTypeKind::Union(_) | DataTypeKind::Struct(_) => {
+                self.name == callee.name
+            }
+            DataTypeKind::Type(data_type_name, _) => {
+                isa.types().get(data_type_name).unwrap().parse_expr_calls_parse(isa, callee)
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_impl_calls_parse(&self, isa: &Isa, callee: &DataType) -> bool {
+        if self.no_parse {
+            return false;
+        }
+        match &self.kind {
+            DataTypeKind::Union(data_type_union) => data_type_union
+                .variants
+                .values()
+                .any(|variant| variant.parse_expr_calls_parse(isa, callee)),
+            DataTypeKind::Struct(data_type_struct) => data_type_struct
+                .fields
+                .iter()
+                .any(|field| field.parse_expr_calls_parse(isa, callee)),
+            _ => false,
+        }
+    }
+
     fn trait_write_fn_tokens(&self, isa: &Isa) -> Option<TokenStream> {
         if !self.write {
             return None;
@@ -556,6 +710,24 @@ impl DataType {
         })
     }
 
+    fn forward_write_fn_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+        if !self.write {
+            return None;
+        }
+
+        let fn_ident = self.trait_write_fn_ident();
+        let value = self.name.as_ident();
+        let type_tokens = self.type_tokens(isa);
+        let cfg = self.cfg_attribute_tokens(isa);
+
+        Some(quote! {
+            #cfg
+            fn #fn_ident(&mut self, #value: #type_tokens) -> core::fmt::Result {
+                self.formatter.#fn_ident(#value)
+            }
+        })
+    }
+
     fn versions(&self, isa: &Isa, arch: Arch) -> IsaVersionSet {
         let mut versions = IsaVersionSet::new();
         for opcode in isa.opcodes().iter() {
@@ -583,23 +755,13 @@ impl DataType {
             if !opcode.uses_data_type(self) {
                 continue;
             }
-            let exts = opcode.extensions(isa, arch);
-            if let Some(extensions) = &mut extensions {
-                *extensions = extensions.intersection(&exts).cloned().collect();
-            } else {
-                extensions = Some(exts);
-            }
+            merge_extensions(&mut extensions, opcode.extensions(isa, arch));
         }
         for data_type in isa.types().iter() {
             if !data_type.uses_data_type(self) {
                 continue;
             }
-            let exts = data_type.extensions(isa, arch);
-            if let Some(extensions) = &mut extensions {
-                *extensions = extensions.intersection(&exts).cloned().collect();
-            } else {
-                extensions = Some(exts);
-            }
+            merge_extensions(&mut extensions, data_type.extensions(isa, arch));
         }
         extensions.unwrap_or_default()
     }
@@ -647,10 +809,18 @@ pub struct DataTypeEnum {
 }
 
 impl DataTypeEnum {
+    pub fn bits(&self) -> &BitRange {
+        &self.bits
+    }
+
     pub fn get_variant(&self, name: &DataTypeEnumVariantName) -> Option<&DataTypeEnumVariant> {
         self.variants.iter().find(|v| v.name() == name)
     }
 
+    pub fn variants(&self) -> &[DataTypeEnumVariant] {
+        &self.variants
+    }
+
     pub fn validate(&self, name: &DataTypeName) -> Result<()> {
         if let Some(default) = &self.default {
             self.get_variant(default).ok_or_else(|| {
@@ -687,6 +857,7 @@ impl DataTypeEnum {
         quote! {
             #[repr(#repr_type)]
             #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum #name_ident {
                 #(#variants),*
             }
@@ -750,6 +921,10 @@ pub struct DataTypeUnion {
 }
 
 impl DataTypeUnion {
+    pub fn bits(&self) -> &BitRange {
+        &self.bits
+    }
+
     pub fn get_variant(
         &self,
         name: &DataTypeEnumVariantName,
@@ -771,6 +946,7 @@ impl DataTypeUnion {
         let variants = self.variants.values().map(|v| v.variant_tokens(isa));
         quote! {
             #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum #name_ident {
                 #(#variants),*
             }
@@ -952,6 +1128,19 @@ impl DataTypeEnumVariant {
         }
     }
 
+    /// Returns whether [`DataTypeEnumVariant::parse_expr_tokens`] calls the `parse` function of
+    /// `callee`.
+    fn parse_expr_calls_parse(&self, isa: &Isa, callee: &DataType) -> bool {
+        match &self.data {
+            Some(DataType { kind: DataTypeKind::Struct(data_type_struct), .. }) => data_type_struct
+                .fields
+                .iter()
+                .any(|field| field.parse_expr_calls_parse(isa, callee)),
+            Some(data) => data.parse_expr_calls_parse(isa, callee),
+            None => false,
+        }
+    }
+
     fn default_expr_tokens(&self, isa: &Isa) -> Option<TokenStream> {
         let variant_ident = self.name.as_pascal_ident();
         if let Some(data) = &self.data {
@@ -972,6 +1161,7 @@ impl DataTypeEnumVariant {
         isa: &Isa,
         enum_name: &DataTypeName,
         value: &OpcodeParamValue,
+        illegal_value: &TokenStream,
     ) -> TokenStream {
         let enum_ident = enum_name.as_pascal_ident();
         let variant_ident = self.name.as_pascal_ident();
@@ -981,8 +1171,12 @@ impl DataTypeEnumVariant {
                 if let DataTypeKind::Struct(data_type_struct) = &canonical_data.kind
                     && let OpcodeParamValue::Struct(struct_params) = value
                 {
-                    let record =
-                        data_type_struct.param_record_tokens(isa, &data.name, struct_params);
+                    let record = data_type_struct.param_record_tokens(
+                        isa,
+                        &data.name,
+                        struct_params,
+                        illegal_value,
+                    );
                     let struct_ident = canonical_data.name.as_pascal_ident();
                     return quote!(#enum_ident::#variant_ident(#struct_ident #record));
                 };
@@ -996,8 +1190,12 @@ impl DataTypeEnumVariant {
                             self.name.0, enum_name.0
                         );
                     };
-                    let record =
-                        data_type_struct.param_record_tokens(isa, &data.name, struct_params);
+                    let record = data_type_struct.param_record_tokens(
+                        isa,
+                        &data.name,
+                        struct_params,
+                        illegal_value,
+                    );
                     quote!(#enum_ident::#variant_ident #record)
                 }
                 _ => {
@@ -1015,7 +1213,10 @@ impl DataTypeEnumVariant {
                         }
                         OpcodeParamValue::Struct(_) => panic!(),
                     };
-                    let parse_expr = data.parse_expr_tokens(isa, Some(value_tokens));
+                    let mut parse_expr = data.parse_expr_tokens(isa, Some(value_tokens));
+                    if data.can_be_illegal(isa) {
+                        parse_expr = legal_expr_tokens(parse_expr, illegal_value);
+                    }
                     quote!(#enum_ident::#variant_ident(#parse_expr))
                 }
             }
@@ -1024,6 +1225,25 @@ impl DataTypeEnumVariant {
         }
     }
 
+    /// Returns whether [`DataTypeEnumVariant::param_expr_tokens`] calls the `parse` function of
+    /// `callee`.
+    pub fn param_calls_parse(
+        &self,
+        isa: &Isa,
+        value: &OpcodeParamValue,
+        callee: &DataType,
+    ) -> bool {
+        let Some(data) = &self.data else {
+            return false;
+        };
+        match (data.canonical(isa).kind(), value) {
+            (DataTypeKind::Struct(data_type_struct), OpcodeParamValue::Struct(struct_params)) => {
+                data_type_struct.param_record_calls_parse(isa, struct_params, callee)
+            }
+            _ => data.parse_expr_calls_parse(isa, callee),
+        }
+    }
+
     pub fn pattern_destructure_tokens(&self) -> TokenStream {
         let variant_ident = self.name.as_pascal_ident();
         if let Some(data) = &self.data {
@@ -1072,6 +1292,10 @@ impl DataTypeEnumVariant {
     pub fn data(&self) -> Option<&DataType> {
         self.data.as_ref()
     }
+
+    pub fn format(&self) -> Option<&Format> {
+        self.format.as_ref()
+    }
 }
 
 #[derive(Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
@@ -1108,6 +1332,7 @@ impl DataTypeStruct {
         let record = self.record_tokens(isa, true);
         quote! {
             #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct #name_ident #record
         }
     }
@@ -1193,12 +1418,13 @@ impl DataTypeStruct {
         isa: &Isa,
         type_name: &DataTypeName,
         params: &IndexMap<String, OpcodeParamValue>,
+        illegal_value: &TokenStream,
     ) -> TokenStream {
         let fields = self.fields.iter().map(|field| {
             let name = &field.name.0;
             let field_ident = field.name.as_ident();
             let param_expr = if let Some(value) = params.get(name) {
-                value.parse_expr_tokens(isa, field)
+                value.parse_legal_expr_tokens(isa, field, illegal_value)
             } else {
                 field.default_expr_tokens(isa).unwrap_or_else(|| {
                     panic!("Field '{}' in struct '{}' has no default value", name, type_name.0)
@@ -1209,14 +1435,28 @@ impl DataTypeStruct {
         quote!({ #(#fields),* })
     }
 
+    /// Returns whether [`DataTypeStruct::param_record_tokens`] calls the `parse` function of
+    /// `callee`.
+    pub fn param_record_calls_parse(
+        &self,
+        isa: &Isa,
+        params: &IndexMap<String, OpcodeParamValue>,
+        callee: &DataType,
+    ) -> bool {
+        self.fields.iter().any(|field| {
+            params.get(&field.name.0).is_some_and(|value| value.calls_parse(isa, field, callee))
+        })
+    }
+
     pub fn param_tokens(
         &self,
         isa: &Isa,
         type_name: &DataTypeName,
         params: &IndexMap<String, OpcodeParamValue>,
+        illegal_value: &TokenStream,
     ) -> TokenStream {
         let type_name_ident = type_name.as_pascal_ident();
-        let record = self.param_record_tokens(isa, type_name, params);
+        let record = self.param_record_tokens(isa, type_name, params, illegal_value);
         quote! {
             #type_name_ident #record
         }
@@ -1250,12 +1490,20 @@ impl DataTypeStruct {
     pub fn fields(&self) -> &[DataType] {
         &self.fields
     }
+
+    pub fn format(&self) -> &Format {
+        &self.format
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct DataTypeCustom {
     inner_types: Vec<DataTypeName>,
+    /// If true, the type's `parse` function returns `None` for values that don't encode a valid
+    /// value.
+    #[serde(default)]
+    can_be_illegal: bool,
 }
 
 impl DataTypeCustom {
@@ -1273,6 +1521,10 @@ impl DataTypeCustom {
 pub struct DataExpr(SynExpr);
 
 impl DataExpr {
+    pub fn expr(&self) -> &syn::Expr {
+        &self.0.0
+    }
+
     pub fn as_tokens(&self, input_ident: Ident) -> TokenStream {
         let mut replace = DataExprReplace { input_ident };
         let mut expr = self.0.0.clone();
@@ -1281,6 +1533,12 @@ impl DataExpr {
     }
 }
 
+impl From<syn::Expr> for DataExpr {
+    fn from(expr: syn::Expr) -> Self {
+        Self(SynExpr(expr))
+    }
+}
+
 struct DataExprReplace {
     input_ident: Ident,
 }
@@ -1327,6 +1585,22 @@ impl VisitMut for DataExprReplace {
                         }
                         *node = syn::parse2(self.input_ident.to_token_stream()).unwrap();
                     }
+                    "extension" => {
+                        if call.args.len() != 1 {
+                            panic!("extension function takes one argument");
+                        }
+                        let arg = &call.args[0];
+                        let name = arg.to_token_stream().to_string();
+                        let ident = Ident::new(&snake_to_pascal_case(&name), Span::call_site());
+                        let result = quote! {
+                            options.extensions.has_any(Extensions::of(&[
+                                #[cfg(feature = #name)] Extension::#ident
+                            ]))
+                        };
+                        *node = syn::parse2(quote!((#result))).unwrap();
+                        // The replacement is plain Rust, so it must not be visited
+                        return;
+                    }
                     _ => panic!("Unknown data expression function {fn_name}"),
                 }
             }
@@ -1348,6 +1622,14 @@ impl VisitMut for DataExprReplace {
                         *node = syn::parse2(quote!((#result))).unwrap();
                     }
 
+                    "thumb_expand_imm" => {
+                        if !call.args.is_empty() {
+                            panic!("thumb_expand_imm function takes zero arguments");
+                        }
+                        let receiver = &call.receiver;
+                        *node = syn::parse2(quote!(thumb_expand_imm(#receiver))).unwrap();
+                    }
+
                     "negate_if" => {
                         if call.args.len() != 1 {
                             panic!("negate_if function takes one argument");
@@ -1368,7 +1650,16 @@ impl VisitMut for DataExprReplace {
                 }
             }
             syn::Expr::Binary(binary) => {
-                let left = &binary.left;
+                let left = match binary.left.as_ref() {
+                    // The left side becomes a method receiver, so it needs parentheses to keep precedence
+                    left @ syn::Expr::Binary(_) => quote!((#left)),
+                    // Untyped literals can't be method receivers
+                    syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) => {
+                        let value = Literal::u32_suffixed(int.base10_parse().unwrap());
+                        quote!(#value)
+                    }
+                    left => quote!(#left),
+                };
                 let right = &binary.right;
                 match binary.op {
                     syn::BinOp::Add(_) => {
@@ -19,6 +19,10 @@ impl DefsUses {
         self.0.is_empty()
     }
 
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
     pub fn defs_exprs_tokens(&self) -> Vec<TokenStream> {
         self.0.iter().map(|def| def.defs_expr_tokens()).collect()
     }
@@ -66,6 +70,12 @@ impl DefUse {
 }
 
 impl Opcodes {
+    /// Returns the most arguments any instruction can push to its defs or uses, which is the size
+    /// of the list in `DefsUses` so that pushing never runs out of space.
+    pub fn max_defs_uses_len(&self, isa: &Isa) -> usize {
+        self.iter().map(|op| op.max_defs_len(isa).max(op.max_uses_len(isa))).max().unwrap_or(0)
+    }
+
     pub fn defs_uses_impl_tokens(&self, isa: &Isa) -> TokenStream {
         let defs_cases = self.iter().filter_map(|op| op.defs_case_tokens(isa));
         let uses_cases = self.iter().filter_map(|op| op.uses_case_tokens(isa));
@@ -116,7 +126,9 @@ impl Opcode {
             })
         });
 
+        let cfg = self.cfg_attribute_tokens(isa);
         Some(quote! {
+            #cfg
             Ins::#variant_ident { #(#param_names),* } => {
                 #(#opcode_defs)*
                 #(#params_defs)*
@@ -140,15 +152,39 @@ impl Opcode {
                 quote!(#param_ident.uses(&mut uses);)
             })
         });
+        let cond_uses = self.has_cond().then(|| quote!(uses.push_cond(*cond);));
 
+        let cfg = self.cfg_attribute_tokens(isa);
         Some(quote! {
+            #cfg
             Ins::#variant_ident { #(#param_names),* } => {
                 #(#opcode_uses)*
                 #(#params_uses)*
+                #cond_uses
             }
         })
     }
 
+    fn max_defs_len(&self, isa: &Isa) -> usize {
+        let params_len: usize = self
+            .params()
+            .values()
+            .map(|type_name| isa.types().get(type_name).unwrap().max_defs_len(isa))
+            .sum();
+        self.defs().len() + params_len
+    }
+
+    fn max_uses_len(&self, isa: &Isa) -> usize {
+        let params_len: usize = self
+            .params()
+            .values()
+            .map(|type_name| isa.types().get(type_name).unwrap().max_uses_len(isa))
+            .sum();
+        // A condition tests at most three flags, such as Z, N and V for GT and LE
+        let cond_len = if self.has_cond() { 3 } else { 0 };
+        self.uses().len() + params_len + cond_len
+    }
+
     fn has_defs(&self, isa: &Isa) -> bool {
         if !self.defs().is_empty() {
             true
@@ -161,7 +197,7 @@ impl Opcode {
     }
 
     fn has_uses(&self, isa: &Isa) -> bool {
-        if !self.uses().is_empty() {
+        if !self.uses().is_empty() || self.has_cond() {
             true
         } else {
             self.params().values().any(|type_name| {
@@ -182,6 +218,44 @@ impl DataTypes {
 }
 
 impl DataType {
+    fn max_defs_len(&self, isa: &Isa) -> usize {
+        match self.kind() {
+            DataTypeKind::Union(data_type_union) => data_type_union
+                .variants()
+                .values()
+                .map(|variant| {
+                    variant.defs().len() + variant.data().map_or(0, |data| data.max_defs_len(isa))
+                })
+                .max()
+                .unwrap_or(0),
+            DataTypeKind::Struct(data_type_struct) => {
+                data_type_struct.defs().len()
+                    + data_type_struct.fields().iter().map(|f| f.max_defs_len(isa)).sum::<usize>()
+            }
+            DataTypeKind::Type(_, _) => self.canonical(isa).max_defs_len(isa),
+            _ => 0,
+        }
+    }
+
+    fn max_uses_len(&self, isa: &Isa) -> usize {
+        match self.kind() {
+            DataTypeKind::Union(data_type_union) => data_type_union
+                .variants()
+                .values()
+                .map(|variant| {
+                    variant.uses().len() + variant.data().map_or(0, |data| data.max_uses_len(isa))
+                })
+                .max()
+                .unwrap_or(0),
+            DataTypeKind::Struct(data_type_struct) => {
+                data_type_struct.uses().len()
+                    + data_type_struct.fields().iter().map(|f| f.max_uses_len(isa)).sum::<usize>()
+            }
+            DataTypeKind::Type(_, _) => self.canonical(isa).max_uses_len(isa),
+            _ => 0,
+        }
+    }
+
     fn has_defs(&self, isa: &Isa) -> bool {
         match self.kind() {
             DataTypeKind::Bool { .. } => false,
@@ -226,11 +300,13 @@ impl DataType {
         });
 
         let type_ident = self.name().as_pascal_ident();
+        let cfg = self.cfg_attribute_tokens(isa);
 
         if defs_fn.is_none() && uses_fn.is_none() {
             None
         } else {
             Some(quote! {
+                #cfg
                 impl #type_ident {
                     #defs_fn
                     #uses_fn
@@ -0,0 +1,895 @@
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::Ident;
+
+use crate::{
+    isa::{
+        Arch, BitRange, DataExpr, DataType, DataTypeEnumVariant, DataTypeFn, DataTypeKind,
+        DataTypeName, DataTypeStruct, DataTypeUnion, DataTypes, IllegalChecks, Isa, Opcode,
+        OpcodeEncoding, OpcodeParamValue, Opcodes,
+    },
+    util::{hex_literal::HexLiteral, str::snake_to_pascal_case},
+};
+
+use indexmap::IndexMap;
+
+impl Opcodes {
+    pub fn encode_fn_tokens(&self, isa: &Isa, arch: Arch) -> TokenStream {
+        let cases = self.iter().filter_map(|opcode| opcode.encode_case_tokens(isa, arch));
+        match arch {
+            Arch::Arm => quote! {
+                /// Encodes this instruction as ARM machine code. The result is verified such that
+                /// [`parse_arm`] with the same `pc` and `options` returns an identical instruction.
+                #[cfg(feature = "arm")]
+                pub fn encode_arm(&self, pc: u32, options: &Options) -> Result<u32, EncodeError> {
+                    let encoders: &[(fn(&Ins, u32) -> Option<u32>, Versions)] = match self {
+                        #(#cases)*
+                        Ins::Word(value) => return Ok(*value),
+                        _ => return Err(EncodeError::NoEncoding),
+                    };
+                    let mut result = Err(EncodeError::NoEncoding);
+                    for (encode, versions) in encoders {
+                        if !versions.has(options.version) {
+                            continue;
+                        }
+                        result = Err(EncodeError::InvalidOperands);
+                        if let Some(code) = encode(self, pc) && parse_arm(code, pc, options) == *self {
+                            return Ok(code);
+                        }
+                    }
+                    result
+                }
+            },
+            Arch::Thumb => quote! {
+                /// Encodes this instruction as Thumb machine code. Returns the code and its size in
+                /// bytes, where 4-byte instructions have their second halfword in the upper half of
+                /// the code. The result is verified such that [`parse_thumb`] with the same `pc` and
+                /// `options` returns an identical instruction.
+                #[cfg(feature = "thumb")]
+                pub fn encode_thumb(
+                    &self,
+                    pc: u32,
+                    options: &Options,
+                ) -> Result<(u32, u32), EncodeError> {
+                    let encoders: &[(fn(&Ins, u32) -> Option<u32>, u32, Versions)] = match self {
+                        #(#cases)*
+                        Ins::Word(value) => return Ok((*value, 4)),
+                        Ins::HalfWord(value) => return Ok((*value as u32, 2)),
+                        _ => return Err(EncodeError::NoEncoding),
+                    };
+                    let mut result = Err(EncodeError::NoEncoding);
+                    for (encode, size, versions) in encoders {
+                        if !versions.has(options.version) {
+                            continue;
+                        }
+                        result = Err(EncodeError::InvalidOperands);
+                        if let Some(code) = encode(self, pc) {
+                            let (ins, ins_size) = parse_thumb(code, pc, options);
+                            if ins == *self && ins_size == *size {
+                                return Ok((code, *size));
+                            }
+                        }
+                    }
+                    result
+                }
+            },
+        }
+    }
+
+    pub fn encode_fns_tokens(&self, isa: &Isa) -> TokenStream {
+        let encode_fns = self.iter().map(|o| o.encode_fns_tokens(isa));
+        quote!(#(#encode_fns)*)
+    }
+}
+
+impl Opcode {
+    pub fn encode_fn_ident(&self, arch: Arch, index: usize) -> Ident {
+        let name = format!("encode_{}_{}_{}", arch, self.mnemonic(), index);
+        Ident::new(&name, Span::call_site())
+    }
+
+    fn encode_fns_tokens(&self, isa: &Isa) -> TokenStream {
+        let arm_encode_fns = self.arm_encodings().iter().enumerate().map(|(i, encoding)| {
+            encoding.encode_fn_tokens(self.encode_fn_ident(Arch::Arm, i), self, isa, Arch::Arm)
+        });
+        let thumb_encode_fns = self.thumb_encodings().iter().enumerate().map(|(i, encoding)| {
+            encoding.encode_fn_tokens(self.encode_fn_ident(Arch::Thumb, i), self, isa, Arch::Thumb)
+        });
+        quote! {
+            #(#arm_encode_fns)*
+            #(#thumb_encode_fns)*
+        }
+    }
+
+    fn encode_case_tokens(&self, isa: &Isa, arch: Arch) -> Option<TokenStream> {
+        let encodings = match arch {
+            Arch::Arm => self.arm_encodings(),
+            Arch::Thumb => self.thumb_encodings(),
+        };
+        if encodings.is_empty() {
+            return None;
+        }
+
+        // Prefer 2-byte Thumb encodings over 4-byte ones
+        let mut encodings: Vec<(usize, &OpcodeEncoding)> = encodings.iter().enumerate().collect();
+        encodings.sort_by_key(|(_, encoding)| encoding.pattern().combined().size());
+
+        let encoders = encodings.iter().map(|(index, encoding)| {
+            let cfg = encoding.cfg_attribute_tokens(isa, arch);
+            let encode_fn_ident = self.encode_fn_ident(arch, *index);
+            let versions = encoding.versions_tokens(isa);
+            match arch {
+                Arch::Arm => quote!(#cfg (#encode_fn_ident, #versions)),
+                Arch::Thumb => {
+                    let size = Literal::u32_unsuffixed(encoding.pattern().combined().size() / 8);
+                    quote!(#cfg (#encode_fn_ident, #size, #versions))
+                }
+            }
+        });
+
+        let variant_ident = Ident::new(&snake_to_pascal_case(self.mnemonic()), Span::call_site());
+        let cfg = self.cfg_attribute_tokens(isa);
+        Some(quote! {
+            #cfg
+            Ins::#variant_ident { .. } => &[#(#encoders),*],
+        })
+    }
+}
+
+impl OpcodeEncoding {
+    fn encode_fn_tokens(
+        &self,
+        fn_ident: Ident,
+        opcode: &Opcode,
+        isa: &Isa,
+        arch: Arch,
+    ) -> TokenStream {
+        let variant_ident = Ident::new(&snake_to_pascal_case(opcode.mnemonic()), Span::call_site());
+        let param_names = opcode.params().keys().map(|k| k.as_ident());
+        // Pattern bits which overlap a plain value are parsed as part of that value, such as the PC
+        // bit in the register list of LDM. Unions, structs and whole instruction values overlap the
+        // opcode bits instead.
+        let param_mask = opcode
+            .params()
+            .iter()
+            .filter_map(|(param_name, type_name)| {
+                let OpcodeParamValue::Bits(bit_range) = self.get_param(param_name)? else {
+                    return None;
+                };
+                if bit_range.0 == (0..32) {
+                    return None;
+                }
+                let data_type = isa.types().get(type_name).unwrap().canonical(isa);
+                match data_type.kind() {
+                    DataTypeKind::Union(_) | DataTypeKind::Struct(_) => None,
+                    _ => Some(bit_range.mask() << bit_range.0.start),
+                }
+            })
+            .fold(0, |acc, mask| acc | mask);
+        let combined = self.pattern().combined();
+        let pattern = (combined.pattern() | combined.should_be_one()) & !param_mask;
+        let pattern = HexLiteral(pattern | self.illegal().required_bits());
+
+        let params = opcode.params().iter().filter_map(|(param_name, type_name)| {
+            let value = self.get_param(param_name)?;
+            let data_type = isa.types().get(type_name).unwrap();
+            let param_ident = param_name.as_ident();
+            let stmts = value.encode_stmts_tokens(isa, data_type, param_ident.to_token_stream());
+            (!stmts.is_empty()).then(|| quote!({ #stmts }))
+        });
+
+        let cfg = self.cfg_attribute_tokens(isa, arch);
+
+        quote! {
+            #cfg
+            fn #fn_ident(ins: &Ins, pc: u32) -> Option<u32> {
+                let Ins::#variant_ident { #(#param_names),* } = ins else {
+                    return None;
+                };
+                let mut value = #pattern;
+                #(#params)*
+                Some(value)
+            }
+        }
+    }
+}
+
+impl OpcodeEncoding {
+    /// Returns whether the encode function of this encoding calls the `encode` function of
+    /// `callee`.
+    pub fn calls_encode(&self, opcode: &Opcode, isa: &Isa, callee: &DataType) -> bool {
+        opcode.params().iter().any(|(param_name, type_name)| {
+            let data_type = isa.types().get(type_name).unwrap();
+            self.get_param(param_name)
+                .is_some_and(|value| value.calls_encode(isa, data_type, callee))
+        })
+    }
+}
+
+impl OpcodeParamValue {
+    /// Generates statements which write `value` (a reference to a value of `data_type`) into the
+    /// instruction bits that this parameter value is parsed from. This is the inverse of
+    /// [`OpcodeParamValue::parse_expr_tokens`].
+    fn encode_stmts_tokens(
+        &self,
+        isa: &Isa,
+        data_type: &DataType,
+        value: TokenStream,
+    ) -> TokenStream {
+        match self {
+            OpcodeParamValue::Bits(bit_range) => {
+                bit_range.encode_tokens(data_type.encode_expr_tokens(isa, value))
+            }
+            OpcodeParamValue::Const(_) => quote!(),
+            OpcodeParamValue::Expr(expr) => {
+                // Expressions which can't be inverted are derived from other parameters, such as
+                // the implicit writeback of Thumb LDM
+                encode_expr_tokens(expr.expr(), data_type.encode_expr_tokens(isa, value))
+                    .unwrap_or_default()
+            }
+            OpcodeParamValue::Enum(variant_name, variant_value) => {
+                let inner_type = data_type.canonical(isa);
+                let variant = match inner_type.kind() {
+                    DataTypeKind::Enum(data_type_enum) => {
+                        data_type_enum.get_variant(variant_name).unwrap()
+                    }
+                    DataTypeKind::Union(data_type_union) => {
+                        data_type_union.get_variant(variant_name).unwrap().1
+                    }
+                    _ => {
+                        panic!("Data type '{}' is not an enum", data_type.name().0);
+                    }
+                };
+                variant.param_encode_stmts_tokens(isa, inner_type.name(), variant_value, value)
+            }
+            OpcodeParamValue::Struct(params) => {
+                let DataTypeKind::Struct(data_type_struct) = data_type.kind() else {
+                    panic!();
+                };
+                data_type_struct.param_encode_stmts_tokens(isa, data_type.name(), params, value)
+            }
+        }
+    }
+}
+
+impl OpcodeParamValue {
+    /// Returns whether [`OpcodeParamValue::encode_stmts_tokens`] calls the `encode` function of
+    /// `callee`.
+    fn calls_encode(&self, isa: &Isa, data_type: &DataType, callee: &DataType) -> bool {
+        match self {
+            OpcodeParamValue::Bits(_) => data_type.encode_expr_calls_encode(isa, callee),
+            OpcodeParamValue::Const(_) => false,
+            OpcodeParamValue::Expr(expr) => {
+                encode_expr_tokens(expr.expr(), quote!()).is_some_and(|stmts| !stmts.is_empty())
+                    && data_type.encode_expr_calls_encode(isa, callee)
+            }
+            OpcodeParamValue::Enum(variant, value) => {
+                OpcodeParamValue::enum_variant(isa, data_type, variant)
+                    .param_encode_calls_encode(isa, value, callee)
+            }
+            OpcodeParamValue::Struct(params) => {
+                let DataTypeKind::Struct(data_type_struct) = data_type.kind() else {
+                    panic!();
+                };
+                data_type_struct.fields_calls_encode(isa, params, callee)
+            }
+        }
+    }
+}
+
+impl DataTypeEnumVariant {
+    /// Inverse of [`DataTypeEnumVariant::param_expr_tokens`]
+    fn param_encode_stmts_tokens(
+        &self,
+        isa: &Isa,
+        enum_name: &DataTypeName,
+        param_value: &OpcodeParamValue,
+        value: TokenStream,
+    ) -> TokenStream {
+        let enum_ident = enum_name.as_pascal_ident();
+        let variant_ident = self.name().as_pascal_ident();
+        let Some(data) = self.data() else {
+            return quote! {
+                let #enum_ident::#variant_ident = #value else {
+                    return None;
+                };
+            };
+        };
+
+        let data_ident = data.name().as_ident();
+        if let DataTypeKind::Type(_, _) = data.kind() {
+            let canonical_data = data.canonical(isa);
+            if let DataTypeKind::Struct(data_type_struct) = canonical_data.kind()
+                && let OpcodeParamValue::Struct(struct_params) = param_value
+            {
+                let struct_stmts = data_type_struct.param_encode_stmts_tokens(
+                    isa,
+                    canonical_data.name(),
+                    struct_params,
+                    data_ident.to_token_stream(),
+                );
+                return quote! {
+                    let #enum_ident::#variant_ident(#data_ident) = #value else {
+                        return None;
+                    };
+                    #struct_stmts
+                };
+            }
+        }
+
+        match data.kind() {
+            DataTypeKind::Struct(data_type_struct) => {
+                let OpcodeParamValue::Struct(struct_params) = param_value else {
+                    panic!(
+                        "Expected struct param for variant '{}' of enum '{}'",
+                        self.name().0,
+                        enum_name.0
+                    );
+                };
+                let field_names =
+                    struct_params.keys().map(|name| Ident::new(name, Span::call_site()));
+                let field_stmts = data_type_struct
+                    .fields_encode_stmts_tokens(isa, struct_params)
+                    .collect::<Vec<_>>();
+                quote! {
+                    let #enum_ident::#variant_ident { #(#field_names,)* .. } = #value else {
+                        return None;
+                    };
+                    #(#field_stmts)*
+                }
+            }
+            _ => {
+                let data_stmts =
+                    param_value.encode_stmts_tokens(isa, data, data_ident.to_token_stream());
+                quote! {
+                    let #enum_ident::#variant_ident(#data_ident) = #value else {
+                        return None;
+                    };
+                    #data_stmts
+                }
+            }
+        }
+    }
+
+    /// Returns whether [`DataTypeEnumVariant::param_encode_stmts_tokens`] calls the `encode`
+    /// function of `callee`.
+    fn param_encode_calls_encode(
+        &self,
+        isa: &Isa,
+        param_value: &OpcodeParamValue,
+        callee: &DataType,
+    ) -> bool {
+        let Some(data) = self.data() else {
+            return false;
+        };
+        match (data.canonical(isa).kind(), param_value) {
+            (DataTypeKind::Struct(data_type_struct), OpcodeParamValue::Struct(struct_params)) => {
+                data_type_struct.fields_calls_encode(isa, struct_params, callee)
+            }
+            _ => param_value.calls_encode(isa, data, callee),
+        }
+    }
+
+    /// Returns whether [`DataTypeEnumVariant::encode_stmts_tokens`] calls the `encode` function of
+    /// `callee`.
+    fn encode_stmts_calls_encode(&self, isa: &Isa, callee: &DataType) -> bool {
+        match self.data() {
+            Some(data) => match data.kind() {
+                DataTypeKind::Struct(data_type_struct) => data_type_struct
+                    .fields()
+                    .iter()
+                    .any(|field| field.encode_expr_calls_encode(isa, callee)),
+                _ => data.encode_expr_calls_encode(isa, callee),
+            },
+            None => false,
+        }
+    }
+
+    fn encode_stmts_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+        let data = self.data()?;
+        if let DataTypeKind::Struct(data_type_struct) = data.kind() {
+            let fields = data_type_struct.fields().iter().map(|field| {
+                let field_ident = field.name().as_ident();
+                field.encode_stmts_tokens(isa, field_ident.to_token_stream())
+            });
+            Some(quote!(#(#fields)*))
+        } else {
+            let data_ident = data.name().as_ident();
+            Some(data.encode_stmts_tokens(isa, data_ident.to_token_stream()))
+        }
+    }
+}
+
+impl DataTypeStruct {
+    fn fields_encode_stmts_tokens<'a>(
+        &'a self,
+        isa: &'a Isa,
+        params: &'a IndexMap<String, OpcodeParamValue>,
+    ) -> impl Iterator<Item = TokenStream> + 'a {
+        self.fields().iter().filter_map(move |field| {
+            let param_value = params.get(&field.name().0)?;
+            let field_ident = field.name().as_ident();
+            Some(param_value.encode_stmts_tokens(isa, field, field_ident.to_token_stream()))
+        })
+    }
+
+    fn fields_calls_encode(
+        &self,
+        isa: &Isa,
+        params: &IndexMap<String, OpcodeParamValue>,
+        callee: &DataType,
+    ) -> bool {
+        self.fields().iter().any(|field| {
+            params.get(&field.name().0).is_some_and(|value| value.calls_encode(isa, field, callee))
+        })
+    }
+
+    /// Inverse of [`DataTypeStruct::param_record_tokens`]
+    fn param_encode_stmts_tokens(
+        &self,
+        isa: &Isa,
+        type_name: &DataTypeName,
+        params: &IndexMap<String, OpcodeParamValue>,
+        value: TokenStream,
+    ) -> TokenStream {
+        let type_ident = type_name.as_pascal_ident();
+        let field_names = params.keys().map(|name| Ident::new(name, Span::call_site()));
+        let field_stmts = self.fields_encode_stmts_tokens(isa, params);
+        quote! {
+            let #type_ident { #(#field_names,)* .. } = #value;
+            #(#field_stmts)*
+        }
+    }
+
+    fn encode_impl_body_tokens(&self, isa: &Isa) -> TokenStream {
+        let field_names = self.fields().iter().map(|f| f.name().as_ident());
+        let field_stmts = self.fields().iter().map(|field| {
+            let field_ident = field.name().as_ident();
+            field.encode_stmts_tokens(isa, field_ident.to_token_stream())
+        });
+        quote! {
+            let Self { #(#field_names),* } = self;
+            let mut value = 0;
+            #(#field_stmts)*
+            Some(value)
+        }
+    }
+}
+
+impl DataTypeUnion {
+    fn encode_impl_body_tokens(&self, isa: &Isa) -> TokenStream {
+        let variants = self.variants().iter().map(|(pattern, variant)| {
+            let case_pattern = variant.pattern_destructure_tokens();
+            let pattern_bits = (pattern.pattern() != 0).then(|| {
+                let pattern = HexLiteral(pattern.pattern());
+                quote!(value |= #pattern;)
+            });
+            let data_stmts = variant.encode_stmts_tokens(isa);
+            quote! {
+                #case_pattern => {
+                    #pattern_bits
+                    #data_stmts
+                }
+            }
+        });
+        quote! {
+            let mut value = 0;
+            match self {
+                #(#variants)*
+            }
+            Some(value)
+        }
+    }
+}
+
+impl DataTypes {
+    pub fn encode_impls_tokens(&self, isa: &Isa) -> TokenStream {
+        let encode_impls = self.iter().filter_map(|dt| dt.encode_impl_tokens(isa));
+        quote!(#(#encode_impls)*)
+    }
+}
+
+impl DataType {
+    fn encode_impl_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+        if self.no_parse() {
+            return None;
+        }
+
+        let body = match self.kind() {
+            DataTypeKind::Union(data_type_union) => data_type_union.encode_impl_body_tokens(isa),
+            DataTypeKind::Struct(data_type_struct) => data_type_struct.encode_impl_body_tokens(isa),
+            _ => return None,
+        };
+
+        let name_ident = self.name().as_pascal_ident();
+        let cfg = self.callers_cfg_attribute_tokens(isa, DataTypeFn::Encode)?;
+
+        Some(quote! {
+            #cfg
+            impl #name_ident {
+                #[inline(always)]
+                pub(crate) fn encode(&self, pc: u32) -> Option<u32> {
+                    #body
+                }
+            }
+        })
+    }
+
+    /// Generates an expression which encodes `value` into the `u32` which, when passed to
+    /// [`DataType::parse_expr_tokens`], parses back into `value`.
+    fn encode_expr_tokens(&self, isa: &Isa, value: TokenStream) -> TokenStream {
+        match self.kind() {
+            DataTypeKind::Bool { .. } => quote!((*#value as u32)),
+            DataTypeKind::UInt(_) => quote!(*#value),
+            DataTypeKind::Int(_) => quote!((*#value as u32)),
+            DataTypeKind::Enum(_) => quote!((*#value as u32)),
+            DataTypeKind::Union(_) => quote!(#value.encode(pc)?),
+            DataTypeKind::Struct(_) => quote!(#value.encode(pc)?),
+            DataTypeKind::Type(_, _) => self.canonical(isa).encode_expr_tokens(isa, value),
+            DataTypeKind::Custom(_) => quote!(#value.encode()),
+        }
+    }
+
+    /// Returns whether [`DataType::encode_expr_tokens`] and [`DataType::encode_stmts_tokens`] call
+    /// the `encode` function of `callee`.
+    pub fn encode_expr_calls_encode(&self, isa: &Isa, callee: &DataType) -> bool {
+        match self.kind() {
+            DataTypeKind::Union(_) | DataTypeKind::Struct(_) => self.name() == callee.name(),
+            DataTypeKind::Type(_, _) => self.canonical(isa).encode_expr_calls_encode(isa, callee),
+            _ => false,
+        }
+    }
+
+    pub fn encode_impl_calls_encode(&self, isa: &Isa, callee: &DataType) -> bool {
+        if self.no_parse() {
+            return false;
+        }
+        match self.kind() {
+            DataTypeKind::Union(data_type_union) => data_type_union
+                .variants()
+                .values()
+                .any(|variant| variant.encode_stmts_calls_encode(isa, callee)),
+            DataTypeKind::Struct(data_type_struct) => data_type_struct
+                .fields()
+                .iter()
+                .any(|field| field.encode_expr_calls_encode(isa, callee)),
+            _ => false,
+        }
+    }
+
+    /// Generates statements which write `value` into the bits that this data type is parsed from
+    /// when no value is passed to [`DataType::parse_expr_tokens`].
+    fn encode_stmts_tokens(&self, isa: &Isa, value: TokenStream) -> TokenStream {
+        match self.kind() {
+            DataTypeKind::Bool { bits, .. } => bits.encode_tokens(quote!((*#value as u32))),
+            DataTypeKind::UInt(data_expr) => data_expr.encode_tokens(quote!(*#value)),
+            DataTypeKind::Int(data_expr) => data_expr.encode_tokens(quote!((*#value as u32))),
+            DataTypeKind::Enum(data_type_enum) => {
+                data_type_enum.bits().encode_tokens(quote!((*#value as u32)))
+            }
+            DataTypeKind::Union(data_type_union) => {
+                data_type_union.bits().encode_tokens(quote!(#value.encode(pc)?))
+            }
+            DataTypeKind::Struct(_) => quote!(value |= #value.encode(pc)?;),
+            DataTypeKind::Type(_, data_expr) => {
+                let inner_value = self.canonical(isa).encode_expr_tokens(isa, value);
+                data_expr.encode_tokens(inner_value)
+            }
+            DataTypeKind::Custom(_) => quote!(value |= #value.encode();),
+        }
+    }
+}
+
+impl IllegalChecks {
+    /// Returns the bits which must be set for checks like `ins() & 0xf00 != 0xf00` to pass.
+    fn required_bits(&self) -> u32 {
+        self.iter()
+            .filter_map(|illegal| {
+                let syn::Expr::Binary(binary) = illegal.expr() else {
+                    return None;
+                };
+                if !matches!(binary.op, syn::BinOp::Ne(_)) {
+                    return None;
+                }
+                let required = literal(&binary.right)?;
+                match binary.left.as_ref() {
+                    syn::Expr::Binary(left) if matches!(left.op, syn::BinOp::BitAnd(_)) => {
+                        Some(required & literal(&left.right)?)
+                    }
+                    left => Some((required & bit_range(left)?.mask()) << bit_range(left)?.0.start),
+                }
+            })
+            .fold(0, |acc, bits| acc | bits)
+    }
+}
+
+impl BitRange {
+    /// Inverse of [`BitRange::shift_mask_tokens`]
+    pub fn encode_tokens(&self, target: TokenStream) -> TokenStream {
+        let mask = HexLiteral(self.mask());
+        if self.0.start == 0 {
+            if self.0.end == 32 {
+                quote!(value |= #target;)
+            } else {
+                quote!(value |= (#target) & #mask;)
+            }
+        } else {
+            let shift = Literal::u8_unsuffixed(self.0.start);
+            quote!(value |= ((#target) & #mask) << #shift;)
+        }
+    }
+}
+
+impl DataExpr {
+    /// Returns the bits that a value parsed by this expression can have set, if known.
+    pub fn value_mask(&self) -> Option<u32> {
+        value_mask(self.expr())
+    }
+
+    pub fn encode_tokens(&self, target: TokenStream) -> TokenStream {
+        encode_expr_tokens(self.expr(), target).unwrap_or_else(|| {
+            panic!("Data expression '{}' can't be encoded", self.expr().to_token_stream())
+        })
+    }
+}
+
+/// Generates statements which write `target` (a `u32` expression) into the bits of `value` that
+/// `expr` reads, such that evaluating `expr` afterwards results in `target`. Bits which don't fit
+/// are truncated, so the caller must verify the result. Returns `None` if `expr` can't be
+/// inverted.
+fn encode_expr_tokens(expr: &syn::Expr, target: TokenStream) -> Option<TokenStream> {
+    match expr {
+        syn::Expr::Paren(paren) => encode_expr_tokens(&paren.expr, target),
+        syn::Expr::Lit(_) => Some(quote!()),
+        syn::Expr::Path(path) if path.path.is_ident("pc") => Some(quote!()),
+        syn::Expr::Call(_) => Some(bit_range(expr)?.encode_tokens(target)),
+        syn::Expr::Binary(binary) => match binary.op {
+            syn::BinOp::Shl(_) => {
+                if let Some(shift) = literal(&binary.right) {
+                    let shift = Literal::u32_unsuffixed(shift);
+                    encode_expr_tokens(&binary.left, quote!((#target) >> #shift))
+                } else if literal(&binary.left) == Some(1) {
+                    encode_expr_tokens(&binary.right, quote!((#target).trailing_zeros()))
+                } else {
+                    None
+                }
+            }
+            syn::BinOp::BitOr(_) => {
+                let left_mask = HexLiteral(value_mask(&binary.left)?);
+                let right_mask = HexLiteral(value_mask(&binary.right)?);
+                let left = encode_expr_tokens(&binary.left, quote!(enc & #left_mask))?;
+                let right = encode_expr_tokens(&binary.right, quote!(enc & #right_mask))?;
+                Some(quote! {{
+                    let enc = #target;
+                    #left
+                    #right
+                }})
+            }
+            syn::BinOp::BitXor(_) => {
+                let xor = operand_tokens(&binary.right);
+                encode_expr_tokens(&binary.left, quote!((#target) ^ #xor))
+            }
+            syn::BinOp::Add(_) => encode_add_tokens(&binary.left, &binary.right, target),
+            syn::BinOp::Sub(_) => {
+                if let Some(minuend) = literal(&binary.left) {
+                    let minuend = Literal::u32_suffixed(minuend);
+                    encode_expr_tokens(&binary.right, quote!(#minuend.wrapping_sub(#target)))
+                } else {
+                    let sub = operand_tokens(&binary.right);
+                    encode_expr_tokens(&binary.left, quote!((#target).wrapping_add(#sub)))
+                }
+            }
+            _ => None,
+        },
+        syn::Expr::MethodCall(call) => match call.method.to_string().as_str() {
+            "sign_extend" => encode_expr_tokens(&call.receiver, target),
+            "wrapping_add" => encode_add_tokens(&call.receiver, call.args.first()?, target),
+            "wrapping_sub" => {
+                let sub = HexLiteral(literal(call.args.first()?)?);
+                encode_expr_tokens(&call.receiver, quote!((#target).wrapping_add(#sub)))
+            }
+            "negate_if" => encode_negate_if_tokens(&call.receiver, call.args.first()?, target),
+            "rotate_right" => {
+                encode_rotate_right_tokens(&call.receiver, call.args.first()?, target)
+            }
+            "thumb_expand_imm" => {
+                let receiver = encode_expr_tokens(&call.receiver, quote!(imm))?;
+                Some(quote! {{
+                    let imm = thumb_encode_imm(#target)?;
+                    #receiver
+                }})
+            }
+            _ => None,
+        },
+        syn::Expr::If(expr_if) => {
+            let then_expr = block_expr(&expr_if.then_branch)?;
+            let syn::Expr::Block(else_block) = expr_if.else_branch.as_ref()?.1.as_ref() else {
+                return None;
+            };
+            let else_expr = block_expr(&else_block.block)?;
+            // One of the branches must be a constant, which is encoded by failing the condition
+            let (constant, other) = if let Some(constant) = literal(then_expr) {
+                (constant, else_expr)
+            } else {
+                (literal(else_expr)?, then_expr)
+            };
+            let constant = HexLiteral(constant);
+            let other = encode_expr_tokens(other, quote!(enc))?;
+            Some(quote! {{
+                let enc = #target;
+                if enc != #constant {
+                    #other
+                }
+            }})
+        }
+        _ => None,
+    }
+}
+
+/// Generates the right operand of an inverted XOR or subtraction. If the operand reads instruction
+/// bits, such as the sign bit which is XORed with J1 and J2 in Thumb-2 branches, those bits must
+/// already be encoded.
+fn operand_tokens(expr: &syn::Expr) -> TokenStream {
+    match literal(expr) {
+        Some(value) => HexLiteral(value).to_token_stream(),
+        None => DataExpr::from(expr.clone()).as_tokens(Ident::new("value", Span::call_site())),
+    }
+}
+
+fn encode_add_tokens(
+    left: &syn::Expr,
+    right: &syn::Expr,
+    target: TokenStream,
+) -> Option<TokenStream> {
+    if is_pc(left) {
+        encode_expr_tokens(right, quote!((#target).wrapping_sub(pc)))
+    } else if is_pc(right) {
+        encode_expr_tokens(left, quote!((#target).wrapping_sub(pc)))
+    } else if let Some(add) = literal(right) {
+        let add = HexLiteral(add);
+        encode_expr_tokens(left, quote!((#target).wrapping_sub(#add)))
+    } else {
+        let add = HexLiteral(literal(left)?);
+        encode_expr_tokens(right, quote!((#target).wrapping_sub(#add)))
+    }
+}
+
+fn encode_negate_if_tokens(
+    receiver: &syn::Expr,
+    cond: &syn::Expr,
+    target: TokenStream,
+) -> Option<TokenStream> {
+    // The condition must be a single bit compared to a constant, e.g. `bit(23) == 0`
+    let syn::Expr::Binary(cond) = cond else {
+        return None;
+    };
+    if !matches!(cond.op, syn::BinOp::Eq(_)) {
+        return None;
+    }
+    let cond_range = bit_range(&cond.left)?;
+    if cond_range.mask() != 1 {
+        return None;
+    }
+    let cond_value = literal(&cond.right)?;
+    let cond_bits = |value: u32| {
+        let bits = (value & 1) << cond_range.0.start;
+        (bits != 0).then(|| {
+            let bits = HexLiteral(bits);
+            quote!(value |= #bits;)
+        })
+    };
+    let negate_bits = cond_bits(cond_value);
+    let keep_bits = cond_bits(cond_value ^ 1);
+
+    let negated = encode_expr_tokens(receiver, quote!(enc.wrapping_neg() as u32))?;
+    let kept = encode_expr_tokens(receiver, quote!(enc as u32))?;
+    Some(quote! {{
+        let enc = (#target) as i32;
+        if enc < 0 {
+            #negated
+            #negate_bits
+        } else {
+            #kept
+            #keep_bits
+        }
+    }})
+}
+
+fn encode_rotate_right_tokens(
+    receiver: &syn::Expr,
+    amount: &syn::Expr,
+    target: TokenStream,
+) -> Option<TokenStream> {
+    // Search all values of the rotation bits for one where the rotated value fits in the receiver
+    let amount_range = single_bit_range(amount)?;
+    let num_amounts = Literal::u32_unsuffixed(1 << amount_range.0.len());
+    let amount_shift = Literal::u8_unsuffixed(amount_range.0.start);
+    let amount_expr =
+        DataExpr::from(amount.clone()).as_tokens(Ident::new("raw", Span::call_site()));
+    let receiver_mask = HexLiteral(value_mask(receiver)?);
+    let receiver_stmts = encode_expr_tokens(receiver, quote!(imm))?;
+    Some(quote! {{
+        let enc = #target;
+        let (imm, raw) = (0..#num_amounts)
+            .map(|raw: u32| raw << #amount_shift)
+            .map(|raw| (enc.rotate_left(#amount_expr), raw))
+            .find(|(imm, _)| imm & !#receiver_mask == 0)?;
+        #receiver_stmts
+        value |= raw;
+    }})
+}
+
+/// Returns the range of a `bits(a..b)` or `bit(n)` call.
+fn bit_range(expr: &syn::Expr) -> Option<BitRange> {
+    let expr = match expr {
+        syn::Expr::Paren(paren) => return bit_range(&paren.expr),
+        syn::Expr::Call(call) => call,
+        _ => return None,
+    };
+    let fn_name = expr.func.to_token_stream().to_string();
+    match fn_name.as_str() {
+        "bits" => {
+            let syn::Expr::Range(range) = expr.args.first()? else {
+                return None;
+            };
+            let start = literal(range.start.as_ref()?)?;
+            let end = literal(range.end.as_ref()?)?;
+            Some(BitRange(start as u8..end as u8))
+        }
+        "bit" => {
+            let bit = literal(expr.args.first()?)?;
+            Some(BitRange(bit as u8..bit as u8 + 1))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the bit range of an expression which only reads one range of bits.
+fn single_bit_range(expr: &syn::Expr) -> Option<BitRange> {
+    match expr {
+        syn::Expr::Paren(paren) => single_bit_range(&paren.expr),
+        syn::Expr::Call(_) => bit_range(expr),
+        syn::Expr::Binary(binary) if literal(&binary.right).is_some() => {
+            single_bit_range(&binary.left)
+        }
+        _ => None,
+    }
+}
+
+/// Returns a mask of the bits that an expression can result in.
+fn value_mask(expr: &syn::Expr) -> Option<u32> {
+    match expr {
+        syn::Expr::Paren(paren) => value_mask(&paren.expr),
+        syn::Expr::Lit(_) => literal(expr),
+        syn::Expr::Call(_) => Some(bit_range(expr)?.mask()),
+        syn::Expr::Binary(binary) => match binary.op {
+            syn::BinOp::Shl(_) => value_mask(&binary.left)?.checked_shl(literal(&binary.right)?),
+            syn::BinOp::BitOr(_) => Some(value_mask(&binary.left)? | value_mask(&binary.right)?),
+            syn::BinOp::BitXor(_) => {
+                let right = literal(&binary.right).or_else(|| value_mask(&binary.right))?;
+                Some(value_mask(&binary.left)? | right)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn literal(expr: &syn::Expr) -> Option<u32> {
+    match expr {
+        syn::Expr::Paren(paren) => literal(&paren.expr),
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) => int.base10_parse().ok(),
+        _ => None,
+    }
+}
+
+fn is_pc(expr: &syn::Expr) -> bool {
+    matches!(expr, syn::Expr::Path(path) if path.path.is_ident("pc"))
+}
+
+fn block_expr(block: &syn::Block) -> Option<&syn::Expr> {
+    match block.stmts.as_slice() {
+        [syn::Stmt::Expr(expr, None)] => Some(expr),
+        _ => None,
+    }
+}
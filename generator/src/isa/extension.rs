@@ -1,3 +1,4 @@
+use indexmap::IndexSet;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use serde::{Deserialize, de};
@@ -82,6 +83,10 @@ impl IsaExtensions {
                 pub fn has_all(self, extensions: Extensions) -> bool {
                     (self.0 & extensions.0) == self.0
                 }
+
+                pub const fn has_any(self, extensions: Extensions) -> bool {
+                    (self.0 & extensions.0) != 0
+                }
             }
         }
     }
@@ -97,6 +102,36 @@ impl IsaExtensions {
     }
 }
 
+/// Adds the extensions of one encoding to those of other encodings, where enabling any of the
+/// extensions is enough. An empty set means that no extension is required.
+pub fn merge_extensions(
+    extensions: &mut Option<IndexSet<IsaExtension>>,
+    other: IndexSet<IsaExtension>,
+) {
+    match extensions {
+        None => *extensions = Some(other),
+        Some(extensions) if extensions.is_empty() => {}
+        Some(extensions) if other.is_empty() => extensions.clear(),
+        Some(extensions) => extensions.extend(other),
+    }
+}
+
+/// Returns an `Extensions` expression of the given extensions.
+pub fn extensions_tokens<'a>(
+    extensions: impl IntoIterator<Item = &'a IsaExtension>,
+) -> TokenStream {
+    let mut extensions = extensions.into_iter().peekable();
+    if extensions.peek().is_none() {
+        return quote!(Extensions::none());
+    }
+    let extensions = extensions.map(|extension| {
+        let name = extension.name();
+        let ident = extension.as_ident();
+        quote!(#[cfg(feature = #name)] Extension::#ident)
+    });
+    quote!(Extensions::of(&[#(#extensions),*]))
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IsaExtension(String);
 
@@ -0,0 +1,173 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use serde::Deserialize;
+use syn::Ident;
+
+use crate::{
+    isa::{FormatCond, Isa, Opcode, Opcodes},
+    util::str::snake_to_pascal_case,
+};
+
+/// How an instruction affects the control flow, see `FlowKind` in the disassembler.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub enum Flow {
+    /// Execution continues with the next instruction
+    #[default]
+    #[serde(rename = "fallthrough")]
+    Fallthrough,
+    /// Direct branch to a `u32` target, conditional unless the `cond` parameter is `al`
+    #[serde(rename = "branch")]
+    Branch(FormatCond),
+    /// Direct branch to a `u32` target which is always conditional, such as CBZ
+    #[serde(rename = "cond_branch")]
+    CondBranch(FormatCond),
+    #[serde(rename = "call")]
+    Call(CallFlow),
+    #[serde(rename = "return")]
+    Return,
+    #[serde(rename = "jump")]
+    Jump,
+    #[serde(rename = "exception")]
+    Exception,
+    #[serde(rename = "if")]
+    If(Box<IfFlow>),
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct CallFlow {
+    /// Evaluates to either `u32` or `Option<u32>`
+    target: FormatCond,
+    /// The callee runs in the other instruction set
+    #[serde(default)]
+    exchange: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct IfFlow {
+    cond: FormatCond,
+    #[serde(rename = "then")]
+    if_true: Flow,
+    #[serde(rename = "else", default)]
+    if_false: Flow,
+}
+
+impl Flow {
+    pub fn is_fallthrough(&self) -> bool {
+        matches!(self, Flow::Fallthrough)
+    }
+
+    fn uses_conditional(&self) -> bool {
+        match self {
+            Flow::Fallthrough | Flow::CondBranch(_) | Flow::Call(_) => false,
+            Flow::Branch(_) | Flow::Return | Flow::Jump | Flow::Exception => true,
+            Flow::If(if_flow) => {
+                if_flow.if_true.uses_conditional() || if_flow.if_false.uses_conditional()
+            }
+        }
+    }
+
+    fn expr_tokens(&self) -> TokenStream {
+        match self {
+            Flow::Fallthrough => quote!(FlowKind::Fallthrough),
+            Flow::Branch(target) => {
+                let target = target.as_tokens(None);
+                quote!(FlowKind::branch(#target, conditional))
+            }
+            Flow::CondBranch(target) => {
+                let target = target.as_tokens(None);
+                quote!(FlowKind::ConditionalBranch { target: #target })
+            }
+            Flow::Call(CallFlow { target, exchange }) => {
+                let target = target.as_tokens(None);
+                quote!(FlowKind::Call { target: Option::<u32>::from(#target), exchange: #exchange })
+            }
+            Flow::Return => quote!(FlowKind::Return { conditional }),
+            Flow::Jump => quote!(FlowKind::IndirectJump { conditional }),
+            Flow::Exception => quote!(FlowKind::Exception { conditional }),
+            Flow::If(if_flow) => {
+                let cond = if_flow.cond.as_tokens(None);
+                let if_true = if_flow.if_true.expr_tokens();
+                let if_false = if_flow.if_false.expr_tokens();
+                quote! {
+                    if #cond {
+                        #if_true
+                    } else {
+                        #if_false
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Opcodes {
+    pub fn flow_impl_tokens(&self, isa: &Isa) -> TokenStream {
+        let flow_cases = self.iter().filter_map(|op| op.flow_case_tokens(isa));
+        let cond_cases = self.iter().filter_map(|op| op.cond_case_tokens(isa));
+
+        quote! {
+            impl Ins {
+                /// Returns the condition for executing this instruction, or [`Cond::Al`] if it
+                /// has none.
+                pub fn cond(&self) -> Cond {
+                    match self {
+                        #(#cond_cases)*
+                        _ => Cond::Al,
+                    }
+                }
+
+                /// Returns how this instruction affects the control flow, see [`FlowKind`].
+                pub fn flow(&self) -> FlowKind {
+                    match self {
+                        #(#flow_cases)*
+                        _ => FlowKind::Fallthrough,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Opcode {
+    fn cond_case_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+        if !self.has_cond() {
+            return None;
+        }
+        let variant_ident = Ident::new(&snake_to_pascal_case(self.mnemonic()), Span::call_site());
+        let cfg = self.cfg_attribute_tokens(isa);
+        Some(quote! {
+            #cfg
+            Ins::#variant_ident { cond, .. } => *cond,
+        })
+    }
+
+    fn flow_case_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+        if self.flow().is_fallthrough() {
+            return None;
+        }
+
+        let variant_ident = Ident::new(&snake_to_pascal_case(self.mnemonic()), Span::call_site());
+        let param_names = self.params().keys().map(|k| Ident::new(&k.0, Span::call_site()));
+
+        let conditional = self.flow().uses_conditional().then(|| {
+            if self.has_cond() {
+                quote!(let conditional = *cond != Cond::Al;)
+            } else {
+                quote!(let conditional = false;)
+            }
+        });
+        let flow_expr = self.flow().expr_tokens();
+
+        let cfg = self.cfg_attribute_tokens(isa);
+        Some(quote! {
+            #cfg
+            Ins::#variant_ident { #(#param_names),* } => {
+                #conditional
+                #flow_expr
+            }
+        })
+    }
+}
@@ -74,6 +74,18 @@ pub struct IfFormat {
 }
 
 impl IfFormat {
+    pub fn cond(&self) -> &FormatCond {
+        &self.cond
+    }
+
+    pub fn if_true(&self) -> &Format {
+        &self.if_true
+    }
+
+    pub fn if_false(&self) -> &Format {
+        &self.if_false
+    }
+
     fn fmt_expr_tokens(
         &self,
         isa: &Isa,
@@ -103,6 +115,18 @@ pub struct MatchFormat {
 }
 
 impl MatchFormat {
+    pub fn value(&self) -> &FormatCond {
+        &self.value
+    }
+
+    pub fn option(&self) -> &str {
+        &self.option
+    }
+
+    pub fn cases(&self) -> &BTreeMap<String, Format> {
+        &self.cases
+    }
+
     fn fmt_expr_tokens(
         &self,
         isa: &Isa,
@@ -135,6 +159,10 @@ impl MatchFormat {
 pub struct FormatCond(SynExpr);
 
 impl FormatCond {
+    pub fn expr(&self) -> &syn::Expr {
+        &self.0.0
+    }
+
     pub fn as_tokens(&self, options_tokens: Option<TokenStream>) -> TokenStream {
         let mut replace = FormatCondReplace {
             options_tokens: options_tokens.unwrap_or_else(|| quote!(formatter.options())),
@@ -194,6 +222,14 @@ pub struct FragmentsFormat {
 }
 
 impl FragmentsFormat {
+    pub fn new(fragments: Vec<FormatFragment>) -> Self {
+        Self { fragments }
+    }
+
+    pub fn fragments(&self) -> &[FormatFragment] {
+        &self.fragments
+    }
+
     fn fmt_expr_tokens(
         &self,
         isa: &Isa,
@@ -211,7 +247,7 @@ impl FragmentsFormat {
 }
 
 #[derive(Debug, Clone)]
-enum FormatFragment {
+pub enum FormatFragment {
     Text(String),
     Space,
     Separator,
@@ -23,6 +23,10 @@ impl IllegalChecks {
         quote!(#(#illegal_checks)*)
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &DataExpr> {
+        self.0.iter()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
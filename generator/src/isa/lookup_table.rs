@@ -125,7 +125,7 @@ impl<'a> OpcodeLookupTable<'a> {
             let pattern_literals = clones.iter().map(|k| HexLiteral(*k));
 
             let bucket = self.buckets.get(*key).unwrap();
-            let body_tokens = bucket.parse_bucket_tokens(self.arch, isa);
+            let body_tokens = bucket.parse_bucket_tokens(self.arch, self.bitmask, isa);
 
             quote! {
                 #(#pattern_literals)|* => #body_tokens
@@ -150,11 +150,13 @@ impl<'a> OpcodeLookupTable<'a> {
 }
 
 impl<'a> Bucket<'a> {
-    pub fn parse_bucket_tokens(&self, arch: Arch, isa: &Isa) -> TokenStream {
+    pub fn parse_bucket_tokens(&self, arch: Arch, bitmask: u32, isa: &Isa) -> TokenStream {
         if self.encodings.is_empty() {
             quote!({})
-        } else if self.encodings.len() == 1 {
-            let encoding = &self.encodings[0];
+        } else if let [encoding] = self.encodings.as_slice()
+            // The pattern only needs to be checked if the bucket key doesn't cover it
+            && encoding.encoding.pattern().combined().bitmask() & !bitmask == 0
+        {
             let parse_fn_ident = encoding.opcode.parse_fn_ident(arch, encoding.index_opcode);
             let cfg = encoding.encoding.cfg_attribute_tokens(isa, arch);
             quote! {
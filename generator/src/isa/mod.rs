@@ -1,27 +1,36 @@
+mod access;
+mod asm;
 mod bit_range;
 mod cfg;
 mod data_type;
 mod defs_uses;
+mod encode;
 mod extension;
+mod flow;
 mod format;
 mod illegal;
 mod lookup_table;
 mod opcode;
+mod opcode_enum;
+mod operand;
 mod option;
 mod pattern;
 mod syn;
 mod version;
+mod wildcard;
 
 use std::{fmt::Display, io::Read};
 
 use anyhow::Result;
 use serde::Deserialize;
 
+pub use access::*;
 pub use bit_range::*;
 pub use cfg::*;
 pub use data_type::*;
 pub use defs_uses::*;
 pub use extension::*;
+pub use flow::*;
 pub use format::*;
 pub use illegal::*;
 pub use lookup_table::*;
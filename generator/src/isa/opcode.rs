@@ -9,10 +9,11 @@ use syn::Ident;
 
 use crate::{
     isa::{
-        Arch, BitRange, DataExpr, DataType, DataTypeEnumVariantName, DataTypeKind, DataTypeName,
-        DefsUses, Format, FormatCond, FormatParams, IllegalChecks, Isa, IsaExtension,
-        IsaExtensionPatterns, IsaVersionPatterns, IsaVersionSet, OpcodeLookupTable, OpcodePattern,
-        cfg_attribute_single_arch_tokens, cfg_attribute_tokens,
+        Access, Arch, BitRange, DataExpr, DataType, DataTypeEnumVariant, DataTypeEnumVariantName,
+        DataTypeKind, DataTypeName, DefsUses, Flow, Format, FormatCond, FormatParams,
+        IllegalChecks, Isa, IsaExtension, IsaExtensionPatterns, IsaVersionPatterns, IsaVersionSet,
+        OpcodeLookupTable, OpcodePattern, cfg_attribute_single_arch_tokens, cfg_attribute_tokens,
+        extensions_tokens, merge_extensions,
     },
     util::str::snake_to_pascal_case,
 };
@@ -30,6 +31,7 @@ impl Opcodes {
         quote! {
             #[repr(u16)]
             #[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub enum Ins {
                 #(#opcodes),*,
                 Word(u32),
@@ -90,6 +92,23 @@ impl Opcodes {
         }
     }
 
+    pub fn set_it_cond_tokens(&self, isa: &Isa) -> TokenStream {
+        let cases = self.iter().filter_map(|o| o.set_it_cond_case_tokens(isa));
+        quote! {
+            impl Ins {
+                /// Applies the condition of the IT block this Thumb instruction is in. Narrow
+                /// instructions which set the flags outside of an IT block don't set them inside one.
+                #[cfg(all(feature = "thumb", feature = "v7"))]
+                pub(crate) fn set_it_cond(&mut self, it_cond: Cond, narrow: bool) {
+                    match self {
+                        #(#cases)*
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
     pub fn parse_arm_lookup_match_tokens(&self, isa: &Isa) -> TokenStream {
         let lookup_table = OpcodeLookupTable::new_arm(isa);
         let parse_fn_body = lookup_table.parse_match_fn_body_tokens(isa);
@@ -113,16 +132,8 @@ impl Opcodes {
     }
 
     pub fn parse_with_discriminant_tokens(&self, isa: &Isa, arch: Arch) -> TokenStream {
-        let num_opcodes = self.0.len();
-
-        // See Opcodes::ins_enum_tokens
-        let id_byte = Literal::usize_unsuffixed(num_opcodes);
-        let id_halfword = Literal::usize_unsuffixed(num_opcodes + 1);
-        let id_word = Literal::usize_unsuffixed(num_opcodes + 2);
-
-        let cases = self.0.iter().enumerate().filter_map(|(discriminant, opcode)| {
-            opcode.parse_with_discriminant_case(isa, discriminant as u16, arch)
-        });
+        let cases =
+            self.0.iter().filter_map(|opcode| opcode.parse_with_discriminant_case(isa, arch));
 
         let fn_name = format!("parse_{arch}_with_discriminant");
         let fn_ident = Ident::new(&fn_name, Span::call_site());
@@ -135,11 +146,14 @@ impl Opcodes {
         quote! {
             #cfg
             pub fn #fn_ident(ins: u32, discriminant: u16, pc: u32, options: &Options) -> Ins {
-                match discriminant {
+                let Some(opcode) = Opcode::from_discriminant(discriminant) else {
+                    return Ins::Illegal;
+                };
+                match opcode {
                     #(#cases),*,
-                    #id_byte => return Ins::Byte(ins as u8),
-                    #id_halfword => return Ins::HalfWord(ins as u16),
-                    #id_word => return Ins::Word(ins),
+                    Opcode::Byte => return Ins::Byte(ins as u8),
+                    Opcode::HalfWord => return Ins::HalfWord(ins as u16),
+                    Opcode::Word => return Ins::Word(ins),
                     _ => {},
                 };
                 Ins::Illegal
@@ -160,6 +174,10 @@ pub struct Opcode {
     #[serde(default)]
     uses: DefsUses,
     #[serde(default)]
+    flow: Flow,
+    #[serde(default)]
+    access: Option<Access>,
+    #[serde(default)]
     arm: Vec<OpcodeEncoding>,
     #[serde(default)]
     thumb: Vec<OpcodeEncoding>,
@@ -171,10 +189,18 @@ impl Opcode {
         &self.mnemonic
     }
 
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
     pub fn params(&self) -> &IndexMap<OpcodeParamName, DataTypeName> {
         &self.params
     }
 
+    pub fn format(&self) -> &OpcodeFormat {
+        &self.format
+    }
+
     pub fn validate(&self, isa: &Isa) -> Result<()> {
         for (param, type_name) in self.params.iter() {
             isa.types().get(type_name).ok_or_else(|| {
@@ -218,6 +244,32 @@ impl Opcode {
         }
     }
 
+    fn set_it_cond_case_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+        let cond_name = OpcodeParamName("cond".into());
+        let s_name = OpcodeParamName("s".into());
+        if self.thumb.is_empty() || !self.params.contains_key(&cond_name) {
+            return None;
+        }
+        let variant_ident = Ident::new(&snake_to_pascal_case(&self.mnemonic), Span::call_site());
+        let cfg = self.cfg_attribute_tokens(isa);
+        let body = if self.params.contains_key(&s_name) {
+            quote! {
+                Ins::#variant_ident { cond, s, .. } => {
+                    *cond = it_cond;
+                    if narrow {
+                        *s = false;
+                    }
+                }
+            }
+        } else {
+            quote!(Ins::#variant_ident { cond, .. } => *cond = it_cond,)
+        };
+        Some(quote! {
+            #cfg
+            #body
+        })
+    }
+
     pub fn parse_fn_ident(&self, arch: Arch, index: usize) -> Ident {
         let name = format!("parse_{}_{}_{}", arch, self.mnemonic, index);
         Ident::new(&name, Span::call_site())
@@ -236,7 +288,7 @@ impl Opcode {
         }
     }
 
-    fn format_params(&self, isa: &Isa) -> FormatParams {
+    pub fn format_params(&self, isa: &Isa) -> FormatParams {
         let mut params: FormatParams = HashMap::new();
         for (param_name, type_name) in &self.params {
             let Some(data_type) = isa.types().get(type_name) else {
@@ -288,12 +340,8 @@ impl Opcode {
         &self.thumb
     }
 
-    fn parse_with_discriminant_case(
-        &self,
-        isa: &Isa,
-        discriminant: u16,
-        arch: Arch,
-    ) -> Option<TokenStream> {
+    /// Returns the encodings with their indices, most specific pattern first.
+    pub fn sorted_encodings(&self, arch: Arch) -> Vec<(usize, &OpcodeEncoding)> {
         let encodings = match arch {
             Arch::Arm => &self.arm,
             Arch::Thumb => &self.thumb,
@@ -304,6 +352,11 @@ impl Opcode {
             let b_pattern = b.pattern().combined();
             b_pattern.num_bits().cmp(&a_pattern.num_bits())
         });
+        encodings
+    }
+
+    fn parse_with_discriminant_case(&self, isa: &Isa, arch: Arch) -> Option<TokenStream> {
+        let encodings = self.sorted_encodings(arch);
 
         let parse_encoding = match encodings.len() {
             0 => return None,
@@ -337,10 +390,10 @@ impl Opcode {
         };
 
         let cfg = self.cfg_attribute_tokens(isa);
-        let discriminant_lit = Literal::u16_unsuffixed(discriminant);
+        let variant_ident = Ident::new(&snake_to_pascal_case(&self.mnemonic), Span::call_site());
         Some(quote! {
             #cfg
-            #discriminant_lit => { #parse_encoding }
+            Opcode::#variant_ident => { #parse_encoding }
         })
     }
 
@@ -379,12 +432,7 @@ impl Opcode {
             Arch::Thumb => &self.thumb,
         };
         for encoding in encodings {
-            let exts = encoding.extensions(isa);
-            if let Some(extensions) = &mut extensions {
-                *extensions = extensions.intersection(&exts).cloned().collect();
-            } else {
-                extensions = Some(exts);
-            }
+            merge_extensions(&mut extensions, encoding.extensions(isa));
         }
         extensions.unwrap_or_default()
     }
@@ -393,7 +441,7 @@ impl Opcode {
         self.params.values().any(|type_name| type_name == data_type.name())
     }
 
-    fn cfg_attribute_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+    pub fn cfg_attribute_tokens(&self, isa: &Isa) -> Option<TokenStream> {
         let arm_versions = self.versions(isa, Arch::Arm);
         let thumb_versions = self.versions(isa, Arch::Thumb);
         let arm_extensions = self.extensions(isa, Arch::Arm);
@@ -414,6 +462,19 @@ impl Opcode {
     pub fn uses(&self) -> &DefsUses {
         &self.uses
     }
+
+    pub fn flow(&self) -> &Flow {
+        &self.flow
+    }
+
+    /// Returns whether this opcode has a condition, which uses the flags it tests.
+    pub fn has_cond(&self) -> bool {
+        self.params.contains_key(&OpcodeParamName("cond".into()))
+    }
+
+    pub fn access(&self) -> Option<&Access> {
+        self.access.as_ref()
+    }
 }
 
 #[derive(Deserialize, Debug, PartialEq, Eq, Hash)]
@@ -438,6 +499,16 @@ pub struct OpcodeFormat {
     params: Format,
 }
 
+impl OpcodeFormat {
+    pub fn opcode(&self) -> &Format {
+        &self.opcode
+    }
+
+    pub fn params(&self) -> &Format {
+        &self.params
+    }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct OpcodeEncoding {
@@ -448,6 +519,10 @@ pub struct OpcodeEncoding {
     ignore: Option<FormatCond>,
     #[serde(default)]
     illegal: IllegalChecks,
+    /// Checks for values which belong to another encoding, such as register operands which
+    /// select a literal form when they are PC. Parsing moves on to the next matching encoding.
+    #[serde(default)]
+    see: IllegalChecks,
     params: IndexMap<OpcodeParamName, OpcodeParamValue>,
 }
 impl OpcodeEncoding {
@@ -455,10 +530,37 @@ impl OpcodeEncoding {
         self.pattern.validate(thumb)
     }
 
+    /// Returns a `Versions` expression of the versions which have this encoding.
+    pub fn versions_tokens(&self, isa: &Isa) -> TokenStream {
+        if isa.versions().matches_all(&self.version) {
+            return quote!(Versions::all());
+        }
+        let versions = self.version.versions(isa);
+        let versions = versions.iter().map(|version| {
+            let ident = version.as_ident();
+            let name = version.name();
+            quote! {
+                #[cfg(feature = #name)]
+                Version::#ident
+            }
+        });
+        quote!(Versions::of(&[#(#versions),*]))
+    }
+
+    /// Returns an `Extensions` expression of the extensions of which any enables this encoding,
+    /// or `Extensions::none()` if it doesn't require an extension.
+    pub fn extensions_tokens(&self, isa: &Isa) -> TokenStream {
+        extensions_tokens(self.extensions.extensions(isa))
+    }
+
     pub fn get_param(&self, name: &OpcodeParamName) -> Option<&OpcodeParamValue> {
         self.params.get(name)
     }
 
+    pub fn illegal(&self) -> &IllegalChecks {
+        &self.illegal
+    }
+
     fn parse_fn_tokens(
         &self,
         fn_ident: Ident,
@@ -472,12 +574,13 @@ impl OpcodeEncoding {
             Arch::Thumb => quote!(Some((Ins::Illegal, #ins_size))),
         };
         let illegal_checks = self.illegal.checks_tokens(Some(illegal_value.clone()));
+        let see_checks = self.see.checks_tokens(Some(quote!(None)));
 
         let params = opcode.params().iter().map(|(param_name, type_name)| {
             let data_type = isa.types().get(type_name).unwrap();
             let value = self.get_param(param_name);
             let parse_expr = if let Some(value) = value {
-                value.parse_expr_tokens(isa, data_type)
+                value.parse_expr_tokens(isa, data_type, &illegal_value)
             } else {
                 data_type.default_expr_tokens(isa).unwrap_or_else(|| {
                     panic!(
@@ -513,17 +616,9 @@ impl OpcodeEncoding {
         let version_check = if isa.versions().matches_all(&self.version) {
             quote!()
         } else {
-            let versions = self.version.versions(isa);
-            let versions = versions.iter().map(|version| {
-                let ident = version.as_ident();
-                let name = version.name();
-                quote! {
-                    #[cfg(feature = #name)]
-                    Version::#ident
-                }
-            });
+            let versions = self.versions_tokens(isa);
             quote! {
-                const VERSIONS: Versions = Versions::of(&[#(#versions),*]);
+                const VERSIONS: Versions = #versions;
                 if !VERSIONS.has(options.version) {
                     return None;
                 }
@@ -533,14 +628,10 @@ impl OpcodeEncoding {
         let extensions_check = if self.extensions.is_empty() {
             quote!()
         } else {
-            let extensions = self.extensions.extensions(isa);
-            let extensions = extensions.iter().map(|extension| {
-                let ident = extension.as_ident();
-                quote!(Extension::#ident)
-            });
+            let extensions = self.extensions_tokens(isa);
             quote! {
-                const EXTENSIONS: Extensions = Extensions::of(&[#(#extensions),*]);
-                if !EXTENSIONS.has_all(options.extensions) {
+                const EXTENSIONS: Extensions = #extensions;
+                if !options.extensions.has_any(EXTENSIONS) {
                     return None;
                 }
             }
@@ -574,6 +665,7 @@ impl OpcodeEncoding {
                 #version_check
                 #extensions_check
                 #ignore_check
+                #see_checks
                 #illegal_checks
                 #(#params)*
                 Some(#return_value)
@@ -585,14 +677,23 @@ impl OpcodeEncoding {
         &self.pattern
     }
 
-    fn versions(&self, isa: &Isa) -> IsaVersionSet {
+    pub fn versions(&self, isa: &Isa) -> IsaVersionSet {
         IsaVersionSet(self.version.versions(isa).into_iter().cloned().collect())
     }
 
-    fn extensions(&self, isa: &Isa) -> IndexSet<IsaExtension> {
+    pub fn extensions(&self, isa: &Isa) -> IndexSet<IsaExtension> {
         self.extensions.extensions(isa).into_iter().cloned().collect()
     }
 
+    /// Returns whether the parse function of this encoding calls the `parse` function of `callee`.
+    pub fn calls_parse(&self, opcode: &Opcode, isa: &Isa, callee: &DataType) -> bool {
+        opcode.params().iter().any(|(param_name, type_name)| {
+            let data_type = isa.types().get(type_name).unwrap();
+            self.get_param(param_name)
+                .is_some_and(|value| value.calls_parse(isa, data_type, callee))
+        })
+    }
+
     pub fn cfg_attribute_tokens(&self, isa: &Isa, arch: Arch) -> Option<TokenStream> {
         let versions = self.versions(isa);
         let extensions = self.extensions(isa);
@@ -614,7 +715,15 @@ pub enum OpcodeParamValue {
     Struct(IndexMap<String, OpcodeParamValue>),
 }
 impl OpcodeParamValue {
-    pub fn parse_expr_tokens(&self, isa: &Isa, data_type: &DataType) -> TokenStream {
+    /// Returns an expression parsing this value as `data_type`. Values of enum variants and struct
+    /// fields which can be illegal return `illegal_value` from the enclosing function, but the
+    /// caller must handle `data_type` itself being illegal.
+    pub fn parse_expr_tokens(
+        &self,
+        isa: &Isa,
+        data_type: &DataType,
+        illegal_value: &TokenStream,
+    ) -> TokenStream {
         match self {
             OpcodeParamValue::Bits(bit_range) => {
                 data_type.parse_expr_tokens(isa, Some(bit_range.shift_mask_tokens(None)))
@@ -640,14 +749,75 @@ impl OpcodeParamValue {
                         panic!("Data type '{}' is not an enum", data_type.name().0);
                     }
                 };
-                variant.param_expr_tokens(isa, inner_type.name(), value)
+                variant.param_expr_tokens(isa, inner_type.name(), value, illegal_value)
+            }
+            OpcodeParamValue::Struct(params) => {
+                let DataTypeKind::Struct(data_type_struct) = data_type.kind() else {
+                    panic!();
+                };
+                data_type_struct.param_tokens(isa, data_type.name(), params, illegal_value)
+            }
+        }
+    }
+
+    /// Like [`OpcodeParamValue::parse_expr_tokens`], but also returns `illegal_value` if
+    /// `data_type` is illegal.
+    pub fn parse_legal_expr_tokens(
+        &self,
+        isa: &Isa,
+        data_type: &DataType,
+        illegal_value: &TokenStream,
+    ) -> TokenStream {
+        let parse_expr = self.parse_expr_tokens(isa, data_type, illegal_value);
+        match self {
+            OpcodeParamValue::Bits(_) | OpcodeParamValue::Const(_) | OpcodeParamValue::Expr(_)
+                if data_type.can_be_illegal(isa) =>
+            {
+                legal_expr_tokens(parse_expr, illegal_value)
+            }
+            _ => parse_expr,
+        }
+    }
+
+    /// Returns whether [`OpcodeParamValue::parse_expr_tokens`] calls the `parse` function of
+    /// `callee`.
+    pub fn calls_parse(&self, isa: &Isa, data_type: &DataType, callee: &DataType) -> bool {
+        match self {
+            OpcodeParamValue::Bits(_) | OpcodeParamValue::Const(_) | OpcodeParamValue::Expr(_) => {
+                data_type.parse_expr_calls_parse(isa, callee)
+            }
+            OpcodeParamValue::Enum(variant, value) => {
+                Self::enum_variant(isa, data_type, variant).param_calls_parse(isa, value, callee)
             }
             OpcodeParamValue::Struct(params) => {
                 let DataTypeKind::Struct(data_type_struct) = data_type.kind() else {
                     panic!();
                 };
-                data_type_struct.param_tokens(isa, data_type.name(), params)
+                data_type_struct.param_record_calls_parse(isa, params, callee)
             }
         }
     }
+
+    pub fn enum_variant<'a>(
+        isa: &'a Isa,
+        data_type: &'a DataType,
+        variant: &DataTypeEnumVariantName,
+    ) -> &'a DataTypeEnumVariant {
+        match data_type.canonical(isa).kind() {
+            DataTypeKind::Enum(data_type_enum) => data_type_enum.get_variant(variant).unwrap(),
+            DataTypeKind::Union(data_type_union) => data_type_union.get_variant(variant).unwrap().1,
+            _ => panic!("Data type '{}' is not an enum", data_type.name().0),
+        }
+    }
+}
+
+/// Returns an expression unwrapping the `Option` of `parse_expr`, or returning `illegal_value` from
+/// the enclosing function if it's `None`.
+pub fn legal_expr_tokens(parse_expr: TokenStream, illegal_value: &TokenStream) -> TokenStream {
+    quote! {
+        match #parse_expr {
+            Some(parsed) => parsed,
+            None => return #illegal_value,
+        }
+    }
 }
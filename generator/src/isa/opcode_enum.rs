@@ -0,0 +1,264 @@
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use syn::Ident;
+
+use crate::{
+    isa::{Arch, Isa, Opcode, OpcodeEncoding, Opcodes, extensions_tokens},
+    util::{hex_literal::HexLiteral, str::snake_to_pascal_case},
+};
+
+/// Variants which `Ins` has in addition to the opcodes, with their names and descriptions
+const DATA_VARIANTS: [(&str, &str, &str); 4] = [
+    ("Word", "word", "Data word"),
+    ("HalfWord", "half_word", "Data halfword"),
+    ("Byte", "byte", "Data byte"),
+    ("Illegal", "illegal", "Illegal instruction"),
+];
+
+impl Opcodes {
+    pub fn opcode_enum_tokens(&self, isa: &Isa) -> TokenStream {
+        let variants = self.iter().map(|opcode| {
+            let variant_ident = opcode.variant_ident();
+            let description = opcode.description();
+            let cfg = opcode.cfg_attribute_tokens(isa);
+            quote! {
+                #cfg
+                #[doc = #description]
+                #variant_ident
+            }
+        });
+        let data_variants = DATA_VARIANTS.iter().map(|(variant, _, description)| {
+            let variant_ident = Ident::new(variant, Span::call_site());
+            quote! {
+                #[doc = #description]
+                #variant_ident
+            }
+        });
+        let all_variants = self.iter().map(|opcode| {
+            let variant_ident = opcode.variant_ident();
+            let cfg = opcode.cfg_attribute_tokens(isa);
+            quote!(#cfg Opcode::#variant_ident)
+        });
+        let all_data_variants = DATA_VARIANTS.iter().map(|(variant, _, _)| {
+            let variant_ident = Ident::new(variant, Span::call_site());
+            quote!(Opcode::#variant_ident)
+        });
+
+        quote! {
+            /// Kind of an [`Ins`] without its parameters, see [`Ins::opcode`]
+            #[repr(u16)]
+            #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            pub enum Opcode {
+                #(#variants),*,
+                #(#data_variants),*
+            }
+
+            impl Opcode {
+                /// All opcodes in the order of their discriminants
+                pub const ALL: &[Opcode] = &[#(#all_variants),*, #(#all_data_variants),*];
+            }
+        }
+    }
+
+    pub fn opcode_impl_tokens(&self, isa: &Isa) -> TokenStream {
+        let name_cases = self.iter().map(|opcode| {
+            let name = opcode.mnemonic();
+            opcode.opcode_case_tokens(isa, quote!(#name))
+        });
+        let description_cases = self.iter().map(|opcode| {
+            let description = opcode.description();
+            opcode.opcode_case_tokens(isa, quote!(#description))
+        });
+        let arm_versions_cases =
+            self.iter().map(|opcode| opcode.versions_case_tokens(isa, Arch::Arm));
+        let thumb_versions_cases =
+            self.iter().map(|opcode| opcode.versions_case_tokens(isa, Arch::Thumb));
+        let arm_extensions_cases =
+            self.iter().map(|opcode| opcode.extensions_case_tokens(isa, Arch::Arm));
+        let thumb_extensions_cases =
+            self.iter().map(|opcode| opcode.extensions_case_tokens(isa, Arch::Thumb));
+        let encodings_cases = self.iter().map(|opcode| opcode.encodings_case_tokens(isa));
+        let ins_opcode_cases = self.iter().map(|opcode| {
+            let variant_ident = opcode.variant_ident();
+            let cfg = opcode.cfg_attribute_tokens(isa);
+            quote!(#cfg Ins::#variant_ident { .. } => Opcode::#variant_ident,)
+        });
+
+        let data_name_cases = DATA_VARIANTS.iter().map(|(variant, name, _)| {
+            let variant_ident = Ident::new(variant, Span::call_site());
+            quote!(Opcode::#variant_ident => #name,)
+        });
+        let data_description_cases = DATA_VARIANTS.iter().map(|(variant, _, description)| {
+            let variant_ident = Ident::new(variant, Span::call_site());
+            quote!(Opcode::#variant_ident => #description,)
+        });
+
+        quote! {
+            impl Opcode {
+                /// Returns the opcode with the given discriminant, see [`Ins::discriminant`].
+                pub fn from_discriminant(discriminant: u16) -> Option<Self> {
+                    Self::ALL.get(discriminant as usize).copied()
+                }
+
+                /// Returns the name of this opcode, which is also the name of its [`Ins`] variant in
+                /// snake case, e.g. `vadd_f32`.
+                pub fn name(self) -> &'static str {
+                    match self {
+                        #(#name_cases)*
+                        #(#data_name_cases)*
+                    }
+                }
+
+                pub fn description(self) -> &'static str {
+                    match self {
+                        #(#description_cases)*
+                        #(#data_description_cases)*
+                    }
+                }
+
+                /// Returns the versions which have any encoding of this opcode in the given mode.
+                pub fn versions(self, mode: ParseMode) -> Versions {
+                    match mode {
+                        #[cfg(feature = "arm")]
+                        ParseMode::Arm => match self {
+                            #(#arm_versions_cases)*
+                            _ => Versions::none(),
+                        },
+                        #[cfg(feature = "thumb")]
+                        ParseMode::Thumb => match self {
+                            #(#thumb_versions_cases)*
+                            _ => Versions::none(),
+                        },
+                        ParseMode::Data => Versions::none(),
+                    }
+                }
+
+                /// Returns the extensions of which any must be enabled to parse this opcode in the
+                /// given mode, or [`Extensions::none`] if none are required.
+                pub fn extensions(self, mode: ParseMode) -> Extensions {
+                    match mode {
+                        #[cfg(feature = "arm")]
+                        ParseMode::Arm => match self {
+                            #(#arm_extensions_cases)*
+                            _ => Extensions::none(),
+                        },
+                        #[cfg(feature = "thumb")]
+                        ParseMode::Thumb => match self {
+                            #(#thumb_extensions_cases)*
+                            _ => Extensions::none(),
+                        },
+                        ParseMode::Data => Extensions::none(),
+                    }
+                }
+
+                /// Returns the ARM and Thumb encodings of this opcode.
+                pub fn encodings(self) -> &'static [Encoding] {
+                    match self {
+                        #(#encodings_cases)*
+                        _ => &[],
+                    }
+                }
+            }
+
+            impl Ins {
+                pub fn opcode(&self) -> Opcode {
+                    match self {
+                        #(#ins_opcode_cases)*
+                        Ins::Word(_) => Opcode::Word,
+                        Ins::HalfWord(_) => Opcode::HalfWord,
+                        Ins::Byte(_) => Opcode::Byte,
+                        Ins::Illegal => Opcode::Illegal,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Opcode {
+    fn variant_ident(&self) -> Ident {
+        Ident::new(&snake_to_pascal_case(self.mnemonic()), Span::call_site())
+    }
+
+    fn opcode_case_tokens(&self, isa: &Isa, value: TokenStream) -> TokenStream {
+        let variant_ident = self.variant_ident();
+        let cfg = self.cfg_attribute_tokens(isa);
+        quote!(#cfg Opcode::#variant_ident => #value,)
+    }
+
+    fn arch_encodings(&self, arch: Arch) -> &[OpcodeEncoding] {
+        match arch {
+            Arch::Arm => self.arm_encodings(),
+            Arch::Thumb => self.thumb_encodings(),
+        }
+    }
+
+    fn versions_case_tokens(&self, isa: &Isa, arch: Arch) -> Option<TokenStream> {
+        if self.arch_encodings(arch).is_empty() {
+            return None;
+        }
+        let versions = self.versions(isa, arch);
+        let versions = if isa.versions().has_all(&versions, Some(arch)) {
+            quote!(Versions::all())
+        } else {
+            let versions = versions.0.iter().map(|version| {
+                let name = version.name();
+                let ident = version.as_ident();
+                quote!(#[cfg(feature = #name)] Version::#ident)
+            });
+            quote!(Versions::of(&[#(#versions),*]))
+        };
+        Some(self.opcode_case_tokens(isa, versions))
+    }
+
+    fn extensions_case_tokens(&self, isa: &Isa, arch: Arch) -> Option<TokenStream> {
+        let extensions = self.extensions(isa, arch);
+        if self.arch_encodings(arch).is_empty() || extensions.is_empty() {
+            return None;
+        }
+        Some(self.opcode_case_tokens(isa, extensions_tokens(&extensions)))
+    }
+
+    fn encodings_case_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+        let arm_encodings = self.arm_encodings().iter().map(|e| e.encoding_tokens(isa, Arch::Arm));
+        let thumb_encodings =
+            self.thumb_encodings().iter().map(|e| e.encoding_tokens(isa, Arch::Thumb));
+        let encodings = arm_encodings.chain(thumb_encodings).collect::<Vec<_>>();
+        if encodings.is_empty() {
+            return None;
+        }
+        let encodings = quote! {{
+            const ENCODINGS: &[Encoding] = &[#(#encodings),*];
+            ENCODINGS
+        }};
+        Some(self.opcode_case_tokens(isa, encodings))
+    }
+}
+
+impl OpcodeEncoding {
+    fn encoding_tokens(&self, isa: &Isa, arch: Arch) -> TokenStream {
+        let pattern = self.pattern().combined();
+        let mask = HexLiteral(pattern.bitmask());
+        let value = HexLiteral(pattern.pattern());
+        let size = Literal::u32_unsuffixed(pattern.size() / 8);
+        let mode = match arch {
+            Arch::Arm => quote!(ParseMode::Arm),
+            Arch::Thumb => quote!(ParseMode::Thumb),
+        };
+        let versions = self.versions_tokens(isa);
+        let extensions = self.extensions_tokens(isa);
+        let cfg = self.cfg_attribute_tokens(isa, arch);
+        quote! {
+            #cfg
+            Encoding {
+                mode: #mode,
+                mask: #mask,
+                value: #value,
+                size: #size,
+                versions: #versions,
+                extensions: #extensions,
+            }
+        }
+    }
+}
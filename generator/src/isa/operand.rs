@@ -0,0 +1,314 @@
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use syn::Ident;
+
+use crate::{
+    isa::{
+        DataTypeEnumVariant, DataTypeKind, DataTypeName, Format, FormatFragment, FragmentsFormat,
+        Isa, Opcode, OpcodeParamName, Opcodes,
+    },
+    util::str::snake_to_pascal_case,
+};
+
+impl Opcodes {
+    pub fn operand_enum_tokens(&self, isa: &Isa) -> TokenStream {
+        let variants = isa.types().iter().filter(|dt| self.has_operand_type(dt.name())).map(|dt| {
+            let variant_ident = dt.name().as_pascal_ident();
+            let type_tokens = dt.type_tokens(isa);
+            let doc = dt.description().map(|desc| quote!(#[doc = #desc]));
+            let cfg = dt.cfg_attribute_tokens(isa);
+            quote! {
+                #cfg
+                #doc
+                #variant_ident(#type_tokens)
+            }
+        });
+        let max_operands = Literal::usize_unsuffixed(
+            self.iter().map(|op| op.operand_params().len()).max().unwrap_or_default(),
+        );
+
+        quote! {
+            pub(crate) const MAX_OPERANDS: usize = #max_operands;
+
+            /// Operand of an instruction, see [`Ins::operands`]
+            #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            pub enum Operand {
+                #(#variants),*
+            }
+        }
+    }
+
+    pub fn operands_impl_tokens(&self, isa: &Isa) -> TokenStream {
+        let mnemonic_cases = self.iter().map(|op| op.mnemonic_case_tokens(isa));
+        let operands_cases = self.iter().filter_map(|op| op.operands_case_tokens(isa));
+
+        quote! {
+            impl Ins {
+                /// Returns the mnemonic of this instruction with its suffixes and data type, but
+                /// without the condition code, e.g. `ldr` for `ldrne r0, [r1]`, `adds` for
+                /// `addsne r0, r1, r2`, `ldmib` for `ldmib r0, {r1}` and `vadd.i8` for
+                /// `vadd.i8 d0, d1, d2`.
+                pub fn mnemonic(&self, options: &Options) -> &'static str {
+                    match self {
+                        #(#mnemonic_cases)*
+                        Ins::Word(_) => ".word",
+                        Ins::HalfWord(_) => ".hword",
+                        Ins::Byte(_) => ".byte",
+                        Ins::Illegal => "<illegal>",
+                    }
+                }
+
+                /// Returns the operands of this instruction in the order of the UAL syntax. An
+                /// operand which is left out in some syntaxes, like the first source register of
+                /// a narrow Thumb instruction, is always included.
+                pub fn operands(&self) -> Operands {
+                    let mut operands = Operands::new();
+                    match self {
+                        #(#operands_cases)*
+                        Ins::Word(value) => operands.push(Operand::Uimm(*value)),
+                        Ins::HalfWord(value) => operands.push(Operand::Uimm(*value as u32)),
+                        Ins::Byte(value) => operands.push(Operand::Uimm(*value as u32)),
+                        _ => {}
+                    }
+                    operands
+                }
+            }
+        }
+    }
+
+    fn has_operand_type(&self, name: &DataTypeName) -> bool {
+        name.0 == "uimm"
+            || self.iter().any(|op| {
+                op.operand_params().iter().any(|param| op.params().get(*param) == Some(name))
+            })
+    }
+}
+
+impl Opcode {
+    /// Returns the parameters written by the params format, in the order they first appear.
+    fn operand_params(&self) -> Vec<&OpcodeParamName> {
+        let mut names = Vec::new();
+        collect_params(self.format().params(), &mut names);
+        names
+            .into_iter()
+            .map(|name| {
+                let Some((param_name, _)) = self.params().get_key_value(&OpcodeParamName(name))
+                else {
+                    panic!("Parameter in format of opcode '{}' does not exist", self.mnemonic());
+                };
+                param_name
+            })
+            .collect()
+    }
+
+    fn mnemonic_case_tokens(&self, isa: &Isa) -> TokenStream {
+        let variant_ident = Ident::new(&snake_to_pascal_case(self.mnemonic()), Span::call_site());
+        let param_names = self.params().keys().map(|k| k.as_ident());
+        let mnemonic = mnemonic_tokens(&[self.format().opcode()], String::new(), self, isa);
+        let cfg = self.cfg_attribute_tokens(isa);
+        quote! {
+            #cfg
+            Ins::#variant_ident { #(#param_names),* } => #mnemonic,
+        }
+    }
+
+    fn operands_case_tokens(&self, isa: &Isa) -> Option<TokenStream> {
+        let operand_params = self.operand_params();
+        if operand_params.is_empty() {
+            return None;
+        }
+        let variant_ident = Ident::new(&snake_to_pascal_case(self.mnemonic()), Span::call_site());
+        let param_names = self.params().keys().map(|k| k.as_ident());
+        let pushes = operand_params.into_iter().map(|param_name| {
+            let type_name = &self.params()[param_name];
+            let operand_ident = type_name.as_pascal_ident();
+            let param_ident = param_name.as_ident();
+            quote!(operands.push(Operand::#operand_ident(*#param_ident));)
+        });
+        let cfg = self.cfg_attribute_tokens(isa);
+        Some(quote! {
+            #cfg
+            Ins::#variant_ident { #(#param_names),* } => {
+                #(#pushes)*
+            }
+        })
+    }
+}
+
+fn collect_params(format: &Format, names: &mut Vec<String>) {
+    match format {
+        Format::If(if_format) => {
+            collect_params(if_format.if_true(), names);
+            collect_params(if_format.if_false(), names);
+        }
+        Format::Match(match_format) => {
+            for format in match_format.cases().values() {
+                collect_params(format, names);
+            }
+        }
+        Format::Fragments(fragments_format) => {
+            for fragment in fragments_format.fragments() {
+                if let FormatFragment::Param(name) = fragment
+                    && !names.contains(name)
+                {
+                    names.push(name.clone());
+                }
+            }
+        }
+        Format::Sequence(formats) => {
+            for format in formats {
+                collect_params(format, names);
+            }
+        }
+    }
+}
+
+/// Returns the format of `param_name` if it's a boolean flag suffix such as S.
+fn flag_suffix_format(param_name: &str, opcode: &Opcode, isa: &Isa) -> Option<Format> {
+    let type_name = opcode.params().get(&OpcodeParamName(param_name.into()))?;
+    let data_type = isa.types().get(type_name)?;
+    let DataTypeKind::Bool { format, .. } = data_type.kind() else {
+        return None;
+    };
+    Some(format.clone().unwrap_or_else(|| {
+        Format::Fragments(FragmentsFormat::new(vec![FormatFragment::Text(type_name.0.clone())]))
+    }))
+}
+
+/// Returns the variants and formats of `param_name` if it's a suffix with a fixed set of values,
+/// such as the LDM/STM mode or a data type. The condition code is not a suffix.
+fn enum_suffix_formats(
+    param_name: &str,
+    opcode: &Opcode,
+    isa: &Isa,
+) -> Option<Vec<(TokenStream, Format)>> {
+    let type_name = opcode.params().get(&OpcodeParamName(param_name.into()))?;
+    if type_name.0 == "cond" {
+        return None;
+    }
+    let data_type = isa.types().get(type_name)?;
+    let variants: Vec<&DataTypeEnumVariant> = match data_type.kind() {
+        DataTypeKind::Enum(data_type_enum) => data_type_enum.variants().iter().collect(),
+        DataTypeKind::Union(data_type_union)
+            if data_type_union.variants().values().all(|variant| variant.data().is_none()) =>
+        {
+            data_type_union.variants().values().collect()
+        }
+        _ => return None,
+    };
+    let type_ident = type_name.as_pascal_ident();
+    Some(
+        variants
+            .into_iter()
+            .map(|variant| {
+                let variant_ident = variant.name().as_pascal_ident();
+                let format = variant.format().cloned().unwrap_or_else(|| {
+                    Format::Fragments(FragmentsFormat::new(vec![FormatFragment::Text(
+                        variant.name().0.clone(),
+                    )]))
+                });
+                (quote!(#type_ident::#variant_ident), format)
+            })
+            .collect(),
+    )
+}
+
+/// Returns an expression evaluating to the text of `formats` without its operands, appended to
+/// `prefix`. Suffixes are included, so `add(s)(cond)` becomes `add` or `adds` and
+/// `vadd(cond).(dt)` becomes `vadd.i8`, `vadd.i16` and so on.
+fn mnemonic_tokens(
+    formats: &[&Format],
+    mut prefix: String,
+    opcode: &Opcode,
+    isa: &Isa,
+) -> TokenStream {
+    let Some((first, rest)) = formats.split_first() else {
+        return quote!(#prefix);
+    };
+    match first {
+        Format::If(if_format) => {
+            let if_true = mnemonic_tokens(
+                &[&[if_format.if_true()], rest].concat(),
+                prefix.clone(),
+                opcode,
+                isa,
+            );
+            let if_false =
+                mnemonic_tokens(&[&[if_format.if_false()], rest].concat(), prefix, opcode, isa);
+            if if_true.to_string() == if_false.to_string() {
+                return if_true;
+            }
+            let condition = if_format.cond().as_tokens(Some(quote!(options)));
+            quote!(if #condition { #if_true } else { #if_false })
+        }
+        Format::Match(match_format) => {
+            let cases = match_format
+                .cases()
+                .iter()
+                .map(|(name, format)| {
+                    (
+                        name,
+                        mnemonic_tokens(&[&[format], rest].concat(), prefix.clone(), opcode, isa),
+                    )
+                })
+                .collect::<Vec<_>>();
+            if cases.windows(2).all(|pair| pair[0].1.to_string() == pair[1].1.to_string())
+                && let Some((_, tokens)) = cases.first()
+            {
+                return tokens.clone();
+            }
+            let value = match_format.value().as_tokens(Some(quote!(options)));
+            let enum_ident =
+                Ident::new(&snake_to_pascal_case(match_format.option()), Span::call_site());
+            let cases = cases.into_iter().map(|(name, tokens)| {
+                let variant_ident = Ident::new(&snake_to_pascal_case(name), Span::call_site());
+                quote!(#enum_ident::#variant_ident => #tokens,)
+            });
+            quote!(match #value { #(#cases)* })
+        }
+        Format::Fragments(fragments_format) => {
+            let fragments = fragments_format.fragments();
+            for (i, fragment) in fragments.iter().enumerate() {
+                match fragment {
+                    FormatFragment::Text(text) => prefix.push_str(text),
+                    FormatFragment::Param(name) => {
+                        let remaining =
+                            Format::Fragments(FragmentsFormat::new(fragments[i + 1..].to_vec()));
+                        let param_ident = Ident::new(name, Span::call_site());
+                        if let Some(suffixes) = enum_suffix_formats(name, opcode, isa) {
+                            let cases = suffixes.into_iter().map(|(pattern, suffix)| {
+                                let tokens = mnemonic_tokens(
+                                    &[&[&suffix, &remaining], rest].concat(),
+                                    prefix.clone(),
+                                    opcode,
+                                    isa,
+                                );
+                                quote!(#pattern => #tokens,)
+                            });
+                            return quote!(match #param_ident { #(#cases)* });
+                        }
+                        let Some(suffix) = flag_suffix_format(name, opcode, isa) else {
+                            continue;
+                        };
+                        let with_suffix = mnemonic_tokens(
+                            &[&[&suffix, &remaining], rest].concat(),
+                            prefix.clone(),
+                            opcode,
+                            isa,
+                        );
+                        let without_suffix =
+                            mnemonic_tokens(&[&[&remaining], rest].concat(), prefix, opcode, isa);
+                        return quote!(if *#param_ident { #with_suffix } else { #without_suffix });
+                    }
+                    _ => {}
+                }
+            }
+            mnemonic_tokens(rest, prefix, opcode, isa)
+        }
+        Format::Sequence(sequence) => {
+            let formats = sequence.iter().chain(rest.iter().copied()).collect::<Vec<_>>();
+            mnemonic_tokens(&formats, prefix, opcode, isa)
+        }
+    }
+}
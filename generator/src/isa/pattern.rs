@@ -14,6 +14,8 @@ use crate::util::hex_literal::HexLiteral;
 pub struct Pattern {
     pattern: u32,
     bitmask: u32,
+    /// Don't care bits which are set when encoding, written as `(1)` like in the ARM manual
+    should_be_one: u32,
     /// Number of bits in the pattern (including don't care bits)
     size: u32,
 }
@@ -27,6 +29,10 @@ impl Pattern {
         self.bitmask
     }
 
+    pub fn should_be_one(&self) -> u32 {
+        self.should_be_one
+    }
+
     pub fn num_bits(&self) -> u32 {
         self.bitmask.count_ones()
     }
@@ -36,6 +42,9 @@ impl Pattern {
     }
 
     pub fn condition_tokens(&self, value: TokenStream) -> TokenStream {
+        if self.bitmask() == 0 {
+            return quote!(true);
+        }
         let bitmask = HexLiteral(self.bitmask());
         let pattern = HexLiteral(self.pattern());
         quote!((#value & #bitmask) == #pattern)
@@ -48,11 +57,13 @@ impl FromStr for Pattern {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut pattern = 0;
         let mut bitmask = 0;
+        let mut should_be_one = 0;
         let mut size = 0;
         let mut chars = s.chars().peekable();
         while chars.peek().is_some() {
             pattern <<= 1;
             bitmask <<= 1;
+            should_be_one <<= 1;
             size += 1;
             for c in chars.by_ref() {
                 match c {
@@ -64,6 +75,11 @@ impl FromStr for Pattern {
                         bitmask |= 1;
                     }
                     'x' => {}
+                    '(' => match (chars.next(), chars.next()) {
+                        (Some('1'), Some(')')) => should_be_one |= 1,
+                        (Some('0'), Some(')')) => {}
+                        _ => bail!("Invalid should-be bit in '{s}'"),
+                    },
                     '_' => continue,
                     _ => bail!("Invalid character '{s}'"),
                 }
@@ -71,7 +87,7 @@ impl FromStr for Pattern {
             }
         }
 
-        Ok(Self { pattern, bitmask, size })
+        Ok(Self { pattern, bitmask, should_be_one, size })
     }
 }
 
@@ -118,6 +134,7 @@ impl OpcodePattern {
             Pattern {
                 pattern: (second.pattern() << 16) | self.first.pattern(),
                 bitmask: (second.bitmask() << 16) | self.first.bitmask(),
+                should_be_one: (second.should_be_one() << 16) | self.first.should_be_one(),
                 size: self.first.size() + second.size(),
             }
         } else {
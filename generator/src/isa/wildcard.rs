@@ -0,0 +1,272 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{Ident, visit_mut::VisitMut};
+
+use crate::{
+    isa::{
+        Arch, BitRange, DataExpr, DataType, DataTypeKind, Isa, Opcode, OpcodeEncoding,
+        OpcodeParamValue, Opcodes,
+    },
+    util::{hex_literal::HexLiteral, str::snake_to_pascal_case},
+};
+
+impl Opcodes {
+    pub fn param_masks_fn_tokens(&self, isa: &Isa, arch: Arch) -> TokenStream {
+        let cases = self.iter().filter_map(|opcode| opcode.param_masks_case_tokens(isa, arch));
+
+        let fn_name = format!("{arch}_param_masks");
+        let fn_ident = Ident::new(&fn_name, Span::call_site());
+
+        let cfg = match arch {
+            Arch::Arm => quote!(#[cfg(feature = "arm")]),
+            Arch::Thumb => quote!(#[cfg(feature = "thumb")]),
+        };
+
+        quote! {
+            /// Returns the parameter bit masks of the encoding which parses `ins` as `opcode`.
+            #cfg
+            pub(crate) fn #fn_ident(
+                ins: u32,
+                opcode: Opcode,
+                options: &Options,
+            ) -> Option<crate::ins::ParamMasks> {
+                match opcode {
+                    #(#cases)*
+                    _ => {}
+                }
+                None
+            }
+        }
+    }
+}
+
+impl Opcode {
+    fn param_masks_case_tokens(&self, isa: &Isa, arch: Arch) -> Option<TokenStream> {
+        let encodings = self.sorted_encodings(arch);
+        if encodings.is_empty() {
+            return None;
+        }
+        let encoding_ifs = encodings.iter().map(|(index, encoding)| {
+            let condition = encoding.pattern().condition_tokens(quote!(ins));
+            let parse_fn_ident = self.parse_fn_ident(arch, *index);
+            let (address, immediate) = encoding.param_masks(isa, self);
+            let address = HexLiteral(address);
+            let immediate = HexLiteral(immediate);
+            let cfg = encoding.cfg_attribute_tokens(isa, arch);
+            quote! {
+                #cfg
+                if #condition && #parse_fn_ident(ins, 0, options).is_some() {
+                    return Some(crate::ins::ParamMasks {
+                        address: #address,
+                        immediate: #immediate,
+                    });
+                }
+            }
+        });
+        let variant_ident = Ident::new(&snake_to_pascal_case(self.mnemonic()), Span::call_site());
+        let cfg = self.cfg_attribute_tokens(isa);
+        Some(quote! {
+            #cfg
+            Opcode::#variant_ident => { #(#encoding_ifs)* }
+        })
+    }
+}
+
+impl OpcodeEncoding {
+    /// Returns the bits of this encoding which are parsed into values computed from the PC, such
+    /// as branch targets, and the bits which are parsed into immediates.
+    fn param_masks(&self, isa: &Isa, opcode: &Opcode) -> (u32, u32) {
+        let mut masks = Masks::default();
+        for (param_name, type_name) in opcode.params() {
+            let Some(value) = self.get_param(param_name) else {
+                continue;
+            };
+            let data_type = isa.types().get(type_name).unwrap();
+            masks.add(value.masks(isa, data_type));
+        }
+        let variable = !self.pattern().combined().bitmask();
+        (masks.address & variable, masks.immediate & variable)
+    }
+}
+
+impl OpcodeParamValue {
+    fn sources(&self) -> Option<BitSources> {
+        match self {
+            OpcodeParamValue::Bits(bit_range) => Some(BitSources::code().range(bit_range)),
+            OpcodeParamValue::Const(_) => Some(BitSources::default()),
+            OpcodeParamValue::Expr(data_expr) => Some(data_expr.sources(&BitSources::code())),
+            OpcodeParamValue::Enum(_, _) | OpcodeParamValue::Struct(_) => None,
+        }
+    }
+
+    fn masks(&self, isa: &Isa, data_type: &DataType) -> Masks {
+        match self {
+            OpcodeParamValue::Bits(_) | OpcodeParamValue::Const(_) | OpcodeParamValue::Expr(_) => {
+                data_type.masks(isa, self.sources(), &BitSources::code())
+            }
+            OpcodeParamValue::Enum(variant, value) => {
+                let inner_type = data_type.canonical(isa);
+                let variant = match inner_type.kind() {
+                    DataTypeKind::Enum(data_type_enum) => {
+                        data_type_enum.get_variant(variant).unwrap()
+                    }
+                    DataTypeKind::Union(data_type_union) => {
+                        data_type_union.get_variant(variant).unwrap().1
+                    }
+                    _ => panic!("Data type '{}' is not an enum", data_type.name().0),
+                };
+                let Some(data) = variant.data() else {
+                    return Masks::default();
+                };
+                match value.as_ref() {
+                    OpcodeParamValue::Struct(_) => value.masks(isa, data.canonical(isa)),
+                    value => data.masks(isa, value.sources(), &BitSources::code()),
+                }
+            }
+            OpcodeParamValue::Struct(params) => {
+                let DataTypeKind::Struct(data_type_struct) = data_type.kind() else {
+                    panic!("Data type '{}' is not a struct", data_type.name().0);
+                };
+                let mut masks = Masks::default();
+                for field in data_type_struct.fields() {
+                    if let Some(value) = params.get(&field.name().0) {
+                        masks.add(value.masks(isa, field));
+                    }
+                }
+                masks
+            }
+        }
+    }
+}
+
+impl DataType {
+    /// Returns the masks of a value of this type parsed from `value`, or from `scope` (the value
+    /// of the enclosing type) if no value is given. This follows [`DataType::parse_expr_tokens`].
+    fn masks(&self, isa: &Isa, value: Option<BitSources>, scope: &BitSources) -> Masks {
+        match self.kind() {
+            DataTypeKind::Bool { .. } | DataTypeKind::Enum(_) | DataTypeKind::Custom(_) => {
+                Masks::default()
+            }
+            DataTypeKind::UInt(data_expr) | DataTypeKind::Int(data_expr) => {
+                let (sources, uses_pc) = match value {
+                    Some(value) => (value, false),
+                    None => (data_expr.sources(scope), data_expr.uses_pc()),
+                };
+                let mask = sources.mask();
+                Masks { address: if uses_pc { mask } else { 0 }, immediate: mask }
+            }
+            DataTypeKind::Union(data_type_union) => {
+                let value = value.unwrap_or_else(|| scope.range(data_type_union.bits()));
+                let mut masks = Masks::default();
+                for variant in data_type_union.variants().values() {
+                    if let Some(data) = variant.data() {
+                        masks.add(data.masks(isa, None, &value));
+                    }
+                }
+                masks
+            }
+            DataTypeKind::Struct(data_type_struct) => {
+                let value = value.unwrap_or(*scope);
+                let mut masks = Masks::default();
+                for field in data_type_struct.fields() {
+                    masks.add(field.masks(isa, None, &value));
+                }
+                masks
+            }
+            DataTypeKind::Type(data_type_name, data_expr) => {
+                let inner_type = isa.types().get(data_type_name).unwrap();
+                let value = value.unwrap_or_else(|| data_expr.sources(scope));
+                inner_type.masks(isa, Some(value), scope)
+            }
+        }
+    }
+}
+
+impl DataExpr {
+    /// Returns the sources of each bit of this expression. Only plain `bits`/`bit` calls keep
+    /// their bit positions, other expressions depend on all bits they read.
+    fn sources(&self, input: &BitSources) -> BitSources {
+        if let syn::Expr::Call(call) = self.expr() {
+            match call.func.to_token_stream().to_string().as_str() {
+                "bits" | "bit" => return input.range(&call_bit_range(call)),
+                "ins" => return *input,
+                _ => {}
+            }
+        }
+        let mut collect = CollectBits { input, mask: 0, uses_pc: false };
+        collect.visit_expr_mut(&mut self.expr().clone());
+        BitSources::broadcast(collect.mask)
+    }
+
+    fn uses_pc(&self) -> bool {
+        let mut collect = CollectBits { input: &BitSources::default(), mask: 0, uses_pc: false };
+        collect.visit_expr_mut(&mut self.expr().clone());
+        collect.uses_pc
+    }
+}
+
+fn call_bit_range(call: &syn::ExprCall) -> BitRange {
+    let arg = call.args[0].to_token_stream().to_string().replace(' ', "");
+    arg.parse().unwrap()
+}
+
+struct CollectBits<'a> {
+    input: &'a BitSources,
+    mask: u32,
+    uses_pc: bool,
+}
+
+impl VisitMut for CollectBits<'_> {
+    fn visit_expr_mut(&mut self, node: &mut syn::Expr) {
+        match node {
+            syn::Expr::Call(call) => match call.func.to_token_stream().to_string().as_str() {
+                "bits" | "bit" => self.mask |= self.input.range(&call_bit_range(call)).mask(),
+                "ins" => self.mask |= self.input.mask(),
+                _ => {}
+            },
+            syn::Expr::Path(path) if path.path.is_ident("pc") => self.uses_pc = true,
+            _ => {}
+        }
+        syn::visit_mut::visit_expr_mut(self, node);
+    }
+}
+
+/// Bits of the encoding which each bit of a value is parsed from
+#[derive(Clone, Copy, Default)]
+struct BitSources([u32; 32]);
+
+impl BitSources {
+    /// The encoding itself
+    fn code() -> Self {
+        Self(core::array::from_fn(|i| 1 << i))
+    }
+
+    fn broadcast(mask: u32) -> Self {
+        Self([mask; 32])
+    }
+
+    fn range(&self, bit_range: &BitRange) -> Self {
+        let mut sources = Self::default();
+        for (i, bit) in bit_range.0.clone().enumerate() {
+            sources.0[i] = self.0[bit as usize];
+        }
+        sources
+    }
+
+    fn mask(&self) -> u32 {
+        self.0.iter().fold(0, |mask, bits| mask | bits)
+    }
+}
+
+#[derive(Default)]
+struct Masks {
+    address: u32,
+    immediate: u32,
+}
+
+impl Masks {
+    fn add(&mut self, other: Masks) {
+        self.address |= other.address;
+        self.immediate |= other.immediate;
+    }
+}
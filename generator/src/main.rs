@@ -22,6 +22,18 @@ fn main() -> anyhow::Result<()> {
     generate_file("disasm/src/generated/display.rs", isa.generate_display())?;
     // println!("{}", isa.generate_defs_uses());
     generate_file("disasm/src/generated/defs_uses.rs", isa.generate_defs_uses())?;
+    // println!("{}", isa.generate_operands());
+    generate_file("disasm/src/generated/operands.rs", isa.generate_operands())?;
+    // println!("{}", isa.generate_opcode());
+    generate_file("disasm/src/generated/opcode.rs", isa.generate_opcode())?;
+    // println!("{}", isa.generate_flow());
+    generate_file("disasm/src/generated/flow.rs", isa.generate_flow())?;
+    // println!("{}", isa.generate_access());
+    generate_file("disasm/src/generated/access.rs", isa.generate_access())?;
+    // println!("{}", isa.generate_encoder());
+    generate_file("disasm/src/generated/encode.rs", isa.generate_encoder())?;
+    // println!("{}", isa.generate_assembler());
+    generate_file("disasm/src/generated/asm.rs", isa.generate_assembler())?;
 
     Ok(())
 }